@@ -22,11 +22,19 @@ export function addressPluginAuthority(address: PublicKey) {
   return pluginAuthority('Address', { address });
 }
 
+export function addressWithExpiryPluginAuthority(
+  address: PublicKey,
+  expiresAt: number | bigint
+) {
+  return pluginAuthority('AddressWithExpiry', { address, expiresAt });
+}
+
 export function mapPluginAuthority(
   authority: PluginAuthority
 ): BasePluginAuthority {
   return {
     type: authority.__kind,
     address: (authority as any).address,
+    expiresAt: (authority as any).expiresAt,
   };
 }
@@ -433,6 +433,321 @@ export class InvalidLogWrapperProgramError extends ProgramError {
 codeToErrorMap.set(0x1e, InvalidLogWrapperProgramError);
 nameToErrorMap.set('InvalidLogWrapperProgram', InvalidLogWrapperProgramError);
 
+/** InvalidDependentAccount: Dependent account is not held by the asset signer */
+export class InvalidDependentAccountError extends ProgramError {
+  override readonly name: string = 'InvalidDependentAccount';
+
+  readonly code: number = 0x1f; // 31
+
+  constructor(program: Program, cause?: Error) {
+    super('Dependent account is not held by the asset signer', program, cause);
+  }
+}
+codeToErrorMap.set(0x1f, InvalidDependentAccountError);
+nameToErrorMap.set('InvalidDependentAccount', InvalidDependentAccountError);
+
+/** InvalidOwner: Asset is not owned by the expected owner */
+export class InvalidOwnerError extends ProgramError {
+  override readonly name: string = 'InvalidOwner';
+
+  readonly code: number = 0x20; // 32
+
+  constructor(program: Program, cause?: Error) {
+    super('Asset is not owned by the expected owner', program, cause);
+  }
+}
+codeToErrorMap.set(0x20, InvalidOwnerError);
+nameToErrorMap.set('InvalidOwner', InvalidOwnerError);
+
+/** CannotUnbind: Asset cannot be unbound early */
+export class CannotUnbindError extends ProgramError {
+  override readonly name: string = 'CannotUnbind';
+
+  readonly code: number = 0x21; // 33
+
+  constructor(program: Program, cause?: Error) {
+    super('Asset cannot be unbound early', program, cause);
+  }
+}
+codeToErrorMap.set(0x21, CannotUnbindError);
+nameToErrorMap.set('CannotUnbind', CannotUnbindError);
+
+/** MissingTransferFeeRecipient: Transfer fee recipient is missing */
+export class MissingTransferFeeRecipientError extends ProgramError {
+  override readonly name: string = 'MissingTransferFeeRecipient';
+
+  readonly code: number = 0x22; // 34
+
+  constructor(program: Program, cause?: Error) {
+    super('Transfer fee recipient is missing', program, cause);
+  }
+}
+codeToErrorMap.set(0x22, MissingTransferFeeRecipientError);
+nameToErrorMap.set(
+  'MissingTransferFeeRecipient',
+  MissingTransferFeeRecipientError
+);
+
+/** MissingBurnRentDestination: Burn rent destination is missing */
+export class MissingBurnRentDestinationError extends ProgramError {
+  override readonly name: string = 'MissingBurnRentDestination';
+
+  readonly code: number = 0x23; // 35
+
+  constructor(program: Program, cause?: Error) {
+    super('Burn rent destination is missing', program, cause);
+  }
+}
+codeToErrorMap.set(0x23, MissingBurnRentDestinationError);
+nameToErrorMap.set(
+  'MissingBurnRentDestination',
+  MissingBurnRentDestinationError
+);
+
+/** MissingInstructionsSysvar: Missing instructions sysvar */
+export class MissingInstructionsSysvarError extends ProgramError {
+  override readonly name: string = 'MissingInstructionsSysvar';
+
+  readonly code: number = 0x24; // 36
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing instructions sysvar', program, cause);
+  }
+}
+codeToErrorMap.set(0x24, MissingInstructionsSysvarError);
+nameToErrorMap.set('MissingInstructionsSysvar', MissingInstructionsSysvarError);
+
+/** BlockedProgram: Transaction contains an instruction from a blocked program */
+export class BlockedProgramError extends ProgramError {
+  override readonly name: string = 'BlockedProgram';
+
+  readonly code: number = 0x25; // 37
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Transaction contains an instruction from a blocked program',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x25, BlockedProgramError);
+nameToErrorMap.set('BlockedProgram', BlockedProgramError);
+
+/** LinkedPairNotTransferred: Linked partner asset is not transferred to the same owner */
+export class LinkedPairNotTransferredError extends ProgramError {
+  override readonly name: string = 'LinkedPairNotTransferred';
+
+  readonly code: number = 0x26; // 38
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Linked partner asset is not transferred to the same owner',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x26, LinkedPairNotTransferredError);
+nameToErrorMap.set('LinkedPairNotTransferred', LinkedPairNotTransferredError);
+
+/** MissingHookedProgram: Hooked program of a lifecycle hook is missing */
+export class MissingHookedProgramError extends ProgramError {
+  override readonly name: string = 'MissingHookedProgram';
+
+  readonly code: number = 0x27; // 39
+
+  constructor(program: Program, cause?: Error) {
+    super('Hooked program of a lifecycle hook is missing', program, cause);
+  }
+}
+codeToErrorMap.set(0x27, MissingHookedProgramError);
+nameToErrorMap.set('MissingHookedProgram', MissingHookedProgramError);
+
+/** InvalidProofCache: Invalid proof cache */
+export class InvalidProofCacheError extends ProgramError {
+  override readonly name: string = 'InvalidProofCache';
+
+  readonly code: number = 0x28; // 40
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid proof cache', program, cause);
+  }
+}
+codeToErrorMap.set(0x28, InvalidProofCacheError);
+nameToErrorMap.set('InvalidProofCache', InvalidProofCacheError);
+
+/** StaleProofCache: Proof cache is stale */
+export class StaleProofCacheError extends ProgramError {
+  override readonly name: string = 'StaleProofCache';
+
+  readonly code: number = 0x29; // 41
+
+  constructor(program: Program, cause?: Error) {
+    super('Proof cache is stale', program, cause);
+  }
+}
+codeToErrorMap.set(0x29, StaleProofCacheError);
+nameToErrorMap.set('StaleProofCache', StaleProofCacheError);
+
+/** MissingListingSeller: Seller of a listed asset is missing */
+export class MissingListingSellerError extends ProgramError {
+  override readonly name: string = 'MissingListingSeller';
+
+  readonly code: number = 0x2a; // 42
+
+  constructor(program: Program, cause?: Error) {
+    super('Seller of a listed asset is missing', program, cause);
+  }
+}
+codeToErrorMap.set(0x2a, MissingListingSellerError);
+nameToErrorMap.set('MissingListingSeller', MissingListingSellerError);
+
+/** InvalidDataOffset: Data offset is outside of the data section */
+export class InvalidDataOffsetError extends ProgramError {
+  override readonly name: string = 'InvalidDataOffset';
+
+  readonly code: number = 0x2b; // 43
+
+  constructor(program: Program, cause?: Error) {
+    super('Data offset is outside of the data section', program, cause);
+  }
+}
+codeToErrorMap.set(0x2b, InvalidDataOffsetError);
+nameToErrorMap.set('InvalidDataOffset', InvalidDataOffsetError);
+
+/** InvalidTokenLinkEscrow: Linked token escrow is missing or does not back the asset */
+export class InvalidTokenLinkEscrowError extends ProgramError {
+  override readonly name: string = 'InvalidTokenLinkEscrow';
+
+  readonly code: number = 0x2c; // 44
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Linked token escrow is missing or does not back the asset',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x2c, InvalidTokenLinkEscrowError);
+nameToErrorMap.set('InvalidTokenLinkEscrow', InvalidTokenLinkEscrowError);
+
+/** FractionsOutstanding: Fractions of the asset are still outstanding */
+export class FractionsOutstandingError extends ProgramError {
+  override readonly name: string = 'FractionsOutstanding';
+
+  readonly code: number = 0x2d; // 45
+
+  constructor(program: Program, cause?: Error) {
+    super('Fractions of the asset are still outstanding', program, cause);
+  }
+}
+codeToErrorMap.set(0x2d, FractionsOutstandingError);
+nameToErrorMap.set('FractionsOutstanding', FractionsOutstandingError);
+
+/** MintLimitReached: Maximum supply of the collection has been reached */
+export class MintLimitReachedError extends ProgramError {
+  override readonly name: string = 'MintLimitReached';
+
+  readonly code: number = 0x2e; // 46
+
+  constructor(program: Program, cause?: Error) {
+    super('Maximum supply of the collection has been reached', program, cause);
+  }
+}
+codeToErrorMap.set(0x2e, MintLimitReachedError);
+nameToErrorMap.set('MintLimitReached', MintLimitReachedError);
+
+/** CollectionNotEmpty: Collection still has assets */
+export class CollectionNotEmptyError extends ProgramError {
+  override readonly name: string = 'CollectionNotEmpty';
+
+  readonly code: number = 0x2f; // 47
+
+  constructor(program: Program, cause?: Error) {
+    super('Collection still has assets', program, cause);
+  }
+}
+codeToErrorMap.set(0x2f, CollectionNotEmptyError);
+nameToErrorMap.set('CollectionNotEmpty', CollectionNotEmptyError);
+
+/** InvalidFeeConfig: Invalid fee config account */
+export class InvalidFeeConfigError extends ProgramError {
+  override readonly name: string = 'InvalidFeeConfig';
+
+  readonly code: number = 0x30; // 48
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid fee config account', program, cause);
+  }
+}
+codeToErrorMap.set(0x30, InvalidFeeConfigError);
+nameToErrorMap.set('InvalidFeeConfig', InvalidFeeConfigError);
+
+/** InvalidTokenMetadata: Invalid Token Metadata account */
+export class InvalidTokenMetadataError extends ProgramError {
+  override readonly name: string = 'InvalidTokenMetadata';
+
+  readonly code: number = 0x31; // 49
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid Token Metadata account', program, cause);
+  }
+}
+codeToErrorMap.set(0x31, InvalidTokenMetadataError);
+nameToErrorMap.set('InvalidTokenMetadata', InvalidTokenMetadataError);
+
+/** AccountGrowthLimitExceeded: Account cannot grow by more than 10KB in one instruction */
+export class AccountGrowthLimitExceededError extends ProgramError {
+  override readonly name: string = 'AccountGrowthLimitExceeded';
+
+  readonly code: number = 0x32; // 50
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Account cannot grow by more than 10KB in one instruction',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x32, AccountGrowthLimitExceededError);
+nameToErrorMap.set(
+  'AccountGrowthLimitExceeded',
+  AccountGrowthLimitExceededError
+);
+
+/** PluginRejected: A plugin rejected the operation */
+export class PluginRejectedError extends ProgramError {
+  override readonly name: string = 'PluginRejected';
+
+  readonly code: number = 0x33; // 51
+
+  constructor(program: Program, cause?: Error) {
+    super('A plugin rejected the operation', program, cause);
+  }
+}
+codeToErrorMap.set(0x33, PluginRejectedError);
+nameToErrorMap.set('PluginRejected', PluginRejectedError);
+
+/** IncorrectAccountKey: Account key does not match the expected account type */
+export class IncorrectAccountKeyError extends ProgramError {
+  override readonly name: string = 'IncorrectAccountKey';
+
+  readonly code: number = 0x34; // 52
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Account key does not match the expected account type',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x34, IncorrectAccountKeyError);
+nameToErrorMap.set('IncorrectAccountKey', IncorrectAccountKeyError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
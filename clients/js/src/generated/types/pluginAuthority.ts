@@ -12,6 +12,7 @@ import {
   GetDataEnumKindContent,
   Serializer,
   dataEnum,
+  i64,
   publicKey as publicKeySerializer,
   struct,
   unit,
@@ -21,9 +22,19 @@ export type PluginAuthority =
   | { __kind: 'None' }
   | { __kind: 'Owner' }
   | { __kind: 'UpdateAuthority' }
-  | { __kind: 'Address'; address: PublicKey };
+  | { __kind: 'Address'; address: PublicKey }
+  | { __kind: 'AddressWithExpiry'; address: PublicKey; expiresAt: bigint };
 
-export type PluginAuthorityArgs = PluginAuthority;
+export type PluginAuthorityArgs =
+  | { __kind: 'None' }
+  | { __kind: 'Owner' }
+  | { __kind: 'UpdateAuthority' }
+  | { __kind: 'Address'; address: PublicKey }
+  | {
+      __kind: 'AddressWithExpiry';
+      address: PublicKey;
+      expiresAt: number | bigint;
+    };
 
 export function getPluginAuthoritySerializer(): Serializer<
   PluginAuthorityArgs,
@@ -40,6 +51,13 @@ export function getPluginAuthoritySerializer(): Serializer<
           ['address', publicKeySerializer()],
         ]),
       ],
+      [
+        'AddressWithExpiry',
+        struct<GetDataEnumKindContent<PluginAuthority, 'AddressWithExpiry'>>([
+          ['address', publicKeySerializer()],
+          ['expiresAt', i64()],
+        ]),
+      ],
     ],
     { description: 'PluginAuthority' }
   ) as Serializer<PluginAuthorityArgs, PluginAuthority>;
@@ -59,6 +77,10 @@ export function pluginAuthority(
   kind: 'Address',
   data: GetDataEnumKindContent<PluginAuthorityArgs, 'Address'>
 ): GetDataEnumKind<PluginAuthorityArgs, 'Address'>;
+export function pluginAuthority(
+  kind: 'AddressWithExpiry',
+  data: GetDataEnumKindContent<PluginAuthorityArgs, 'AddressWithExpiry'>
+): GetDataEnumKind<PluginAuthorityArgs, 'AddressWithExpiry'>;
 export function pluginAuthority<K extends PluginAuthorityArgs['__kind']>(
   kind: K,
   data?: any
@@ -20,6 +20,7 @@ import {
 export type BasePluginAuthority = {
   type: PluginAuthorityType;
   address?: PublicKey;
+  expiresAt?: bigint;
 };
 
 export type BaseUpdateAuthority = {
@@ -98,7 +98,7 @@ test('it cannot burn an asset if it is frozen', async (t) => {
     asset: asset.publicKey,
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+  await t.throwsAsync(result, { name: 'PluginRejected' });
   await assertAsset(t, umi, {
     ...DEFAULT_ASSET,
     asset: asset.publicKey,
@@ -172,7 +172,7 @@ test('it cannot burn an asset if collection permanently frozen', async (t) => {
     collection: collection.publicKey,
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+  await t.throwsAsync(result, { name: 'PluginRejected' });
   await assertAsset(t, umi, {
     ...DEFAULT_ASSET,
     asset: asset.publicKey,
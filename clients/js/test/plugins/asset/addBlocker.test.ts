@@ -32,7 +32,7 @@ test('it cannot add UA-managed plugin if addBlocker had been added on creation',
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, {
-    name: 'InvalidAuthority',
+    name: 'PluginRejected',
   });
 });
 
@@ -85,7 +85,7 @@ test('it can add plugins unless AddBlocker is added', async (t) => {
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, {
-    name: 'InvalidAuthority',
+    name: 'PluginRejected',
   });
 });
 
@@ -99,7 +99,7 @@ test('it cannot remove edition plugin', async (t) => {
     pluginType: PluginType.Edition,
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(res, { name: 'InvalidAuthority' });
+  await t.throwsAsync(res, { name: 'PluginRejected' });
 
   await assertAsset(t, umi, {
     ...DEFAULT_ASSET,
@@ -272,7 +272,7 @@ test('it can make edition plugin immutable', async (t) => {
     pluginType: PluginType.Edition,
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(revokeRes, { name: 'InvalidAuthority' });
+  await t.throwsAsync(revokeRes, { name: 'PluginRejected' });
 
   const res = updatePluginV1(umi, {
     asset: asset.publicKey,
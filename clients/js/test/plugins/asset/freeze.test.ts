@@ -120,7 +120,7 @@ test('owner cannot undelegate a freeze plugin with a delegate', async (t) => {
     pluginType: PluginType.FreezeDelegate,
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+  await t.throwsAsync(result, { name: 'PluginRejected' });
 });
 
 test('owner cannot approve to reassign authority back to owner if frozen', async (t) => {
@@ -211,7 +211,7 @@ test('it cannot remove freeze plugin if update authority and frozen', async (t)
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, {
-    name: 'InvalidAuthority',
+    name: 'PluginRejected',
   });
 
   await assertAsset(t, umi, {
@@ -102,7 +102,7 @@ test('it cannot be transferred while frozen', async (t) => {
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, {
-    name: 'InvalidAuthority',
+    name: 'PluginRejected',
   });
 
   await assertAsset(t, umi, {
@@ -178,7 +178,7 @@ test('it cannot move asset in a permanently frozen collection', async (t) => {
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, {
-    name: 'InvalidAuthority',
+    name: 'PluginRejected',
   });
 
   await assertAsset(t, umi, {
@@ -298,7 +298,7 @@ test('it cannot remove permanent freeze plugin if update authority and frozen',
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, {
-    name: 'InvalidAuthority',
+    name: 'PluginRejected',
   });
 
   await assertAsset(t, umi, {
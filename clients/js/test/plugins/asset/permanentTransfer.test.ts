@@ -535,7 +535,7 @@ test('it cannot transfer asset that is frozen with permanent transfer by owner',
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, {
-    name: 'InvalidAuthority',
+    name: 'PluginRejected',
   });
 
   await assertAsset(t, umi, {
@@ -281,7 +281,7 @@ test('it cannot transfer an asset with royalties to a program address not on the
     newOwner: programOwned.publicKey,
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+  await t.throwsAsync(result, { name: 'PluginRejected' });
 });
 
 test('it cannot transfer an asset with collection royalties to a program address not on allowlist', async (t) => {
@@ -338,7 +338,7 @@ test('it cannot transfer an asset with collection royalties to a program address
     authority: programOwner,
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+  await t.throwsAsync(result, { name: 'PluginRejected' });
 
   await assertAsset(t, umi, {
     asset: asset.publicKey,
@@ -483,7 +483,7 @@ test('it cannot transfer an asset with royalties to a denylisted program', async
     newOwner: programOwned.publicKey,
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+  await t.throwsAsync(result, { name: 'PluginRejected' });
 });
 
 test('it cannot transfer an asset with collection royalties to a program address on the denylist', async (t) => {
@@ -536,7 +536,7 @@ test('it cannot transfer an asset with collection royalties to a program address
     newOwner: programOwned.publicKey,
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+  await t.throwsAsync(result, { name: 'PluginRejected' });
 
   await assertAsset(t, umi, {
     asset: asset.publicKey,
@@ -57,7 +57,7 @@ test('it cannot add UA-managed plugin to a collection if addBlocker had been add
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, {
-    name: 'InvalidAuthority',
+    name: 'PluginRejected',
   });
 });
 
@@ -83,7 +83,7 @@ test('it cannot add UA-managed plugin to an asset in a collection if addBlocker
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, {
-    name: 'InvalidAuthority',
+    name: 'PluginRejected',
   });
 });
 
@@ -112,7 +112,7 @@ test('it prevents plugins from being added to both collection and plugins when c
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, {
-    name: 'InvalidAuthority',
+    name: 'PluginRejected',
   });
 
   result = addPluginV1(umi, {
@@ -124,7 +124,7 @@ test('it prevents plugins from being added to both collection and plugins when c
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, {
-    name: 'InvalidAuthority',
+    name: 'PluginRejected',
   });
 });
 
@@ -148,7 +148,7 @@ test('it prevents plugins from being added to both collection and plugins when A
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, {
-    name: 'InvalidAuthority',
+    name: 'PluginRejected',
   });
 
   result = addPluginV1(umi, {
@@ -160,6 +160,6 @@ test('it prevents plugins from being added to both collection and plugins when A
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, {
-    name: 'InvalidAuthority',
+    name: 'PluginRejected',
   });
 });
@@ -62,7 +62,7 @@ test('it can prevent collection assets metadata from being updated', async (t) =
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, {
-    name: 'InvalidAuthority',
+    name: 'PluginRejected',
   });
 });
 
@@ -141,7 +141,7 @@ test('it prevents both collection and asset from their meta updating when Immuta
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, {
-    name: 'InvalidAuthority',
+    name: 'PluginRejected',
   });
 
   result = updateCollectionV1(umi, {
@@ -152,6 +152,6 @@ test('it prevents both collection and asset from their meta updating when Immuta
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, {
-    name: 'InvalidAuthority',
+    name: 'PluginRejected',
   });
 });
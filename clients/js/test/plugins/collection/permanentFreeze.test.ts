@@ -103,7 +103,7 @@ test('it cannot remove permanentFreezeDelegate from collection when frozen', asy
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, {
-    name: 'InvalidAuthority',
+    name: 'PluginRejected',
   });
 
   await assertCollection(t, umi, {
@@ -470,7 +470,7 @@ test('it cannot remove a plugin from a frozen asset', async (t) => {
     pluginType: PluginType.BurnDelegate,
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(result1, { name: 'InvalidAuthority' });
+  await t.throwsAsync(result1, { name: 'PluginRejected' });
 
   await assertAsset(t, umi, {
     ...DEFAULT_ASSET,
@@ -545,7 +545,7 @@ test('it cannot remove a plugin from an asset with a frozen collection', async (
     collection: collection.publicKey,
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+  await t.throwsAsync(result, { name: 'PluginRejected' });
 });
 
 test('it cannot use an invalid system program for assets', async (t) => {
@@ -791,7 +791,7 @@ test('it cannot remove an authority managed plugin when the authority is None',
     pluginType: PluginType.Attributes,
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+  await t.throwsAsync(result, { name: 'PluginRejected' });
 });
 
 test('it cannot remove an owner managed plugin when the authority is None', async (t) => {
@@ -825,7 +825,7 @@ test('it cannot remove an owner managed plugin when the authority is None', asyn
     pluginType: PluginType.TransferDelegate,
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+  await t.throwsAsync(result, { name: 'PluginRejected' });
 });
 
 test('it can remove an owner managed plugin from an asset when the authority is None, after transferring', async (t) => {
@@ -912,5 +912,5 @@ test('it cannot remove an authority managed plugin from an asset when the author
     pluginType: PluginType.PermanentTransferDelegate,
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+  await t.throwsAsync(result, { name: 'PluginRejected' });
 });
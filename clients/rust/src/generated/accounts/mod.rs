@@ -7,12 +7,18 @@
 
 pub(crate) mod r#base_asset_v1;
 pub(crate) mod r#base_collection_v1;
+pub(crate) mod r#fee_config_v1;
 pub(crate) mod r#hashed_asset_v1;
+pub(crate) mod r#linked_data_section_v1;
 pub(crate) mod r#plugin_header_v1;
 pub(crate) mod r#plugin_registry_v1;
+pub(crate) mod r#proof_cache_v1;
 
 pub use self::r#base_asset_v1::*;
 pub use self::r#base_collection_v1::*;
+pub use self::r#fee_config_v1::*;
 pub use self::r#hashed_asset_v1::*;
+pub use self::r#linked_data_section_v1::*;
 pub use self::r#plugin_header_v1::*;
 pub use self::r#plugin_registry_v1::*;
+pub use self::r#proof_cache_v1::*;
@@ -103,6 +103,72 @@ pub enum MplCoreError {
     /// 30 (0x1E) - Invalid Log Wrapper Program
     #[error("Invalid Log Wrapper Program")]
     InvalidLogWrapperProgram,
+    /// 31 (0x1F) - Dependent account is not held by the asset signer
+    #[error("Dependent account is not held by the asset signer")]
+    InvalidDependentAccount,
+    /// 32 (0x20) - Asset is not owned by the expected owner
+    #[error("Asset is not owned by the expected owner")]
+    InvalidOwner,
+    /// 33 (0x21) - Asset cannot be unbound early
+    #[error("Asset cannot be unbound early")]
+    CannotUnbind,
+    /// 34 (0x22) - Transfer fee recipient is missing
+    #[error("Transfer fee recipient is missing")]
+    MissingTransferFeeRecipient,
+    /// 35 (0x23) - Burn rent destination is missing
+    #[error("Burn rent destination is missing")]
+    MissingBurnRentDestination,
+    /// 36 (0x24) - Missing instructions sysvar
+    #[error("Missing instructions sysvar")]
+    MissingInstructionsSysvar,
+    /// 37 (0x25) - Transaction contains an instruction from a blocked program
+    #[error("Transaction contains an instruction from a blocked program")]
+    BlockedProgram,
+    /// 38 (0x26) - Linked partner asset is not transferred to the same owner
+    #[error("Linked partner asset is not transferred to the same owner")]
+    LinkedPairNotTransferred,
+    /// 39 (0x27) - Hooked program of a lifecycle hook is missing
+    #[error("Hooked program of a lifecycle hook is missing")]
+    MissingHookedProgram,
+    /// 40 (0x28) - Invalid proof cache
+    #[error("Invalid proof cache")]
+    InvalidProofCache,
+    /// 41 (0x29) - Proof cache is stale
+    #[error("Proof cache is stale")]
+    StaleProofCache,
+    /// 42 (0x2A) - Seller of a listed asset is missing
+    #[error("Seller of a listed asset is missing")]
+    MissingListingSeller,
+    /// 43 (0x2B) - Data offset is outside of the data section
+    #[error("Data offset is outside of the data section")]
+    InvalidDataOffset,
+    /// 44 (0x2C) - Linked token escrow is missing or does not back the asset
+    #[error("Linked token escrow is missing or does not back the asset")]
+    InvalidTokenLinkEscrow,
+    /// 45 (0x2D) - Fractions of the asset are still outstanding
+    #[error("Fractions of the asset are still outstanding")]
+    FractionsOutstanding,
+    /// 46 (0x2E) - Maximum supply of the collection has been reached
+    #[error("Maximum supply of the collection has been reached")]
+    MintLimitReached,
+    /// 47 (0x2F) - Collection still has assets
+    #[error("Collection still has assets")]
+    CollectionNotEmpty,
+    /// 48 (0x30) - Invalid fee config account
+    #[error("Invalid fee config account")]
+    InvalidFeeConfig,
+    /// 49 (0x31) - Invalid Token Metadata account
+    #[error("Invalid Token Metadata account")]
+    InvalidTokenMetadata,
+    /// 50 (0x32) - Account cannot grow by more than 10KB in one instruction
+    #[error("Account cannot grow by more than 10KB in one instruction")]
+    AccountGrowthLimitExceeded,
+    /// 51 (0x33) - A plugin rejected the operation
+    #[error("A plugin rejected the operation")]
+    PluginRejected,
+    /// 52 (0x34) - Account key does not match the expected account type
+    #[error("Account key does not match the expected account type")]
+    IncorrectAccountKey,
 }
 
 impl solana_program::program_error::PrintProgramError for MplCoreError {
@@ -12,10 +12,8 @@ use borsh::{BorshDeserialize, BorshSerialize};
 
 /// Accounts.
 pub struct Collect {
-    /// The address of the recipient 1
-    pub recipient1: solana_program::pubkey::Pubkey,
-    /// The address of the recipient 2
-    pub recipient2: solana_program::pubkey::Pubkey,
+    /// The fee config receiving the fees
+    pub fee_config: solana_program::pubkey::Pubkey,
 }
 
 impl Collect {
@@ -27,13 +25,9 @@ impl Collect {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(1 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
-            self.recipient1,
-            false,
-        ));
-        accounts.push(solana_program::instruction::AccountMeta::new(
-            self.recipient2,
+            self.fee_config,
             false,
         ));
         accounts.extend_from_slice(remaining_accounts);
@@ -63,12 +57,10 @@ impl CollectInstructionData {
 ///
 /// ### Accounts:
 ///
-///   0. `[writable, optional]` recipient1 (default to `8AT6o8Qk5T9QnZvPThMrF9bcCQLTGkyGvVZZzHgCw11v`)
-///   1. `[writable, optional]` recipient2 (default to `MmHsqX4LxTfifxoH8BVRLUKrwDn1LPCac6YcCZTHhwt`)
+///   0. `[writable]` fee_config
 #[derive(Default)]
 pub struct CollectBuilder {
-    recipient1: Option<solana_program::pubkey::Pubkey>,
-    recipient2: Option<solana_program::pubkey::Pubkey>,
+    fee_config: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -76,18 +68,10 @@ impl CollectBuilder {
     pub fn new() -> Self {
         Self::default()
     }
-    /// `[optional account, default to '8AT6o8Qk5T9QnZvPThMrF9bcCQLTGkyGvVZZzHgCw11v']`
-    /// The address of the recipient 1
-    #[inline(always)]
-    pub fn recipient1(&mut self, recipient1: solana_program::pubkey::Pubkey) -> &mut Self {
-        self.recipient1 = Some(recipient1);
-        self
-    }
-    /// `[optional account, default to 'MmHsqX4LxTfifxoH8BVRLUKrwDn1LPCac6YcCZTHhwt']`
-    /// The address of the recipient 2
+    /// The fee config receiving the fees
     #[inline(always)]
-    pub fn recipient2(&mut self, recipient2: solana_program::pubkey::Pubkey) -> &mut Self {
-        self.recipient2 = Some(recipient2);
+    pub fn fee_config(&mut self, fee_config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.fee_config = Some(fee_config);
         self
     }
     /// Add an aditional account to the instruction.
@@ -111,12 +95,7 @@ impl CollectBuilder {
     #[allow(clippy::clone_on_copy)]
     pub fn instruction(&self) -> solana_program::instruction::Instruction {
         let accounts = Collect {
-            recipient1: self.recipient1.unwrap_or(solana_program::pubkey!(
-                "8AT6o8Qk5T9QnZvPThMrF9bcCQLTGkyGvVZZzHgCw11v"
-            )),
-            recipient2: self.recipient2.unwrap_or(solana_program::pubkey!(
-                "MmHsqX4LxTfifxoH8BVRLUKrwDn1LPCac6YcCZTHhwt"
-            )),
+            fee_config: self.fee_config.expect("fee_config is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -125,20 +104,16 @@ impl CollectBuilder {
 
 /// `collect` CPI accounts.
 pub struct CollectCpiAccounts<'a, 'b> {
-    /// The address of the recipient 1
-    pub recipient1: &'b solana_program::account_info::AccountInfo<'a>,
-    /// The address of the recipient 2
-    pub recipient2: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The fee config receiving the fees
+    pub fee_config: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `collect` CPI instruction.
 pub struct CollectCpi<'a, 'b> {
     /// The program to invoke.
     pub __program: &'b solana_program::account_info::AccountInfo<'a>,
-    /// The address of the recipient 1
-    pub recipient1: &'b solana_program::account_info::AccountInfo<'a>,
-    /// The address of the recipient 2
-    pub recipient2: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The fee config receiving the fees
+    pub fee_config: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> CollectCpi<'a, 'b> {
@@ -148,8 +123,7 @@ impl<'a, 'b> CollectCpi<'a, 'b> {
     ) -> Self {
         Self {
             __program: program,
-            recipient1: accounts.recipient1,
-            recipient2: accounts.recipient2,
+            fee_config: accounts.fee_config,
         }
     }
     #[inline(always)]
@@ -185,13 +159,9 @@ impl<'a, 'b> CollectCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(1 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
-            *self.recipient1.key,
-            false,
-        ));
-        accounts.push(solana_program::instruction::AccountMeta::new(
-            *self.recipient2.key,
+            *self.fee_config.key,
             false,
         ));
         remaining_accounts.iter().for_each(|remaining_account| {
@@ -208,10 +178,9 @@ impl<'a, 'b> CollectCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(2 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(1 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
-        account_infos.push(self.recipient1.clone());
-        account_infos.push(self.recipient2.clone());
+        account_infos.push(self.fee_config.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -228,8 +197,7 @@ impl<'a, 'b> CollectCpi<'a, 'b> {
 ///
 /// ### Accounts:
 ///
-///   0. `[writable]` recipient1
-///   1. `[writable]` recipient2
+///   0. `[writable]` fee_config
 pub struct CollectCpiBuilder<'a, 'b> {
     instruction: Box<CollectCpiBuilderInstruction<'a, 'b>>,
 }
@@ -238,28 +206,18 @@ impl<'a, 'b> CollectCpiBuilder<'a, 'b> {
     pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
         let instruction = Box::new(CollectCpiBuilderInstruction {
             __program: program,
-            recipient1: None,
-            recipient2: None,
+            fee_config: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
     }
-    /// The address of the recipient 1
+    /// The fee config receiving the fees
     #[inline(always)]
-    pub fn recipient1(
+    pub fn fee_config(
         &mut self,
-        recipient1: &'b solana_program::account_info::AccountInfo<'a>,
+        fee_config: &'b solana_program::account_info::AccountInfo<'a>,
     ) -> &mut Self {
-        self.instruction.recipient1 = Some(recipient1);
-        self
-    }
-    /// The address of the recipient 2
-    #[inline(always)]
-    pub fn recipient2(
-        &mut self,
-        recipient2: &'b solana_program::account_info::AccountInfo<'a>,
-    ) -> &mut Self {
-        self.instruction.recipient2 = Some(recipient2);
+        self.instruction.fee_config = Some(fee_config);
         self
     }
     /// Add an additional account to the instruction.
@@ -306,9 +264,7 @@ impl<'a, 'b> CollectCpiBuilder<'a, 'b> {
         let instruction = CollectCpi {
             __program: self.instruction.__program,
 
-            recipient1: self.instruction.recipient1.expect("recipient1 is not set"),
-
-            recipient2: self.instruction.recipient2.expect("recipient2 is not set"),
+            fee_config: self.instruction.fee_config.expect("fee_config is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -319,8 +275,7 @@ impl<'a, 'b> CollectCpiBuilder<'a, 'b> {
 
 struct CollectCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
-    recipient1: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-    recipient2: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    fee_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -5,44 +5,136 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#add_collection_external_plugin_v1;
 pub(crate) mod r#add_collection_plugin_v1;
+pub(crate) mod r#add_external_plugin_v1;
 pub(crate) mod r#add_plugin_v1;
+pub(crate) mod r#add_plugin_v2;
+pub(crate) mod r#airdrop_v1;
+pub(crate) mod r#approve_collection_join_v1;
 pub(crate) mod r#approve_collection_plugin_authority_v1;
 pub(crate) mod r#approve_plugin_authority_v1;
+pub(crate) mod r#approve_transfer_v1;
+pub(crate) mod r#assert_ownership_v1;
 pub(crate) mod r#burn_collection_v1;
+pub(crate) mod r#burn_compressed_v1;
 pub(crate) mod r#burn_v1;
+pub(crate) mod r#close_collection_v1;
 pub(crate) mod r#collect;
 pub(crate) mod r#compress_v1;
+pub(crate) mod r#create_batch_v1;
 pub(crate) mod r#create_collection_v1;
+pub(crate) mod r#create_edition_v1;
 pub(crate) mod r#create_v1;
+pub(crate) mod r#create_v2;
 pub(crate) mod r#decompress_v1;
+pub(crate) mod r#execute_v1;
+pub(crate) mod r#export_to_token_metadata_v1;
+pub(crate) mod r#freeze_asset_v1;
+pub(crate) mod r#get_delegates_v1;
+pub(crate) mod r#migrate_from_token_metadata_v1;
+pub(crate) mod r#reap_v1;
+pub(crate) mod r#redeem_v1;
+pub(crate) mod r#remove_collection_external_plugin_v1;
 pub(crate) mod r#remove_collection_plugin_v1;
+pub(crate) mod r#remove_external_plugin_v1;
 pub(crate) mod r#remove_plugin_v1;
+pub(crate) mod r#repair_registry_v1;
+pub(crate) mod r#reserve_v1;
+pub(crate) mod r#revoke_all_v1;
 pub(crate) mod r#revoke_collection_plugin_authority_v1;
 pub(crate) mod r#revoke_plugin_authority_v1;
+pub(crate) mod r#revoke_transfer_approval_v1;
+pub(crate) mod r#set_fee_config_v1;
+pub(crate) mod r#stage_proof_v1;
+pub(crate) mod r#thaw_asset_v1;
+pub(crate) mod r#transfer_batch_v1;
+pub(crate) mod r#transfer_compressed_v1;
 pub(crate) mod r#transfer_v1;
+pub(crate) mod r#truncate_collection_external_plugin_data_v1;
+pub(crate) mod r#truncate_external_plugin_data_v1;
+pub(crate) mod r#unbind_v1;
+pub(crate) mod r#update_collection_external_plugin_v1;
 pub(crate) mod r#update_collection_plugin_v1;
 pub(crate) mod r#update_collection_v1;
+pub(crate) mod r#update_external_plugin_v1;
 pub(crate) mod r#update_plugin_v1;
 pub(crate) mod r#update_v1;
+pub(crate) mod r#update_v2;
+pub(crate) mod r#validate_lifecycle_v1;
+pub(crate) mod r#verify_grouping_v1;
+pub(crate) mod r#withdraw_fees_v1;
+pub(crate) mod r#write_app_data_chunk_v1;
+pub(crate) mod r#write_app_data_v1;
+pub(crate) mod r#write_collection_app_data_v1;
+pub(crate) mod r#write_collection_external_plugin_data_v1;
+pub(crate) mod r#write_external_plugin_data_v1;
+pub(crate) mod r#write_inscription_v1;
+pub(crate) mod r#write_linked_app_data_v1;
 
+pub use self::r#add_collection_external_plugin_v1::*;
 pub use self::r#add_collection_plugin_v1::*;
+pub use self::r#add_external_plugin_v1::*;
 pub use self::r#add_plugin_v1::*;
+pub use self::r#add_plugin_v2::*;
+pub use self::r#airdrop_v1::*;
+pub use self::r#approve_collection_join_v1::*;
 pub use self::r#approve_collection_plugin_authority_v1::*;
 pub use self::r#approve_plugin_authority_v1::*;
+pub use self::r#approve_transfer_v1::*;
+pub use self::r#assert_ownership_v1::*;
 pub use self::r#burn_collection_v1::*;
+pub use self::r#burn_compressed_v1::*;
 pub use self::r#burn_v1::*;
+pub use self::r#close_collection_v1::*;
 pub use self::r#collect::*;
 pub use self::r#compress_v1::*;
+pub use self::r#create_batch_v1::*;
 pub use self::r#create_collection_v1::*;
+pub use self::r#create_edition_v1::*;
 pub use self::r#create_v1::*;
+pub use self::r#create_v2::*;
 pub use self::r#decompress_v1::*;
+pub use self::r#execute_v1::*;
+pub use self::r#export_to_token_metadata_v1::*;
+pub use self::r#freeze_asset_v1::*;
+pub use self::r#get_delegates_v1::*;
+pub use self::r#migrate_from_token_metadata_v1::*;
+pub use self::r#reap_v1::*;
+pub use self::r#redeem_v1::*;
+pub use self::r#remove_collection_external_plugin_v1::*;
 pub use self::r#remove_collection_plugin_v1::*;
+pub use self::r#remove_external_plugin_v1::*;
 pub use self::r#remove_plugin_v1::*;
+pub use self::r#repair_registry_v1::*;
+pub use self::r#reserve_v1::*;
+pub use self::r#revoke_all_v1::*;
 pub use self::r#revoke_collection_plugin_authority_v1::*;
 pub use self::r#revoke_plugin_authority_v1::*;
+pub use self::r#revoke_transfer_approval_v1::*;
+pub use self::r#set_fee_config_v1::*;
+pub use self::r#stage_proof_v1::*;
+pub use self::r#thaw_asset_v1::*;
+pub use self::r#transfer_batch_v1::*;
+pub use self::r#transfer_compressed_v1::*;
 pub use self::r#transfer_v1::*;
+pub use self::r#truncate_collection_external_plugin_data_v1::*;
+pub use self::r#truncate_external_plugin_data_v1::*;
+pub use self::r#unbind_v1::*;
+pub use self::r#update_collection_external_plugin_v1::*;
 pub use self::r#update_collection_plugin_v1::*;
 pub use self::r#update_collection_v1::*;
+pub use self::r#update_external_plugin_v1::*;
 pub use self::r#update_plugin_v1::*;
 pub use self::r#update_v1::*;
+pub use self::r#update_v2::*;
+pub use self::r#validate_lifecycle_v1::*;
+pub use self::r#verify_grouping_v1::*;
+pub use self::r#withdraw_fees_v1::*;
+pub use self::r#write_app_data_chunk_v1::*;
+pub use self::r#write_app_data_v1::*;
+pub use self::r#write_collection_app_data_v1::*;
+pub use self::r#write_collection_external_plugin_data_v1::*;
+pub use self::r#write_external_plugin_data_v1::*;
+pub use self::r#write_inscription_v1::*;
+pub use self::r#write_linked_app_data_v1::*;
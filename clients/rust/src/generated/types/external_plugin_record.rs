@@ -5,6 +5,8 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::ExternalPluginSchema;
+use crate::generated::types::ExternalPluginType;
 use crate::generated::types::PluginAuthority;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
@@ -16,6 +18,9 @@ use borsh::{BorshDeserialize, BorshSerialize};
 #[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ExternalPluginRecord {
+    pub plugin_type: ExternalPluginType,
     pub authority: PluginAuthority,
     pub offset: u64,
+    pub schema: ExternalPluginSchema,
+    pub data_len: u64,
 }
@@ -22,4 +22,7 @@ pub enum Key {
     PluginHeaderV1,
     PluginRegistryV1,
     CollectionV1,
+    ProofCacheV1,
+    LinkedDataSectionV1,
+    FeeConfigV1,
 }
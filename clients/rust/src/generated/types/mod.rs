@@ -6,61 +6,183 @@
 //!
 
 pub(crate) mod r#add_blocker;
+pub(crate) mod r#app_data_init_info;
 pub(crate) mod r#attribute;
 pub(crate) mod r#attributes;
+pub(crate) mod r#autograph;
+pub(crate) mod r#autograph_signature;
+pub(crate) mod r#batch_item_result;
+pub(crate) mod r#batch_item_status;
+pub(crate) mod r#bound_until;
 pub(crate) mod r#burn_delegate;
+pub(crate) mod r#burn_rent_destination;
+pub(crate) mod r#claim_status;
+pub(crate) mod r#collection_join_request;
 pub(crate) mod r#compression_proof;
+pub(crate) mod r#create_batch_v1_item;
 pub(crate) mod r#creator;
 pub(crate) mod r#data_state;
+pub(crate) mod r#delegate_record;
 pub(crate) mod r#edition;
+pub(crate) mod r#expiration;
+pub(crate) mod r#external_plugin_init_info;
+pub(crate) mod r#external_plugin_key;
 pub(crate) mod r#external_plugin_record;
+pub(crate) mod r#external_plugin_schema;
+pub(crate) mod r#external_plugin_type;
+pub(crate) mod r#external_plugin_update_info;
 pub(crate) mod r#extra_accounts;
+pub(crate) mod r#fractionalized;
 pub(crate) mod r#freeze_delegate;
+pub(crate) mod r#grouping;
+pub(crate) mod r#groupings;
 pub(crate) mod r#hashable_plugin_schema;
 pub(crate) mod r#hashed_asset_schema;
+pub(crate) mod r#hookable_lifecycle_event;
 pub(crate) mod r#immutable_metadata;
+pub(crate) mod r#inscription_init_info;
 pub(crate) mod r#key;
+pub(crate) mod r#lifecycle_hook;
+pub(crate) mod r#lifecycle_hook_args;
+pub(crate) mod r#lifecycle_validation;
+pub(crate) mod r#linked_pair;
+pub(crate) mod r#listing;
 pub(crate) mod r#master_edition;
+pub(crate) mod r#mint_limit;
+pub(crate) mod r#mint_referral;
+pub(crate) mod r#multisig;
+pub(crate) mod r#named_score;
+pub(crate) mod r#owner_allow_list;
+pub(crate) mod r#paused;
 pub(crate) mod r#permanent_burn_delegate;
 pub(crate) mod r#permanent_freeze_delegate;
 pub(crate) mod r#permanent_transfer_delegate;
+pub(crate) mod r#physical_claim;
 pub(crate) mod r#plugin;
 pub(crate) mod r#plugin_authority;
 pub(crate) mod r#plugin_authority_pair;
 pub(crate) mod r#plugin_type;
+pub(crate) mod r#policy_preset;
+pub(crate) mod r#progression;
+pub(crate) mod r#provenance;
+pub(crate) mod r#reaper;
+pub(crate) mod r#redemption;
+pub(crate) mod r#redemption_record;
 pub(crate) mod r#registry_record;
+pub(crate) mod r#rental;
 pub(crate) mod r#royalties;
 pub(crate) mod r#rule_set;
+pub(crate) mod r#score;
+pub(crate) mod r#score_mode;
+pub(crate) mod r#staking;
+pub(crate) mod r#ticket;
+pub(crate) mod r#ticket_status;
+pub(crate) mod r#time_lock;
+pub(crate) mod r#token_link;
+pub(crate) mod r#token_metadata;
+pub(crate) mod r#token_metadata_asset_data;
+pub(crate) mod r#token_metadata_collection;
+pub(crate) mod r#token_metadata_creator;
+pub(crate) mod r#transfer_approval;
 pub(crate) mod r#transfer_delegate;
+pub(crate) mod r#transfer_fee;
+pub(crate) mod r#tx_guard;
+pub(crate) mod r#update_approval;
+pub(crate) mod r#update_approver;
 pub(crate) mod r#update_authority;
 pub(crate) mod r#update_delegate;
+pub(crate) mod r#usage_limit;
+pub(crate) mod r#validation_result;
+pub(crate) mod r#verified_creators;
+pub(crate) mod r#verified_creators_signature;
 
 pub use self::r#add_blocker::*;
+pub use self::r#app_data_init_info::*;
 pub use self::r#attribute::*;
 pub use self::r#attributes::*;
+pub use self::r#autograph::*;
+pub use self::r#autograph_signature::*;
+pub use self::r#batch_item_result::*;
+pub use self::r#batch_item_status::*;
+pub use self::r#bound_until::*;
 pub use self::r#burn_delegate::*;
+pub use self::r#burn_rent_destination::*;
+pub use self::r#claim_status::*;
+pub use self::r#collection_join_request::*;
 pub use self::r#compression_proof::*;
+pub use self::r#create_batch_v1_item::*;
 pub use self::r#creator::*;
 pub use self::r#data_state::*;
+pub use self::r#delegate_record::*;
 pub use self::r#edition::*;
+pub use self::r#expiration::*;
+pub use self::r#external_plugin_init_info::*;
+pub use self::r#external_plugin_key::*;
 pub use self::r#external_plugin_record::*;
+pub use self::r#external_plugin_schema::*;
+pub use self::r#external_plugin_type::*;
+pub use self::r#external_plugin_update_info::*;
 pub use self::r#extra_accounts::*;
+pub use self::r#fractionalized::*;
 pub use self::r#freeze_delegate::*;
+pub use self::r#grouping::*;
+pub use self::r#groupings::*;
 pub use self::r#hashable_plugin_schema::*;
 pub use self::r#hashed_asset_schema::*;
+pub use self::r#hookable_lifecycle_event::*;
 pub use self::r#immutable_metadata::*;
+pub use self::r#inscription_init_info::*;
 pub use self::r#key::*;
+pub use self::r#lifecycle_hook::*;
+pub use self::r#lifecycle_hook_args::*;
+pub use self::r#lifecycle_validation::*;
+pub use self::r#linked_pair::*;
+pub use self::r#listing::*;
 pub use self::r#master_edition::*;
+pub use self::r#mint_limit::*;
+pub use self::r#mint_referral::*;
+pub use self::r#multisig::*;
+pub use self::r#named_score::*;
+pub use self::r#owner_allow_list::*;
+pub use self::r#paused::*;
 pub use self::r#permanent_burn_delegate::*;
 pub use self::r#permanent_freeze_delegate::*;
 pub use self::r#permanent_transfer_delegate::*;
+pub use self::r#physical_claim::*;
 pub use self::r#plugin::*;
 pub use self::r#plugin_authority::*;
 pub use self::r#plugin_authority_pair::*;
 pub use self::r#plugin_type::*;
+pub use self::r#policy_preset::*;
+pub use self::r#progression::*;
+pub use self::r#provenance::*;
+pub use self::r#reaper::*;
+pub use self::r#redemption::*;
+pub use self::r#redemption_record::*;
 pub use self::r#registry_record::*;
+pub use self::r#rental::*;
 pub use self::r#royalties::*;
 pub use self::r#rule_set::*;
+pub use self::r#score::*;
+pub use self::r#score_mode::*;
+pub use self::r#staking::*;
+pub use self::r#ticket::*;
+pub use self::r#ticket_status::*;
+pub use self::r#time_lock::*;
+pub use self::r#token_link::*;
+pub use self::r#token_metadata::*;
+pub use self::r#token_metadata_asset_data::*;
+pub use self::r#token_metadata_collection::*;
+pub use self::r#token_metadata_creator::*;
+pub use self::r#transfer_approval::*;
 pub use self::r#transfer_delegate::*;
+pub use self::r#transfer_fee::*;
+pub use self::r#tx_guard::*;
+pub use self::r#update_approval::*;
+pub use self::r#update_approver::*;
 pub use self::r#update_authority::*;
 pub use self::r#update_delegate::*;
+pub use self::r#usage_limit::*;
+pub use self::r#validation_result::*;
+pub use self::r#verified_creators::*;
+pub use self::r#verified_creators_signature::*;
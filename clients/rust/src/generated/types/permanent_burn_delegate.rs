@@ -14,4 +14,6 @@ use borsh::{BorshDeserialize, BorshSerialize};
 #[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
 #[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct PermanentBurnDelegate {}
+pub struct PermanentBurnDelegate {
+    pub sunset: Option<i64>,
+}
@@ -7,17 +7,47 @@
 
 use crate::generated::types::AddBlocker;
 use crate::generated::types::Attributes;
+use crate::generated::types::Autograph;
+use crate::generated::types::BoundUntil;
 use crate::generated::types::BurnDelegate;
+use crate::generated::types::BurnRentDestination;
+use crate::generated::types::CollectionJoinRequest;
 use crate::generated::types::Edition;
+use crate::generated::types::Expiration;
+use crate::generated::types::Fractionalized;
 use crate::generated::types::FreezeDelegate;
+use crate::generated::types::Groupings;
 use crate::generated::types::ImmutableMetadata;
+use crate::generated::types::LinkedPair;
+use crate::generated::types::Listing;
 use crate::generated::types::MasterEdition;
+use crate::generated::types::MintLimit;
+use crate::generated::types::Multisig;
+use crate::generated::types::OwnerAllowList;
+use crate::generated::types::Paused;
 use crate::generated::types::PermanentBurnDelegate;
 use crate::generated::types::PermanentFreezeDelegate;
 use crate::generated::types::PermanentTransferDelegate;
+use crate::generated::types::PhysicalClaim;
+use crate::generated::types::Progression;
+use crate::generated::types::Provenance;
+use crate::generated::types::Reaper;
+use crate::generated::types::Redemption;
+use crate::generated::types::Rental;
 use crate::generated::types::Royalties;
+use crate::generated::types::Score;
+use crate::generated::types::Staking;
+use crate::generated::types::Ticket;
+use crate::generated::types::TimeLock;
+use crate::generated::types::TokenLink;
+use crate::generated::types::TransferApproval;
 use crate::generated::types::TransferDelegate;
+use crate::generated::types::TransferFee;
+use crate::generated::types::TxGuard;
+use crate::generated::types::UpdateApproval;
 use crate::generated::types::UpdateDelegate;
+use crate::generated::types::UsageLimit;
+use crate::generated::types::VerifiedCreators;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
@@ -41,4 +71,34 @@ pub enum Plugin {
     MasterEdition(MasterEdition),
     AddBlocker(AddBlocker),
     ImmutableMetadata(ImmutableMetadata),
+    Groupings(Groupings),
+    CollectionJoinRequest(CollectionJoinRequest),
+    Paused(Paused),
+    Autograph(Autograph),
+    BoundUntil(BoundUntil),
+    VerifiedCreators(VerifiedCreators),
+    Staking(Staking),
+    Rental(Rental),
+    UpdateApproval(UpdateApproval),
+    Reaper(Reaper),
+    TimeLock(TimeLock),
+    UsageLimit(UsageLimit),
+    Expiration(Expiration),
+    TransferFee(TransferFee),
+    BurnRentDestination(BurnRentDestination),
+    TxGuard(TxGuard),
+    OwnerAllowList(OwnerAllowList),
+    Multisig(Multisig),
+    LinkedPair(LinkedPair),
+    Redemption(Redemption),
+    PhysicalClaim(PhysicalClaim),
+    Listing(Listing),
+    Ticket(Ticket),
+    Progression(Progression),
+    TokenLink(TokenLink),
+    Fractionalized(Fractionalized),
+    Score(Score),
+    MintLimit(MintLimit),
+    TransferApproval(TransferApproval),
+    Provenance(Provenance),
 }
@@ -26,4 +26,12 @@ pub enum PluginAuthority {
         )]
         address: Pubkey,
     },
+    AddressWithExpiry {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        address: Pubkey,
+        expires_at: i64,
+    },
 }
@@ -29,4 +29,34 @@ pub enum PluginType {
     MasterEdition,
     AddBlocker,
     ImmutableMetadata,
+    Groupings,
+    CollectionJoinRequest,
+    Paused,
+    Autograph,
+    BoundUntil,
+    VerifiedCreators,
+    Staking,
+    Rental,
+    UpdateApproval,
+    Reaper,
+    TimeLock,
+    UsageLimit,
+    Expiration,
+    TransferFee,
+    BurnRentDestination,
+    TxGuard,
+    OwnerAllowList,
+    Multisig,
+    LinkedPair,
+    Redemption,
+    PhysicalClaim,
+    Listing,
+    Ticket,
+    Progression,
+    TokenLink,
+    Fractionalized,
+    Score,
+    MintLimit,
+    TransferApproval,
+    Provenance,
 }
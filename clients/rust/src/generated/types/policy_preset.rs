@@ -0,0 +1,32 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::Creator;
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PolicyPreset {
+    Soulbound,
+    RoyaltyEnforced {
+        basis_points: u16,
+        creators: Vec<Creator>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+        )]
+        allowed_programs: Vec<Pubkey>,
+    },
+    GameItem,
+    Credential,
+}
@@ -8,18 +8,25 @@ use std::{cmp::Ordering, io::ErrorKind};
 use crate::{
     accounts::{BaseAssetV1, BaseCollectionV1, PluginHeaderV1},
     types::{
-        AddBlocker, Attributes, BurnDelegate, Edition, FreezeDelegate, ImmutableMetadata, Key,
-        MasterEdition, PermanentBurnDelegate, PermanentFreezeDelegate, PermanentTransferDelegate,
-        PluginAuthority, Royalties, TransferDelegate, UpdateDelegate,
+        AddBlocker, Attributes, Autograph, BoundUntil, BurnDelegate, BurnRentDestination,
+        CollectionJoinRequest, Edition, Expiration, Fractionalized, FreezeDelegate, Groupings,
+        ImmutableMetadata, Key, LinkedPair, Listing, MasterEdition, MintLimit, Multisig,
+        OwnerAllowList, Paused, PermanentBurnDelegate, PermanentFreezeDelegate,
+        PermanentTransferDelegate, PhysicalClaim, PluginAuthority, Progression, Provenance, Reaper,
+        Redemption, Rental, Royalties, Score, Staking, Ticket, TimeLock, TokenLink,
+        TransferApproval, TransferDelegate, TransferFee, TxGuard, UpdateApproval, UpdateDelegate,
+        UsageLimit, VerifiedCreators,
     },
 };
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub enum AuthorityType {
     None,
     Owner,
     UpdateAuthority,
     Address,
+    AddressWithExpiry,
 }
 
 impl From<PluginAuthority> for AuthorityType {
@@ -29,14 +36,21 @@ impl From<PluginAuthority> for AuthorityType {
             PluginAuthority::Owner => AuthorityType::Owner,
             PluginAuthority::UpdateAuthority => AuthorityType::UpdateAuthority,
             PluginAuthority::Address { address: _ } => AuthorityType::Address,
+            PluginAuthority::AddressWithExpiry { .. } => AuthorityType::AddressWithExpiry,
         }
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub struct BaseAuthority {
     pub authority_type: AuthorityType,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<Option<serde_with::DisplayFromStr>>")
+    )]
     pub address: Option<Pubkey>,
+    pub expires_at: Option<i64>,
 }
 
 impl From<PluginAuthority> for BaseAuthority {
@@ -45,108 +59,345 @@ impl From<PluginAuthority> for BaseAuthority {
             PluginAuthority::None => BaseAuthority {
                 authority_type: AuthorityType::None,
                 address: None,
+                expires_at: None,
             },
             PluginAuthority::Owner => BaseAuthority {
                 authority_type: AuthorityType::Owner,
                 address: None,
+                expires_at: None,
             },
             PluginAuthority::UpdateAuthority => BaseAuthority {
                 authority_type: AuthorityType::UpdateAuthority,
                 address: None,
+                expires_at: None,
             },
             PluginAuthority::Address { address } => BaseAuthority {
                 authority_type: AuthorityType::Address,
                 address: Some(address),
+                expires_at: None,
+            },
+            PluginAuthority::AddressWithExpiry {
+                address,
+                expires_at,
+            } => BaseAuthority {
+                authority_type: AuthorityType::AddressWithExpiry,
+                address: Some(address),
+                expires_at: Some(expires_at),
             },
         }
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub struct BasePlugin {
     pub authority: BaseAuthority,
     pub offset: Option<u64>,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct RoyaltiesPlugin {
     pub base: BasePlugin,
     pub royalties: Royalties,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct FreezeDelegatePlugin {
     pub base: BasePlugin,
     pub freeze_delegate: FreezeDelegate,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct BurnDelegatePlugin {
     pub base: BasePlugin,
     pub burn_delegate: BurnDelegate,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct TransferDelegatePlugin {
     pub base: BasePlugin,
     pub transfer_delegate: TransferDelegate,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct UpdateDelegatePlugin {
     pub base: BasePlugin,
     pub update_delegate: UpdateDelegate,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct PermanentFreezeDelegatePlugin {
     pub base: BasePlugin,
     pub permanent_freeze_delegate: PermanentFreezeDelegate,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct AttributesPlugin {
     pub base: BasePlugin,
     pub attributes: Attributes,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct PermanentTransferDelegatePlugin {
     pub base: BasePlugin,
     pub permanent_transfer_delegate: PermanentTransferDelegate,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct PermanentBurnDelegatePlugin {
     pub base: BasePlugin,
     pub permanent_burn_delegate: PermanentBurnDelegate,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct EditionPlugin {
     pub base: BasePlugin,
     pub edition: Edition,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct MasterEditionPlugin {
     pub base: BasePlugin,
     pub master_edition: MasterEdition,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct AddBlockerPlugin {
     pub base: BasePlugin,
     pub add_blocker: AddBlocker,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct ImmutableMetadataPlugin {
     pub base: BasePlugin,
     pub immutable_metadata: ImmutableMetadata,
 }
 
-#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct GroupingsPlugin {
+    pub base: BasePlugin,
+    pub groupings: Groupings,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct CollectionJoinRequestPlugin {
+    pub base: BasePlugin,
+    pub collection_join_request: CollectionJoinRequest,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct PausedPlugin {
+    pub base: BasePlugin,
+    pub paused: Paused,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct AutographPlugin {
+    pub base: BasePlugin,
+    pub autograph: Autograph,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct BoundUntilPlugin {
+    pub base: BasePlugin,
+    pub bound_until: BoundUntil,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct VerifiedCreatorsPlugin {
+    pub base: BasePlugin,
+    pub verified_creators: VerifiedCreators,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct StakingPlugin {
+    pub base: BasePlugin,
+    pub staking: Staking,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct RentalPlugin {
+    pub base: BasePlugin,
+    pub rental: Rental,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct UpdateApprovalPlugin {
+    pub base: BasePlugin,
+    pub update_approval: UpdateApproval,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ReaperPlugin {
+    pub base: BasePlugin,
+    pub reaper: Reaper,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct TimeLockPlugin {
+    pub base: BasePlugin,
+    pub time_lock: TimeLock,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct UsageLimitPlugin {
+    pub base: BasePlugin,
+    pub usage_limit: UsageLimit,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ExpirationPlugin {
+    pub base: BasePlugin,
+    pub expiration: Expiration,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct TransferFeePlugin {
+    pub base: BasePlugin,
+    pub transfer_fee: TransferFee,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct BurnRentDestinationPlugin {
+    pub base: BasePlugin,
+    pub burn_rent_destination: BurnRentDestination,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct TxGuardPlugin {
+    pub base: BasePlugin,
+    pub tx_guard: TxGuard,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct OwnerAllowListPlugin {
+    pub base: BasePlugin,
+    pub owner_allow_list: OwnerAllowList,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct MultisigPlugin {
+    pub base: BasePlugin,
+    pub multisig: Multisig,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct LinkedPairPlugin {
+    pub base: BasePlugin,
+    pub linked_pair: LinkedPair,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct RedemptionPlugin {
+    pub base: BasePlugin,
+    pub redemption: Redemption,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct PhysicalClaimPlugin {
+    pub base: BasePlugin,
+    pub physical_claim: PhysicalClaim,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ListingPlugin {
+    pub base: BasePlugin,
+    pub listing: Listing,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct TicketPlugin {
+    pub base: BasePlugin,
+    pub ticket: Ticket,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ProgressionPlugin {
+    pub base: BasePlugin,
+    pub progression: Progression,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct TokenLinkPlugin {
+    pub base: BasePlugin,
+    pub token_link: TokenLink,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct FractionalizedPlugin {
+    pub base: BasePlugin,
+    pub fractionalized: Fractionalized,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ScorePlugin {
+    pub base: BasePlugin,
+    pub score: Score,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct MintLimitPlugin {
+    pub base: BasePlugin,
+    pub mint_limit: MintLimit,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct TransferApprovalPlugin {
+    pub base: BasePlugin,
+    pub transfer_approval: TransferApproval,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ProvenancePlugin {
+    pub base: BasePlugin,
+    pub provenance: Provenance,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default)]
 pub struct PluginsList {
     pub royalties: Option<RoyaltiesPlugin>,
     pub freeze_delegate: Option<FreezeDelegatePlugin>,
@@ -161,16 +412,48 @@ pub struct PluginsList {
     pub master_edition: Option<MasterEditionPlugin>,
     pub add_blocker: Option<AddBlockerPlugin>,
     pub immutable_metadata: Option<ImmutableMetadataPlugin>,
+    pub groupings: Option<GroupingsPlugin>,
+    pub collection_join_request: Option<CollectionJoinRequestPlugin>,
+    pub paused: Option<PausedPlugin>,
+    pub autograph: Option<AutographPlugin>,
+    pub bound_until: Option<BoundUntilPlugin>,
+    pub verified_creators: Option<VerifiedCreatorsPlugin>,
+    pub staking: Option<StakingPlugin>,
+    pub rental: Option<RentalPlugin>,
+    pub update_approval: Option<UpdateApprovalPlugin>,
+    pub reaper: Option<ReaperPlugin>,
+    pub time_lock: Option<TimeLockPlugin>,
+    pub usage_limit: Option<UsageLimitPlugin>,
+    pub expiration: Option<ExpirationPlugin>,
+    pub transfer_fee: Option<TransferFeePlugin>,
+    pub burn_rent_destination: Option<BurnRentDestinationPlugin>,
+    pub tx_guard: Option<TxGuardPlugin>,
+    pub owner_allow_list: Option<OwnerAllowListPlugin>,
+    pub multisig: Option<MultisigPlugin>,
+    pub linked_pair: Option<LinkedPairPlugin>,
+    pub redemption: Option<RedemptionPlugin>,
+    pub physical_claim: Option<PhysicalClaimPlugin>,
+    pub listing: Option<ListingPlugin>,
+    pub ticket: Option<TicketPlugin>,
+    pub progression: Option<ProgressionPlugin>,
+    pub token_link: Option<TokenLinkPlugin>,
+    pub fractionalized: Option<FractionalizedPlugin>,
+    pub score: Option<ScorePlugin>,
+    pub mint_limit: Option<MintLimitPlugin>,
+    pub transfer_approval: Option<TransferApprovalPlugin>,
+    pub provenance: Option<ProvenancePlugin>,
 }
 
-#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
 pub struct Asset {
     pub base: BaseAssetV1,
     pub plugin_list: PluginsList,
     pub plugin_header: Option<PluginHeaderV1>,
 }
 
-#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
 pub struct Collection {
     pub base: BaseCollectionV1,
     pub plugin_list: PluginsList,
@@ -0,0 +1,145 @@
+//! Anchor integration, so that Anchor programs can take Core accounts as
+//! `Account<'info, Asset>` or `Account<'info, Collection>` and CPI into Core.
+
+use anchor_lang::{
+    error::ErrorCode,
+    prelude::{AccountInfo, AccountMeta, Pubkey, Result},
+    AccountDeserialize, AccountSerialize, Owner, ToAccountInfos, ToAccountMetas,
+};
+
+use crate::{
+    instructions::{TransferV1Cpi, TransferV1CpiAccounts, TransferV1InstructionArgs},
+    types::{CompressionProof, Key},
+    Asset, Collection,
+};
+
+impl AccountDeserialize for Asset {
+    fn try_deserialize(buf: &mut &[u8]) -> Result<Self> {
+        if buf.first() != Some(&(Key::AssetV1 as u8)) {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+
+        Self::try_deserialize_unchecked(buf)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        Self::deserialize(buf).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+
+/// Core accounts are only written by the Core program, so serializing them is a no-op.
+impl AccountSerialize for Asset {}
+
+impl Owner for Asset {
+    fn owner() -> Pubkey {
+        crate::ID
+    }
+}
+
+impl AccountDeserialize for Collection {
+    fn try_deserialize(buf: &mut &[u8]) -> Result<Self> {
+        if buf.first() != Some(&(Key::CollectionV1 as u8)) {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+
+        Self::try_deserialize_unchecked(buf)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        Self::deserialize(buf).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+
+/// Core accounts are only written by the Core program, so serializing them is a no-op.
+impl AccountSerialize for Collection {}
+
+impl Owner for Collection {
+    fn owner() -> Pubkey {
+        crate::ID
+    }
+}
+
+/// The accounts of a `TransferV1` CPI, for use with `CpiContext`.
+pub struct TransferV1<'info> {
+    /// The address of the asset
+    pub asset: AccountInfo<'info>,
+    /// The collection to which the asset belongs
+    pub collection: Option<AccountInfo<'info>>,
+    /// The account paying for the storage fees
+    pub payer: AccountInfo<'info>,
+    /// The owner or delegate of the asset
+    pub authority: Option<AccountInfo<'info>>,
+    /// The new owner to which to transfer the asset
+    pub new_owner: AccountInfo<'info>,
+    /// The system program
+    pub system_program: Option<AccountInfo<'info>>,
+    /// The SPL Noop Program
+    pub log_wrapper: Option<AccountInfo<'info>>,
+}
+
+impl ToAccountMetas for TransferV1<'_> {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        // Missing optional accounts are passed as the Core program.
+        let optional = |account: &Option<AccountInfo>, is_signer| match account {
+            Some(account) => AccountMeta::new_readonly(*account.key, is_signer),
+            None => AccountMeta::new_readonly(crate::ID, false),
+        };
+
+        vec![
+            AccountMeta::new(*self.asset.key, false),
+            optional(&self.collection, false),
+            AccountMeta::new(*self.payer.key, true),
+            optional(&self.authority, true),
+            AccountMeta::new_readonly(*self.new_owner.key, false),
+            optional(&self.system_program, false),
+            optional(&self.log_wrapper, false),
+        ]
+    }
+}
+
+impl<'info> ToAccountInfos<'info> for TransferV1<'info> {
+    fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+        [
+            Some(&self.asset),
+            self.collection.as_ref(),
+            Some(&self.payer),
+            self.authority.as_ref(),
+            Some(&self.new_owner),
+            self.system_program.as_ref(),
+            self.log_wrapper.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect()
+    }
+}
+
+/// Transfer an asset through a CPI into Core, signing with the seeds of the context and passing
+/// its remaining accounts on to the plugins of the asset.
+pub fn transfer_v1<'info>(
+    ctx: anchor_lang::context::CpiContext<'_, '_, '_, 'info, TransferV1<'info>>,
+    compression_proof: Option<CompressionProof>,
+) -> Result<()> {
+    let remaining_accounts = ctx
+        .remaining_accounts
+        .iter()
+        .map(|account| (account, account.is_signer, account.is_writable))
+        .collect::<Vec<_>>();
+
+    TransferV1Cpi::new(
+        &ctx.program,
+        TransferV1CpiAccounts {
+            asset: &ctx.accounts.asset,
+            collection: ctx.accounts.collection.as_ref(),
+            payer: &ctx.accounts.payer,
+            authority: ctx.accounts.authority.as_ref(),
+            new_owner: &ctx.accounts.new_owner,
+            system_program: ctx.accounts.system_program.as_ref(),
+            log_wrapper: ctx.accounts.log_wrapper.as_ref(),
+        },
+        TransferV1InstructionArgs { compression_proof },
+    )
+    .invoke_signed_with_remaining_accounts(ctx.signer_seeds, &remaining_accounts)
+    .map_err(Into::into)
+}
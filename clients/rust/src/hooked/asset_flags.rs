@@ -0,0 +1,98 @@
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::AnchorDeserialize as CrateDeserialize;
+#[cfg(not(feature = "anchor"))]
+use borsh::BorshDeserialize as CrateDeserialize;
+use num_traits::FromPrimitive;
+
+use crate::{
+    accounts::{BaseAssetV1, BaseCollectionV1, PluginHeaderV1},
+    types::{
+        FreezeDelegate, Paused, PermanentFreezeDelegate, Plugin, PluginAuthority, PluginType,
+        Staking,
+    },
+    DataBlob, PluginRegistryV1Safe,
+};
+
+/// The asset is frozen by a freeze delegate or permanent freeze delegate, fractionalized, or in
+/// a paused collection.
+pub const ASSET_FLAG_FROZEN: u8 = 1 << 0;
+/// The asset is permanently frozen with no authority able to thaw it.
+pub const ASSET_FLAG_SOULBOUND: u8 = 1 << 1;
+/// The asset has a transfer delegate or transfer approval, such as a marketplace listing.
+pub const ASSET_FLAG_LISTED: u8 = 1 << 2;
+/// The asset is staked.
+pub const ASSET_FLAG_STAKED: u8 = 1 << 3;
+/// The asset has a permanent freeze, transfer, or burn delegate.
+pub const ASSET_FLAG_PERMANENT_DELEGATES: u8 = 1 << 4;
+
+/// Summarize the state of an asset as an `ASSET_FLAG_*` bitfield, derived from the plugins in
+/// the data of the asset account and, if the asset is in a collection, of the collection account,
+/// whose permanent delegates and pause apply to every asset in it.  Plugins too new for this
+/// client to know about are skipped.
+///
+/// The flags are derived rather than stored on chain, since a stored bitfield could not follow a
+/// collection being frozen or paused without rewriting every asset in it.
+pub fn asset_flags(
+    asset_data: &[u8],
+    collection_data: Option<&[u8]>,
+) -> Result<u8, std::io::Error> {
+    let mut flags = plugin_flags(asset_data, BaseAssetV1::from_bytes(asset_data)?.get_size())?;
+    if let Some(collection_data) = collection_data {
+        flags |= plugin_flags(
+            collection_data,
+            BaseCollectionV1::from_bytes(collection_data)?.get_size(),
+        )?;
+    }
+
+    Ok(flags)
+}
+
+/// The flags of the plugins of an asset or collection account whose base is `base_size` bytes.
+fn plugin_flags(account_data: &[u8], base_size: usize) -> Result<u8, std::io::Error> {
+    if base_size == account_data.len() {
+        return Ok(0);
+    }
+
+    let header = PluginHeaderV1::from_bytes(&account_data[base_size..])?;
+    let plugin_registry = PluginRegistryV1Safe::from_bytes(
+        &account_data[(header.plugin_registry_offset as usize)..],
+    )?;
+
+    let mut flags = 0;
+    for record in &plugin_registry.registry {
+        // Only the plugins whose flags depend on their data are deserialized.
+        match PluginType::from_u8(record.plugin_type) {
+            Some(
+                PluginType::FreezeDelegate
+                | PluginType::PermanentFreezeDelegate
+                | PluginType::Staking
+                | PluginType::Paused,
+            ) => match Plugin::deserialize(&mut &account_data[record.offset as usize..])? {
+                Plugin::FreezeDelegate(FreezeDelegate { frozen: true })
+                | Plugin::Paused(Paused { paused: true }) => flags |= ASSET_FLAG_FROZEN,
+                Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate { frozen }) => {
+                    flags |= ASSET_FLAG_PERMANENT_DELEGATES;
+                    if frozen {
+                        flags |= ASSET_FLAG_FROZEN;
+                        if record.authority == PluginAuthority::None {
+                            flags |= ASSET_FLAG_SOULBOUND;
+                        }
+                    }
+                }
+                Plugin::Staking(Staking { staked: true, .. }) => flags |= ASSET_FLAG_STAKED,
+                _ => (),
+            },
+            Some(PluginType::TransferDelegate) if record.authority != PluginAuthority::Owner => {
+                flags |= ASSET_FLAG_LISTED
+            }
+            Some(PluginType::TransferApproval) => flags |= ASSET_FLAG_LISTED,
+            Some(PluginType::PermanentTransferDelegate | PluginType::PermanentBurnDelegate) => {
+                flags |= ASSET_FLAG_PERMANENT_DELEGATES
+            }
+            Some(PluginType::Fractionalized) => flags |= ASSET_FLAG_FROZEN,
+            _ => (),
+        }
+    }
+
+    Ok(flags)
+}
@@ -0,0 +1,10 @@
+use solana_program::pubkey::Pubkey;
+
+/// The seed prefix used when deriving the signer of an asset.
+pub const ASSET_SIGNER_PREFIX: &[u8] = b"mpl-core-execute";
+
+/// Find the address of the signer of an asset. Accounts such as token vaults and escrows that
+/// are held by the asset signer are controlled by whoever owns the asset.
+pub fn find_asset_signer_address(asset: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ASSET_SIGNER_PREFIX, asset.as_ref()], &crate::ID)
+}
@@ -0,0 +1,60 @@
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::AnchorSerialize;
+#[cfg(not(feature = "anchor"))]
+use borsh::BorshSerialize;
+use num_traits::FromPrimitive;
+
+use crate::{
+    accounts::{BaseAssetV1, BaseCollectionV1, PluginHeaderV1},
+    types::{DelegateRecord, PluginAuthority, PluginType},
+    PluginRegistryV1Safe,
+};
+
+/// List the authorities of every plugin on an asset and, if given, its collection, so wallets
+/// can show who is able to move or modify the asset. Plugins with no authority and plugins too
+/// new for this client to know about are skipped.
+pub fn list_delegates(
+    asset_data: &[u8],
+    collection_data: Option<&[u8]>,
+) -> Result<Vec<DelegateRecord>, std::io::Error> {
+    let asset = BaseAssetV1::from_bytes(asset_data)?;
+    let mut delegates = registry_delegates(asset_data, asset.try_to_vec()?.len(), false)?;
+
+    if let Some(collection_data) = collection_data {
+        let collection = BaseCollectionV1::from_bytes(collection_data)?;
+        delegates.extend(registry_delegates(
+            collection_data,
+            collection.try_to_vec()?.len(),
+            true,
+        )?);
+    }
+
+    Ok(delegates)
+}
+
+fn registry_delegates(
+    account_data: &[u8],
+    base_len: usize,
+    collection_level: bool,
+) -> Result<Vec<DelegateRecord>, std::io::Error> {
+    if base_len == account_data.len() {
+        return Ok(vec![]);
+    }
+
+    let header = PluginHeaderV1::from_bytes(&account_data[base_len..])?;
+    let plugin_registry =
+        PluginRegistryV1Safe::from_bytes(&account_data[header.plugin_registry_offset as usize..])?;
+
+    Ok(plugin_registry
+        .registry
+        .into_iter()
+        .filter(|record| record.authority != PluginAuthority::None)
+        .filter_map(|record| {
+            PluginType::from_u8(record.plugin_type).map(|plugin_type| DelegateRecord {
+                plugin_type,
+                authority: record.authority,
+                collection_level,
+            })
+        })
+        .collect())
+}
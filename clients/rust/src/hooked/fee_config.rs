@@ -0,0 +1,10 @@
+use solana_program::pubkey::Pubkey;
+
+/// The seed prefix used when deriving the fee config of the program.
+pub const FEE_CONFIG_PREFIX: &[u8] = b"fee_config";
+
+/// Find the address of the fee config of the program.  Passing it as the last remaining account
+/// of a create pays the creation fee into it.
+pub fn find_fee_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_CONFIG_PREFIX], &crate::ID)
+}
@@ -0,0 +1,93 @@
+use solana_account_decoder::UiAccountEncoding;
+use solana_program::pubkey::Pubkey;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::{
+    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    filter::RpcFilterType,
+};
+
+use crate::{errors::MplCoreError, rpc, types::Key, Asset, Collection};
+
+/// Fetch an account owned by Core, checking its key.
+async fn fetch_core_account(
+    rpc_client: &RpcClient,
+    address: &Pubkey,
+    key: Key,
+) -> Result<Vec<u8>, std::io::Error> {
+    let account = rpc_client
+        .get_account(address)
+        .await
+        .map_err(std::io::Error::other)?;
+
+    if account.owner != crate::ID || account.data.first() != Some(&(key as u8)) {
+        return Err(std::io::Error::other(
+            MplCoreError::IncorrectAccount.to_string(),
+        ));
+    }
+
+    Ok(account.data)
+}
+
+/// Fetch every account owned by Core that matches the filters.
+async fn fetch_core_accounts(
+    rpc_client: &RpcClient,
+    filters: Vec<RpcFilterType>,
+) -> Result<Vec<(Pubkey, Vec<u8>)>, std::io::Error> {
+    let accounts = rpc_client
+        .get_program_accounts_with_config(
+            &crate::ID,
+            RpcProgramAccountsConfig {
+                filters: Some(filters),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    ..RpcAccountInfoConfig::default()
+                },
+                ..RpcProgramAccountsConfig::default()
+            },
+        )
+        .await
+        .map_err(std::io::Error::other)?;
+
+    Ok(accounts
+        .into_iter()
+        .map(|(address, account)| (address, account.data))
+        .collect())
+}
+
+impl Asset {
+    /// Fetch an asset and decode its plugins.
+    pub async fn fetch(rpc_client: &RpcClient, address: &Pubkey) -> Result<Self, std::io::Error> {
+        Self::from_bytes(&fetch_core_account(rpc_client, address, Key::AssetV1).await?)
+    }
+
+    /// Fetch every asset owned by an address, with its plugins decoded.
+    pub async fn fetch_all_by_owner(
+        rpc_client: &RpcClient,
+        owner: &Pubkey,
+    ) -> Result<Vec<(Pubkey, Self)>, std::io::Error> {
+        fetch_core_accounts(rpc_client, rpc::assets_by_owner(owner))
+            .await?
+            .into_iter()
+            .map(|(address, data)| Ok((address, Self::from_bytes(&data)?)))
+            .collect()
+    }
+}
+
+impl Collection {
+    /// Fetch a collection and decode its plugins.
+    pub async fn fetch(rpc_client: &RpcClient, address: &Pubkey) -> Result<Self, std::io::Error> {
+        Self::from_bytes(&fetch_core_account(rpc_client, address, Key::CollectionV1).await?)
+    }
+
+    /// Fetch every asset of a collection, with its plugins decoded.
+    pub async fn fetch_all_assets(
+        rpc_client: &RpcClient,
+        collection: &Pubkey,
+    ) -> Result<Vec<(Pubkey, Asset)>, std::io::Error> {
+        fetch_core_accounts(rpc_client, rpc::assets_by_collection(collection))
+            .await?
+            .into_iter()
+            .map(|(address, data)| Ok((address, Asset::from_bytes(&data)?)))
+            .collect()
+    }
+}
@@ -0,0 +1,103 @@
+use solana_program::pubkey::Pubkey;
+
+use crate::types::Key;
+
+/// The offset of the account key, which is the first byte of every Core account.
+pub const KEY_OFFSET: usize = 0;
+
+/// The offset of the owner of an `AssetV1` account.
+pub const ASSET_OWNER_OFFSET: usize = 1;
+
+/// The offset of the update authority of an `AssetV1` account.  The update authority is stored
+/// as its `UpdateAuthority` variant followed by its address.
+pub const ASSET_UPDATE_AUTHORITY_OFFSET: usize = 33;
+
+/// The offset of the update authority of a `CollectionV1` account.
+pub const COLLECTION_UPDATE_AUTHORITY_OFFSET: usize = 1;
+
+/// The `UpdateAuthority::Address` variant.
+const UPDATE_AUTHORITY_ADDRESS: u8 = 1;
+
+/// The `UpdateAuthority::Collection` variant.
+const UPDATE_AUTHORITY_COLLECTION: u8 = 2;
+
+/// The memcmp filters, as offset and bytes pairs, for the assets owned by an address.
+pub fn assets_by_owner_memcmps(owner: &Pubkey) -> Vec<(usize, Vec<u8>)> {
+    vec![
+        (KEY_OFFSET, vec![Key::AssetV1 as u8]),
+        (ASSET_OWNER_OFFSET, owner.to_bytes().to_vec()),
+    ]
+}
+
+/// The memcmp filters, as offset and bytes pairs, for the assets of a collection.
+pub fn assets_by_collection_memcmps(collection: &Pubkey) -> Vec<(usize, Vec<u8>)> {
+    vec![
+        (KEY_OFFSET, vec![Key::AssetV1 as u8]),
+        (
+            ASSET_UPDATE_AUTHORITY_OFFSET,
+            [&[UPDATE_AUTHORITY_COLLECTION][..], collection.as_ref()].concat(),
+        ),
+    ]
+}
+
+/// The memcmp filters, as offset and bytes pairs, for the assets with an update authority
+/// address.  Assets in a collection use the update authority of their collection and are not
+/// matched.
+pub fn assets_by_update_authority_memcmps(update_authority: &Pubkey) -> Vec<(usize, Vec<u8>)> {
+    vec![
+        (KEY_OFFSET, vec![Key::AssetV1 as u8]),
+        (
+            ASSET_UPDATE_AUTHORITY_OFFSET,
+            [&[UPDATE_AUTHORITY_ADDRESS][..], update_authority.as_ref()].concat(),
+        ),
+    ]
+}
+
+/// The memcmp filters, as offset and bytes pairs, for the collections with an update authority.
+pub fn collections_by_update_authority_memcmps(update_authority: &Pubkey) -> Vec<(usize, Vec<u8>)> {
+    vec![
+        (KEY_OFFSET, vec![Key::CollectionV1 as u8]),
+        (
+            COLLECTION_UPDATE_AUTHORITY_OFFSET,
+            update_authority.to_bytes().to_vec(),
+        ),
+    ]
+}
+
+/// `getProgramAccounts` filters built from the memcmp filters above.
+#[cfg(feature = "rpc-filters")]
+pub mod rpc {
+    use solana_program::pubkey::Pubkey;
+    use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+
+    fn to_rpc_filters(memcmps: Vec<(usize, Vec<u8>)>) -> Vec<RpcFilterType> {
+        memcmps
+            .into_iter()
+            .map(|(offset, bytes)| {
+                RpcFilterType::Memcmp(Memcmp::new_base58_encoded(offset, &bytes))
+            })
+            .collect()
+    }
+
+    /// The filters for the assets owned by an address.
+    pub fn assets_by_owner(owner: &Pubkey) -> Vec<RpcFilterType> {
+        to_rpc_filters(super::assets_by_owner_memcmps(owner))
+    }
+
+    /// The filters for the assets of a collection.
+    pub fn assets_by_collection(collection: &Pubkey) -> Vec<RpcFilterType> {
+        to_rpc_filters(super::assets_by_collection_memcmps(collection))
+    }
+
+    /// The filters for the assets with an update authority address.
+    pub fn assets_by_update_authority(update_authority: &Pubkey) -> Vec<RpcFilterType> {
+        to_rpc_filters(super::assets_by_update_authority_memcmps(update_authority))
+    }
+
+    /// The filters for the collections with an update authority.
+    pub fn collections_by_update_authority(update_authority: &Pubkey) -> Vec<RpcFilterType> {
+        to_rpc_filters(super::collections_by_update_authority_memcmps(
+            update_authority,
+        ))
+    }
+}
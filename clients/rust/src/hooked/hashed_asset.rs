@@ -0,0 +1,186 @@
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use num_traits::FromPrimitive;
+use solana_program::keccak;
+
+use crate::{
+    accounts::{BaseAssetV1, HashedAssetV1},
+    add_plugin_to_list,
+    errors::MplCoreError,
+    types::{
+        CompressionProof, DataState, HashablePluginSchema, HashedAssetSchema, Key, Plugin,
+        PluginAuthority, PluginType,
+    },
+    Asset, BasePlugin, PluginRegistryV1Safe, PluginsList,
+};
+
+/// A plugin of an asset together with its authority.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FullPlugin {
+    /// The plugin data.
+    pub plugin: Plugin,
+    /// The authority of the plugin.
+    pub authority: PluginAuthority,
+    /// The offset of the plugin in the asset account, or `None` for a compressed asset.
+    pub offset: Option<u64>,
+}
+
+/// An asset read from either account state or, for compressed assets, from a verified
+/// compression proof.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug)]
+pub struct FullAsset {
+    /// Where the asset data is stored.
+    pub data_state: DataState,
+    /// The asset data.
+    pub asset: Asset,
+    /// All plugins of the asset in registry order, dropping any unknown plugins
+    /// (i.e. `PluginType`s that are too new for this client to know about).
+    pub plugins: Vec<FullPlugin>,
+}
+
+impl FullAsset {
+    /// Read an asset and all of its plugins from the data of an `AssetV1` account.
+    pub fn deserialize(account_data: &[u8]) -> Result<Self, std::io::Error> {
+        if account_data.first().copied() != Some(Key::AssetV1 as u8) {
+            return Err(std::io::Error::other(
+                MplCoreError::DeserializationError.to_string(),
+            ));
+        }
+
+        let asset = Asset::from_bytes(account_data)?;
+        let plugins = match &asset.plugin_header {
+            Some(plugin_header) => {
+                let plugin_registry = PluginRegistryV1Safe::from_bytes(
+                    &account_data[plugin_header.plugin_registry_offset as usize..],
+                )?;
+
+                plugin_registry
+                    .registry
+                    .iter()
+                    .filter(|record| PluginType::from_u8(record.plugin_type).is_some())
+                    .map(|record| {
+                        Ok(FullPlugin {
+                            plugin: Plugin::deserialize(
+                                &mut &account_data[record.offset as usize..],
+                            )?,
+                            authority: record.authority.clone(),
+                            offset: Some(record.offset),
+                        })
+                    })
+                    .collect::<Result<Vec<_>, std::io::Error>>()?
+            }
+            None => vec![],
+        };
+
+        Ok(Self {
+            data_state: DataState::AccountState,
+            asset,
+            plugins,
+        })
+    }
+}
+
+impl CompressionProof {
+    /// Compute the hash stored in the `HashedAssetV1` account for this proof.
+    pub fn hash(&self) -> Result<[u8; 32], std::io::Error> {
+        let asset = BaseAssetV1 {
+            key: Key::AssetV1,
+            owner: self.owner,
+            update_authority: self.update_authority.clone(),
+            name: self.name.clone(),
+            uri: self.uri.clone(),
+            seq: Some(self.seq),
+        };
+
+        let mut sorted_plugins = self.plugins.iter().collect::<Vec<_>>();
+        sorted_plugins.sort_by_key(|plugin| plugin.index);
+
+        let plugin_hashes = sorted_plugins
+            .iter()
+            .map(|plugin| Ok(keccak::hash(&plugin.try_to_vec()?).to_bytes()))
+            .collect::<Result<Vec<[u8; 32]>, std::io::Error>>()?;
+
+        let hashed_asset_schema = HashedAssetSchema {
+            asset_hash: keccak::hash(&asset.try_to_vec()?).to_bytes(),
+            plugin_hashes,
+        };
+
+        Ok(keccak::hash(&hashed_asset_schema.try_to_vec()?).to_bytes())
+    }
+}
+
+/// Read an asset from the data of either an `AssetV1` or a `HashedAssetV1` account.  Compressed
+/// assets require the compression proof, which is verified against the on-chain hash.
+pub fn fetch_asset_any(
+    account_data: &[u8],
+    compression_proof: Option<&CompressionProof>,
+) -> Result<FullAsset, std::io::Error> {
+    match account_data.first().copied() {
+        Some(key) if key == Key::AssetV1 as u8 => FullAsset::deserialize(account_data),
+        Some(key) if key == Key::HashedAssetV1 as u8 => {
+            let hashed_asset = HashedAssetV1::from_bytes(account_data)?;
+            let compression_proof = compression_proof.ok_or(std::io::Error::other(
+                MplCoreError::MissingCompressionProof.to_string(),
+            ))?;
+
+            if compression_proof.hash()? != hashed_asset.hash {
+                return Err(std::io::Error::other(
+                    MplCoreError::IncorrectAssetHash.to_string(),
+                ));
+            }
+
+            Ok(proof_to_asset(compression_proof))
+        }
+        _ => Err(std::io::Error::other(
+            MplCoreError::DeserializationError.to_string(),
+        )),
+    }
+}
+
+// Convert a verified compression proof into the `FullAsset` type.  The plugins have no offset
+// because they are not stored in account state.
+fn proof_to_asset(compression_proof: &CompressionProof) -> FullAsset {
+    let mut sorted_plugins = compression_proof.plugins.iter().collect::<Vec<_>>();
+    sorted_plugins.sort_by_key(|plugin| plugin.index);
+
+    let mut plugin_list = PluginsList::default();
+    let mut plugins = Vec::with_capacity(sorted_plugins.len());
+    for HashablePluginSchema {
+        authority, plugin, ..
+    } in sorted_plugins
+    {
+        let base = BasePlugin {
+            authority: authority.clone().into(),
+            offset: None,
+        };
+        add_plugin_to_list(&mut plugin_list, base, plugin.clone());
+        plugins.push(FullPlugin {
+            plugin: plugin.clone(),
+            authority: authority.clone(),
+            offset: None,
+        });
+    }
+
+    let asset = Asset {
+        base: BaseAssetV1 {
+            key: Key::AssetV1,
+            owner: compression_proof.owner,
+            update_authority: compression_proof.update_authority.clone(),
+            name: compression_proof.name.clone(),
+            uri: compression_proof.uri.clone(),
+            seq: Some(compression_proof.seq),
+        },
+        plugin_list,
+        plugin_header: None,
+    };
+
+    FullAsset {
+        data_state: DataState::LedgerState,
+        asset,
+        plugins,
+    }
+}
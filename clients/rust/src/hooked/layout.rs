@@ -0,0 +1,124 @@
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::AnchorDeserialize as CrateDeserialize;
+#[cfg(not(feature = "anchor"))]
+use borsh::BorshDeserialize as CrateDeserialize;
+use num_traits::FromPrimitive;
+
+use crate::{
+    accounts::{BaseAssetV1, BaseCollectionV1, PluginHeaderV1},
+    errors::MplCoreError,
+    types::{Key, PluginAuthority, PluginType},
+    DataBlob,
+};
+
+/// A named region of the data of an account.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LayoutRegion {
+    /// The name of the region, e.g. `asset`, `plugin_header` or `plugin:Royalties`.
+    pub name: String,
+    /// The offset of the region in the account data.
+    pub offset: usize,
+    /// The length of the region in bytes.
+    pub length: usize,
+}
+
+impl LayoutRegion {
+    fn new(name: impl Into<String>, offset: usize, length: usize) -> Self {
+        Self {
+            name: name.into(),
+            offset,
+            length,
+        }
+    }
+}
+
+/// Map the data of an asset or collection account into its regions, in order of offset: the
+/// core account, the plugin header, each plugin, each external plugin data section, and the
+/// plugin registry.  Plugins too new for this client to know about are named by their
+/// discriminator, e.g. `plugin:Unknown(42)`.
+pub fn account_layout(account_data: &[u8]) -> Result<Vec<LayoutRegion>, std::io::Error> {
+    let (name, core_size) = match account_data.first().copied().and_then(Key::from_u8) {
+        Some(Key::AssetV1) => ("asset", BaseAssetV1::from_bytes(account_data)?.get_size()),
+        Some(Key::CollectionV1) => (
+            "collection",
+            BaseCollectionV1::from_bytes(account_data)?.get_size(),
+        ),
+        _ => return Err(deserialization_error()),
+    };
+
+    let mut regions = vec![LayoutRegion::new(name, 0, core_size)];
+    if core_size == account_data.len() {
+        return Ok(regions);
+    }
+
+    let header = PluginHeaderV1::from_bytes(&account_data[core_size..])?;
+    regions.push(LayoutRegion::new(
+        "plugin_header",
+        core_size,
+        PluginHeaderV1::LEN,
+    ));
+
+    let registry_offset = header.plugin_registry_offset as usize;
+    let mut registry_data = account_data
+        .get(registry_offset..)
+        .ok_or_else(deserialization_error)?;
+
+    // The registry records, followed by the external plugin records.
+    let mut sections = vec![];
+    let _key = Key::deserialize(&mut registry_data)?;
+    for _ in 0..u32::deserialize(&mut registry_data)? {
+        let plugin_type = u8::deserialize(&mut registry_data)?;
+        let _authority = PluginAuthority::deserialize(&mut registry_data)?;
+        let offset = u64::deserialize(&mut registry_data)? as usize;
+
+        let name = match PluginType::from_u8(plugin_type) {
+            Some(plugin_type) => format!("plugin:{:?}", plugin_type),
+            None => format!("plugin:Unknown({})", plugin_type),
+        };
+        sections.push((name, offset, None));
+    }
+    for index in 0..u32::deserialize(&mut registry_data)? {
+        let _plugin_type = u8::deserialize(&mut registry_data)?;
+        let _authority = PluginAuthority::deserialize(&mut registry_data)?;
+        let offset = u64::deserialize(&mut registry_data)? as usize;
+        let _schema = u8::deserialize(&mut registry_data)?;
+        let data_len = u64::deserialize(&mut registry_data)? as usize;
+
+        sections.push((
+            format!("external_plugin_data:{}", index),
+            offset,
+            Some(data_len),
+        ));
+    }
+    let registry_end = account_data.len() - registry_data.len();
+
+    // Plugins are packed between the header and the registry, so each one ends where the next
+    // one starts.
+    sections.sort_by_key(|(_, offset, _)| *offset);
+    let ends: Vec<usize> = sections
+        .iter()
+        .skip(1)
+        .map(|(_, offset, _)| *offset)
+        .chain(std::iter::once(registry_offset))
+        .collect();
+    for ((name, offset, data_len), end) in sections.into_iter().zip(ends) {
+        let length = match data_len {
+            Some(data_len) => data_len,
+            None => end.checked_sub(offset).ok_or_else(deserialization_error)?,
+        };
+        regions.push(LayoutRegion::new(name, offset, length));
+    }
+
+    regions.push(LayoutRegion::new(
+        "plugin_registry",
+        registry_offset,
+        registry_end - registry_offset,
+    ));
+
+    Ok(regions)
+}
+
+fn deserialization_error() -> std::io::Error {
+    std::io::Error::other(MplCoreError::DeserializationError.to_string())
+}
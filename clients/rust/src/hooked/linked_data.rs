@@ -0,0 +1,37 @@
+use solana_program::pubkey::Pubkey;
+
+use crate::types::PluginAuthority;
+
+/// The seed prefix used when deriving the location of a linked data section.
+pub const LINKED_DATA_SECTION_PREFIX: &[u8] = b"linked_data_section";
+
+impl PluginAuthority {
+    /// Get the bytes used to represent the authority in a PDA derivation.
+    pub fn to_seed(&self) -> &[u8] {
+        match self {
+            PluginAuthority::None => b"none",
+            PluginAuthority::Owner => b"owner",
+            PluginAuthority::UpdateAuthority => b"update_authority",
+            PluginAuthority::Address { address }
+            | PluginAuthority::AddressWithExpiry { address, .. } => address.as_ref(),
+        }
+    }
+}
+
+/// Find the address of the data section for an asset that belongs to a collection-level
+/// linked adapter, identified by the collection and the adapter's data authority.
+pub fn find_linked_data_section_address(
+    collection: &Pubkey,
+    data_authority: &PluginAuthority,
+    asset: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            LINKED_DATA_SECTION_PREFIX,
+            collection.as_ref(),
+            data_authority.to_seed(),
+            asset.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
@@ -7,9 +7,39 @@ pub use advanced_types::*;
 pub mod asset;
 pub use asset::*;
 
+pub mod asset_flags;
+pub use asset_flags::*;
+
+pub mod asset_signer;
+pub use asset_signer::*;
+
 pub mod collection;
 pub use collection::*;
 
+pub mod delegates;
+pub use delegates::*;
+
+pub mod fee_config;
+pub use fee_config::*;
+
+#[cfg(feature = "rpc")]
+pub mod fetch;
+
+pub mod filters;
+pub use filters::*;
+
+pub mod hashed_asset;
+pub use hashed_asset::*;
+
+pub mod layout;
+pub use layout::*;
+
+pub mod linked_data;
+pub use linked_data::*;
+
+#[cfg(feature = "anchor")]
+pub mod anchor;
+
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{
     AnchorDeserialize as CrateDeserialize, AnchorSerialize as CrateSerialize,
@@ -42,6 +72,36 @@ impl From<&Plugin> for PluginType {
             Plugin::MasterEdition(_) => PluginType::MasterEdition,
             Plugin::AddBlocker(_) => PluginType::AddBlocker,
             Plugin::ImmutableMetadata(_) => PluginType::ImmutableMetadata,
+            Plugin::Groupings(_) => PluginType::Groupings,
+            Plugin::CollectionJoinRequest(_) => PluginType::CollectionJoinRequest,
+            Plugin::Paused(_) => PluginType::Paused,
+            Plugin::Autograph(_) => PluginType::Autograph,
+            Plugin::BoundUntil(_) => PluginType::BoundUntil,
+            Plugin::VerifiedCreators(_) => PluginType::VerifiedCreators,
+            Plugin::Staking(_) => PluginType::Staking,
+            Plugin::Rental(_) => PluginType::Rental,
+            Plugin::UpdateApproval(_) => PluginType::UpdateApproval,
+            Plugin::Reaper(_) => PluginType::Reaper,
+            Plugin::TimeLock(_) => PluginType::TimeLock,
+            Plugin::UsageLimit(_) => PluginType::UsageLimit,
+            Plugin::Expiration(_) => PluginType::Expiration,
+            Plugin::TransferFee(_) => PluginType::TransferFee,
+            Plugin::BurnRentDestination(_) => PluginType::BurnRentDestination,
+            Plugin::TxGuard(_) => PluginType::TxGuard,
+            Plugin::OwnerAllowList(_) => PluginType::OwnerAllowList,
+            Plugin::Multisig(_) => PluginType::Multisig,
+            Plugin::LinkedPair(_) => PluginType::LinkedPair,
+            Plugin::Redemption(_) => PluginType::Redemption,
+            Plugin::PhysicalClaim(_) => PluginType::PhysicalClaim,
+            Plugin::Listing(_) => PluginType::Listing,
+            Plugin::Ticket(_) => PluginType::Ticket,
+            Plugin::Progression(_) => PluginType::Progression,
+            Plugin::TokenLink(_) => PluginType::TokenLink,
+            Plugin::Fractionalized(_) => PluginType::Fractionalized,
+            Plugin::Score(_) => PluginType::Score,
+            Plugin::MintLimit(_) => PluginType::MintLimit,
+            Plugin::TransferApproval(_) => PluginType::TransferApproval,
+            Plugin::Provenance(_) => PluginType::Provenance,
         }
     }
 }
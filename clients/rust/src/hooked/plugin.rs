@@ -6,14 +6,20 @@ use num_traits::FromPrimitive;
 use solana_program::account_info::AccountInfo;
 
 use crate::{
-    accounts::{BaseAssetV1, PluginHeaderV1},
+    accounts::{BaseAssetV1, BaseCollectionV1, PluginHeaderV1},
     errors::MplCoreError,
-    types::{Plugin, PluginAuthority, PluginType, RegistryRecord},
-    AddBlockerPlugin, AttributesPlugin, BaseAuthority, BasePlugin, BurnDelegatePlugin, DataBlob,
-    EditionPlugin, FreezeDelegatePlugin, ImmutableMetadataPlugin, MasterEditionPlugin,
+    types::{Key, Plugin, PluginAuthority, PluginType, RegistryRecord},
+    AddBlockerPlugin, AttributesPlugin, AutographPlugin, BaseAuthority, BasePlugin,
+    BoundUntilPlugin, BurnDelegatePlugin, BurnRentDestinationPlugin, CollectionJoinRequestPlugin,
+    DataBlob, EditionPlugin, ExpirationPlugin, FractionalizedPlugin, FreezeDelegatePlugin,
+    GroupingsPlugin, ImmutableMetadataPlugin, LinkedPairPlugin, ListingPlugin, MasterEditionPlugin,
+    MintLimitPlugin, MultisigPlugin, OwnerAllowListPlugin, PausedPlugin,
     PermanentBurnDelegatePlugin, PermanentFreezeDelegatePlugin, PermanentTransferDelegatePlugin,
-    PluginRegistryV1Safe, PluginsList, RegistryRecordSafe, RoyaltiesPlugin, SolanaAccount,
-    TransferDelegatePlugin, UpdateDelegatePlugin,
+    PhysicalClaimPlugin, PluginRegistryV1Safe, PluginsList, ProgressionPlugin, ProvenancePlugin,
+    ReaperPlugin, RedemptionPlugin, RegistryRecordSafe, RentalPlugin, RoyaltiesPlugin, ScorePlugin,
+    SolanaAccount, StakingPlugin, TicketPlugin, TimeLockPlugin, TokenLinkPlugin,
+    TransferApprovalPlugin, TransferDelegatePlugin, TransferFeePlugin, TxGuardPlugin,
+    UpdateApprovalPlugin, UpdateDelegatePlugin, UsageLimitPlugin, VerifiedCreatorsPlugin,
 };
 
 /// Fetch the plugin from the registry.
@@ -21,62 +27,58 @@ pub fn fetch_plugin<T: DataBlob + SolanaAccount, U: CrateDeserialize>(
     account: &AccountInfo,
     plugin_type: PluginType,
 ) -> Result<(PluginAuthority, U, usize), std::io::Error> {
-    let asset = T::load(account, 0)?;
+    // Check that the account is of the expected type before reading its plugins.
+    T::load(account, 0)?;
 
-    if asset.get_size() == account.data_len() {
-        return Err(std::io::Error::new(
-            std::io::ErrorKind::Other,
-            MplCoreError::PluginNotFound.to_string(),
-        ));
+    fetch_plugin_from_data(&(*account.data).borrow(), plugin_type)
+}
+
+/// Fetch a plugin and its authority and offset from the raw data of an asset or collection
+/// account, such as the data returned by an RPC node.
+pub fn fetch_plugin_from_data<U: CrateDeserialize>(
+    account_data: &[u8],
+    plugin_type: PluginType,
+) -> Result<(PluginAuthority, U, usize), std::io::Error> {
+    let plugin_not_found = || std::io::Error::other(MplCoreError::PluginNotFound.to_string());
+
+    // The plugin header follows the asset or collection.
+    let header_offset = match account_data.first().and_then(|key| Key::from_u8(*key)) {
+        Some(Key::AssetV1) => BaseAssetV1::from_bytes(account_data)?.get_size(),
+        Some(Key::CollectionV1) => BaseCollectionV1::from_bytes(account_data)?.get_size(),
+        _ => return Err(std::io::ErrorKind::InvalidInput.into()),
+    };
+
+    if header_offset == account_data.len() {
+        return Err(plugin_not_found());
     }
 
-    let header = PluginHeaderV1::from_bytes(&(*account.data).borrow()[asset.get_size()..])?;
+    let header = PluginHeaderV1::from_bytes(&account_data[header_offset..])?;
     let plugin_registry = PluginRegistryV1Safe::from_bytes(
-        &(*account.data).borrow()[header.plugin_registry_offset as usize..],
+        &account_data[(header.plugin_registry_offset as usize)..],
     )?;
 
     // Find the plugin in the registry.
+    let plugin_type = plugin_type as u8;
     let registry_record = plugin_registry
         .registry
         .iter()
-        .find(|record| {
-            if let Some(plugin) = PluginType::from_u8(record.plugin_type) {
-                plugin == plugin_type
-            } else {
-                false
-            }
-        })
-        .ok_or(std::io::Error::new(
-            std::io::ErrorKind::Other,
-            MplCoreError::PluginNotFound.to_string(),
-        ))?;
-
-    // Deserialize the plugin.
-    let plugin =
-        Plugin::deserialize(&mut &(*account.data).borrow()[(registry_record.offset as usize)..])?;
-
-    if PluginType::from(&plugin) != plugin_type {
-        return Err(std::io::Error::new(
-            std::io::ErrorKind::Other,
-            MplCoreError::PluginNotFound.to_string(),
-        ));
+        .find(|record| record.plugin_type == plugin_type)
+        .ok_or_else(plugin_not_found)?;
+
+    // The plugin is stored as its type followed by its data.
+    let offset = registry_record.offset as usize;
+    if account_data.get(offset) != Some(&plugin_type) {
+        return Err(plugin_not_found());
     }
 
     let inner = U::deserialize(
-        &mut &(*account.data).borrow()[registry_record.offset.checked_add(1).ok_or(
-            std::io::Error::new(
-                std::io::ErrorKind::Other,
-                MplCoreError::NumericalOverflow.to_string(),
-            ),
-        )? as usize..],
+        &mut account_data
+            .get(offset + 1..)
+            .ok_or_else(plugin_not_found)?,
     )?;
 
     // Return the plugin and its authority.
-    Ok((
-        registry_record.authority.clone(),
-        inner,
-        registry_record.offset as usize,
-    ))
+    Ok((registry_record.authority.clone(), inner, offset))
 }
 
 /// Fetch the plugin registry, dropping any unknown plugins (i.e. `PluginType`s that are too new
@@ -139,75 +141,170 @@ pub(crate) fn registry_records_to_plugin_list(
                 };
                 let plugin = Plugin::deserialize(&mut &account_data[record.offset as usize..])?;
 
-                match plugin {
-                    Plugin::Royalties(royalties) => {
-                        acc.royalties = Some(RoyaltiesPlugin { base, royalties });
-                    }
-                    Plugin::FreezeDelegate(freeze_delegate) => {
-                        acc.freeze_delegate = Some(FreezeDelegatePlugin {
-                            base,
-                            freeze_delegate,
-                        });
-                    }
-                    Plugin::BurnDelegate(burn_delegate) => {
-                        acc.burn_delegate = Some(BurnDelegatePlugin {
-                            base,
-                            burn_delegate,
-                        });
-                    }
-                    Plugin::TransferDelegate(transfer_delegate) => {
-                        acc.transfer_delegate = Some(TransferDelegatePlugin {
-                            base,
-                            transfer_delegate,
-                        });
-                    }
-                    Plugin::UpdateDelegate(update_delegate) => {
-                        acc.update_delegate = Some(UpdateDelegatePlugin {
-                            base,
-                            update_delegate,
-                        });
-                    }
-                    Plugin::PermanentFreezeDelegate(permanent_freeze_delegate) => {
-                        acc.permanent_freeze_delegate = Some(PermanentFreezeDelegatePlugin {
-                            base,
-                            permanent_freeze_delegate,
-                        });
-                    }
-                    Plugin::Attributes(attributes) => {
-                        acc.attributes = Some(AttributesPlugin { base, attributes });
-                    }
-                    Plugin::PermanentTransferDelegate(permanent_transfer_delegate) => {
-                        acc.permanent_transfer_delegate = Some(PermanentTransferDelegatePlugin {
-                            base,
-                            permanent_transfer_delegate,
-                        })
-                    }
-                    Plugin::PermanentBurnDelegate(permanent_burn_delegate) => {
-                        acc.permanent_burn_delegate = Some(PermanentBurnDelegatePlugin {
-                            base,
-                            permanent_burn_delegate,
-                        })
-                    }
-                    Plugin::Edition(edition) => acc.edition = Some(EditionPlugin { base, edition }),
-                    Plugin::MasterEdition(master_edition) => {
-                        acc.master_edition = Some(MasterEditionPlugin {
-                            base,
-                            master_edition,
-                        })
-                    }
-                    Plugin::AddBlocker(add_blocker) => {
-                        acc.add_blocker = Some(AddBlockerPlugin { base, add_blocker })
-                    }
-                    Plugin::ImmutableMetadata(immutable_metadata) => {
-                        acc.immutable_metadata = Some(ImmutableMetadataPlugin {
-                            base,
-                            immutable_metadata,
-                        })
-                    }
-                }
+                add_plugin_to_list(&mut acc, base, plugin);
             }
             Ok(acc)
         });
 
     result
 }
+
+// Add a deserialized plugin to the `PluginsList` type.
+pub(crate) fn add_plugin_to_list(acc: &mut PluginsList, base: BasePlugin, plugin: Plugin) {
+    match plugin {
+        Plugin::Royalties(royalties) => {
+            acc.royalties = Some(RoyaltiesPlugin { base, royalties });
+        }
+        Plugin::FreezeDelegate(freeze_delegate) => {
+            acc.freeze_delegate = Some(FreezeDelegatePlugin {
+                base,
+                freeze_delegate,
+            });
+        }
+        Plugin::BurnDelegate(burn_delegate) => {
+            acc.burn_delegate = Some(BurnDelegatePlugin {
+                base,
+                burn_delegate,
+            });
+        }
+        Plugin::TransferDelegate(transfer_delegate) => {
+            acc.transfer_delegate = Some(TransferDelegatePlugin {
+                base,
+                transfer_delegate,
+            });
+        }
+        Plugin::UpdateDelegate(update_delegate) => {
+            acc.update_delegate = Some(UpdateDelegatePlugin {
+                base,
+                update_delegate,
+            });
+        }
+        Plugin::PermanentFreezeDelegate(permanent_freeze_delegate) => {
+            acc.permanent_freeze_delegate = Some(PermanentFreezeDelegatePlugin {
+                base,
+                permanent_freeze_delegate,
+            });
+        }
+        Plugin::Attributes(attributes) => {
+            acc.attributes = Some(AttributesPlugin { base, attributes });
+        }
+        Plugin::PermanentTransferDelegate(permanent_transfer_delegate) => {
+            acc.permanent_transfer_delegate = Some(PermanentTransferDelegatePlugin {
+                base,
+                permanent_transfer_delegate,
+            })
+        }
+        Plugin::PermanentBurnDelegate(permanent_burn_delegate) => {
+            acc.permanent_burn_delegate = Some(PermanentBurnDelegatePlugin {
+                base,
+                permanent_burn_delegate,
+            })
+        }
+        Plugin::Edition(edition) => acc.edition = Some(EditionPlugin { base, edition }),
+        Plugin::MasterEdition(master_edition) => {
+            acc.master_edition = Some(MasterEditionPlugin {
+                base,
+                master_edition,
+            })
+        }
+        Plugin::AddBlocker(add_blocker) => {
+            acc.add_blocker = Some(AddBlockerPlugin { base, add_blocker })
+        }
+        Plugin::ImmutableMetadata(immutable_metadata) => {
+            acc.immutable_metadata = Some(ImmutableMetadataPlugin {
+                base,
+                immutable_metadata,
+            })
+        }
+        Plugin::Groupings(groupings) => acc.groupings = Some(GroupingsPlugin { base, groupings }),
+        Plugin::CollectionJoinRequest(collection_join_request) => {
+            acc.collection_join_request = Some(CollectionJoinRequestPlugin {
+                base,
+                collection_join_request,
+            })
+        }
+        Plugin::Paused(paused) => acc.paused = Some(PausedPlugin { base, paused }),
+        Plugin::Autograph(autograph) => acc.autograph = Some(AutographPlugin { base, autograph }),
+        Plugin::BoundUntil(bound_until) => {
+            acc.bound_until = Some(BoundUntilPlugin { base, bound_until })
+        }
+        Plugin::VerifiedCreators(verified_creators) => {
+            acc.verified_creators = Some(VerifiedCreatorsPlugin {
+                base,
+                verified_creators,
+            })
+        }
+        Plugin::Staking(staking) => acc.staking = Some(StakingPlugin { base, staking }),
+        Plugin::Rental(rental) => acc.rental = Some(RentalPlugin { base, rental }),
+        Plugin::UpdateApproval(update_approval) => {
+            acc.update_approval = Some(UpdateApprovalPlugin {
+                base,
+                update_approval,
+            })
+        }
+        Plugin::Reaper(reaper) => acc.reaper = Some(ReaperPlugin { base, reaper }),
+        Plugin::TimeLock(time_lock) => acc.time_lock = Some(TimeLockPlugin { base, time_lock }),
+        Plugin::UsageLimit(usage_limit) => {
+            acc.usage_limit = Some(UsageLimitPlugin { base, usage_limit })
+        }
+        Plugin::Expiration(expiration) => {
+            acc.expiration = Some(ExpirationPlugin { base, expiration })
+        }
+        Plugin::TransferFee(transfer_fee) => {
+            acc.transfer_fee = Some(TransferFeePlugin { base, transfer_fee })
+        }
+        Plugin::BurnRentDestination(burn_rent_destination) => {
+            acc.burn_rent_destination = Some(BurnRentDestinationPlugin {
+                base,
+                burn_rent_destination,
+            })
+        }
+        Plugin::TxGuard(tx_guard) => acc.tx_guard = Some(TxGuardPlugin { base, tx_guard }),
+        Plugin::OwnerAllowList(owner_allow_list) => {
+            acc.owner_allow_list = Some(OwnerAllowListPlugin {
+                base,
+                owner_allow_list,
+            })
+        }
+        Plugin::Multisig(multisig) => acc.multisig = Some(MultisigPlugin { base, multisig }),
+        Plugin::LinkedPair(linked_pair) => {
+            acc.linked_pair = Some(LinkedPairPlugin { base, linked_pair })
+        }
+        Plugin::Redemption(redemption) => {
+            acc.redemption = Some(RedemptionPlugin { base, redemption })
+        }
+        Plugin::PhysicalClaim(physical_claim) => {
+            acc.physical_claim = Some(PhysicalClaimPlugin {
+                base,
+                physical_claim,
+            })
+        }
+        Plugin::Listing(listing) => acc.listing = Some(ListingPlugin { base, listing }),
+        Plugin::Ticket(ticket) => acc.ticket = Some(TicketPlugin { base, ticket }),
+        Plugin::Progression(progression) => {
+            acc.progression = Some(ProgressionPlugin { base, progression })
+        }
+        Plugin::TokenLink(token_link) => {
+            acc.token_link = Some(TokenLinkPlugin { base, token_link })
+        }
+        Plugin::Fractionalized(fractionalized) => {
+            acc.fractionalized = Some(FractionalizedPlugin {
+                base,
+                fractionalized,
+            })
+        }
+        Plugin::Score(score) => acc.score = Some(ScorePlugin { base, score }),
+        Plugin::MintLimit(mint_limit) => {
+            acc.mint_limit = Some(MintLimitPlugin { base, mint_limit })
+        }
+        Plugin::TransferApproval(transfer_approval) => {
+            acc.transfer_approval = Some(TransferApprovalPlugin {
+                base,
+                transfer_approval,
+            })
+        }
+        Plugin::Provenance(provenance) => {
+            acc.provenance = Some(ProvenancePlugin { base, provenance })
+        }
+    }
+}
@@ -0,0 +1,178 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{AddExternalPluginV1Builder, TransferV1Builder, WriteAppDataV1Builder},
+    types::{
+        AppDataInitInfo, ExternalPluginInitInfo, ExternalPluginSchema, ExternalPluginType,
+        PluginAuthority,
+    },
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset owned by the context payer with app data written by `data_authority`.
+async fn create_asset_with_app_data(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    data_authority: Pubkey,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let add_external_plugin_ix = AddExternalPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .init_info(ExternalPluginInitInfo::AppData(AppDataInitInfo {
+            data_authority: PluginAuthority::Address {
+                address: data_authority,
+            },
+            schema: ExternalPluginSchema::Binary,
+        }))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_external_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+fn write_app_data_tx(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    authority: &Keypair,
+    data_authority: Pubkey,
+    data: Vec<u8>,
+) -> Transaction {
+    let write_app_data_ix = WriteAppDataV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .data_authority(PluginAuthority::Address {
+            address: data_authority,
+        })
+        .data(data)
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[write_app_data_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn data_authority_writes_app_data() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let data_authority = Keypair::new();
+    create_asset_with_app_data(&mut context, &asset, data_authority.pubkey()).await;
+
+    let tx = write_app_data_tx(
+        &context,
+        asset.pubkey(),
+        &data_authority,
+        data_authority.pubkey(),
+        vec![1, 2, 3, 4],
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let data = get_external_plugin_data(
+        &mut context,
+        asset.pubkey(),
+        ExternalPluginType::AppData,
+        PluginAuthority::Address {
+            address: data_authority.pubkey(),
+        },
+    )
+    .await;
+    assert_eq!(data, vec![1, 2, 3, 4]);
+
+    // Writing again replaces the data, shrinking it.
+    let tx = write_app_data_tx(
+        &context,
+        asset.pubkey(),
+        &data_authority,
+        data_authority.pubkey(),
+        vec![5, 6],
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let data = get_external_plugin_data(
+        &mut context,
+        asset.pubkey(),
+        ExternalPluginType::AppData,
+        PluginAuthority::Address {
+            address: data_authority.pubkey(),
+        },
+    )
+    .await;
+    assert_eq!(data, vec![5, 6]);
+}
+
+#[tokio::test]
+async fn cannot_write_app_data_without_data_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let data_authority = Keypair::new();
+    create_asset_with_app_data(&mut context, &asset, data_authority.pubkey()).await;
+
+    // Not even the owner and update authority can write the data.
+    let owner = context.payer.insecure_clone();
+    let tx = write_app_data_tx(
+        &context,
+        asset.pubkey(),
+        &owner,
+        data_authority.pubkey(),
+        vec![1, 2, 3, 4],
+    );
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+}
+
+#[tokio::test]
+async fn data_authority_cannot_transfer_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let data_authority = Keypair::new();
+    create_asset_with_app_data(&mut context, &asset, data_authority.pubkey()).await;
+
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(data_authority.pubkey()))
+        .new_owner(data_authority.pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &data_authority],
+        context.last_blockhash,
+    );
+    assert_program_error(&mut context, tx, MplCoreError::NoApprovals).await;
+}
@@ -0,0 +1,178 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{AddExternalPluginV1Builder, WriteAppDataChunkV1Builder},
+    types::{
+        AppDataInitInfo, ExternalPluginInitInfo, ExternalPluginSchema, ExternalPluginType,
+        PluginAuthority,
+    },
+};
+pub use setup::*;
+
+use solana_program::{entrypoint::MAX_PERMITTED_DATA_INCREASE, pubkey::Pubkey};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// The data written by each instruction, small enough to fit in a transaction.
+const CHUNK_SIZE: usize = 700;
+
+/// Create an asset with app data written by `data_authority`.
+async fn create_asset_with_app_data(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    data_authority: Pubkey,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let add_external_plugin_ix = AddExternalPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .init_info(ExternalPluginInitInfo::AppData(AppDataInitInfo {
+            data_authority: PluginAuthority::Address {
+                address: data_authority,
+            },
+            schema: ExternalPluginSchema::Binary,
+        }))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_external_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+fn write_chunk_tx(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    authority: &Keypair,
+    data_authority: Pubkey,
+    offset: u64,
+    data: Vec<u8>,
+) -> Transaction {
+    let write_chunk_ix = WriteAppDataChunkV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .data_authority(PluginAuthority::Address {
+            address: data_authority,
+        })
+        .offset(offset)
+        .data(data)
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[write_chunk_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn data_authority_writes_app_data_past_realloc_limit_in_chunks() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let data_authority = Keypair::new();
+    create_asset_with_app_data(&mut context, &asset, data_authority.pubkey()).await;
+
+    // More data than an account can grow by in one instruction.
+    let data: Vec<u8> = (0..MAX_PERMITTED_DATA_INCREASE + CHUNK_SIZE)
+        .map(|i| i as u8)
+        .collect();
+    for (i, chunk) in data.chunks(CHUNK_SIZE).enumerate() {
+        let tx = write_chunk_tx(
+            &context,
+            asset.pubkey(),
+            &data_authority,
+            data_authority.pubkey(),
+            (i * CHUNK_SIZE) as u64,
+            chunk.to_vec(),
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+    }
+
+    // Rewriting a chunk in place does not grow the data.
+    let tx = write_chunk_tx(
+        &context,
+        asset.pubkey(),
+        &data_authority,
+        data_authority.pubkey(),
+        0,
+        vec![0xff; 4],
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let mut expected = data;
+    expected[..4].copy_from_slice(&[0xff; 4]);
+    let app_data = get_external_plugin_data(
+        &mut context,
+        asset.pubkey(),
+        ExternalPluginType::AppData,
+        PluginAuthority::Address {
+            address: data_authority.pubkey(),
+        },
+    )
+    .await;
+    assert_eq!(app_data, expected);
+}
+
+#[tokio::test]
+async fn cannot_write_chunk_past_end_of_app_data() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let data_authority = Keypair::new();
+    create_asset_with_app_data(&mut context, &asset, data_authority.pubkey()).await;
+
+    // Chunks must not leave a gap after the existing data.
+    let tx = write_chunk_tx(
+        &context,
+        asset.pubkey(),
+        &data_authority,
+        data_authority.pubkey(),
+        1,
+        vec![1, 2, 3, 4],
+    );
+    assert_program_error(&mut context, tx, MplCoreError::InvalidDataOffset).await;
+}
+
+#[tokio::test]
+async fn cannot_write_chunk_without_data_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let data_authority = Keypair::new();
+    create_asset_with_app_data(&mut context, &asset, data_authority.pubkey()).await;
+
+    let update_authority = context.payer.insecure_clone();
+    let tx = write_chunk_tx(
+        &context,
+        asset.pubkey(),
+        &update_authority,
+        data_authority.pubkey(),
+        0,
+        vec![1, 2, 3, 4],
+    );
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::AssertOwnershipV1Builder,
+    types::{FreezeDelegate, Plugin, PluginAuthorityPair},
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+async fn create_owned_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    owner: Pubkey,
+    plugins: Vec<PluginAuthorityPair>,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: Some(owner),
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins,
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn assert_ownership_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    owner: Pubkey,
+    require_unfrozen: bool,
+) -> Transaction {
+    let assert_ownership_ix = AssertOwnershipV1Builder::new()
+        .asset(asset.pubkey())
+        .owner(owner)
+        .require_unfrozen(require_unfrozen)
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[assert_ownership_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn assert_ownership_of_owned_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let owner = Keypair::new().pubkey();
+    create_owned_asset(&mut context, &asset, owner, vec![]).await;
+
+    let tx = assert_ownership_tx(&context, &asset, owner, true);
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn cannot_assert_ownership_for_another_owner() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_owned_asset(&mut context, &asset, Keypair::new().pubkey(), vec![]).await;
+
+    let tx = assert_ownership_tx(&context, &asset, Keypair::new().pubkey(), false);
+    assert_program_error(&mut context, tx, MplCoreError::InvalidOwner).await;
+}
+
+#[tokio::test]
+async fn cannot_assert_unfrozen_ownership_of_frozen_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let owner = Keypair::new().pubkey();
+    create_owned_asset(
+        &mut context,
+        &asset,
+        owner,
+        vec![PluginAuthorityPair {
+            plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: true }),
+            authority: None,
+        }],
+    )
+    .await;
+
+    // The owner still holds a frozen asset, it just cannot be used as unfrozen.
+    let tx = assert_ownership_tx(&context, &asset, owner, false);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = assert_ownership_tx(&context, &asset, owner, true);
+    assert_program_error(&mut context, tx, MplCoreError::AssetIsFrozen).await;
+}
@@ -0,0 +1,176 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    asset_flags,
+    instructions::UpdateCollectionPluginV1Builder,
+    types::{
+        FreezeDelegate, Paused, PermanentFreezeDelegate, PermanentTransferDelegate, Plugin,
+        PluginAuthority, PluginAuthorityPair,
+    },
+    ASSET_FLAG_FROZEN, ASSET_FLAG_PERMANENT_DELEGATES, ASSET_FLAG_SOULBOUND,
+};
+pub use setup::*;
+
+use solana_program_test::tokio;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+#[tokio::test]
+async fn asset_without_plugins_has_no_flags() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    assert_eq!(asset_flags(&asset_account.data, None).unwrap(), 0);
+}
+
+#[tokio::test]
+async fn asset_flags_are_derived_from_plugins() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![
+                PluginAuthorityPair {
+                    plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+                    authority: None,
+                },
+                PluginAuthorityPair {
+                    plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate {
+                        frozen: true,
+                    }),
+                    authority: Some(PluginAuthority::None),
+                },
+            ],
+        },
+    )
+    .await
+    .unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    assert_eq!(
+        asset_flags(&asset_account.data, None).unwrap(),
+        ASSET_FLAG_FROZEN | ASSET_FLAG_SOULBOUND | ASSET_FLAG_PERMANENT_DELEGATES
+    );
+}
+
+#[tokio::test]
+async fn asset_flags_include_collection_plugins() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![
+                PluginAuthorityPair {
+                    plugin: Plugin::PermanentTransferDelegate(PermanentTransferDelegate {
+                        sunset: None,
+                    }),
+                    authority: None,
+                },
+                PluginAuthorityPair {
+                    plugin: Plugin::Paused(Paused { paused: false }),
+                    authority: None,
+                },
+            ],
+        },
+    )
+    .await
+    .unwrap();
+
+    // The collection's plugins apply to the asset, which has none of its own.
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // Assets cannot be minted into a paused collection, so it is paused afterwards.
+    let update_plugin_ix = UpdateCollectionPluginV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin(Plugin::Paused(Paused { paused: true }))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[update_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let collection_account = context
+        .banks_client
+        .get_account(collection.pubkey())
+        .await
+        .expect("get_account")
+        .expect("collection account not found");
+    assert_eq!(asset_flags(&asset_account.data, None).unwrap(), 0);
+    assert_eq!(
+        asset_flags(&asset_account.data, Some(&collection_account.data)).unwrap(),
+        ASSET_FLAG_FROZEN | ASSET_FLAG_PERMANENT_DELEGATES
+    );
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    instructions::UpdatePluginV1Builder,
+    types::{Autograph, AutographSignature, Plugin, PluginAuthorityPair, PluginType},
+};
+pub use setup::*;
+
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset owned by the context payer with the given autograph signatures.
+async fn create_signed_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    signatures: Vec<AutographSignature>,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Autograph(Autograph { signatures }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn update_autograph_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    authority: &Keypair,
+    signatures: Vec<AutographSignature>,
+) -> Transaction {
+    let update_plugin_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .plugin(Plugin::Autograph(Autograph { signatures }))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[update_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+fn signature(signer: &Keypair, message: &str) -> AutographSignature {
+    AutographSignature {
+        address: signer.pubkey(),
+        message: message.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn signer_adds_their_autograph() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_signed_asset(&mut context, &asset, vec![]).await;
+
+    let artist = Keypair::new();
+    let signatures = vec![signature(&artist, "Signed by the artist")];
+    let tx = update_autograph_tx(&context, &asset, &artist, signatures.clone());
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        asset.plugin_list.autograph.unwrap().autograph,
+        Autograph { signatures }
+    );
+}
+
+#[tokio::test]
+async fn cannot_add_autograph_for_another_signer() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_signed_asset(&mut context, &asset, vec![]).await;
+
+    let signer = Keypair::new();
+    let signatures = vec![signature(&Keypair::new(), "Not my autograph")];
+    let tx = update_autograph_tx(&context, &asset, &signer, signatures);
+    assert_rejected_by(&mut context, tx, PluginType::Autograph).await;
+}
+
+#[tokio::test]
+async fn only_owner_removes_autographs() {
+    let mut context = program_test().start_with_context().await;
+
+    let artist = Keypair::new();
+    let asset = Keypair::new();
+    create_signed_asset(&mut context, &asset, vec![]).await;
+
+    let tx = update_autograph_tx(
+        &context,
+        &asset,
+        &artist,
+        vec![signature(&artist, "Signed by the artist")],
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The artist cannot take their signature back.
+    let tx = update_autograph_tx(&context, &asset, &artist, vec![]);
+    assert_rejected_by(&mut context, tx, PluginType::Autograph).await;
+
+    let owner = context.payer.insecure_clone();
+    let tx = update_autograph_tx(&context, &asset, &owner, vec![]);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        asset.plugin_list.autograph.unwrap().autograph,
+        Autograph { signatures: vec![] }
+    );
+}
@@ -0,0 +1,516 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use borsh::BorshDeserialize;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{AirdropV1Builder, BurnV1Builder, TransferBatchV1Builder},
+    types::BatchItemStatus,
+};
+pub use setup::*;
+
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    account::Account, instruction::AccountMeta, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    system_program, transaction::Transaction,
+};
+
+/// Process a transaction, returning the batch item statuses it set as return data.
+async fn process_batch(context: &mut ProgramTestContext, tx: Transaction) -> Vec<BatchItemStatus> {
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .expect("process_transaction_with_metadata");
+    result.result.expect("batch failed");
+
+    let return_data = result
+        .metadata
+        .expect("transaction metadata")
+        .return_data
+        .expect("return data");
+    assert_eq!(return_data.program_id, mpl_core::ID);
+    Vec::<BatchItemStatus>::try_from_slice(&return_data.data).unwrap()
+}
+
+/// Write an initialized SPL Token mint with `decimals` into account state.
+async fn set_mint(context: &mut ProgramTestContext, mint: Pubkey, supply: u64, decimals: u8) {
+    // The optional mint authority, then the supply, the decimals and the initialized flag.
+    let mut data = vec![0; 82];
+    data[36..44].copy_from_slice(&supply.to_le_bytes());
+    data[44] = decimals;
+    data[45] = 1;
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    context.set_account(
+        &mint,
+        &Account {
+            lamports: rent.minimum_balance(data.len()),
+            data,
+            owner: SPL_TOKEN_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+}
+
+async fn token_amount(context: &mut ProgramTestContext, token_account: Pubkey) -> u64 {
+    let account = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    u64::from_le_bytes(account.data[64..72].try_into().unwrap())
+}
+
+async fn create_owned_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    owner: Option<Pubkey>,
+    collection: Option<Pubkey>,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn transfer_batch_skips_assets_already_transferred() {
+    let mut context = program_test().start_with_context().await;
+
+    let new_owner = Keypair::new();
+    let asset = Keypair::new();
+    let transferred_asset = Keypair::new();
+    create_owned_asset(&mut context, &asset, None, None).await;
+    create_owned_asset(
+        &mut context,
+        &transferred_asset,
+        Some(new_owner.pubkey()),
+        None,
+    )
+    .await;
+
+    let transfer_batch_ix = TransferBatchV1Builder::new()
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner.pubkey())
+        .asset_count(2)
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new_readonly(mpl_core::ID, false),
+            AccountMeta::new(transferred_asset.pubkey(), false),
+            AccountMeta::new_readonly(mpl_core::ID, false),
+        ])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_batch_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        process_batch(&mut context, tx).await,
+        vec![BatchItemStatus::Success, BatchItemStatus::Skipped]
+    );
+}
+
+#[tokio::test]
+async fn cannot_transfer_batch_with_asset_of_another_owner() {
+    let mut context = program_test().start_with_context().await;
+
+    let new_owner = Keypair::new();
+    let asset = Keypair::new();
+    let other_asset = Keypair::new();
+    create_owned_asset(&mut context, &asset, None, None).await;
+    create_owned_asset(
+        &mut context,
+        &other_asset,
+        Some(Keypair::new().pubkey()),
+        None,
+    )
+    .await;
+
+    let transfer_batch_ix = TransferBatchV1Builder::new()
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner.pubkey())
+        .asset_count(2)
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new_readonly(mpl_core::ID, false),
+            AccountMeta::new(other_asset.pubkey(), false),
+            AccountMeta::new_readonly(mpl_core::ID, false),
+        ])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_batch_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+}
+
+#[tokio::test]
+async fn airdrop_skips_burned_assets() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let owner = Keypair::new();
+    let asset = Keypair::new();
+    let burned_asset = Keypair::new();
+    create_owned_asset(
+        &mut context,
+        &asset,
+        Some(owner.pubkey()),
+        Some(collection.pubkey()),
+    )
+    .await;
+    create_owned_asset(&mut context, &burned_asset, None, Some(collection.pubkey())).await;
+
+    let burn_ix = BurnV1Builder::new()
+        .asset(burned_asset.pubkey())
+        .collection(Some(collection.pubkey()))
+        .payer(context.payer.pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let airdrop_ix = AirdropV1Builder::new()
+        .collection(collection.pubkey())
+        .vault(context.payer.pubkey())
+        .system_program(system_program::ID)
+        .amount_per_asset(1_000_000)
+        .add_remaining_accounts(&[
+            AccountMeta::new_readonly(asset.pubkey(), false),
+            AccountMeta::new(owner.pubkey(), false),
+            AccountMeta::new_readonly(burned_asset.pubkey(), false),
+            AccountMeta::new(context.payer.pubkey(), false),
+        ])
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[airdrop_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        process_batch(&mut context, tx).await,
+        vec![BatchItemStatus::Success, BatchItemStatus::Skipped]
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .get_balance(owner.pubkey())
+            .await
+            .unwrap(),
+        1_000_000
+    );
+}
+
+#[tokio::test]
+async fn cannot_airdrop_to_previous_owner() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let asset = Keypair::new();
+    create_owned_asset(
+        &mut context,
+        &asset,
+        Some(Keypair::new().pubkey()),
+        Some(collection.pubkey()),
+    )
+    .await;
+
+    let airdrop_ix = AirdropV1Builder::new()
+        .collection(collection.pubkey())
+        .vault(context.payer.pubkey())
+        .system_program(system_program::ID)
+        .amount_per_asset(1_000_000)
+        .add_remaining_accounts(&[
+            AccountMeta::new_readonly(asset.pubkey(), false),
+            AccountMeta::new(Keypair::new().pubkey(), false),
+        ])
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[airdrop_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_program_error(&mut context, tx, MplCoreError::InvalidOwner).await;
+}
+
+#[tokio::test]
+async fn cannot_airdrop_without_collection_update_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let owner = Keypair::new();
+    let asset = Keypair::new();
+    create_owned_asset(
+        &mut context,
+        &asset,
+        Some(owner.pubkey()),
+        Some(collection.pubkey()),
+    )
+    .await;
+
+    let vault = Keypair::new();
+    airdrop(&mut context, &vault.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+
+    let airdrop_ix = AirdropV1Builder::new()
+        .collection(collection.pubkey())
+        .vault(vault.pubkey())
+        .system_program(system_program::ID)
+        .amount_per_asset(1_000_000)
+        .add_remaining_accounts(&[
+            AccountMeta::new_readonly(asset.pubkey(), false),
+            AccountMeta::new(owner.pubkey(), false),
+        ])
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[airdrop_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &vault],
+        context.last_blockhash,
+    );
+
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+}
+
+#[tokio::test]
+async fn airdrop_tokens() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let owner = Keypair::new();
+    let asset = Keypair::new();
+    create_owned_asset(
+        &mut context,
+        &asset,
+        Some(owner.pubkey()),
+        Some(collection.pubkey()),
+    )
+    .await;
+
+    let mint = Keypair::new().pubkey();
+    let vault_token_account = Keypair::new().pubkey();
+    let owner_token_account =
+        find_associated_token_address(&owner.pubkey(), &SPL_TOKEN_PROGRAM_ID, &mint);
+    let payer = context.payer.pubkey();
+    set_mint(&mut context, mint, 1_000_000, 6).await;
+    set_token_account(
+        &mut context,
+        vault_token_account,
+        SPL_TOKEN_PROGRAM_ID,
+        mint,
+        payer,
+        1_000_000,
+    )
+    .await;
+    set_token_account(
+        &mut context,
+        owner_token_account,
+        SPL_TOKEN_PROGRAM_ID,
+        mint,
+        owner.pubkey(),
+        0,
+    )
+    .await;
+
+    let airdrop_ix = AirdropV1Builder::new()
+        .collection(collection.pubkey())
+        .vault(context.payer.pubkey())
+        .system_program(system_program::ID)
+        .mint(Some(mint))
+        .vault_token_account(Some(vault_token_account))
+        .token_program(Some(SPL_TOKEN_PROGRAM_ID))
+        .amount_per_asset(1_000)
+        .add_remaining_accounts(&[
+            AccountMeta::new_readonly(asset.pubkey(), false),
+            AccountMeta::new_readonly(owner.pubkey(), false),
+            AccountMeta::new(owner_token_account, false),
+        ])
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[airdrop_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        process_batch(&mut context, tx).await,
+        vec![BatchItemStatus::Success]
+    );
+    assert_eq!(token_amount(&mut context, owner_token_account).await, 1_000);
+    assert_eq!(
+        token_amount(&mut context, vault_token_account).await,
+        999_000
+    );
+}
+
+#[tokio::test]
+async fn cannot_airdrop_tokens_to_other_token_account() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let owner = Keypair::new();
+    let asset = Keypair::new();
+    create_owned_asset(
+        &mut context,
+        &asset,
+        Some(owner.pubkey()),
+        Some(collection.pubkey()),
+    )
+    .await;
+
+    // A token account of the owner that is not their associated token account.
+    let mint = Keypair::new().pubkey();
+    let vault_token_account = Keypair::new().pubkey();
+    let other_token_account = Keypair::new().pubkey();
+    let payer = context.payer.pubkey();
+    set_mint(&mut context, mint, 1_000_000, 6).await;
+    set_token_account(
+        &mut context,
+        vault_token_account,
+        SPL_TOKEN_PROGRAM_ID,
+        mint,
+        payer,
+        1_000_000,
+    )
+    .await;
+    set_token_account(
+        &mut context,
+        other_token_account,
+        SPL_TOKEN_PROGRAM_ID,
+        mint,
+        owner.pubkey(),
+        0,
+    )
+    .await;
+
+    let airdrop_ix = AirdropV1Builder::new()
+        .collection(collection.pubkey())
+        .vault(context.payer.pubkey())
+        .system_program(system_program::ID)
+        .mint(Some(mint))
+        .vault_token_account(Some(vault_token_account))
+        .token_program(Some(SPL_TOKEN_PROGRAM_ID))
+        .amount_per_asset(1_000)
+        .add_remaining_accounts(&[
+            AccountMeta::new_readonly(asset.pubkey(), false),
+            AccountMeta::new_readonly(owner.pubkey(), false),
+            AccountMeta::new(other_token_account, false),
+        ])
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[airdrop_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_program_error(&mut context, tx, MplCoreError::IncorrectAccount).await;
+}
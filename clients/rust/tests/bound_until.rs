@@ -0,0 +1,138 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{TransferV1Builder, UnbindV1Builder},
+    types::{BoundUntil, Plugin, PluginAuthorityPair, PluginType},
+};
+pub use setup::*;
+
+use solana_program::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset owned by the context payer that is bound for the next day.
+async fn create_bound_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    unbind_fee: u64,
+    fee_recipient: Pubkey,
+) {
+    let clock = context
+        .banks_client
+        .get_sysvar::<solana_program::clock::Clock>()
+        .await
+        .unwrap();
+
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::BoundUntil(BoundUntil {
+                    unlock_at: clock.unix_timestamp + 86_400,
+                    unbind_fee,
+                    fee_recipient,
+                }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn unbind_tx(context: &ProgramTestContext, asset: &Keypair, fee_recipient: Pubkey) -> Transaction {
+    let unbind_ix = UnbindV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .fee_recipient(fee_recipient)
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[unbind_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn cannot_transfer_bound_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_bound_asset(&mut context, &asset, 0, Pubkey::default()).await;
+
+    assert_transfer_rejected_by(&mut context, asset.pubkey(), None, PluginType::BoundUntil).await;
+}
+
+#[tokio::test]
+async fn owner_pays_to_unbind_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let fee_recipient = Keypair::new().pubkey();
+    create_bound_asset(&mut context, &asset, LAMPORTS_PER_SOL, fee_recipient).await;
+
+    let tx = unbind_tx(&context, &asset, fee_recipient);
+    context.banks_client.process_transaction(tx).await.unwrap();
+    assert_eq!(
+        context
+            .banks_client
+            .get_balance(fee_recipient)
+            .await
+            .unwrap(),
+        LAMPORTS_PER_SOL
+    );
+
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(Keypair::new().pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn cannot_unbind_without_unbind_fee() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let fee_recipient = Keypair::new().pubkey();
+    create_bound_asset(&mut context, &asset, 0, fee_recipient).await;
+
+    let tx = unbind_tx(&context, &asset, fee_recipient);
+    assert_program_error(&mut context, tx, MplCoreError::CannotUnbind).await;
+}
+
+#[tokio::test]
+async fn cannot_unbind_to_another_fee_recipient() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_bound_asset(
+        &mut context,
+        &asset,
+        LAMPORTS_PER_SOL,
+        Keypair::new().pubkey(),
+    )
+    .await;
+
+    let tx = unbind_tx(&context, &asset, Keypair::new().pubkey());
+    assert_program_error(&mut context, tx, MplCoreError::IncorrectAccount).await;
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::BurnCompressedV1Builder,
+    types::{
+        CompressionProof, FreezeDelegate, HashablePluginSchema, Plugin, PluginAuthority,
+        PluginType, UpdateAuthority,
+    },
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// The compression proof of an asset owned by the context payer with a freeze delegate.
+fn freezable_compression_proof(context: &ProgramTestContext, frozen: bool) -> CompressionProof {
+    compression_proof(
+        context.payer.pubkey(),
+        UpdateAuthority::Address(context.payer.pubkey()),
+        vec![HashablePluginSchema {
+            index: 0,
+            authority: PluginAuthority::Owner,
+            plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen }),
+        }],
+    )
+}
+
+fn burn_compressed_tx(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    compression_proof: CompressionProof,
+) -> Transaction {
+    let burn_ix = BurnCompressedV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .compression_proof(compression_proof)
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn burn_compressed_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new().pubkey();
+    let compression_proof = freezable_compression_proof(&context, false);
+    create_hashed_asset(&mut context, asset, &compression_proof).await;
+
+    let tx = burn_compressed_tx(&context, asset, compression_proof);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset)
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    assert_eq!(asset_account.data.len(), 1);
+}
+
+#[tokio::test]
+async fn cannot_burn_compressed_asset_with_incorrect_proof() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new().pubkey();
+    let compression_proof = freezable_compression_proof(&context, false);
+    create_hashed_asset(&mut context, asset, &compression_proof).await;
+
+    let incorrect_proof = CompressionProof {
+        name: "Another Asset".to_owned(),
+        ..compression_proof
+    };
+    let tx = burn_compressed_tx(&context, asset, incorrect_proof);
+    assert_program_error(&mut context, tx, MplCoreError::IncorrectAssetHash).await;
+}
+
+#[tokio::test]
+async fn cannot_burn_frozen_compressed_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new().pubkey();
+    let compression_proof = freezable_compression_proof(&context, true);
+    create_hashed_asset(&mut context, asset, &compression_proof).await;
+
+    let tx = burn_compressed_tx(&context, asset, compression_proof);
+    assert_rejected_by(&mut context, tx, PluginType::FreezeDelegate).await;
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::BurnV1Builder,
+    types::{BurnRentDestination, Plugin, PluginAuthorityPair},
+};
+pub use setup::*;
+
+use solana_program::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::AccountMeta, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+/// Create a collection returning the rent of burned assets to `destination`, and an asset in it
+/// owned by the context payer.
+async fn create_asset_in_collection(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    collection: &Keypair,
+    destination: Pubkey,
+) {
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::BurnRentDestination(BurnRentDestination { destination }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn burn_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    collection: &Keypair,
+    remaining_accounts: &[AccountMeta],
+) -> Transaction {
+    let burn_ix = BurnV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(Some(collection.pubkey()))
+        .payer(context.payer.pubkey())
+        .add_remaining_accounts(remaining_accounts)
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn burn_returns_rent_to_destination() {
+    let mut context = program_test().start_with_context().await;
+
+    // The destination is funded so it stays rent exempt with any amount of returned rent.
+    let treasury = Keypair::new().pubkey();
+    airdrop(&mut context, &treasury, LAMPORTS_PER_SOL)
+        .await
+        .unwrap();
+
+    let asset = Keypair::new();
+    let collection = Keypair::new();
+    create_asset_in_collection(&mut context, &asset, &collection, treasury).await;
+    let asset_lamports = context
+        .banks_client
+        .get_balance(asset.pubkey())
+        .await
+        .unwrap();
+
+    let tx = burn_tx(
+        &context,
+        &asset,
+        &collection,
+        &[AccountMeta::new(treasury, false)],
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let returned_lamports = asset_lamports
+        - context
+            .banks_client
+            .get_balance(asset.pubkey())
+            .await
+            .unwrap();
+    assert_eq!(
+        context.banks_client.get_balance(treasury).await.unwrap(),
+        LAMPORTS_PER_SOL + returned_lamports
+    );
+}
+
+#[tokio::test]
+async fn cannot_burn_without_rent_destination() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let collection = Keypair::new();
+    create_asset_in_collection(&mut context, &asset, &collection, Keypair::new().pubkey()).await;
+
+    let tx = burn_tx(&context, &asset, &collection, &[]);
+    assert_program_error(&mut context, tx, MplCoreError::MissingBurnRentDestination).await;
+}
@@ -0,0 +1,285 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{UpdateCollectionPluginV1Builder, UpdateV2Builder},
+    types::{MintLimit, Paused, Plugin, PluginAuthorityPair, PluginType, UpdateAuthority},
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset in `collection`, and a second collection managed by `new_collection_authority`
+/// with `new_collection_plugins` for it to move to.
+async fn create_asset_and_collections(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    collection: &Keypair,
+    new_collection: &Keypair,
+    new_collection_authority: Pubkey,
+    new_collection_plugins: Vec<PluginAuthorityPair>,
+) {
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection: new_collection,
+            update_authority: Some(new_collection_authority),
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: new_collection_plugins,
+        },
+    )
+    .await
+    .unwrap();
+
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn change_collection_tx(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    collection: Pubkey,
+    new_collection: Pubkey,
+    new_collection_authority: &Keypair,
+) -> Transaction {
+    let update_ix = UpdateV2Builder::new()
+        .asset(asset)
+        .collection(Some(collection))
+        .payer(context.payer.pubkey())
+        .new_collection(Some(new_collection))
+        .new_collection_authority(Some(new_collection_authority.pubkey()))
+        .new_update_authority(UpdateAuthority::Collection(new_collection))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, new_collection_authority],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn move_asset_to_another_collection() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let collection = Keypair::new();
+    let new_collection = Keypair::new();
+    let new_collection_authority = Keypair::new();
+    create_asset_and_collections(
+        &mut context,
+        &asset,
+        &collection,
+        &new_collection,
+        new_collection_authority.pubkey(),
+        vec![],
+    )
+    .await;
+
+    let tx = change_collection_tx(
+        &context,
+        asset.pubkey(),
+        collection.pubkey(),
+        new_collection.pubkey(),
+        &new_collection_authority,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        fetched.base.update_authority,
+        UpdateAuthority::Collection(new_collection.pubkey())
+    );
+
+    let fetched_collection = get_collection(&mut context, collection.pubkey()).await;
+    assert_eq!(fetched_collection.base.current_size, 0);
+    let fetched_new_collection = get_collection(&mut context, new_collection.pubkey()).await;
+    assert_eq!(fetched_new_collection.base.current_size, 1);
+}
+
+#[tokio::test]
+async fn cannot_move_asset_without_new_collection_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let collection = Keypair::new();
+    let new_collection = Keypair::new();
+    create_asset_and_collections(
+        &mut context,
+        &asset,
+        &collection,
+        &new_collection,
+        Keypair::new().pubkey(),
+        vec![],
+    )
+    .await;
+
+    let tx = change_collection_tx(
+        &context,
+        asset.pubkey(),
+        collection.pubkey(),
+        new_collection.pubkey(),
+        &Keypair::new(),
+    );
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+}
+
+#[tokio::test]
+async fn move_asset_counts_against_new_collection_mint_limit() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let collection = Keypair::new();
+    let new_collection = Keypair::new();
+    let new_collection_authority = Keypair::new();
+    create_asset_and_collections(
+        &mut context,
+        &asset,
+        &collection,
+        &new_collection,
+        new_collection_authority.pubkey(),
+        vec![PluginAuthorityPair {
+            plugin: Plugin::MintLimit(MintLimit {
+                max_supply: 1,
+                minted: 0,
+            }),
+            authority: None,
+        }],
+    )
+    .await;
+
+    let tx = change_collection_tx(
+        &context,
+        asset.pubkey(),
+        collection.pubkey(),
+        new_collection.pubkey(),
+        &new_collection_authority,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let fetched_new_collection = get_collection(&mut context, new_collection.pubkey()).await;
+    assert_eq!(
+        fetched_new_collection
+            .plugin_list
+            .mint_limit
+            .unwrap()
+            .mint_limit,
+        MintLimit {
+            max_supply: 1,
+            minted: 1,
+        }
+    );
+}
+
+#[tokio::test]
+async fn cannot_move_asset_beyond_new_collection_mint_limit() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let collection = Keypair::new();
+    let new_collection = Keypair::new();
+    let new_collection_authority = Keypair::new();
+    create_asset_and_collections(
+        &mut context,
+        &asset,
+        &collection,
+        &new_collection,
+        new_collection_authority.pubkey(),
+        vec![PluginAuthorityPair {
+            plugin: Plugin::MintLimit(MintLimit {
+                max_supply: 0,
+                minted: 0,
+            }),
+            authority: None,
+        }],
+    )
+    .await;
+
+    let tx = change_collection_tx(
+        &context,
+        asset.pubkey(),
+        collection.pubkey(),
+        new_collection.pubkey(),
+        &new_collection_authority,
+    );
+    assert_program_error(&mut context, tx, MplCoreError::MintLimitReached).await;
+}
+
+#[tokio::test]
+async fn cannot_move_asset_into_paused_collection() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let collection = Keypair::new();
+    let new_collection = Keypair::new();
+    let new_collection_authority = Keypair::new();
+    create_asset_and_collections(
+        &mut context,
+        &asset,
+        &collection,
+        &new_collection,
+        new_collection_authority.pubkey(),
+        vec![PluginAuthorityPair {
+            plugin: Plugin::Paused(Paused { paused: false }),
+            authority: None,
+        }],
+    )
+    .await;
+
+    let pause_ix = UpdateCollectionPluginV1Builder::new()
+        .collection(new_collection.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(new_collection_authority.pubkey()))
+        .plugin(Plugin::Paused(Paused { paused: true }))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[pause_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &new_collection_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = change_collection_tx(
+        &context,
+        asset.pubkey(),
+        collection.pubkey(),
+        new_collection.pubkey(),
+        &new_collection_authority,
+    );
+    assert_rejected_by(&mut context, tx, PluginType::Paused).await;
+}
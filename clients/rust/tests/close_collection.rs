@@ -0,0 +1,103 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{errors::MplCoreError, instructions::CloseCollectionV1Builder};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+async fn create_empty_collection(context: &mut ProgramTestContext, collection: &Keypair) {
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn close_collection_tx(
+    context: &ProgramTestContext,
+    collection: Pubkey,
+    authority: &Keypair,
+) -> Transaction {
+    let close_collection_ix = CloseCollectionV1Builder::new()
+        .collection(collection)
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[close_collection_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn close_empty_collection() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_empty_collection(&mut context, &collection).await;
+
+    let update_authority = context.payer.insecure_clone();
+    let tx = close_collection_tx(&context, collection.pubkey(), &update_authority);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let collection_account = context
+        .banks_client
+        .get_account(collection.pubkey())
+        .await
+        .expect("get_account")
+        .expect("collection account not found");
+    assert_eq!(collection_account.data.len(), 1);
+}
+
+#[tokio::test]
+async fn cannot_close_collection_with_assets() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_empty_collection(&mut context, &collection).await;
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &Keypair::new(),
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let update_authority = context.payer.insecure_clone();
+    let tx = close_collection_tx(&context, collection.pubkey(), &update_authority);
+    assert_program_error(&mut context, tx, MplCoreError::CollectionNotEmpty).await;
+}
+
+#[tokio::test]
+async fn cannot_close_collection_without_update_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_empty_collection(&mut context, &collection).await;
+
+    let tx = close_collection_tx(&context, collection.pubkey(), &Keypair::new());
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+}
@@ -0,0 +1,280 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::ApproveCollectionJoinV1Builder,
+    types::{CollectionJoinRequest, MintLimit, Plugin, PluginAuthorityPair, UpdateAuthority},
+    Collection,
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    instruction::InstructionError,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+
+/// Create a collection with a `MintLimit` plugin and one member, and an asset outside of the
+/// collection requesting to join it.
+async fn create_collection_and_request(
+    context: &mut ProgramTestContext,
+    collection: &Keypair,
+    asset: &Keypair,
+    max_supply: u32,
+) {
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::MintLimit(MintLimit {
+                    max_supply,
+                    minted: 0,
+                }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &Keypair::new(),
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let requester = context.payer.pubkey();
+    create_requesting_asset(context, asset, collection.pubkey(), requester)
+        .await
+        .unwrap();
+}
+
+/// Create an asset outside of any collection with a request to join `collection`.
+async fn create_requesting_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    collection: Pubkey,
+    requester: Pubkey,
+) -> Result<(), BanksClientError> {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::CollectionJoinRequest(CollectionJoinRequest {
+                    collection,
+                    requester,
+                    approver: Pubkey::default(),
+                }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+}
+
+fn approve_collection_join_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    collection: &Keypair,
+) -> Transaction {
+    let approve_ix = ApproveCollectionJoinV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[approve_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn approved_join_counts_against_mint_limit() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    create_collection_and_request(&mut context, &collection, &asset, 2).await;
+
+    let tx = approve_collection_join_tx(&context, &asset, &collection);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let owner = context.payer.pubkey();
+    assert_asset(
+        &mut context,
+        AssertAssetHelperArgs {
+            asset: asset.pubkey(),
+            owner,
+            update_authority: Some(UpdateAuthority::Collection(collection.pubkey())),
+            name: None,
+            uri: None,
+            plugins: vec![],
+        },
+    )
+    .await;
+
+    let collection_account = context
+        .banks_client
+        .get_account(collection.pubkey())
+        .await
+        .expect("get_account")
+        .expect("collection account not found");
+    let collection = Collection::from_bytes(&collection_account.data).unwrap();
+    assert_eq!(collection.base.current_size, 2);
+    assert_eq!(
+        collection.plugin_list.mint_limit.unwrap().mint_limit,
+        MintLimit {
+            max_supply: 2,
+            minted: 2,
+        }
+    );
+}
+
+#[tokio::test]
+async fn cannot_approve_join_beyond_mint_limit() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    create_collection_and_request(&mut context, &collection, &asset, 1).await;
+
+    let tx = approve_collection_join_tx(&context, &asset, &collection);
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(MplCoreError::MintLimitReached as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn approved_join_moves_asset_into_collection() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let asset = Keypair::new();
+    let requester = context.payer.pubkey();
+    create_requesting_asset(&mut context, &asset, collection.pubkey(), requester)
+        .await
+        .unwrap();
+
+    let tx = approve_collection_join_tx(&context, &asset, &collection);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        asset.base.update_authority,
+        UpdateAuthority::Collection(collection.pubkey())
+    );
+    assert_eq!(
+        asset
+            .plugin_list
+            .collection_join_request
+            .unwrap()
+            .collection_join_request,
+        CollectionJoinRequest {
+            collection: collection.pubkey(),
+            requester,
+            approver: requester,
+        }
+    );
+    let collection = get_collection(&mut context, collection.pubkey()).await;
+    assert_eq!(collection.base.current_size, 1);
+}
+
+#[tokio::test]
+async fn cannot_approve_join_without_collection_update_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: Some(Keypair::new().pubkey()),
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let asset = Keypair::new();
+    let requester = context.payer.pubkey();
+    create_requesting_asset(&mut context, &asset, collection.pubkey(), requester)
+        .await
+        .unwrap();
+
+    let tx = approve_collection_join_tx(&context, &asset, &collection);
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+}
+
+#[tokio::test]
+async fn cannot_request_join_on_behalf_of_another_requester() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_requesting_asset(
+        &mut context,
+        &asset,
+        Keypair::new().pubkey(),
+        Keypair::new().pubkey(),
+    )
+    .await
+    .unwrap_err();
+}
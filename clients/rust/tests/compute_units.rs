@@ -0,0 +1,112 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    instructions::{AddPluginV1Builder, RemovePluginV1Builder, TransferV1Builder},
+    types::{Attributes, Plugin, PluginType},
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Transfer an asset from `owner` to `new_owner`, returning the compute units it consumed.
+async fn transfer_compute_units(
+    context: &mut ProgramTestContext,
+    asset: Pubkey,
+    owner: &Keypair,
+    new_owner: Pubkey,
+) -> u64 {
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .authority(Some(owner.pubkey()))
+        .new_owner(new_owner)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, owner],
+        context.last_blockhash,
+    );
+
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .expect("process_transaction_with_metadata");
+    result.result.expect("transfer failed");
+
+    result
+        .metadata
+        .expect("transaction metadata")
+        .compute_units_consumed
+}
+
+/// Add then remove an `Attributes` plugin, which leaves the asset without plugins but with an
+/// empty plugin registry.
+async fn add_and_remove_plugin(context: &mut ProgramTestContext, asset: Pubkey) {
+    let add_plugin_ix = AddPluginV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .plugin(Plugin::Attributes(Attributes {
+            attribute_list: vec![],
+        }))
+        .instruction();
+    let remove_plugin_ix = RemovePluginV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .plugin_type(PluginType::Attributes)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_plugin_ix, remove_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn plain_transfer_skips_lifecycle_validation() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let owner = context.payer.insecure_clone();
+    let new_owner = Keypair::new();
+    let plain_compute_units =
+        transfer_compute_units(&mut context, asset.pubkey(), &owner, new_owner.pubkey()).await;
+
+    // The plugin registry left behind sends the same asset through the full lifecycle
+    // validation, as every asset went before the plain transfer path.
+    add_and_remove_plugin(&mut context, asset.pubkey()).await;
+    let compute_units = transfer_compute_units(
+        &mut context,
+        asset.pubkey(),
+        &new_owner,
+        Keypair::new().pubkey(),
+    )
+    .await;
+
+    assert!(plain_compute_units < compute_units);
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::UpdatePluginV1Builder,
+    types::{FreezeDelegate, Plugin, PluginAuthority, PluginAuthorityPair},
+    AuthorityType,
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset whose freeze delegate is set to `delegate` at creation.
+async fn create_asset_with_freeze_delegate(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    delegate: Pubkey,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+                authority: Some(PluginAuthority::Address { address: delegate }),
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn freeze_tx(context: &ProgramTestContext, asset: Pubkey, authority: &Keypair) -> Transaction {
+    let update_plugin_ix = UpdatePluginV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: true }))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[update_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn delegate_set_at_creation_freezes_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_asset_with_freeze_delegate(&mut context, &asset, delegate.pubkey()).await;
+
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    let freeze_delegate = fetched.plugin_list.freeze_delegate.unwrap();
+    assert_eq!(
+        freeze_delegate.base.authority.authority_type,
+        AuthorityType::Address
+    );
+    assert_eq!(
+        freeze_delegate.base.authority.address,
+        Some(delegate.pubkey())
+    );
+
+    // No separate approval is needed before the delegate acts.
+    let tx = freeze_tx(&context, asset.pubkey(), &delegate);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        fetched.plugin_list.freeze_delegate.unwrap().freeze_delegate,
+        FreezeDelegate { frozen: true }
+    );
+}
+
+#[tokio::test]
+async fn cannot_freeze_as_another_delegate() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset_with_freeze_delegate(&mut context, &asset, Keypair::new().pubkey()).await;
+
+    let tx = freeze_tx(&context, asset.pubkey(), &Keypair::new());
+    assert_program_error(&mut context, tx, MplCoreError::NoApprovals).await;
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::CreateBatchV1Builder,
+    types::{
+        Attribute, Attributes, CreateBatchV1Item, Plugin, PluginAuthorityPair, UpdateAuthority,
+    },
+};
+pub use setup::*;
+
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::AccountMeta, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+fn attributes() -> Attributes {
+    Attributes {
+        attribute_list: vec![Attribute {
+            key: "drop".to_owned(),
+            value: "1".to_owned(),
+        }],
+    }
+}
+
+fn items(count: usize) -> Vec<CreateBatchV1Item> {
+    (0..count)
+        .map(|index| CreateBatchV1Item {
+            name: format!("Drop #{}", index),
+            uri: format!("https://example.com/drop/{}", index),
+        })
+        .collect()
+}
+
+/// Mint `items` into `collection` as the given new `assets`, with an `Attributes` template.
+fn create_batch_tx(
+    context: &ProgramTestContext,
+    collection: &Keypair,
+    assets: &[Keypair],
+    items: Vec<CreateBatchV1Item>,
+) -> Transaction {
+    let asset_metas: Vec<AccountMeta> = assets
+        .iter()
+        .map(|asset| AccountMeta::new(asset.pubkey(), true))
+        .collect();
+    let create_batch_ix = CreateBatchV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .assets(items)
+        .plugins(vec![PluginAuthorityPair {
+            plugin: Plugin::Attributes(attributes()),
+            authority: None,
+        }])
+        .add_remaining_accounts(&asset_metas)
+        .instruction();
+
+    let mut signers = vec![&context.payer];
+    signers.extend(assets);
+    Transaction::new_signed_with_payer(
+        &[create_batch_ix],
+        Some(&context.payer.pubkey()),
+        &signers,
+        context.last_blockhash,
+    )
+}
+
+async fn create_drop_collection(context: &mut ProgramTestContext, collection: &Keypair) {
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn create_batch_into_collection() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_drop_collection(&mut context, &collection).await;
+
+    let assets = [Keypair::new(), Keypair::new()];
+    let tx = create_batch_tx(&context, &collection, &assets, items(2));
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    for (index, asset) in assets.iter().enumerate() {
+        let fetched = get_asset(&mut context, asset.pubkey()).await;
+        assert_eq!(fetched.base.name, format!("Drop #{}", index));
+        assert_eq!(
+            fetched.base.update_authority,
+            UpdateAuthority::Collection(collection.pubkey())
+        );
+        assert_eq!(
+            fetched.plugin_list.attributes.unwrap().attributes,
+            attributes()
+        );
+    }
+
+    let fetched_collection = get_collection(&mut context, collection.pubkey()).await;
+    assert_eq!(fetched_collection.base.current_size, 2);
+}
+
+#[tokio::test]
+async fn cannot_create_batch_with_missing_asset_account() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_drop_collection(&mut context, &collection).await;
+
+    let assets = [Keypair::new()];
+    let tx = create_batch_tx(&context, &collection, &assets, items(2));
+    assert_program_error(&mut context, tx, MplCoreError::IncorrectAccount).await;
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::CreateEditionV1Builder,
+    types::{Edition, MasterEdition, Plugin, PluginAuthorityPair, UpdateAuthority},
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create a master edition collection of a single print named "Print".
+async fn create_master_edition(context: &mut ProgramTestContext, collection: &Keypair) {
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::MasterEdition(MasterEdition {
+                    max_supply: Some(1),
+                    name: Some("Print".to_owned()),
+                    uri: None,
+                }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn create_edition_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    collection: Pubkey,
+) -> Transaction {
+    let create_edition_ix = CreateEditionV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(collection)
+        .payer(context.payer.pubkey())
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[create_edition_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, asset],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn print_edition_from_master_edition() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_master_edition(&mut context, &collection).await;
+    let fetched_collection = get_collection(&mut context, collection.pubkey()).await;
+
+    let asset = Keypair::new();
+    let tx = create_edition_tx(&context, &asset, collection.pubkey());
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The name comes from the master edition and the uri from the collection.
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(fetched.base.name, "Print");
+    assert_eq!(fetched.base.uri, fetched_collection.base.uri);
+    assert_eq!(
+        fetched.base.update_authority,
+        UpdateAuthority::Collection(collection.pubkey())
+    );
+    assert_eq!(
+        fetched.plugin_list.edition.unwrap().edition,
+        Edition { number: 1 }
+    );
+}
+
+#[tokio::test]
+async fn cannot_print_beyond_max_supply() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_master_edition(&mut context, &collection).await;
+
+    let tx = create_edition_tx(&context, &Keypair::new(), collection.pubkey());
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = create_edition_tx(&context, &Keypair::new(), collection.pubkey());
+    assert_program_error(&mut context, tx, MplCoreError::MintLimitReached).await;
+}
@@ -0,0 +1,184 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{AddExternalPluginV1Builder, AddPluginV1Builder, WriteAppDataV1Builder},
+    types::{
+        AppDataInitInfo, Attribute, Attributes, ExternalPluginInitInfo, ExternalPluginSchema,
+        ExternalPluginType, Plugin, PluginAuthority,
+    },
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::Instruction, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+fn attributes() -> Attributes {
+    Attributes {
+        attribute_list: vec![Attribute {
+            key: "level".to_owned(),
+            value: "7".to_owned(),
+        }],
+    }
+}
+
+/// Create an asset with app data for each of `data_authorities`, followed by an `Attributes`
+/// plugin, so that every data section has data after it.
+async fn create_asset_with_data_sections(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    data_authorities: &[Pubkey],
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let mut instructions: Vec<Instruction> = data_authorities
+        .iter()
+        .map(|data_authority| {
+            AddExternalPluginV1Builder::new()
+                .asset(asset.pubkey())
+                .payer(context.payer.pubkey())
+                .init_info(ExternalPluginInitInfo::AppData(AppDataInitInfo {
+                    data_authority: PluginAuthority::Address {
+                        address: *data_authority,
+                    },
+                    schema: ExternalPluginSchema::Binary,
+                }))
+                .instruction()
+        })
+        .collect();
+    instructions.push(
+        AddPluginV1Builder::new()
+            .asset(asset.pubkey())
+            .payer(context.payer.pubkey())
+            .plugin(Plugin::Attributes(attributes()))
+            .instruction(),
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+async fn write_app_data(
+    context: &mut ProgramTestContext,
+    asset: Pubkey,
+    data_authority: &Keypair,
+    data: Vec<u8>,
+) {
+    let write_app_data_ix = WriteAppDataV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .authority(Some(data_authority.pubkey()))
+        .data_authority(PluginAuthority::Address {
+            address: data_authority.pubkey(),
+        })
+        .data(data)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[write_app_data_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, data_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+async fn assert_app_data(
+    context: &mut ProgramTestContext,
+    asset: Pubkey,
+    data_authority: Pubkey,
+    data: &[u8],
+) {
+    let app_data = get_external_plugin_data(
+        context,
+        asset,
+        ExternalPluginType::AppData,
+        PluginAuthority::Address {
+            address: data_authority,
+        },
+    )
+    .await;
+    assert_eq!(app_data, data);
+}
+
+#[tokio::test]
+async fn data_section_resizes_without_disturbing_later_data() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let first = Keypair::new();
+    let second = Keypair::new();
+    create_asset_with_data_sections(&mut context, &asset, &[first.pubkey(), second.pubkey()]).await;
+
+    write_app_data(&mut context, asset.pubkey(), &second, vec![9; 8]).await;
+
+    // Growing the first section moves the second section and the plugin after it.
+    write_app_data(&mut context, asset.pubkey(), &first, vec![1; 100]).await;
+    assert_app_data(&mut context, asset.pubkey(), first.pubkey(), &[1; 100]).await;
+    assert_app_data(&mut context, asset.pubkey(), second.pubkey(), &[9; 8]).await;
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        fetched.plugin_list.attributes.unwrap().attributes,
+        attributes()
+    );
+
+    // Shrinking it moves them back.
+    write_app_data(&mut context, asset.pubkey(), &first, vec![2; 3]).await;
+    assert_app_data(&mut context, asset.pubkey(), first.pubkey(), &[2; 3]).await;
+    assert_app_data(&mut context, asset.pubkey(), second.pubkey(), &[9; 8]).await;
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        fetched.plugin_list.attributes.unwrap().attributes,
+        attributes()
+    );
+}
+
+#[tokio::test]
+async fn cannot_write_missing_data_section() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let data_authority = Keypair::new();
+    create_asset_with_data_sections(&mut context, &asset, &[Keypair::new().pubkey()]).await;
+
+    let write_app_data_ix = WriteAppDataV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(data_authority.pubkey()))
+        .data_authority(PluginAuthority::Address {
+            address: data_authority.pubkey(),
+        })
+        .data(vec![1, 2, 3, 4])
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[write_app_data_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &data_authority],
+        context.last_blockhash,
+    );
+    assert_program_error(&mut context, tx, MplCoreError::PluginNotFound).await;
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::DecompressV1Builder,
+    types::{CompressionProof, UpdateAuthority},
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+fn decompress_tx(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    compression_proof: CompressionProof,
+) -> Transaction {
+    let decompress_ix = DecompressV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .compression_proof(compression_proof)
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[decompress_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn cannot_decompress_while_decompression_is_disabled() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new().pubkey();
+    let compression_proof = compression_proof(
+        context.payer.pubkey(),
+        UpdateAuthority::Address(context.payer.pubkey()),
+        vec![],
+    );
+    create_hashed_asset(&mut context, asset, &compression_proof).await;
+
+    // The proof is verified, but the rebuilt asset is not kept.
+    let tx = decompress_tx(&context, asset, compression_proof);
+    assert_program_error(&mut context, tx, MplCoreError::NotAvailable).await;
+}
+
+#[tokio::test]
+async fn cannot_decompress_uncompressed_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let compression_proof = compression_proof(
+        context.payer.pubkey(),
+        UpdateAuthority::Address(context.payer.pubkey()),
+        vec![],
+    );
+    let tx = decompress_tx(&context, asset.pubkey(), compression_proof);
+    assert_program_error(&mut context, tx, MplCoreError::AlreadyDecompressed).await;
+}
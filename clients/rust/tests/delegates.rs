@@ -0,0 +1,153 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use borsh::BorshDeserialize;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::GetDelegatesV1Builder,
+    list_delegates,
+    types::{
+        Attributes, DelegateRecord, FreezeDelegate, Plugin, PluginAuthority, PluginAuthorityPair,
+        PluginType,
+    },
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create a collection with an `Attributes` plugin, and an asset in it with a freeze delegate.
+async fn create_delegated_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    collection: &Keypair,
+    delegate: Pubkey,
+) {
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Attributes(Attributes {
+                    attribute_list: vec![],
+                }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+                authority: Some(PluginAuthority::Address { address: delegate }),
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn get_delegates_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    collection: Pubkey,
+) -> Transaction {
+    let get_delegates_ix = GetDelegatesV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(Some(collection))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[get_delegates_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn get_delegates_of_asset_and_collection() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let collection = Keypair::new();
+    let delegate = Keypair::new().pubkey();
+    create_delegated_asset(&mut context, &asset, &collection, delegate).await;
+
+    let tx = get_delegates_tx(&context, &asset, collection.pubkey());
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .expect("process_transaction_with_metadata");
+    result.result.expect("get delegates failed");
+    let return_data = result
+        .metadata
+        .expect("transaction metadata")
+        .return_data
+        .expect("delegates");
+    let delegates = Vec::<DelegateRecord>::try_from_slice(&return_data.data).unwrap();
+
+    assert_eq!(
+        delegates,
+        vec![
+            DelegateRecord {
+                plugin_type: PluginType::FreezeDelegate,
+                authority: PluginAuthority::Address { address: delegate },
+                collection_level: false,
+            },
+            DelegateRecord {
+                plugin_type: PluginType::Attributes,
+                authority: PluginAuthority::UpdateAuthority,
+                collection_level: true,
+            },
+        ]
+    );
+
+    // The off-chain listing reads the same delegates from the account data.
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let collection_account = context
+        .banks_client
+        .get_account(collection.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        list_delegates(&asset_account.data, Some(&collection_account.data)).unwrap(),
+        delegates
+    );
+}
+
+#[tokio::test]
+async fn cannot_get_delegates_with_another_collection() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let collection = Keypair::new();
+    create_delegated_asset(&mut context, &asset, &collection, Keypair::new().pubkey()).await;
+
+    let tx = get_delegates_tx(&context, &asset, Keypair::new().pubkey());
+    assert_program_error(&mut context, tx, MplCoreError::InvalidCollection).await;
+}
@@ -0,0 +1,119 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{errors::MplCoreError, find_asset_signer_address, instructions::ExecuteV1Builder};
+pub use setup::*;
+
+use solana_program::{
+    native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, system_instruction, system_program,
+};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset owned by the context payer whose asset signer holds 1 SOL.
+async fn create_funded_asset(context: &mut ProgramTestContext, asset: &Keypair) -> Pubkey {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let (asset_signer, _) = find_asset_signer_address(&asset.pubkey());
+    airdrop(context, &asset_signer, LAMPORTS_PER_SOL)
+        .await
+        .unwrap();
+    asset_signer
+}
+
+/// Pay `lamports` from the asset signer to `recipient` through `ExecuteV1`.
+fn execute_transfer_tx(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    authority: &Keypair,
+    recipient: Pubkey,
+    lamports: u64,
+) -> Transaction {
+    let (asset_signer, _) = find_asset_signer_address(&asset);
+    let transfer_ix = system_instruction::transfer(&asset_signer, &recipient, lamports);
+
+    let execute_ix = ExecuteV1Builder::new()
+        .asset(asset)
+        .asset_signer(asset_signer)
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .system_program(system_program::ID)
+        .program_id(transfer_ix.program_id)
+        .instruction_data(transfer_ix.data)
+        .add_remaining_accounts(&transfer_ix.accounts)
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[execute_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn owner_executes_as_asset_signer() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let asset_signer = create_funded_asset(&mut context, &asset).await;
+
+    let recipient = Keypair::new().pubkey();
+    let owner = context.payer.insecure_clone();
+    let tx = execute_transfer_tx(
+        &context,
+        asset.pubkey(),
+        &owner,
+        recipient,
+        LAMPORTS_PER_SOL / 2,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let recipient_account = context
+        .banks_client
+        .get_account(recipient)
+        .await
+        .expect("get_account")
+        .expect("recipient account not found");
+    assert_eq!(recipient_account.lamports, LAMPORTS_PER_SOL / 2);
+
+    let asset_signer_account = context
+        .banks_client
+        .get_account(asset_signer)
+        .await
+        .expect("get_account")
+        .expect("asset signer account not found");
+    assert_eq!(asset_signer_account.lamports, LAMPORTS_PER_SOL / 2);
+}
+
+#[tokio::test]
+async fn cannot_execute_without_owner() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_funded_asset(&mut context, &asset).await;
+
+    let tx = execute_transfer_tx(
+        &context,
+        asset.pubkey(),
+        &Keypair::new(),
+        Keypair::new().pubkey(),
+        LAMPORTS_PER_SOL / 2,
+    );
+    assert_program_error(&mut context, tx, MplCoreError::NoApprovals).await;
+}
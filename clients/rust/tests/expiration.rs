@@ -0,0 +1,95 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::BurnV1Builder,
+    types::{Expiration, Plugin, PluginAuthority, PluginAuthorityPair},
+};
+pub use setup::*;
+
+use solana_program::{clock::Clock, pubkey::Pubkey};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset owned by someone else which `issuer` can burn once it expires.
+async fn create_expiring_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    issuer: Pubkey,
+    expires_at: i64,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: Some(Keypair::new().pubkey()),
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Expiration(Expiration { expires_at }),
+                authority: Some(PluginAuthority::Address { address: issuer }),
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn burn_tx(context: &ProgramTestContext, asset: &Keypair, issuer: &Keypair) -> Transaction {
+    let burn_ix = BurnV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(issuer.pubkey()))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, issuer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn issuer_burns_expired_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let issuer = Keypair::new();
+    let asset = Keypair::new();
+    create_expiring_asset(&mut context, &asset, issuer.pubkey(), 0).await;
+
+    let tx = burn_tx(&context, &asset, &issuer);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(asset_account.data.len(), 1);
+}
+
+#[tokio::test]
+async fn issuer_cannot_burn_asset_before_expiry() {
+    let mut context = program_test().start_with_context().await;
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let issuer = Keypair::new();
+    let asset = Keypair::new();
+    create_expiring_asset(
+        &mut context,
+        &asset,
+        issuer.pubkey(),
+        clock.unix_timestamp + 86_400,
+    )
+    .await;
+
+    let tx = burn_tx(&context, &asset, &issuer);
+    assert_program_error(&mut context, tx, MplCoreError::NoApprovals).await;
+}
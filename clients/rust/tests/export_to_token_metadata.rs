@@ -0,0 +1,141 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use borsh::BorshDeserialize;
+use mpl_core::{errors::MplCoreError, instructions::ExportToTokenMetadataV1Builder};
+pub use setup::*;
+
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey, sysvar,
+};
+use solana_program_test::{processor, tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+fn find_token_metadata_master_edition_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"metadata",
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            mint.as_ref(),
+            b"edition",
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    )
+}
+
+/// A stand-in for Token Metadata that logs the NFTs it is asked to create and mint.
+fn token_metadata(_program_id: &Pubkey, _accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    match data[0] {
+        42 => {
+            let name = String::deserialize(&mut &data[2..]).unwrap();
+            msg!("Create {}", name);
+        }
+        43 => msg!("Mint"),
+        _ => panic!("unexpected Token Metadata instruction {}", data[0]),
+    }
+    Ok(())
+}
+
+fn export_tx(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    update_authority: &Keypair,
+    mint: &Keypair,
+) -> Transaction {
+    let export_ix = ExportToTokenMetadataV1Builder::new()
+        .asset(asset)
+        .owner(context.payer.pubkey())
+        .update_authority(update_authority.pubkey())
+        .payer(context.payer.pubkey())
+        .mint(mint.pubkey())
+        .metadata(find_token_metadata_address(&mint.pubkey()).0)
+        .master_edition(find_token_metadata_master_edition_address(&mint.pubkey()).0)
+        .token_account(Keypair::new().pubkey())
+        .token_metadata_program(TOKEN_METADATA_PROGRAM_ID)
+        .token_program(SPL_TOKEN_PROGRAM_ID)
+        .associated_token_program(SPL_ASSOCIATED_TOKEN_PROGRAM_ID)
+        .sysvar_instructions(sysvar::instructions::ID)
+        .symbol("CORE".to_owned())
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[export_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, update_authority, mint],
+        context.last_blockhash,
+    )
+}
+
+async fn create_exportable_asset(context: &mut ProgramTestContext, asset: &Keypair) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn export_asset_to_token_metadata() {
+    let mut program_test = program_test();
+    program_test.add_program(
+        "token_metadata",
+        TOKEN_METADATA_PROGRAM_ID,
+        processor!(token_metadata),
+    );
+    let mut context = program_test.start_with_context().await;
+
+    let asset = Keypair::new();
+    create_exportable_asset(&mut context, &asset).await;
+    let name = get_asset(&mut context, asset.pubkey()).await.base.name;
+
+    let update_authority = context.payer.insecure_clone();
+    let tx = export_tx(&context, asset.pubkey(), &update_authority, &Keypair::new());
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .expect("process_transaction_with_metadata");
+    result.result.expect("export failed");
+
+    // The NFT is created with the name of the asset and minted to the owner.
+    let log_messages = result.metadata.expect("transaction metadata").log_messages;
+    assert!(log_messages.contains(&format!("Program log: Create {}", name)));
+    assert!(log_messages.contains(&"Program log: Mint".to_owned()));
+
+    // The asset is burned.
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    assert_eq!(asset_account.data.len(), 1);
+}
+
+#[tokio::test]
+async fn cannot_export_without_update_authority() {
+    let mut program_test = program_test();
+    program_test.add_program(
+        "token_metadata",
+        TOKEN_METADATA_PROGRAM_ID,
+        processor!(token_metadata),
+    );
+    let mut context = program_test.start_with_context().await;
+
+    let asset = Keypair::new();
+    create_exportable_asset(&mut context, &asset).await;
+
+    let tx = export_tx(&context, asset.pubkey(), &Keypair::new(), &Keypair::new());
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+}
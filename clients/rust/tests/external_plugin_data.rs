@@ -0,0 +1,339 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{
+        AddCollectionExternalPluginV1Builder, AddExternalPluginV1Builder,
+        TruncateCollectionExternalPluginDataV1Builder, TruncateExternalPluginDataV1Builder,
+        WriteCollectionExternalPluginDataV1Builder, WriteExternalPluginDataV1Builder,
+    },
+    types::{
+        AppDataInitInfo, ExternalPluginInitInfo, ExternalPluginKey, ExternalPluginSchema,
+        ExternalPluginType, PluginAuthority,
+    },
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::Instruction, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+fn app_data_init_info(data_authority: Pubkey) -> ExternalPluginInitInfo {
+    ExternalPluginInitInfo::AppData(AppDataInitInfo {
+        data_authority: PluginAuthority::Address {
+            address: data_authority,
+        },
+        schema: ExternalPluginSchema::Binary,
+    })
+}
+
+fn app_data_key(data_authority: Pubkey) -> ExternalPluginKey {
+    ExternalPluginKey::AppData(PluginAuthority::Address {
+        address: data_authority,
+    })
+}
+
+fn authority_tx(
+    context: &ProgramTestContext,
+    instruction: Instruction,
+    authority: &Keypair,
+) -> Transaction {
+    Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+/// Create an asset with app data written by `data_authority`.
+async fn create_asset_with_app_data(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    data_authority: Pubkey,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let add_ix = AddExternalPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .init_info(app_data_init_info(data_authority))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[add_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+/// Create a collection with app data written by `data_authority`.
+async fn create_collection_with_app_data(
+    context: &mut ProgramTestContext,
+    collection: &Keypair,
+    data_authority: Pubkey,
+) {
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let add_ix = AddCollectionExternalPluginV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .init_info(app_data_init_info(data_authority))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[add_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+fn write_tx(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    authority: &Keypair,
+    data_authority: Pubkey,
+    data: Vec<u8>,
+    append: bool,
+) -> Transaction {
+    let write_ix = WriteExternalPluginDataV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .key(app_data_key(data_authority))
+        .data(data)
+        .append(append)
+        .instruction();
+    authority_tx(context, write_ix, authority)
+}
+
+fn truncate_tx(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    authority: &Keypair,
+    data_authority: Pubkey,
+    len: u64,
+) -> Transaction {
+    let truncate_ix = TruncateExternalPluginDataV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .key(app_data_key(data_authority))
+        .len(len)
+        .instruction();
+    authority_tx(context, truncate_ix, authority)
+}
+
+async fn assert_app_data(
+    context: &mut ProgramTestContext,
+    address: Pubkey,
+    data_authority: Pubkey,
+    data: &[u8],
+) {
+    let app_data = get_external_plugin_data(
+        context,
+        address,
+        ExternalPluginType::AppData,
+        PluginAuthority::Address {
+            address: data_authority,
+        },
+    )
+    .await;
+    assert_eq!(app_data, data);
+}
+
+#[tokio::test]
+async fn data_authority_writes_appends_and_truncates() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let data_authority = Keypair::new();
+    create_asset_with_app_data(&mut context, &asset, data_authority.pubkey()).await;
+
+    let tx = write_tx(
+        &context,
+        asset.pubkey(),
+        &data_authority,
+        data_authority.pubkey(),
+        vec![1, 2, 3],
+        false,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = write_tx(
+        &context,
+        asset.pubkey(),
+        &data_authority,
+        data_authority.pubkey(),
+        vec![4, 5],
+        true,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+    assert_app_data(
+        &mut context,
+        asset.pubkey(),
+        data_authority.pubkey(),
+        &[1, 2, 3, 4, 5],
+    )
+    .await;
+
+    let tx = truncate_tx(
+        &context,
+        asset.pubkey(),
+        &data_authority,
+        data_authority.pubkey(),
+        2,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+    assert_app_data(
+        &mut context,
+        asset.pubkey(),
+        data_authority.pubkey(),
+        &[1, 2],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn cannot_write_external_plugin_data_without_data_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let data_authority = Keypair::new();
+    create_asset_with_app_data(&mut context, &asset, data_authority.pubkey()).await;
+
+    let update_authority = context.payer.insecure_clone();
+    let tx = write_tx(
+        &context,
+        asset.pubkey(),
+        &update_authority,
+        data_authority.pubkey(),
+        vec![1, 2, 3],
+        false,
+    );
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+}
+
+#[tokio::test]
+async fn cannot_truncate_external_plugin_data_past_its_end() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let data_authority = Keypair::new();
+    create_asset_with_app_data(&mut context, &asset, data_authority.pubkey()).await;
+
+    let tx = write_tx(
+        &context,
+        asset.pubkey(),
+        &data_authority,
+        data_authority.pubkey(),
+        vec![1, 2, 3],
+        false,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = truncate_tx(
+        &context,
+        asset.pubkey(),
+        &data_authority,
+        data_authority.pubkey(),
+        4,
+    );
+    assert_program_error(&mut context, tx, MplCoreError::InvalidDataOffset).await;
+}
+
+#[tokio::test]
+async fn data_authority_writes_and_truncates_collection_data() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let data_authority = Keypair::new();
+    create_collection_with_app_data(&mut context, &collection, data_authority.pubkey()).await;
+
+    let write_ix = WriteCollectionExternalPluginDataV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(data_authority.pubkey()))
+        .key(app_data_key(data_authority.pubkey()))
+        .data(vec![1, 2, 3])
+        .append(false)
+        .instruction();
+    let tx = authority_tx(&context, write_ix, &data_authority);
+    context.banks_client.process_transaction(tx).await.unwrap();
+    assert_app_data(
+        &mut context,
+        collection.pubkey(),
+        data_authority.pubkey(),
+        &[1, 2, 3],
+    )
+    .await;
+
+    let truncate_ix = TruncateCollectionExternalPluginDataV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(data_authority.pubkey()))
+        .key(app_data_key(data_authority.pubkey()))
+        .len(0)
+        .instruction();
+    let tx = authority_tx(&context, truncate_ix, &data_authority);
+    context.banks_client.process_transaction(tx).await.unwrap();
+    assert_app_data(
+        &mut context,
+        collection.pubkey(),
+        data_authority.pubkey(),
+        &[],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn cannot_truncate_collection_data_without_data_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let data_authority = Keypair::new();
+    create_collection_with_app_data(&mut context, &collection, data_authority.pubkey()).await;
+
+    let update_authority = context.payer.insecure_clone();
+    let truncate_ix = TruncateCollectionExternalPluginDataV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(update_authority.pubkey()))
+        .key(app_data_key(data_authority.pubkey()))
+        .len(0)
+        .instruction();
+    let tx = authority_tx(&context, truncate_ix, &update_authority);
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+}
@@ -0,0 +1,274 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{
+        AddCollectionExternalPluginV1Builder, AddExternalPluginV1Builder,
+        RemoveCollectionExternalPluginV1Builder, RemoveExternalPluginV1Builder,
+        UpdateCollectionExternalPluginV1Builder, UpdateExternalPluginV1Builder,
+    },
+    types::{
+        AppDataInitInfo, ExternalPluginInitInfo, ExternalPluginKey, ExternalPluginRecord,
+        ExternalPluginSchema, ExternalPluginType, ExternalPluginUpdateInfo, PluginAuthority,
+    },
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::Instruction, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+fn app_data_init_info(data_authority: Pubkey) -> ExternalPluginInitInfo {
+    ExternalPluginInitInfo::AppData(AppDataInitInfo {
+        data_authority: PluginAuthority::Address {
+            address: data_authority,
+        },
+        schema: ExternalPluginSchema::Binary,
+    })
+}
+
+fn app_data_key(data_authority: Pubkey) -> ExternalPluginKey {
+    ExternalPluginKey::AppData(PluginAuthority::Address {
+        address: data_authority,
+    })
+}
+
+async fn process_instruction(context: &mut ProgramTestContext, instruction: Instruction) {
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+/// Assert that the only external plugin is app data of `data_authority` with `schema`.
+async fn assert_app_data_record(
+    context: &mut ProgramTestContext,
+    address: Pubkey,
+    data_authority: Pubkey,
+    schema: ExternalPluginSchema,
+) {
+    let records = get_external_plugin_records(context, address).await;
+    assert_eq!(records.len(), 1);
+    let ExternalPluginRecord {
+        plugin_type,
+        authority,
+        schema: record_schema,
+        ..
+    } = &records[0];
+    assert_eq!(*plugin_type, ExternalPluginType::AppData);
+    assert_eq!(
+        *authority,
+        PluginAuthority::Address {
+            address: data_authority,
+        }
+    );
+    assert_eq!(*record_schema, schema);
+}
+
+async fn create_plain_asset(context: &mut ProgramTestContext, asset: &Keypair) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+async fn create_plain_collection(context: &mut ProgramTestContext, collection: &Keypair) {
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn add_update_and_remove_external_plugin() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let data_authority = Keypair::new().pubkey();
+    create_plain_asset(&mut context, &asset).await;
+
+    let add_ix = AddExternalPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .init_info(app_data_init_info(data_authority))
+        .instruction();
+    process_instruction(&mut context, add_ix).await;
+    assert_app_data_record(
+        &mut context,
+        asset.pubkey(),
+        data_authority,
+        ExternalPluginSchema::Binary,
+    )
+    .await;
+
+    let update_ix = UpdateExternalPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .key(app_data_key(data_authority))
+        .update_info(ExternalPluginUpdateInfo::AppData {
+            schema: ExternalPluginSchema::Json,
+        })
+        .instruction();
+    process_instruction(&mut context, update_ix).await;
+    assert_app_data_record(
+        &mut context,
+        asset.pubkey(),
+        data_authority,
+        ExternalPluginSchema::Json,
+    )
+    .await;
+
+    let remove_ix = RemoveExternalPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .key(app_data_key(data_authority))
+        .instruction();
+    process_instruction(&mut context, remove_ix).await;
+    assert!(get_external_plugin_records(&mut context, asset.pubkey())
+        .await
+        .is_empty());
+}
+
+#[tokio::test]
+async fn cannot_update_external_plugin_without_update_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let data_authority = Keypair::new().pubkey();
+    create_plain_asset(&mut context, &asset).await;
+
+    let add_ix = AddExternalPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .init_info(app_data_init_info(data_authority))
+        .instruction();
+    process_instruction(&mut context, add_ix).await;
+
+    let authority = Keypair::new();
+    let update_ix = UpdateExternalPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .key(app_data_key(data_authority))
+        .update_info(ExternalPluginUpdateInfo::AppData {
+            schema: ExternalPluginSchema::Json,
+        })
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+}
+
+#[tokio::test]
+async fn add_update_and_remove_collection_external_plugin() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let data_authority = Keypair::new().pubkey();
+    create_plain_collection(&mut context, &collection).await;
+
+    let add_ix = AddCollectionExternalPluginV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .init_info(app_data_init_info(data_authority))
+        .instruction();
+    process_instruction(&mut context, add_ix).await;
+    assert_app_data_record(
+        &mut context,
+        collection.pubkey(),
+        data_authority,
+        ExternalPluginSchema::Binary,
+    )
+    .await;
+
+    let update_ix = UpdateCollectionExternalPluginV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .key(app_data_key(data_authority))
+        .update_info(ExternalPluginUpdateInfo::AppData {
+            schema: ExternalPluginSchema::Json,
+        })
+        .instruction();
+    process_instruction(&mut context, update_ix).await;
+    assert_app_data_record(
+        &mut context,
+        collection.pubkey(),
+        data_authority,
+        ExternalPluginSchema::Json,
+    )
+    .await;
+
+    let remove_ix = RemoveCollectionExternalPluginV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .key(app_data_key(data_authority))
+        .instruction();
+    process_instruction(&mut context, remove_ix).await;
+    assert!(
+        get_external_plugin_records(&mut context, collection.pubkey())
+            .await
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn cannot_remove_collection_external_plugin_without_update_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let data_authority = Keypair::new().pubkey();
+    create_plain_collection(&mut context, &collection).await;
+
+    let add_ix = AddCollectionExternalPluginV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .init_info(app_data_init_info(data_authority))
+        .instruction();
+    process_instruction(&mut context, add_ix).await;
+
+    let authority = Keypair::new();
+    let remove_ix = RemoveCollectionExternalPluginV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .key(app_data_key(data_authority))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[remove_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+}
@@ -0,0 +1,319 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use borsh::BorshSerialize;
+use mpl_core::{
+    accounts::FeeConfigV1,
+    errors::MplCoreError,
+    find_fee_config_address,
+    instructions::{
+        CollectBuilder, CreateV1Builder, ReserveV1Builder, SetFeeConfigV1Builder,
+        WithdrawFeesV1Builder,
+    },
+    types::{DataState, Key},
+};
+pub use setup::*;
+
+use solana_program::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    account::Account, instruction::AccountMeta, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+const CREATE_FEE: u64 = LAMPORTS_PER_SOL / 100;
+
+/// Write a fee config managed by `authority` into account state, since the fee config is first
+/// initialized by the program authority.
+async fn set_fee_config(context: &mut ProgramTestContext, authority: Pubkey) {
+    let (fee_config, bump) = find_fee_config_address();
+    let data = FeeConfigV1 {
+        key: Key::FeeConfigV1,
+        authority,
+        create_fee: CREATE_FEE,
+        create_collection_fee: 0,
+        bump,
+    }
+    .try_to_vec()
+    .unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    context.set_account(
+        &fee_config,
+        &Account {
+            lamports: rent.minimum_balance(data.len()),
+            data,
+            owner: mpl_core::ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+}
+
+async fn get_fee_config(context: &mut ProgramTestContext) -> Account {
+    let (fee_config, _) = find_fee_config_address();
+    context
+        .banks_client
+        .get_account(fee_config)
+        .await
+        .expect("get_account")
+        .expect("fee config account not found")
+}
+
+fn set_fee_config_tx(
+    context: &ProgramTestContext,
+    authority: &Keypair,
+    create_fee: u64,
+    new_authority: Option<Pubkey>,
+) -> Transaction {
+    let (fee_config, _) = find_fee_config_address();
+    let mut set_fee_config = SetFeeConfigV1Builder::new();
+    set_fee_config
+        .fee_config(fee_config)
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .create_fee(create_fee)
+        .create_collection_fee(0);
+    if let Some(new_authority) = new_authority {
+        set_fee_config.new_authority(new_authority);
+    }
+
+    Transaction::new_signed_with_payer(
+        &[set_fee_config.instruction()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+fn withdraw_fees_tx(
+    context: &ProgramTestContext,
+    authority: &Keypair,
+    recipient: Pubkey,
+) -> Transaction {
+    let (fee_config, _) = find_fee_config_address();
+    let withdraw_fees_ix = WithdrawFeesV1Builder::new()
+        .fee_config(fee_config)
+        .authority(authority.pubkey())
+        .recipient(recipient)
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[withdraw_fees_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+fn collect_tx(context: &ProgramTestContext, asset: Pubkey) -> Transaction {
+    let (fee_config, _) = find_fee_config_address();
+    let collect_ix = CollectBuilder::new()
+        .fee_config(fee_config)
+        .add_remaining_account(AccountMeta::new(asset, false))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[collect_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+/// Create an asset without the fee config, leaving the legacy fee on it, and return the fee.
+async fn create_asset_with_legacy_fee(context: &mut ProgramTestContext, asset: &Keypair) -> u64 {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    excess_lamports(context, asset.pubkey()).await
+}
+
+/// The lamports an account holds beyond the rent for its size.
+async fn excess_lamports(context: &mut ProgramTestContext, address: Pubkey) -> u64 {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let account = context
+        .banks_client
+        .get_account(address)
+        .await
+        .expect("get_account")
+        .expect("account not found");
+    account.lamports - rent.minimum_balance(account.data.len())
+}
+
+#[tokio::test]
+async fn create_pays_fee_and_authority_withdraws_it() {
+    let mut context = program_test().start_with_context().await;
+
+    let fee_authority = Keypair::new();
+    set_fee_config(&mut context, fee_authority.pubkey()).await;
+    let fee_config_rent = get_fee_config(&mut context).await.lamports;
+
+    let asset = Keypair::new();
+    let (fee_config, _) = find_fee_config_address();
+    let create_ix = CreateV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .data_state(DataState::AccountState)
+        .name("Test Asset".to_owned())
+        .uri("https://example.com/asset".to_owned())
+        .add_remaining_account(AccountMeta::new(fee_config, false))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &asset],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+    assert_eq!(
+        get_fee_config(&mut context).await.lamports,
+        fee_config_rent + CREATE_FEE
+    );
+
+    let recipient = Keypair::new().pubkey();
+    let tx = withdraw_fees_tx(&context, &fee_authority, recipient);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let recipient_account = context
+        .banks_client
+        .get_account(recipient)
+        .await
+        .expect("get_account")
+        .expect("recipient account not found");
+    assert_eq!(recipient_account.lamports, CREATE_FEE);
+    assert_eq!(get_fee_config(&mut context).await.lamports, fee_config_rent);
+}
+
+#[tokio::test]
+async fn fee_authority_sets_fee_config() {
+    let mut context = program_test().start_with_context().await;
+
+    let fee_authority = Keypair::new();
+    let new_authority = Keypair::new().pubkey();
+    set_fee_config(&mut context, fee_authority.pubkey()).await;
+
+    let tx = set_fee_config_tx(
+        &context,
+        &fee_authority,
+        2 * CREATE_FEE,
+        Some(new_authority),
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let fee_config = FeeConfigV1::from_bytes(&get_fee_config(&mut context).await.data).unwrap();
+    assert_eq!(fee_config.authority, new_authority);
+    assert_eq!(fee_config.create_fee, 2 * CREATE_FEE);
+}
+
+#[tokio::test]
+async fn cannot_set_fee_config_without_fee_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    set_fee_config(&mut context, Keypair::new().pubkey()).await;
+
+    let tx = set_fee_config_tx(&context, &Keypair::new(), 0, None);
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+}
+
+#[tokio::test]
+async fn cannot_withdraw_fees_without_fee_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    set_fee_config(&mut context, Keypair::new().pubkey()).await;
+
+    let tx = withdraw_fees_tx(&context, &Keypair::new(), Keypair::new().pubkey());
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+}
+
+#[tokio::test]
+async fn collect_takes_legacy_fee_once() {
+    let mut context = program_test().start_with_context().await;
+
+    set_fee_config(&mut context, Keypair::new().pubkey()).await;
+    let fee_config_rent = get_fee_config(&mut context).await.lamports;
+
+    let asset = Keypair::new();
+    let legacy_fee = create_asset_with_legacy_fee(&mut context, &asset).await;
+    assert!(legacy_fee > 0);
+
+    let tx = collect_tx(&context, asset.pubkey());
+    context.banks_client.process_transaction(tx).await.unwrap();
+    assert_eq!(
+        get_fee_config(&mut context).await.lamports,
+        fee_config_rent + legacy_fee
+    );
+    assert_eq!(excess_lamports(&mut context, asset.pubkey()).await, 0);
+
+    // A second collect takes nothing.
+    context.last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let tx = collect_tx(&context, asset.pubkey());
+    context.banks_client.process_transaction(tx).await.unwrap();
+    assert_eq!(
+        get_fee_config(&mut context).await.lamports,
+        fee_config_rent + legacy_fee
+    );
+}
+
+#[tokio::test]
+async fn collect_leaves_reserved_rent() {
+    let mut context = program_test().start_with_context().await;
+
+    set_fee_config(&mut context, Keypair::new().pubkey()).await;
+
+    let asset = Keypair::new();
+    create_asset_with_legacy_fee(&mut context, &asset).await;
+    let tx = collect_tx(&context, asset.pubkey());
+    context.banks_client.process_transaction(tx).await.unwrap();
+    let fee_config_lamports = get_fee_config(&mut context).await.lamports;
+
+    let asset_len = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found")
+        .data
+        .len();
+    let reserve_ix = ReserveV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .size(asset_len as u64 + 500)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[reserve_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+    let reserved = excess_lamports(&mut context, asset.pubkey()).await;
+    assert!(reserved > 0);
+
+    context.last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let tx = collect_tx(&context, asset.pubkey());
+    context.banks_client.process_transaction(tx).await.unwrap();
+    assert_eq!(
+        get_fee_config(&mut context).await.lamports,
+        fee_config_lamports
+    );
+    assert_eq!(
+        excess_lamports(&mut context, asset.pubkey()).await,
+        reserved
+    );
+}
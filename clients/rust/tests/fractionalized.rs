@@ -0,0 +1,143 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{AddPluginV1Builder, RemovePluginV1Builder},
+    types::{Fractionalized, Plugin, PluginType},
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    account::Account, instruction::AccountMeta, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+/// Write an initialized Token-2022 mint with the given supply into account state.
+async fn set_fraction_mint(context: &mut ProgramTestContext, address: Pubkey, supply: u64) {
+    // The optional mint authority, then the supply, the decimals and the initialized flag.
+    let mut data = vec![0; 82];
+    data[36..44].copy_from_slice(&supply.to_le_bytes());
+    data[45] = 1;
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    context.set_account(
+        &address,
+        &Account {
+            lamports: rent.minimum_balance(data.len()),
+            data,
+            owner: SPL_TOKEN_2022_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+}
+
+/// Create an asset owned by the context payer and fractionalized into `fraction_mint`.
+async fn create_fractionalized_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    fraction_mint: Pubkey,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let add_plugin_ix = AddPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin(Plugin::Fractionalized(Fractionalized {
+            vault: Pubkey::new_unique(),
+            fraction_mint,
+        }))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+fn remove_fractionalized_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    fraction_mint: Pubkey,
+) -> Transaction {
+    let remove_plugin_ix = RemovePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin_type(PluginType::Fractionalized)
+        .add_remaining_account(AccountMeta::new_readonly(fraction_mint, false))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[remove_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn recover_asset_once_fractions_are_burned() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let fraction_mint = Pubkey::new_unique();
+    create_fractionalized_asset(&mut context, &asset, fraction_mint).await;
+
+    // The asset is locked while fractionalized.
+    assert_transfer_rejected_by(
+        &mut context,
+        asset.pubkey(),
+        None,
+        PluginType::Fractionalized,
+    )
+    .await;
+    assert_burn_rejected_by(
+        &mut context,
+        asset.pubkey(),
+        None,
+        PluginType::Fractionalized,
+    )
+    .await;
+
+    set_fraction_mint(&mut context, fraction_mint, 0).await;
+    let tx = remove_fractionalized_tx(&context, &asset, fraction_mint);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert!(fetched.plugin_list.fractionalized.is_none());
+}
+
+#[tokio::test]
+async fn cannot_recover_asset_with_fractions_outstanding() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let fraction_mint = Pubkey::new_unique();
+    create_fractionalized_asset(&mut context, &asset, fraction_mint).await;
+
+    set_fraction_mint(&mut context, fraction_mint, 1_000).await;
+    let tx = remove_fractionalized_tx(&context, &asset, fraction_mint);
+    assert_program_error(&mut context, tx, MplCoreError::FractionsOutstanding).await;
+}
@@ -0,0 +1,144 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{FreezeAssetV1Builder, ThawAssetV1Builder},
+    types::{FreezeDelegate, Plugin, PluginAuthority, PluginAuthorityPair, PluginType},
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset owned by the context payer with the given plugins.
+async fn create_asset_with_plugins(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    plugins: Vec<PluginAuthorityPair>,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins,
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn freeze_tx(context: &ProgramTestContext, asset: Pubkey, authority: &Keypair) -> Transaction {
+    let freeze_asset_ix = FreezeAssetV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[freeze_asset_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+fn thaw_tx(context: &ProgramTestContext, asset: Pubkey, authority: &Keypair) -> Transaction {
+    let thaw_asset_ix = ThawAssetV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[thaw_asset_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn freeze_delegate_freezes_and_thaws_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_asset_with_plugins(
+        &mut context,
+        &asset,
+        vec![PluginAuthorityPair {
+            plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+            authority: Some(PluginAuthority::Address {
+                address: delegate.pubkey(),
+            }),
+        }],
+    )
+    .await;
+
+    let tx = freeze_tx(&context, asset.pubkey(), &delegate);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        fetched.plugin_list.freeze_delegate.unwrap().freeze_delegate,
+        FreezeDelegate { frozen: true }
+    );
+    assert_transfer_rejected_by(
+        &mut context,
+        asset.pubkey(),
+        None,
+        PluginType::FreezeDelegate,
+    )
+    .await;
+
+    let tx = thaw_tx(&context, asset.pubkey(), &delegate);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        fetched.plugin_list.freeze_delegate.unwrap().freeze_delegate,
+        FreezeDelegate { frozen: false }
+    );
+}
+
+#[tokio::test]
+async fn cannot_freeze_without_freeze_delegate() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset_with_plugins(
+        &mut context,
+        &asset,
+        vec![PluginAuthorityPair {
+            plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+            authority: Some(PluginAuthority::Address {
+                address: Keypair::new().pubkey(),
+            }),
+        }],
+    )
+    .await;
+
+    let tx = freeze_tx(&context, asset.pubkey(), &Keypair::new());
+    assert_program_error(&mut context, tx, MplCoreError::NoApprovals).await;
+}
+
+#[tokio::test]
+async fn cannot_freeze_asset_without_freeze_plugin() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset_with_plugins(&mut context, &asset, vec![]).await;
+
+    let owner = context.payer.insecure_clone();
+    let tx = freeze_tx(&context, asset.pubkey(), &owner);
+    assert_program_error(&mut context, tx, MplCoreError::PluginNotFound).await;
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::VerifyGroupingV1Builder,
+    types::{Grouping, Groupings, Plugin, PluginAuthorityPair},
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create a group collection with the given update authority, and an asset with a grouping to it.
+async fn create_grouped_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    group: &Keypair,
+    group_update_authority: Option<Pubkey>,
+    verified: bool,
+) -> Result<(), solana_program_test::BanksClientError> {
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection: group,
+            update_authority: group_update_authority,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Groupings(Groupings {
+                    groupings: vec![Grouping {
+                        collection: group.pubkey(),
+                        verified,
+                    }],
+                }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+}
+
+fn verify_grouping_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    group: &Keypair,
+) -> Transaction {
+    let verify_grouping_ix = VerifyGroupingV1Builder::new()
+        .asset(asset.pubkey())
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .verified(true)
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[verify_grouping_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn group_update_authority_verifies_grouping() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let group = Keypair::new();
+    create_grouped_asset(&mut context, &asset, &group, None, false)
+        .await
+        .unwrap();
+
+    let tx = verify_grouping_tx(&context, &asset, &group);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        asset.plugin_list.groupings.unwrap().groupings,
+        Groupings {
+            groupings: vec![Grouping {
+                collection: group.pubkey(),
+                verified: true,
+            }],
+        }
+    );
+}
+
+#[tokio::test]
+async fn cannot_verify_grouping_without_group_update_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let group = Keypair::new();
+    create_grouped_asset(
+        &mut context,
+        &asset,
+        &group,
+        Some(Keypair::new().pubkey()),
+        false,
+    )
+    .await
+    .unwrap();
+
+    let tx = verify_grouping_tx(&context, &asset, &group);
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+}
+
+#[tokio::test]
+async fn cannot_create_asset_with_verified_grouping() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let group = Keypair::new();
+    create_grouped_asset(&mut context, &asset, &group, None, true)
+        .await
+        .unwrap_err();
+}
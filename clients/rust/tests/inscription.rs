@@ -0,0 +1,155 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{AddExternalPluginV1Builder, WriteInscriptionV1Builder},
+    types::{
+        ExternalPluginInitInfo, ExternalPluginSchema, ExternalPluginType, InscriptionInitInfo,
+        PluginAuthority,
+    },
+};
+pub use setup::*;
+
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset with an empty inscription written by `artist`.
+async fn create_inscribed_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    artist: &Keypair,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let add_external_plugin_ix = AddExternalPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .init_info(ExternalPluginInitInfo::Inscription(InscriptionInitInfo {
+            inscription_authority: PluginAuthority::Address {
+                address: artist.pubkey(),
+            },
+            schema: ExternalPluginSchema::Binary,
+        }))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_external_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+fn write_inscription_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    authority: &Keypair,
+    offset: u64,
+    data: Vec<u8>,
+    seal: bool,
+) -> Transaction {
+    let write_inscription_ix = WriteInscriptionV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .inscription_authority(PluginAuthority::Address {
+            address: authority.pubkey(),
+        })
+        .offset(offset)
+        .data(data)
+        .seal(seal)
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[write_inscription_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn artist_writes_inscription_in_chunks_and_seals_it() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let artist = Keypair::new();
+    create_inscribed_asset(&mut context, &asset, &artist).await;
+
+    let tx = write_inscription_tx(&context, &asset, &artist, 0, vec![1; 600], false);
+    context.banks_client.process_transaction(tx).await.unwrap();
+    let tx = write_inscription_tx(&context, &asset, &artist, 600, vec![2; 600], true);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Sealing removes the inscription authority.
+    let data = get_external_plugin_data(
+        &mut context,
+        asset.pubkey(),
+        ExternalPluginType::Inscription,
+        PluginAuthority::None,
+    )
+    .await;
+    assert_eq!(data, [vec![1; 600], vec![2; 600]].concat());
+
+    let tx = write_inscription_tx(&context, &asset, &artist, 0, vec![3; 600], false);
+    assert_program_error(&mut context, tx, MplCoreError::PluginNotFound).await;
+}
+
+#[tokio::test]
+async fn cannot_write_inscription_past_its_end() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let artist = Keypair::new();
+    create_inscribed_asset(&mut context, &asset, &artist).await;
+
+    let tx = write_inscription_tx(&context, &asset, &artist, 0, vec![1; 600], false);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = write_inscription_tx(&context, &asset, &artist, 601, vec![2; 600], false);
+    assert_program_error(&mut context, tx, MplCoreError::InvalidDataOffset).await;
+}
+
+#[tokio::test]
+async fn cannot_write_inscription_without_inscription_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let artist = Keypair::new();
+    create_inscribed_asset(&mut context, &asset, &artist).await;
+
+    let write_inscription_ix = WriteInscriptionV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .inscription_authority(PluginAuthority::Address {
+            address: artist.pubkey(),
+        })
+        .offset(0)
+        .data(vec![1; 600])
+        .seal(false)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[write_inscription_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+}
@@ -0,0 +1,241 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use borsh::BorshDeserialize;
+use mpl_core::{
+    accounts::LinkedDataSectionV1,
+    errors::MplCoreError,
+    find_linked_data_section_address,
+    instructions::{AddCollectionExternalPluginV1Builder, WriteLinkedAppDataV1Builder},
+    types::{AppDataInitInfo, ExternalPluginInitInfo, ExternalPluginSchema, Key, PluginAuthority},
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create a collection with linked app data written by `data_authority`, and an asset in it.
+async fn create_collection_with_linked_app_data(
+    context: &mut ProgramTestContext,
+    collection: &Keypair,
+    asset: &Keypair,
+    data_authority: Pubkey,
+) {
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let add_external_plugin_ix = AddCollectionExternalPluginV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .init_info(ExternalPluginInitInfo::LinkedAppData(AppDataInitInfo {
+            data_authority: PluginAuthority::Address {
+                address: data_authority,
+            },
+            schema: ExternalPluginSchema::Binary,
+        }))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_external_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn write_linked_app_data_tx(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    collection: Pubkey,
+    linked_data_section: Pubkey,
+    authority: &Keypair,
+    data_authority: Pubkey,
+    data: Vec<u8>,
+) -> Transaction {
+    let write_linked_app_data_ix = WriteLinkedAppDataV1Builder::new()
+        .asset(asset)
+        .collection(collection)
+        .linked_data_section(linked_data_section)
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .data_authority(PluginAuthority::Address {
+            address: data_authority,
+        })
+        .data(data)
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[write_linked_app_data_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn data_authority_writes_linked_app_data() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    let data_authority = Keypair::new();
+    create_collection_with_linked_app_data(
+        &mut context,
+        &collection,
+        &asset,
+        data_authority.pubkey(),
+    )
+    .await;
+
+    let asset_len = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found")
+        .data
+        .len();
+
+    let plugin_authority = PluginAuthority::Address {
+        address: data_authority.pubkey(),
+    };
+    let (linked_data_section, _) =
+        find_linked_data_section_address(&collection.pubkey(), &plugin_authority, &asset.pubkey());
+    let tx = write_linked_app_data_tx(
+        &context,
+        asset.pubkey(),
+        collection.pubkey(),
+        linked_data_section,
+        &data_authority,
+        data_authority.pubkey(),
+        vec![1, 2, 3, 4],
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let linked_data_section_account = context
+        .banks_client
+        .get_account(linked_data_section)
+        .await
+        .expect("get_account")
+        .expect("linked data section account not found");
+    let mut data: &[u8] = &linked_data_section_account.data;
+    let header = LinkedDataSectionV1::deserialize(&mut data).unwrap();
+    assert_eq!(
+        header,
+        LinkedDataSectionV1 {
+            key: Key::LinkedDataSectionV1,
+            collection: collection.pubkey(),
+            asset: asset.pubkey(),
+            data_authority: plugin_authority,
+        }
+    );
+    assert_eq!(data, &[1, 2, 3, 4]);
+
+    // The data lives in its own account, so the asset is not resized.
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    assert_eq!(asset_account.data.len(), asset_len);
+}
+
+#[tokio::test]
+async fn cannot_write_linked_app_data_without_data_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    let data_authority = Keypair::new();
+    create_collection_with_linked_app_data(
+        &mut context,
+        &collection,
+        &asset,
+        data_authority.pubkey(),
+    )
+    .await;
+
+    let (linked_data_section, _) = find_linked_data_section_address(
+        &collection.pubkey(),
+        &PluginAuthority::Address {
+            address: data_authority.pubkey(),
+        },
+        &asset.pubkey(),
+    );
+    let update_authority = context.payer.insecure_clone();
+    let tx = write_linked_app_data_tx(
+        &context,
+        asset.pubkey(),
+        collection.pubkey(),
+        linked_data_section,
+        &update_authority,
+        data_authority.pubkey(),
+        vec![1, 2, 3, 4],
+    );
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+}
+
+#[tokio::test]
+async fn cannot_write_linked_app_data_to_another_data_section() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    let data_authority = Keypair::new();
+    create_collection_with_linked_app_data(
+        &mut context,
+        &collection,
+        &asset,
+        data_authority.pubkey(),
+    )
+    .await;
+
+    let (linked_data_section, _) = find_linked_data_section_address(
+        &collection.pubkey(),
+        &PluginAuthority::Address {
+            address: data_authority.pubkey(),
+        },
+        &Keypair::new().pubkey(),
+    );
+    let tx = write_linked_app_data_tx(
+        &context,
+        asset.pubkey(),
+        collection.pubkey(),
+        linked_data_section,
+        &data_authority,
+        data_authority.pubkey(),
+        vec![1, 2, 3, 4],
+    );
+    assert_program_error(&mut context, tx, MplCoreError::IncorrectAccount).await;
+}
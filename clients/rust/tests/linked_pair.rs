@@ -0,0 +1,119 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::TransferV1Builder,
+    types::{LinkedPair, Plugin, PluginAuthorityPair},
+};
+pub use setup::*;
+
+use solana_program::{instruction::Instruction, pubkey::Pubkey, sysvar};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::AccountMeta, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+/// Create two assets owned by the context payer which are linked to each other.
+async fn create_linked_pair(context: &mut ProgramTestContext, key: &Keypair, lock: &Keypair) {
+    for (asset, partner) in [(key, lock), (lock, key)] {
+        create_asset(
+            context,
+            CreateAssetHelperArgs {
+                owner: None,
+                payer: None,
+                asset,
+                data_state: None,
+                name: None,
+                uri: None,
+                authority: None,
+                update_authority: None,
+                collection: None,
+                plugins: vec![PluginAuthorityPair {
+                    plugin: Plugin::LinkedPair(LinkedPair {
+                        partner: partner.pubkey(),
+                    }),
+                    authority: None,
+                }],
+            },
+        )
+        .await
+        .unwrap();
+    }
+}
+
+fn transfer_ix(context: &ProgramTestContext, asset: &Keypair, new_owner: Pubkey) -> Instruction {
+    TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner)
+        .add_remaining_account(AccountMeta::new_readonly(sysvar::instructions::ID, false))
+        .instruction()
+}
+
+fn transaction(context: &ProgramTestContext, instructions: &[Instruction]) -> Transaction {
+    Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn transfer_linked_pair_together() {
+    let mut context = program_test().start_with_context().await;
+
+    let key = Keypair::new();
+    let lock = Keypair::new();
+    create_linked_pair(&mut context, &key, &lock).await;
+
+    let new_owner = Keypair::new().pubkey();
+    let tx = transaction(
+        &context,
+        &[
+            transfer_ix(&context, &key, new_owner),
+            transfer_ix(&context, &lock, new_owner),
+        ],
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    for asset in [key, lock] {
+        assert_eq!(
+            get_asset(&mut context, asset.pubkey()).await.base.owner,
+            new_owner
+        );
+    }
+}
+
+#[tokio::test]
+async fn cannot_transfer_linked_asset_alone() {
+    let mut context = program_test().start_with_context().await;
+
+    let key = Keypair::new();
+    let lock = Keypair::new();
+    create_linked_pair(&mut context, &key, &lock).await;
+
+    let tx = transaction(
+        &context,
+        &[transfer_ix(&context, &key, Keypair::new().pubkey())],
+    );
+    assert_program_error(&mut context, tx, MplCoreError::LinkedPairNotTransferred).await;
+}
+
+#[tokio::test]
+async fn cannot_transfer_linked_pair_to_different_owners() {
+    let mut context = program_test().start_with_context().await;
+
+    let key = Keypair::new();
+    let lock = Keypair::new();
+    create_linked_pair(&mut context, &key, &lock).await;
+
+    let tx = transaction(
+        &context,
+        &[
+            transfer_ix(&context, &key, Keypair::new().pubkey()),
+            transfer_ix(&context, &lock, Keypair::new().pubkey()),
+        ],
+    );
+    assert_program_error(&mut context, tx, MplCoreError::LinkedPairNotTransferred).await;
+}
@@ -0,0 +1,198 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{AddPluginV1Builder, TransferV1CpiBuilder},
+    types::{Listing, Plugin, PluginAuthority},
+};
+pub use setup::*;
+
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey, system_program,
+};
+use solana_program_test::{processor, tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    signature::Keypair,
+    signer::Signer,
+    transaction::Transaction,
+};
+
+const LISTING_AUTHORITY_PREFIX: &[u8] = b"mpl-core-listing";
+
+fn find_listing_authority_address(marketplace: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LISTING_AUTHORITY_PREFIX], marketplace)
+}
+
+/// A marketplace program that sells an asset to the buyer, signing the transfer with its
+/// listing authority.  The accounts are the asset, the buyer, the listing authority, the system
+/// program, the program and optionally the seller.
+fn marketplace(program_id: &Pubkey, accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let (_, bump) = find_listing_authority_address(program_id);
+
+    let mut transfer = TransferV1CpiBuilder::new(&accounts[4]);
+    transfer
+        .asset(&accounts[0])
+        .payer(&accounts[1])
+        .authority(Some(&accounts[2]))
+        .new_owner(&accounts[1])
+        .system_program(Some(&accounts[3]));
+    if let Some(seller) = accounts.get(5) {
+        transfer.add_remaining_account(seller, true, false);
+    }
+    transfer.invoke_signed(&[&[LISTING_AUTHORITY_PREFIX, &[bump]]])
+}
+
+/// Create an asset owned by `seller` listed for 1 SOL on `marketplace`.
+async fn create_listed_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    seller: &Keypair,
+    marketplace: Pubkey,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: Some(seller.pubkey()),
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let (listing_authority, _) = find_listing_authority_address(&marketplace);
+    let add_plugin_ix = AddPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(seller.pubkey()))
+        .plugin(Plugin::Listing(Listing {
+            price: LAMPORTS_PER_SOL,
+            marketplace,
+        }))
+        .init_authority(PluginAuthority::Address {
+            address: listing_authority,
+        })
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, seller],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+/// Buy an asset through `marketplace`, passing the seller to be paid if given.
+fn buy_tx(
+    context: &ProgramTestContext,
+    marketplace: Pubkey,
+    asset: Pubkey,
+    seller: Option<Pubkey>,
+) -> Transaction {
+    let (listing_authority, _) = find_listing_authority_address(&marketplace);
+    let mut accounts = vec![
+        AccountMeta::new(asset, false),
+        AccountMeta::new(context.payer.pubkey(), true),
+        AccountMeta::new_readonly(listing_authority, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(mpl_core::ID, false),
+    ];
+    if let Some(seller) = seller {
+        accounts.push(AccountMeta::new(seller, false));
+    }
+    let buy_ix = Instruction {
+        program_id: marketplace,
+        accounts,
+        data: vec![],
+    };
+
+    Transaction::new_signed_with_payer(
+        &[buy_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn marketplace_sells_listed_asset() {
+    let marketplace_id = Pubkey::new_unique();
+    let mut program_test = program_test();
+    program_test.add_program("marketplace", marketplace_id, processor!(marketplace));
+    let mut context = program_test.start_with_context().await;
+
+    let asset = Keypair::new();
+    let seller = Keypair::new();
+    create_listed_asset(&mut context, &asset, &seller, marketplace_id).await;
+
+    let tx = buy_tx(
+        &context,
+        marketplace_id,
+        asset.pubkey(),
+        Some(seller.pubkey()),
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let buyer = context.payer.pubkey();
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(fetched.base.owner, buyer);
+
+    let seller_account = context
+        .banks_client
+        .get_account(seller.pubkey())
+        .await
+        .expect("get_account")
+        .expect("seller account not found");
+    assert_eq!(seller_account.lamports, LAMPORTS_PER_SOL);
+}
+
+#[tokio::test]
+async fn cannot_sell_listed_asset_without_paying_seller() {
+    let marketplace_id = Pubkey::new_unique();
+    let mut program_test = program_test();
+    program_test.add_program("marketplace", marketplace_id, processor!(marketplace));
+    let mut context = program_test.start_with_context().await;
+
+    let asset = Keypair::new();
+    let seller = Keypair::new();
+    create_listed_asset(&mut context, &asset, &seller, marketplace_id).await;
+
+    let tx = buy_tx(&context, marketplace_id, asset.pubkey(), None);
+    assert_program_error(&mut context, tx, MplCoreError::MissingListingSeller).await;
+}
+
+#[tokio::test]
+async fn cannot_sell_asset_listed_on_another_marketplace() {
+    let marketplace_id = Pubkey::new_unique();
+    let other_marketplace_id = Pubkey::new_unique();
+    let mut program_test = program_test();
+    program_test.add_program("marketplace", marketplace_id, processor!(marketplace));
+    program_test.add_program(
+        "other_marketplace",
+        other_marketplace_id,
+        processor!(marketplace),
+    );
+    let mut context = program_test.start_with_context().await;
+
+    let asset = Keypair::new();
+    let seller = Keypair::new();
+    create_listed_asset(&mut context, &asset, &seller, marketplace_id).await;
+
+    let tx = buy_tx(
+        &context,
+        other_marketplace_id,
+        asset.pubkey(),
+        Some(seller.pubkey()),
+    );
+    assert_program_error(&mut context, tx, MplCoreError::NoApprovals).await;
+}
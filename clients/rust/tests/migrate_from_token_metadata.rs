@@ -0,0 +1,227 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use borsh::BorshSerialize;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::MigrateFromTokenMetadataV1Builder,
+    types::{Creator, Royalties, RuleSet, UpdateAuthority},
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// The account key of a Token Metadata metadata account.
+const TOKEN_METADATA_KEY_METADATA_V1: u8 = 4;
+
+#[derive(BorshSerialize)]
+struct TokenMetadataCreator {
+    address: Pubkey,
+    verified: bool,
+    share: u8,
+}
+
+#[derive(BorshSerialize)]
+struct TokenMetadataCollection {
+    verified: bool,
+    key: Pubkey,
+}
+
+/// The leading fields of a Token Metadata metadata account, after its key.
+#[derive(BorshSerialize)]
+struct TokenMetadata {
+    update_authority: Pubkey,
+    mint: Pubkey,
+    name: String,
+    symbol: String,
+    uri: String,
+    seller_fee_basis_points: u16,
+    creators: Option<Vec<TokenMetadataCreator>>,
+    primary_sale_happened: bool,
+    is_mutable: bool,
+    edition_nonce: Option<u8>,
+    token_standard: Option<u8>,
+    collection: Option<TokenMetadataCollection>,
+}
+
+fn set_account(context: &mut ProgramTestContext, address: &Pubkey, owner: Pubkey, data: Vec<u8>) {
+    context.set_account(
+        address,
+        &Account {
+            lamports: 1_000_000_000,
+            data,
+            owner,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+}
+
+/// Write an NFT of `owner` with its metadata managed by `update_authority` into account state.
+/// Returns the metadata address.
+async fn set_token_metadata_nft(
+    context: &mut ProgramTestContext,
+    mint: Pubkey,
+    token_account: Pubkey,
+    owner: Pubkey,
+    update_authority: Pubkey,
+    creator: Pubkey,
+) -> Pubkey {
+    // The optional mint authority, then the supply, the decimals and the initialized flag.
+    let mut mint_data = vec![0; 82];
+    mint_data[36..44].copy_from_slice(&1u64.to_le_bytes());
+    mint_data[45] = 1;
+    set_account(context, &mint, SPL_TOKEN_PROGRAM_ID, mint_data);
+
+    set_token_account(context, token_account, SPL_TOKEN_PROGRAM_ID, mint, owner, 1).await;
+
+    // Token Metadata pads its strings with nulls.
+    let mut metadata_data = vec![TOKEN_METADATA_KEY_METADATA_V1];
+    TokenMetadata {
+        update_authority,
+        mint,
+        name: format!("{:\0<32}", "Legacy NFT"),
+        symbol: format!("{:\0<10}", "LGCY"),
+        uri: format!("{:\0<200}", "https://example.com/nft"),
+        seller_fee_basis_points: 500,
+        creators: Some(vec![TokenMetadataCreator {
+            address: creator,
+            verified: true,
+            share: 100,
+        }]),
+        primary_sale_happened: true,
+        is_mutable: true,
+        edition_nonce: Some(255),
+        token_standard: Some(0),
+        collection: None,
+    }
+    .serialize(&mut metadata_data)
+    .unwrap();
+    let (metadata, _) = find_token_metadata_address(&mint);
+    set_account(context, &metadata, TOKEN_METADATA_PROGRAM_ID, metadata_data);
+
+    metadata
+}
+
+fn migrate_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    update_authority: Pubkey,
+    metadata: Pubkey,
+    mint: Pubkey,
+    token_account: Pubkey,
+) -> Transaction {
+    let migrate_ix = MigrateFromTokenMetadataV1Builder::new()
+        .asset(asset.pubkey())
+        .owner(context.payer.pubkey())
+        .update_authority(update_authority)
+        .payer(context.payer.pubkey())
+        .metadata(metadata)
+        .mint(mint)
+        .token_account(token_account)
+        .token_program(SPL_TOKEN_PROGRAM_ID)
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[migrate_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, asset],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn migrate_nft_to_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let mint = Keypair::new().pubkey();
+    let token_account = Keypair::new().pubkey();
+    let update_authority = Keypair::new().pubkey();
+    let creator = Keypair::new().pubkey();
+    let owner = context.payer.pubkey();
+    let metadata = set_token_metadata_nft(
+        &mut context,
+        mint,
+        token_account,
+        owner,
+        update_authority,
+        creator,
+    )
+    .await;
+
+    let asset = Keypair::new();
+    let tx = migrate_tx(
+        &context,
+        &asset,
+        update_authority,
+        metadata,
+        mint,
+        token_account,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(fetched.base.owner, owner);
+    assert_eq!(
+        fetched.base.update_authority,
+        UpdateAuthority::Address(update_authority)
+    );
+    assert_eq!(fetched.base.name, "Legacy NFT");
+    assert_eq!(fetched.base.uri, "https://example.com/nft");
+    assert_eq!(
+        fetched.plugin_list.royalties.unwrap().royalties,
+        Royalties {
+            basis_points: 500,
+            creators: vec![Creator {
+                address: creator,
+                percentage: 100,
+            }],
+            rule_set: RuleSet::None,
+        }
+    );
+
+    // The NFT is burned and its token account closed.
+    let token_account = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .expect("get_account");
+    assert!(token_account.is_none());
+    let mint_account = context
+        .banks_client
+        .get_account(mint)
+        .await
+        .expect("get_account")
+        .expect("mint account not found");
+    assert_eq!(mint_account.data[36..44], 0u64.to_le_bytes());
+}
+
+#[tokio::test]
+async fn cannot_migrate_without_metadata_update_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let mint = Keypair::new().pubkey();
+    let token_account = Keypair::new().pubkey();
+    let owner = context.payer.pubkey();
+    let metadata = set_token_metadata_nft(
+        &mut context,
+        mint,
+        token_account,
+        owner,
+        Keypair::new().pubkey(),
+        Keypair::new().pubkey(),
+    )
+    .await;
+
+    let tx = migrate_tx(
+        &context,
+        &Keypair::new(),
+        Keypair::new().pubkey(),
+        metadata,
+        mint,
+        token_account,
+    );
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    types::{MintLimit, Plugin, PluginAuthorityPair},
+};
+pub use setup::*;
+
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    instruction::InstructionError, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::TransactionError,
+};
+
+async fn create_limited_collection(
+    context: &mut ProgramTestContext,
+    collection: &Keypair,
+    mint_limit: MintLimit,
+) -> Result<(), BanksClientError> {
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::MintLimit(mint_limit),
+                authority: None,
+            }],
+        },
+    )
+    .await
+}
+
+async fn create_member_asset(
+    context: &mut ProgramTestContext,
+    collection: Pubkey,
+) -> Result<(), BanksClientError> {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &Keypair::new(),
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection),
+            plugins: vec![],
+        },
+    )
+    .await
+}
+
+#[tokio::test]
+async fn create_assets_up_to_mint_limit() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_limited_collection(
+        &mut context,
+        &collection,
+        MintLimit {
+            max_supply: 2,
+            minted: 0,
+        },
+    )
+    .await
+    .unwrap();
+
+    for _ in 0..2 {
+        create_member_asset(&mut context, collection.pubkey())
+            .await
+            .unwrap();
+    }
+
+    let fetched = get_collection(&mut context, collection.pubkey()).await;
+    assert_eq!(
+        fetched.plugin_list.mint_limit.unwrap().mint_limit,
+        MintLimit {
+            max_supply: 2,
+            minted: 2,
+        }
+    );
+
+    let err = create_member_asset(&mut context, collection.pubkey())
+        .await
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(MplCoreError::MintLimitReached as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn cannot_create_collection_with_minted_count() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_limited_collection(
+        &mut context,
+        &collection,
+        MintLimit {
+            max_supply: 2,
+            minted: 1,
+        },
+    )
+    .await
+    .unwrap_err();
+}
@@ -0,0 +1,135 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::UpdatePluginV1Builder,
+    types::{Attribute, Attributes, Multisig, Plugin, PluginAuthority, PluginAuthorityPair},
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::AccountMeta, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+/// The address identifying the multisig of an asset, as derived by the program.
+fn find_multisig_address(asset: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"multisig", asset.as_ref()], &mpl_core::ID).0
+}
+
+/// Create an asset whose attributes are managed by a 2-of-2 multisig of `signers`.
+async fn create_multisig_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    signers: &[&Keypair; 2],
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![
+                PluginAuthorityPair {
+                    plugin: Plugin::Multisig(Multisig {
+                        threshold: 2,
+                        signers: signers.iter().map(|signer| signer.pubkey()).collect(),
+                    }),
+                    authority: None,
+                },
+                PluginAuthorityPair {
+                    plugin: Plugin::Attributes(Attributes {
+                        attribute_list: vec![],
+                    }),
+                    authority: Some(PluginAuthority::Address {
+                        address: find_multisig_address(&asset.pubkey()),
+                    }),
+                },
+            ],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// Update the attributes of an asset signed by `authority` and co-signed by `co_signers`.
+fn update_attributes_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    authority: &Keypair,
+    co_signers: &[&Keypair],
+) -> Transaction {
+    let update_plugin_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .plugin(Plugin::Attributes(Attributes {
+            attribute_list: vec![Attribute {
+                key: "level".to_owned(),
+                value: "2".to_owned(),
+            }],
+        }))
+        .add_remaining_accounts(
+            &co_signers
+                .iter()
+                .map(|co_signer| AccountMeta::new_readonly(co_signer.pubkey(), true))
+                .collect::<Vec<_>>(),
+        )
+        .instruction();
+
+    let mut signers = vec![&context.payer, authority];
+    signers.extend_from_slice(co_signers);
+    Transaction::new_signed_with_payer(
+        &[update_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &signers,
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn multisig_updates_plugin_at_threshold() {
+    let mut context = program_test().start_with_context().await;
+
+    let first = Keypair::new();
+    let second = Keypair::new();
+    let asset = Keypair::new();
+    create_multisig_asset(&mut context, &asset, &[&first, &second]).await;
+
+    let tx = update_attributes_tx(&context, &asset, &first, &[&second]);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        asset
+            .plugin_list
+            .attributes
+            .unwrap()
+            .attributes
+            .attribute_list,
+        vec![Attribute {
+            key: "level".to_owned(),
+            value: "2".to_owned(),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn cannot_update_plugin_below_threshold() {
+    let mut context = program_test().start_with_context().await;
+
+    let first = Keypair::new();
+    let second = Keypair::new();
+    let asset = Keypair::new();
+    create_multisig_asset(&mut context, &asset, &[&first, &second]).await;
+
+    let tx = update_attributes_tx(&context, &asset, &first, &[]);
+    assert_program_error(&mut context, tx, MplCoreError::NoApprovals).await;
+}
@@ -0,0 +1,111 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    instructions::TransferV1Builder,
+    types::{OwnerAllowList, Plugin, PluginAuthorityPair, PluginType},
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset owned by the context payer which can only be transferred to `allowed`.
+async fn create_restricted_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    allowed: Vec<Pubkey>,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::OwnerAllowList(OwnerAllowList { allowed }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+async fn transfer(context: &mut ProgramTestContext, asset: &Keypair, new_owner: Pubkey) {
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn transfer_to_allowed_owner() {
+    let mut context = program_test().start_with_context().await;
+
+    let allowed_owner = Keypair::new().pubkey();
+    let asset = Keypair::new();
+    create_restricted_asset(&mut context, &asset, vec![allowed_owner]).await;
+
+    transfer(&mut context, &asset, allowed_owner).await;
+    assert_eq!(
+        get_asset(&mut context, asset.pubkey()).await.base.owner,
+        allowed_owner
+    );
+}
+
+#[tokio::test]
+async fn transfer_to_account_of_allowed_program() {
+    let mut context = program_test().start_with_context().await;
+
+    // Any account owned by an allowed program can own the asset, such as a collection.
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let asset = Keypair::new();
+    create_restricted_asset(&mut context, &asset, vec![mpl_core::ID]).await;
+
+    transfer(&mut context, &asset, collection.pubkey()).await;
+}
+
+#[tokio::test]
+async fn cannot_transfer_to_owner_not_allowed() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_restricted_asset(&mut context, &asset, vec![Keypair::new().pubkey()]).await;
+
+    assert_transfer_rejected_by(
+        &mut context,
+        asset.pubkey(),
+        None,
+        PluginType::OwnerAllowList,
+    )
+    .await;
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::UpdateV2Builder,
+    types::{Plugin, PluginAuthority, PluginAuthorityPair, UpdateAuthority, UpdateDelegate},
+};
+pub use setup::*;
+
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset whose update delegate is `delegate`.
+async fn create_delegated_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    delegate: &Keypair,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::UpdateDelegate(UpdateDelegate {
+                    additional_delegates: vec![],
+                }),
+                authority: Some(PluginAuthority::Address {
+                    address: delegate.pubkey(),
+                }),
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn update_delegate_updates_name_only() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_delegated_asset(&mut context, &asset, &delegate).await;
+    let before = get_asset(&mut context, asset.pubkey()).await;
+
+    let update_ix = UpdateV2Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .new_name("Renamed Asset".to_owned())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Fields that are not given are left as they are.
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(fetched.base.name, "Renamed Asset");
+    assert_eq!(fetched.base.uri, before.base.uri);
+    assert_eq!(fetched.base.update_authority, before.base.update_authority);
+}
+
+#[tokio::test]
+async fn cannot_hand_over_update_authority_as_update_delegate() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_delegated_asset(&mut context, &asset, &delegate).await;
+
+    let update_ix = UpdateV2Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .new_update_authority(UpdateAuthority::Address(delegate.pubkey()))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+    assert_program_error(&mut context, tx, MplCoreError::NoApprovals).await;
+}
@@ -0,0 +1,169 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    instructions::{CreateV1Builder, UpdateCollectionPluginV1Builder},
+    types::{DataState, Paused, Plugin, PluginAuthorityPair, PluginType, UpdateAuthority},
+};
+pub use setup::*;
+
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, system_program, transaction::Transaction};
+
+/// Create a collection with an unpaused `Paused` plugin, and an asset in it owned by the payer.
+async fn create_collection_and_asset(
+    context: &mut ProgramTestContext,
+    collection: &Keypair,
+    asset: &Keypair,
+) {
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Paused(Paused { paused: false }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+async fn set_paused(context: &mut ProgramTestContext, collection: &Keypair, paused: bool) {
+    let update_plugin_ix = UpdateCollectionPluginV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin(Plugin::Paused(Paused { paused }))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+fn create_in_collection_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    collection: &Keypair,
+) -> Transaction {
+    let create_ix = CreateV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(Some(collection.pubkey()))
+        .payer(context.payer.pubkey())
+        .system_program(system_program::ID)
+        .data_state(DataState::AccountState)
+        .name("Test Asset".to_owned())
+        .uri("https://example.com/asset".to_owned())
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&context.payer.pubkey()),
+        &[asset, &context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn mint_into_unpaused_collection() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    create_collection_and_asset(&mut context, &collection, &asset).await;
+
+    let new_asset = Keypair::new();
+    let tx = create_in_collection_tx(&context, &new_asset, &collection);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let owner = context.payer.pubkey();
+    assert_asset(
+        &mut context,
+        AssertAssetHelperArgs {
+            asset: new_asset.pubkey(),
+            owner,
+            update_authority: Some(UpdateAuthority::Collection(collection.pubkey())),
+            name: None,
+            uri: None,
+            plugins: vec![],
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn cannot_mint_into_paused_collection() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    create_collection_and_asset(&mut context, &collection, &asset).await;
+    set_paused(&mut context, &collection, true).await;
+
+    let new_asset = Keypair::new();
+    let tx = create_in_collection_tx(&context, &new_asset, &collection);
+    assert_rejected_by(&mut context, tx, PluginType::Paused).await;
+}
+
+#[tokio::test]
+async fn cannot_transfer_asset_in_paused_collection() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    create_collection_and_asset(&mut context, &collection, &asset).await;
+    set_paused(&mut context, &collection, true).await;
+
+    assert_transfer_rejected_by(
+        &mut context,
+        asset.pubkey(),
+        Some(collection.pubkey()),
+        PluginType::Paused,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn cannot_burn_asset_in_paused_collection() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    create_collection_and_asset(&mut context, &collection, &asset).await;
+    set_paused(&mut context, &collection, true).await;
+
+    assert_burn_rejected_by(
+        &mut context,
+        asset.pubkey(),
+        Some(collection.pubkey()),
+        PluginType::Paused,
+    )
+    .await;
+}
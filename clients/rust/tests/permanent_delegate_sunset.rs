@@ -0,0 +1,111 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{TransferV1Builder, UpdatePluginV1Builder},
+    types::{PermanentTransferDelegate, Plugin, PluginAuthorityPair, PluginType},
+};
+pub use setup::*;
+
+use solana_program::clock::Clock;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset owned by someone else whose update authority, the context payer, can transfer
+/// it until the next day. Returns the sunset of the delegate.
+async fn create_delegated_asset(context: &mut ProgramTestContext, asset: &Keypair) -> i64 {
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let sunset = clock.unix_timestamp + 86_400;
+
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: Some(Keypair::new().pubkey()),
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::PermanentTransferDelegate(PermanentTransferDelegate {
+                    sunset: Some(sunset),
+                }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+
+    sunset
+}
+
+/// Transfer an asset by its permanent transfer delegate, the context payer.
+fn delegate_transfer_tx(context: &ProgramTestContext, asset: &Keypair) -> Transaction {
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(Keypair::new().pubkey())
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn delegate_transfers_before_sunset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_delegated_asset(&mut context, &asset).await;
+
+    let tx = delegate_transfer_tx(&context, &asset);
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn delegate_cannot_transfer_after_sunset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let sunset = create_delegated_asset(&mut context, &asset).await;
+
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = sunset;
+    context.set_sysvar(&clock);
+
+    let tx = delegate_transfer_tx(&context, &asset);
+    assert_program_error(&mut context, tx, MplCoreError::NoApprovals).await;
+}
+
+#[tokio::test]
+async fn cannot_push_back_sunset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let sunset = create_delegated_asset(&mut context, &asset).await;
+
+    let update_plugin_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin(Plugin::PermanentTransferDelegate(
+            PermanentTransferDelegate {
+                sunset: Some(sunset + 1),
+            },
+        ))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[update_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_rejected_by(&mut context, tx, PluginType::PermanentTransferDelegate).await;
+}
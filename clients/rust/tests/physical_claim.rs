@@ -0,0 +1,154 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{TransferV1Builder, UpdatePluginV1Builder},
+    types::{ClaimStatus, PhysicalClaim, Plugin, PluginAuthority, PluginAuthorityPair, PluginType},
+};
+pub use setup::*;
+
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset owned by the context payer with an unclaimed, freeze-in-flight physical
+/// claim managed by `authority`.
+async fn create_claimable_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    authority: Option<PluginAuthority>,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::PhysicalClaim(PhysicalClaim {
+                    status: ClaimStatus::Unclaimed,
+                    freeze_in_flight: true,
+                }),
+                authority,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn update_claim_status_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    authority: &Keypair,
+    status: ClaimStatus,
+) -> Transaction {
+    let update_plugin_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .plugin(Plugin::PhysicalClaim(PhysicalClaim {
+            status,
+            freeze_in_flight: true,
+        }))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[update_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn fulfillment_delegate_ships_and_delivers_claim() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_claimable_asset(
+        &mut context,
+        &asset,
+        Some(PluginAuthority::Address {
+            address: delegate.pubkey(),
+        }),
+    )
+    .await;
+
+    let tx = update_claim_status_tx(&context, &asset, &delegate, ClaimStatus::Shipped);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The asset is frozen while the item is in flight.
+    assert_transfer_rejected_by(
+        &mut context,
+        asset.pubkey(),
+        None,
+        PluginType::PhysicalClaim,
+    )
+    .await;
+
+    let tx = update_claim_status_tx(&context, &asset, &delegate, ClaimStatus::Delivered);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        fetched.plugin_list.physical_claim.unwrap().physical_claim,
+        PhysicalClaim {
+            status: ClaimStatus::Delivered,
+            freeze_in_flight: true,
+        }
+    );
+
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(Keypair::new().pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn cannot_update_claim_without_fulfillment_delegate() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_claimable_asset(&mut context, &asset, None).await;
+
+    let update_authority = context.payer.insecure_clone();
+    let tx = update_claim_status_tx(&context, &asset, &update_authority, ClaimStatus::Shipped);
+    assert_rejected_by(&mut context, tx, PluginType::PhysicalClaim).await;
+}
+
+#[tokio::test]
+async fn cannot_move_claim_status_backwards() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_claimable_asset(
+        &mut context,
+        &asset,
+        Some(PluginAuthority::Address {
+            address: delegate.pubkey(),
+        }),
+    )
+    .await;
+
+    let tx = update_claim_status_tx(&context, &asset, &delegate, ClaimStatus::Delivered);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = update_claim_status_tx(&context, &asset, &delegate, ClaimStatus::Shipped);
+    assert_program_error(&mut context, tx, MplCoreError::InvalidPluginSetting).await;
+}
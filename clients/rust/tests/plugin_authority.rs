@@ -0,0 +1,315 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    accounts::BaseAssetV1,
+    errors::MplCoreError,
+    fetch_plugin,
+    instructions::{
+        RemovePluginV1Builder, RevokePluginAuthorityV1Builder, ThawAssetV1Builder,
+        TransferV1Builder,
+    },
+    types::{
+        FreezeDelegate, Plugin, PluginAuthority, PluginAuthorityPair, PluginType, TransferDelegate,
+    },
+    Asset, AuthorityType,
+};
+pub use setup::*;
+
+use solana_program::{account_info::AccountInfo, clock::Clock, pubkey::Pubkey};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+#[tokio::test]
+async fn address_with_expiry_authority_round_trips() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    let authority = PluginAuthority::AddressWithExpiry {
+        address: delegate.pubkey(),
+        expires_at: i64::MAX,
+    };
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+                authority: Some(authority.clone()),
+            }],
+        },
+    )
+    .await
+    .unwrap();
+
+    let mut asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+
+    let full_asset = Asset::from_bytes(&asset_account.data).unwrap();
+    let base_authority = full_asset
+        .plugin_list
+        .freeze_delegate
+        .unwrap()
+        .base
+        .authority;
+    assert_eq!(
+        base_authority.authority_type,
+        AuthorityType::AddressWithExpiry
+    );
+    assert_eq!(base_authority.address, Some(delegate.pubkey()));
+    assert_eq!(base_authority.expires_at, Some(i64::MAX));
+
+    let asset_pubkey = asset.pubkey();
+    let mut lamports = 1_000_000_000;
+    let account_info = AccountInfo::new(
+        &asset_pubkey,
+        false,
+        false,
+        &mut lamports,
+        &mut asset_account.data,
+        &asset_account.owner,
+        false,
+        1_000_000_000,
+    );
+
+    let (fetched_authority, freeze_delegate, _) =
+        fetch_plugin::<BaseAssetV1, FreezeDelegate>(&account_info, PluginType::FreezeDelegate)
+            .unwrap();
+    assert_eq!(fetched_authority, authority);
+    assert_eq!(freeze_delegate, FreezeDelegate { frozen: false });
+}
+
+/// Create an asset owned by the context payer with `plugin` delegated to `delegate` for a day,
+/// returning the expiry.
+async fn create_asset_with_expiring_delegate(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    plugin: Plugin,
+    delegate: Pubkey,
+) -> i64 {
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let expires_at = clock.unix_timestamp + 86_400;
+
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin,
+                authority: Some(PluginAuthority::AddressWithExpiry {
+                    address: delegate,
+                    expires_at,
+                }),
+            }],
+        },
+    )
+    .await
+    .unwrap();
+
+    expires_at
+}
+
+/// Move the clock to `expires_at`, at which point the delegate has expired.
+async fn expire(context: &mut ProgramTestContext, expires_at: i64) {
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = expires_at;
+    context.set_sysvar(&clock);
+}
+
+fn delegate_transfer_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    delegate: &Keypair,
+) -> Transaction {
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .new_owner(delegate.pubkey())
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, delegate],
+        context.last_blockhash,
+    )
+}
+
+fn thaw_tx(context: &ProgramTestContext, asset: &Keypair) -> Transaction {
+    let thaw_ix = ThawAssetV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[thaw_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn address_with_expiry_authority_approves_before_expiry() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_asset_with_expiring_delegate(
+        &mut context,
+        &asset,
+        Plugin::TransferDelegate(TransferDelegate {}),
+        delegate.pubkey(),
+    )
+    .await;
+
+    let tx = delegate_transfer_tx(&context, &asset, &delegate);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(fetched.base.owner, delegate.pubkey());
+}
+
+#[tokio::test]
+async fn address_with_expiry_authority_lapses_after_expiry() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    let expires_at = create_asset_with_expiring_delegate(
+        &mut context,
+        &asset,
+        Plugin::TransferDelegate(TransferDelegate {}),
+        delegate.pubkey(),
+    )
+    .await;
+
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = expires_at;
+    context.set_sysvar(&clock);
+
+    let tx = delegate_transfer_tx(&context, &asset, &delegate);
+    assert_program_error(&mut context, tx, MplCoreError::NoApprovals).await;
+}
+
+#[tokio::test]
+async fn owner_revokes_expired_delegate() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let expires_at = create_asset_with_expiring_delegate(
+        &mut context,
+        &asset,
+        Plugin::TransferDelegate(TransferDelegate {}),
+        Keypair::new().pubkey(),
+    )
+    .await;
+    expire(&mut context, expires_at).await;
+
+    let revoke_ix = RevokePluginAuthorityV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin_type(PluginType::TransferDelegate)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[revoke_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        fetched
+            .plugin_list
+            .transfer_delegate
+            .unwrap()
+            .base
+            .authority
+            .authority_type,
+        AuthorityType::Owner
+    );
+}
+
+#[tokio::test]
+async fn owner_removes_expired_delegate() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let expires_at = create_asset_with_expiring_delegate(
+        &mut context,
+        &asset,
+        Plugin::TransferDelegate(TransferDelegate {}),
+        Keypair::new().pubkey(),
+    )
+    .await;
+    expire(&mut context, expires_at).await;
+
+    let remove_plugin_ix = RemovePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin_type(PluginType::TransferDelegate)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[remove_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert!(fetched.plugin_list.transfer_delegate.is_none());
+}
+
+#[tokio::test]
+async fn owner_thaws_asset_after_freeze_delegate_expires() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let expires_at = create_asset_with_expiring_delegate(
+        &mut context,
+        &asset,
+        Plugin::FreezeDelegate(FreezeDelegate { frozen: true }),
+        Keypair::new().pubkey(),
+    )
+    .await;
+
+    // The owner cannot thaw the asset while the delegate is in effect.
+    let tx = thaw_tx(&context, &asset);
+    assert_program_error(&mut context, tx, MplCoreError::NoApprovals).await;
+
+    expire(&mut context, expires_at).await;
+
+    context.last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let tx = thaw_tx(&context, &asset);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        fetched.plugin_list.freeze_delegate.unwrap().freeze_delegate,
+        FreezeDelegate { frozen: false }
+    );
+}
@@ -1,19 +1,24 @@
 #![cfg(feature = "test-sbf")]
 pub mod setup;
+use borsh::BorshSerialize;
 use mpl_core::{
-    accounts::{BaseAssetV1, PluginHeaderV1},
-    fetch_plugin, fetch_plugins, list_plugins,
+    accounts::{BaseAssetV1, BaseCollectionV1, PluginHeaderV1, PluginRegistryV1},
+    fetch_plugin, fetch_plugin_from_data, fetch_plugins,
+    instructions::UpdatePluginV1Builder,
+    list_plugins,
     types::{
-        Creator, FreezeDelegate, Plugin, PluginAuthority, PluginAuthorityPair, PluginType,
-        RegistryRecord, Royalties, RuleSet, UpdateAuthority,
+        Attribute, Attributes, Creator, FreezeDelegate, Plugin, PluginAuthority,
+        PluginAuthorityPair, PluginType, RegistryRecord, Royalties, RuleSet, UpdateAuthority,
     },
-    DataBlob,
+    Asset, DataBlob, FullAsset, FullPlugin,
 };
 pub use setup::*;
 
 use solana_program::account_info::AccountInfo;
 use solana_program_test::tokio;
-use solana_sdk::{signature::Keypair, signer::Signer};
+use solana_sdk::{
+    account::AccountSharedData, signature::Keypair, signer::Signer, transaction::Transaction,
+};
 use std::mem::size_of;
 
 #[tokio::test]
@@ -124,6 +129,145 @@ async fn test_fetch_plugin() {
     assert_eq!(plugin, expected);
 }
 
+#[tokio::test]
+async fn test_fetch_plugin_from_data() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let creator = context.payer.pubkey();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                authority: None,
+                plugin: Plugin::Royalties(Royalties {
+                    basis_points: 500,
+                    creators: vec![Creator {
+                        address: creator,
+                        percentage: 100,
+                    }],
+                    rule_set: RuleSet::None,
+                }),
+            }],
+        },
+    )
+    .await
+    .unwrap();
+
+    let collection_account = context
+        .banks_client
+        .get_account(collection.pubkey())
+        .await
+        .expect("get_account")
+        .expect("collection account not found");
+
+    let plugin =
+        fetch_plugin_from_data::<Royalties>(&collection_account.data, PluginType::Royalties)
+            .unwrap();
+
+    let expected_plugin_offset = BaseCollectionV1::from_bytes(&collection_account.data)
+        .unwrap()
+        .get_size()
+        + PluginHeaderV1::LEN;
+
+    let expected = (
+        PluginAuthority::UpdateAuthority,
+        Royalties {
+            basis_points: 500,
+            creators: vec![Creator {
+                address: creator,
+                percentage: 100,
+            }],
+            rule_set: RuleSet::None,
+        },
+        expected_plugin_offset,
+    );
+    assert_eq!(plugin, expected);
+
+    assert!(fetch_plugin_from_data::<FreezeDelegate>(
+        &collection_account.data,
+        PluginType::FreezeDelegate
+    )
+    .is_err());
+}
+
+#[tokio::test]
+async fn test_full_asset_deserialize() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let creator = context.payer.pubkey();
+    let royalties = Royalties {
+        basis_points: 500,
+        creators: vec![Creator {
+            address: creator,
+            percentage: 100,
+        }],
+        rule_set: RuleSet::None,
+    };
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![
+                PluginAuthorityPair {
+                    plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+                    authority: None,
+                },
+                PluginAuthorityPair {
+                    plugin: Plugin::Royalties(royalties.clone()),
+                    authority: None,
+                },
+            ],
+        },
+    )
+    .await
+    .unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+
+    let full_asset = FullAsset::deserialize(&asset_account.data).unwrap();
+    assert_eq!(full_asset.asset.base.owner, context.payer.pubkey());
+
+    let expected_first_plugin_offset = BaseAssetV1::from_bytes(&asset_account.data)
+        .unwrap()
+        .get_size()
+        + PluginHeaderV1::LEN;
+    // The registry is sorted by plugin type rather than by offset.
+    assert_eq!(full_asset.plugins.len(), 2);
+    assert_eq!(full_asset.plugins[0].plugin, Plugin::Royalties(royalties));
+    assert_eq!(
+        full_asset.plugins[0].authority,
+        PluginAuthority::UpdateAuthority
+    );
+    assert_eq!(
+        full_asset.plugins[1],
+        FullPlugin {
+            plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+            authority: PluginAuthority::Owner,
+            offset: Some(expected_first_plugin_offset as u64),
+        }
+    );
+}
+
 #[tokio::test]
 async fn test_fetch_plugins() {
     let mut context = program_test().start_with_context().await;
@@ -224,11 +368,12 @@ async fn test_fetch_plugins() {
         offset: expected_second_plugin_offset as u64,
     };
 
+    // The registry is sorted by plugin type rather than by offset.
     assert_eq!(
         plugins,
         vec![
-            first_expected_registry_record,
-            second_expected_registry_record
+            second_expected_registry_record,
+            first_expected_registry_record
         ]
     )
 }
@@ -311,9 +456,105 @@ async fn test_list_plugins() {
         .expect("get_account")
         .expect("asset account not found");
 
+    // The registry is sorted by plugin type.
     let plugins = list_plugins(&asset_account.data).unwrap();
     assert_eq!(
         plugins,
-        vec![PluginType::FreezeDelegate, PluginType::Royalties]
+        vec![PluginType::Royalties, PluginType::FreezeDelegate]
     )
 }
+
+#[tokio::test]
+async fn test_update_plugin_in_unsorted_registry() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let creator = context.payer.pubkey();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![
+                PluginAuthorityPair {
+                    authority: None,
+                    plugin: Plugin::Royalties(Royalties {
+                        basis_points: 500,
+                        creators: vec![Creator {
+                            address: creator,
+                            percentage: 100,
+                        }],
+                        rule_set: RuleSet::ProgramDenyList(vec![]),
+                    }),
+                },
+                PluginAuthorityPair {
+                    authority: None,
+                    plugin: Plugin::Attributes(Attributes {
+                        attribute_list: vec![],
+                    }),
+                },
+            ],
+        },
+    )
+    .await
+    .unwrap();
+
+    // Rewrite the registry out of order, as it was stored before registries were kept sorted.
+    let mut asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let header_offset = BaseAssetV1::from_bytes(&asset_account.data)
+        .unwrap()
+        .get_size();
+    let registry_offset = PluginHeaderV1::from_bytes(&asset_account.data[header_offset..])
+        .unwrap()
+        .plugin_registry_offset as usize;
+    let mut registry =
+        PluginRegistryV1::from_bytes(&asset_account.data[registry_offset..]).unwrap();
+    registry.registry.reverse();
+    let registry_data = registry.try_to_vec().unwrap();
+    asset_account.data[registry_offset..registry_offset + registry_data.len()]
+        .copy_from_slice(&registry_data);
+    context.set_account(&asset.pubkey(), &AccountSharedData::from(asset_account));
+
+    let attributes = Attributes {
+        attribute_list: vec![Attribute {
+            key: "key".to_owned(),
+            value: "value".to_owned(),
+        }],
+    };
+    let update_plugin_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin(Plugin::Attributes(attributes.clone()))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[update_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let full_asset = Asset::from_bytes(&asset_account.data).unwrap();
+    assert_eq!(
+        full_asset.plugin_list.attributes.unwrap().attributes,
+        attributes
+    );
+}
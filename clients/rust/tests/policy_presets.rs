@@ -0,0 +1,110 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{BurnV1Builder, CreateV2Builder},
+    types::{
+        DataState, PermanentFreezeDelegate, Plugin, PluginAuthorityPair, PluginType, PolicyPreset,
+    },
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, system_program, transaction::Transaction};
+
+fn create_with_preset_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    owner: Pubkey,
+    preset: PolicyPreset,
+    plugins: Vec<PluginAuthorityPair>,
+) -> Transaction {
+    let create_ix = CreateV2Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .owner(Some(owner))
+        .system_program(system_program::ID)
+        .data_state(DataState::AccountState)
+        .name("Test Asset".to_owned())
+        .uri("https://example.com/asset".to_owned())
+        .plugins(plugins)
+        .rent_contributions(vec![])
+        .preset(preset)
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, asset],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn cannot_transfer_soulbound_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let owner = context.payer.pubkey();
+    let tx = create_with_preset_tx(&context, &asset, owner, PolicyPreset::Soulbound, vec![]);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_transfer_rejected_by(
+        &mut context,
+        asset.pubkey(),
+        None,
+        PluginType::PermanentFreezeDelegate,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn update_authority_revokes_credential() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let owner = Keypair::new().pubkey();
+    let tx = create_with_preset_tx(&context, &asset, owner, PolicyPreset::Credential, vec![]);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The update authority burns the credential through its permanent burn delegate.
+    let burn_ix = BurnV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(asset_account.data.len(), 1);
+}
+
+#[tokio::test]
+async fn cannot_repeat_preset_plugin() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let owner = context.payer.pubkey();
+    let tx = create_with_preset_tx(
+        &context,
+        &asset,
+        owner,
+        PolicyPreset::Soulbound,
+        vec![PluginAuthorityPair {
+            plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate { frozen: false }),
+            authority: None,
+        }],
+    );
+    assert_program_error(&mut context, tx, MplCoreError::PluginAlreadyExists).await;
+}
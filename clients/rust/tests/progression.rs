@@ -0,0 +1,124 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::UpdatePluginV1Builder,
+    types::{Plugin, PluginAuthority, PluginAuthorityPair, Progression},
+};
+pub use setup::*;
+
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+fn progression(experience: u64, level: u32) -> Progression {
+    Progression {
+        experience,
+        level,
+        max_experience: 1_000,
+        max_level: 10,
+    }
+}
+
+/// Create an asset with no progress whose progression is managed by `game`.
+async fn create_game_asset(context: &mut ProgramTestContext, asset: &Keypair, game: &Keypair) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Progression(progression(0, 1)),
+                authority: Some(PluginAuthority::Address {
+                    address: game.pubkey(),
+                }),
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn update_progression_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    authority: &Keypair,
+    progression: Progression,
+) -> Transaction {
+    let update_plugin_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .plugin(Plugin::Progression(progression))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[update_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn game_delegate_increments_progression() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let game = Keypair::new();
+    create_game_asset(&mut context, &asset, &game).await;
+
+    let tx = update_progression_tx(&context, &asset, &game, progression(250, 3));
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        fetched.plugin_list.progression.unwrap().progression,
+        progression(250, 3)
+    );
+}
+
+#[tokio::test]
+async fn cannot_progress_beyond_caps() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let game = Keypair::new();
+    create_game_asset(&mut context, &asset, &game).await;
+
+    let tx = update_progression_tx(&context, &asset, &game, progression(250, 11));
+    assert_program_error(&mut context, tx, MplCoreError::InvalidPluginSetting).await;
+}
+
+#[tokio::test]
+async fn cannot_lower_progression() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let game = Keypair::new();
+    create_game_asset(&mut context, &asset, &game).await;
+
+    let tx = update_progression_tx(&context, &asset, &game, progression(250, 3));
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = update_progression_tx(&context, &asset, &game, progression(100, 3));
+    assert_program_error(&mut context, tx, MplCoreError::InvalidPluginSetting).await;
+}
+
+#[tokio::test]
+async fn cannot_progress_without_game_delegate() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let game = Keypair::new();
+    create_game_asset(&mut context, &asset, &game).await;
+
+    let tx = update_progression_tx(&context, &asset, &Keypair::new(), progression(250, 3));
+    assert_program_error(&mut context, tx, MplCoreError::NoApprovals).await;
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    instructions::{AddPluginV1Builder, RemovePluginV1Builder},
+    types::{FreezeDelegate, Plugin, PluginAuthorityPair, PluginType, TransferDelegate},
+    Asset,
+};
+pub use setup::*;
+
+use solana_program_test::tokio;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+#[tokio::test]
+async fn owner_cannot_remove_owner_managed_plugin_added_at_mint() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let owner = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: Some(owner.pubkey()),
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let full_asset = Asset::from_bytes(&asset_account.data).unwrap();
+    assert!(full_asset.plugin_list.freeze_delegate.is_some());
+    assert!(full_asset.plugin_list.provenance.is_some());
+
+    let remove_plugin_ix = RemovePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(owner.pubkey()))
+        .plugin_type(PluginType::FreezeDelegate)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[remove_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap_err();
+}
+
+#[tokio::test]
+async fn owner_can_remove_owner_managed_plugin_it_added() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let owner = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: Some(owner.pubkey()),
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+
+    let add_plugin_ix = AddPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(owner.pubkey()))
+        .plugin(Plugin::TransferDelegate(TransferDelegate {}))
+        .instruction();
+    let remove_plugin_ix = RemovePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(owner.pubkey()))
+        .plugin_type(PluginType::TransferDelegate)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_plugin_ix, remove_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let full_asset = Asset::from_bytes(&asset_account.data).unwrap();
+    assert!(full_asset.plugin_list.transfer_delegate.is_none());
+    assert!(full_asset.plugin_list.freeze_delegate.is_some());
+}
@@ -0,0 +1,240 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{AddCollectionPluginV1Builder, ReapV1Builder},
+    types::{Attribute, Attributes, Plugin, PluginAuthorityPair, Reaper},
+};
+pub use setup::*;
+
+use solana_program::{clock::Clock, native_token::LAMPORTS_PER_SOL};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+const SUBSIDY_PER_MINT: u64 = 1_000_000;
+
+/// Create a collection whose assets expire at `expires_at`, and an asset in it owned by
+/// someone other than the context payer.
+async fn create_expiring_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    collection: &Keypair,
+    expires_at: i64,
+) {
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Reaper(Reaper {
+                    expires_at,
+                    subsidy_per_mint: SUBSIDY_PER_MINT,
+                    pool_balance: 0,
+                }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: Some(Keypair::new().pubkey()),
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// Reap an asset by an account that is neither its owner nor the collection update authority.
+async fn reap_tx(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    collection: &Keypair,
+) -> Transaction {
+    let reaper = Keypair::new();
+    airdrop(context, &reaper.pubkey(), LAMPORTS_PER_SOL)
+        .await
+        .unwrap();
+
+    let reap_ix = ReapV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(collection.pubkey())
+        .payer(reaper.pubkey())
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[reap_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &reaper],
+        context.last_blockhash,
+    )
+}
+
+async fn reaper(context: &mut ProgramTestContext, collection: &Keypair) -> Reaper {
+    get_collection(context, collection.pubkey())
+        .await
+        .plugin_list
+        .reaper
+        .unwrap()
+        .reaper
+}
+
+#[tokio::test]
+async fn reaped_rent_subsidizes_next_mint() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let collection = Keypair::new();
+    create_expiring_asset(&mut context, &asset, &collection, 0).await;
+    let asset_lamports = context
+        .banks_client
+        .get_balance(asset.pubkey())
+        .await
+        .unwrap();
+
+    let tx = reap_tx(&mut context, &asset, &collection).await;
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The asset keeps the rent of the single byte marking it as burned.
+    let reaped_lamports = asset_lamports
+        - context
+            .banks_client
+            .get_balance(asset.pubkey())
+            .await
+            .unwrap();
+    assert_eq!(
+        reaper(&mut context, &collection).await.pool_balance,
+        reaped_lamports
+    );
+    assert_eq!(
+        get_collection(&mut context, collection.pubkey())
+            .await
+            .base
+            .current_size,
+        0
+    );
+
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &Keypair::new(),
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        reaper(&mut context, &collection).await.pool_balance,
+        reaped_lamports - SUBSIDY_PER_MINT
+    );
+}
+
+/// The lamports the collection holds beyond the rent for its size.
+async fn collection_excess_lamports(context: &mut ProgramTestContext, collection: &Keypair) -> u64 {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let collection_account = context
+        .banks_client
+        .get_account(collection.pubkey())
+        .await
+        .expect("get_account")
+        .expect("collection account not found");
+    collection_account.lamports - rent.minimum_balance(collection_account.data.len())
+}
+
+#[tokio::test]
+async fn collection_growth_does_not_spend_rent_pool() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let collection = Keypair::new();
+    create_expiring_asset(&mut context, &asset, &collection, 0).await;
+
+    let tx = reap_tx(&mut context, &asset, &collection).await;
+    context.banks_client.process_transaction(tx).await.unwrap();
+    let excess_lamports = collection_excess_lamports(&mut context, &collection).await;
+
+    let add_plugin_ix = AddCollectionPluginV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin(Plugin::Attributes(Attributes {
+            attribute_list: vec![Attribute {
+                key: "season".to_owned(),
+                value: "1".to_owned(),
+            }],
+        }))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[add_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The payer funds the rent of the new plugin, so the pool still backs the next subsidy.
+    assert_eq!(
+        collection_excess_lamports(&mut context, &collection).await,
+        excess_lamports
+    );
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &Keypair::new(),
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn cannot_reap_before_expiry() {
+    let mut context = program_test().start_with_context().await;
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let asset = Keypair::new();
+    let collection = Keypair::new();
+    create_expiring_asset(
+        &mut context,
+        &asset,
+        &collection,
+        clock.unix_timestamp + 86_400,
+    )
+    .await;
+
+    let tx = reap_tx(&mut context, &asset, &collection).await;
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+}
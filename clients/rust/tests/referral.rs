@@ -0,0 +1,70 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{errors::MplCoreError, instructions::CreateV1Builder, types::DataState};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::AccountMeta, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+/// Create an asset referred by `referrer`, recording the referral through `log_wrapper`.
+fn create_referred_asset_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    referrer: Pubkey,
+    log_wrapper: Pubkey,
+) -> Transaction {
+    let create_ix = CreateV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .log_wrapper(Some(log_wrapper))
+        .data_state(DataState::AccountState)
+        .name("Test Asset".to_owned())
+        .uri("https://example.com/asset".to_owned())
+        .add_remaining_account(AccountMeta::new_readonly(referrer, false))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&context.payer.pubkey()),
+        &[asset, &context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn create_records_referral() {
+    let mut context = program_test_with_noop().start_with_context().await;
+
+    let asset = Keypair::new();
+    let tx = create_referred_asset_tx(&context, &asset, Keypair::new().pubkey(), SPL_NOOP_ID);
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .expect("process_transaction_with_metadata");
+    result.result.expect("create failed");
+
+    let invoke_noop = format!("Program {} invoke [2]", SPL_NOOP_ID);
+    assert!(result
+        .metadata
+        .expect("transaction metadata")
+        .log_messages
+        .contains(&invoke_noop));
+}
+
+#[tokio::test]
+async fn cannot_record_referral_through_another_program() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let tx = create_referred_asset_tx(
+        &context,
+        &asset,
+        Keypair::new().pubkey(),
+        Keypair::new().pubkey(),
+    );
+    assert_program_error(&mut context, tx, MplCoreError::InvalidLogWrapperProgram).await;
+}
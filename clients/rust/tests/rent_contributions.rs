@@ -0,0 +1,155 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    instructions::{CreateV2Builder, ReapV1Builder},
+    types::{DataState, Plugin, PluginAuthorityPair, Reaper},
+};
+pub use setup::*;
+
+use solana_program_test::tokio;
+use solana_sdk::{
+    instruction::AccountMeta, signature::Keypair, signer::Signer, system_program,
+    transaction::Transaction,
+};
+
+const CONTRIBUTION: u64 = 100_000_000;
+
+#[tokio::test]
+async fn funder_pays_only_the_rent_of_the_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    // A collection whose Reaper pool subsidizes the payer of every new mint.
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Reaper(Reaper {
+                    expires_at: 0,
+                    subsidy_per_mint: CONTRIBUTION,
+                    pool_balance: 0,
+                }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+
+    let reaped_asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &reaped_asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let reap_ix = ReapV1Builder::new()
+        .asset(reaped_asset.pubkey())
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[reap_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let funder = Keypair::new();
+    airdrop(&mut context, &funder.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    let funder_lamports = context
+        .banks_client
+        .get_balance(funder.pubkey())
+        .await
+        .unwrap();
+
+    let asset = Keypair::new();
+    let create_ix = CreateV2Builder::new()
+        .asset(asset.pubkey())
+        .collection(Some(collection.pubkey()))
+        .payer(context.payer.pubkey())
+        .system_program(system_program::ID)
+        .data_state(DataState::AccountState)
+        .name("Test Asset".to_owned())
+        .uri("https://example.com/asset".to_owned())
+        .rent_contributions(vec![CONTRIBUTION])
+        .add_remaining_account(AccountMeta::new(funder.pubkey(), true))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &asset, &funder],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The subsidy paid to the payer is not refunded to the funder as unspent rent.
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let funder_spent = funder_lamports
+        - context
+            .banks_client
+            .get_balance(funder.pubkey())
+            .await
+            .unwrap();
+    assert_eq!(funder_spent, rent.minimum_balance(asset_account.data.len()));
+}
+
+#[tokio::test]
+async fn cannot_create_without_every_funder() {
+    let mut context = program_test().start_with_context().await;
+
+    let funder = Keypair::new();
+    airdrop(&mut context, &funder.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+
+    let asset = Keypair::new();
+    let create_ix = CreateV2Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .system_program(system_program::ID)
+        .data_state(DataState::AccountState)
+        .name("Test Asset".to_owned())
+        .uri("https://example.com/asset".to_owned())
+        .rent_contributions(vec![CONTRIBUTION, CONTRIBUTION])
+        .add_remaining_account(AccountMeta::new(funder.pubkey(), true))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &asset, &funder],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+}
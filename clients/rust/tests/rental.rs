@@ -0,0 +1,114 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    instructions::{TransferV1Builder, UpdatePluginV1Builder},
+    types::{Plugin, PluginAuthorityPair, PluginType, Rental},
+};
+pub use setup::*;
+
+use solana_program::{clock::Clock, pubkey::Pubkey};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset owned by the context payer, rented to `renter` for the next day.
+async fn create_rented_asset(context: &mut ProgramTestContext, asset: &Keypair, renter: Pubkey) {
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Rental(Rental {
+                    renter,
+                    expires_at: clock.unix_timestamp + 86_400,
+                }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// End the rental of an asset immediately, signed by `authority`.
+fn end_rental_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    authority: &Keypair,
+    renter: Pubkey,
+) -> Transaction {
+    let update_plugin_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .plugin(Plugin::Rental(Rental {
+            renter,
+            expires_at: 0,
+        }))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[update_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn cannot_transfer_or_burn_rented_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_rented_asset(&mut context, &asset, Keypair::new().pubkey()).await;
+
+    assert_transfer_rejected_by(&mut context, asset.pubkey(), None, PluginType::Rental).await;
+    assert_burn_rejected_by(&mut context, asset.pubkey(), None, PluginType::Rental).await;
+}
+
+#[tokio::test]
+async fn owner_cannot_end_rental_early() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let renter = Keypair::new().pubkey();
+    create_rented_asset(&mut context, &asset, renter).await;
+
+    let owner = context.payer.insecure_clone();
+    let tx = end_rental_tx(&context, &asset, &owner, renter);
+    assert_rejected_by(&mut context, tx, PluginType::Rental).await;
+}
+
+#[tokio::test]
+async fn renter_ends_rental_early() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let renter = Keypair::new();
+    create_rented_asset(&mut context, &asset, renter.pubkey()).await;
+
+    let tx = end_rental_tx(&context, &asset, &renter, renter.pubkey());
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(Keypair::new().pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
@@ -0,0 +1,152 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    accounts::{BaseAssetV1, PluginHeaderV1},
+    instructions::RepairRegistryV1Builder,
+    types::{FreezeDelegate, Plugin, PluginAuthority, PluginAuthorityPair, PluginType},
+    Asset, AuthorityType, DataBlob,
+};
+pub use setup::*;
+
+use solana_program::keccak;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    account::AccountSharedData, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+/// Create an asset with an owner-managed plugin delegated to `delegate`, then corrupt the offset
+/// of that plugin in the registry.  Returns the hash of the plugin data.
+async fn create_corrupted_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    owner: &Pubkey,
+    delegate: &Pubkey,
+) -> [u8; 32] {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: Some(*owner),
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: true }),
+                authority: Some(PluginAuthority::Address { address: *delegate }),
+            }],
+        },
+    )
+    .await
+    .unwrap();
+
+    let mut asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+
+    let header_offset = BaseAssetV1::from_bytes(&asset_account.data)
+        .unwrap()
+        .get_size();
+    let plugins_offset = header_offset + PluginHeaderV1::LEN;
+    let registry_offset = PluginHeaderV1::from_bytes(&asset_account.data[header_offset..])
+        .unwrap()
+        .plugin_registry_offset as usize;
+
+    // The freeze delegate is the first record: key, length, plugin type, then its authority.
+    let offset_position = registry_offset + 1 + 4 + 1 + 1 + 32;
+    asset_account.data[offset_position..offset_position + 8].copy_from_slice(&0u64.to_le_bytes());
+    context.set_account(
+        &asset.pubkey(),
+        &AccountSharedData::from(asset_account.clone()),
+    );
+
+    keccak::hash(&asset_account.data[plugins_offset..registry_offset]).to_bytes()
+}
+
+#[tokio::test]
+async fn repair_registry_keeps_plugin_authorities() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let owner = Keypair::new();
+    let delegate = Keypair::new();
+    let plugin_data_hash =
+        create_corrupted_asset(&mut context, &asset, &owner.pubkey(), &delegate.pubkey()).await;
+
+    let repair_registry_ix = RepairRegistryV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin_types(vec![PluginType::FreezeDelegate, PluginType::Provenance])
+        .plugin_data_hash(plugin_data_hash)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[repair_registry_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let full_asset = Asset::from_bytes(&asset_account.data).unwrap();
+    let freeze_delegate = full_asset.plugin_list.freeze_delegate.unwrap();
+    assert_eq!(
+        freeze_delegate.freeze_delegate,
+        FreezeDelegate { frozen: true }
+    );
+    assert_eq!(
+        freeze_delegate.base.authority.authority_type,
+        AuthorityType::Address
+    );
+    assert_eq!(
+        freeze_delegate.base.authority.address,
+        Some(delegate.pubkey())
+    );
+    assert!(full_asset.plugin_list.provenance.is_some());
+}
+
+#[tokio::test]
+async fn repair_registry_cannot_drop_a_plugin() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let owner = Keypair::new();
+    let delegate = Keypair::new();
+    let plugin_data_hash =
+        create_corrupted_asset(&mut context, &asset, &owner.pubkey(), &delegate.pubkey()).await;
+
+    // Leaving out the provenance would drop it from the registry.
+    let repair_registry_ix = RepairRegistryV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin_types(vec![PluginType::FreezeDelegate])
+        .plugin_data_hash(plugin_data_hash)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[repair_registry_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+}
@@ -0,0 +1,126 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{AddPluginV1Builder, ReserveV1Builder},
+    types::{FreezeDelegate, Plugin},
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+fn reserve_tx(context: &ProgramTestContext, asset: Pubkey, size: u64) -> Transaction {
+    let reserve_ix = ReserveV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .size(size)
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[reserve_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn reserve_rent_for_asset_growth() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset_len = asset_account.data.len();
+    let size = asset_len as u64 + 200;
+
+    // The legacy fee left on the asset at creation stays on top of the reserved rent.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let legacy_fee = asset_account.lamports - rent.minimum_balance(asset_len);
+
+    let tx = reserve_tx(&context, asset.pubkey(), size);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The asset holds the rent for the reserved size without growing yet.
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    assert_eq!(asset_account.data.len(), asset_len);
+    assert_eq!(
+        asset_account.lamports,
+        rent.minimum_balance(size as usize) + legacy_fee
+    );
+
+    // Growing within the reserved size is paid from the reserved rent.
+    let add_plugin_ix = AddPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: false }))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[add_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let grown_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    assert!(grown_account.data.len() > asset_len);
+    assert_eq!(grown_account.lamports, asset_account.lamports);
+}
+
+#[tokio::test]
+async fn cannot_reserve_for_collection() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let tx = reserve_tx(&context, collection.pubkey(), 1000);
+    assert_program_error(&mut context, tx, MplCoreError::NotAvailable).await;
+}
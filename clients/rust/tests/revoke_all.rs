@@ -0,0 +1,101 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::RevokeAllV1Builder,
+    types::{Attributes, FreezeDelegate, Plugin, PluginAuthority, PluginAuthorityPair},
+    AuthorityType,
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset with an owner managed and an update authority managed plugin, both delegated
+/// to `delegate`.
+async fn create_delegated_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    delegate: Pubkey,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![
+                PluginAuthorityPair {
+                    plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+                    authority: Some(PluginAuthority::Address { address: delegate }),
+                },
+                PluginAuthorityPair {
+                    plugin: Plugin::Attributes(Attributes {
+                        attribute_list: vec![],
+                    }),
+                    authority: Some(PluginAuthority::Address { address: delegate }),
+                },
+            ],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn revoke_all_tx(context: &ProgramTestContext, asset: Pubkey, authority: &Keypair) -> Transaction {
+    let revoke_all_ix = RevokeAllV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[revoke_all_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn revoke_all_delegates() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_delegated_asset(&mut context, &asset, Keypair::new().pubkey()).await;
+
+    let owner = context.payer.insecure_clone();
+    let tx = revoke_all_tx(&context, asset.pubkey(), &owner);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Every plugin is back with its manager.
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    let freeze_delegate = fetched.plugin_list.freeze_delegate.unwrap();
+    assert_eq!(
+        freeze_delegate.base.authority.authority_type,
+        AuthorityType::Owner
+    );
+    let attributes = fetched.plugin_list.attributes.unwrap();
+    assert_eq!(
+        attributes.base.authority.authority_type,
+        AuthorityType::UpdateAuthority
+    );
+}
+
+#[tokio::test]
+async fn cannot_revoke_all_without_managers() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_delegated_asset(&mut context, &asset, Keypair::new().pubkey()).await;
+
+    let tx = revoke_all_tx(&context, asset.pubkey(), &Keypair::new());
+    assert_program_error(&mut context, tx, MplCoreError::NoApprovals).await;
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::UpdatePluginV1Builder,
+    types::{NamedScore, Plugin, PluginAuthorityPair, PluginType, Score, ScoreMode},
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// The scores of an identity asset: a reputation that can only increase and a rating out of 5.
+fn score(writer: Pubkey, reputation: i64, rating: i64) -> Score {
+    Score {
+        writers: vec![writer],
+        scores: vec![
+            NamedScore {
+                name: "reputation".to_owned(),
+                value: reputation,
+                mode: ScoreMode::MonotonicIncrease,
+            },
+            NamedScore {
+                name: "rating".to_owned(),
+                value: rating,
+                mode: ScoreMode::Bounded { min: 0, max: 5 },
+            },
+        ],
+    }
+}
+
+/// Create an asset with scores written by `writer`.
+async fn create_scored_asset(context: &mut ProgramTestContext, asset: &Keypair, writer: Pubkey) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Score(score(writer, 10, 3)),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn update_score_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    authority: &Keypair,
+    score: Score,
+) -> Transaction {
+    let update_plugin_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .plugin(Plugin::Score(score))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[update_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn writer_updates_scores() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let writer = Keypair::new();
+    create_scored_asset(&mut context, &asset, writer.pubkey()).await;
+
+    let tx = update_score_tx(&context, &asset, &writer, score(writer.pubkey(), 15, 5));
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        fetched.plugin_list.score.unwrap().score,
+        score(writer.pubkey(), 15, 5)
+    );
+}
+
+#[tokio::test]
+async fn cannot_update_scores_without_writer() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let writer = Keypair::new();
+    create_scored_asset(&mut context, &asset, writer.pubkey()).await;
+
+    // The update authority manages the plugin but cannot change the values.
+    let update_authority = context.payer.insecure_clone();
+    let tx = update_score_tx(
+        &context,
+        &asset,
+        &update_authority,
+        score(writer.pubkey(), 15, 5),
+    );
+    assert_rejected_by(&mut context, tx, PluginType::Score).await;
+}
+
+#[tokio::test]
+async fn cannot_lower_monotonic_score() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let writer = Keypair::new();
+    create_scored_asset(&mut context, &asset, writer.pubkey()).await;
+
+    let tx = update_score_tx(&context, &asset, &writer, score(writer.pubkey(), 5, 3));
+    assert_program_error(&mut context, tx, MplCoreError::InvalidPluginSetting).await;
+}
+
+#[tokio::test]
+async fn cannot_set_bounded_score_out_of_range() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let writer = Keypair::new();
+    create_scored_asset(&mut context, &asset, writer.pubkey()).await;
+
+    let tx = update_score_tx(&context, &asset, &writer, score(writer.pubkey(), 10, 6));
+    assert_program_error(&mut context, tx, MplCoreError::InvalidPluginSetting).await;
+}
@@ -1,18 +1,40 @@
+use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_core::{
-    instructions::{CreateCollectionV1Builder, CreateV1Builder},
-    types::{DataState, Key, Plugin, PluginAuthorityPair, UpdateAuthority},
-    Asset, Collection,
+    accounts::{BaseAssetV1, BaseCollectionV1, HashedAssetV1, PluginHeaderV1, PluginRegistryV1},
+    errors::MplCoreError,
+    instructions::{BurnV1Builder, CreateCollectionV1Builder, CreateV1Builder, TransferV1Builder},
+    types::{
+        CompressionProof, DataState, ExternalPluginRecord, ExternalPluginType,
+        HashablePluginSchema, Key, Plugin, PluginAuthority, PluginAuthorityPair, PluginType,
+        UpdateAuthority,
+    },
+    Asset, Collection, DataBlob,
 };
 use solana_program_test::{BanksClientError, ProgramTest, ProgramTestContext};
 use solana_sdk::{
-    pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction, system_program,
-    transaction::Transaction,
+    account::Account,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_instruction, system_program,
+    transaction::{Transaction, TransactionError},
 };
 
 pub fn program_test() -> ProgramTest {
     ProgramTest::new("mpl_core_program", mpl_core::ID, None)
 }
 
+/// The SPL Noop program, which is dumped next to the program binary for the log wrapper.
+pub const SPL_NOOP_ID: Pubkey = solana_sdk::pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+/// The program test with the SPL Noop program loaded, for instructions using a log wrapper.
+pub fn program_test_with_noop() -> ProgramTest {
+    let mut program_test = program_test();
+    program_test.add_program("spl_noop", SPL_NOOP_ID, None);
+    program_test
+}
+
 const DEFAULT_ASSET_NAME: &str = "Test Asset";
 const DEFAULT_ASSET_URI: &str = "https://example.com/asset";
 const DEFAULT_COLLECTION_NAME: &str = "Test Collection";
@@ -250,3 +272,290 @@ pub async fn airdrop(
     context.banks_client.process_transaction(tx).await.unwrap();
     Ok(())
 }
+
+/// Process a transaction that is expected to fail, asserting that it failed with
+/// `PluginRejected` and that the rejecting plugin type, set as return data, is the given plugin.
+pub async fn assert_rejected_by(
+    context: &mut ProgramTestContext,
+    tx: Transaction,
+    plugin_type: PluginType,
+) {
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .expect("process_transaction_with_metadata");
+    assert_eq!(
+        result.result.expect_err("transaction was expected to fail"),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(MplCoreError::PluginRejected as u32)
+        )
+    );
+
+    let return_data = result
+        .metadata
+        .expect("transaction metadata")
+        .return_data
+        .expect("rejecting plugin type");
+    assert_eq!(return_data.program_id, mpl_core::ID);
+    assert_eq!(
+        PluginType::try_from_slice(&return_data.data).unwrap(),
+        plugin_type
+    );
+}
+
+/// Transfer an asset owned by the context payer, asserting that the given plugin rejected it.
+pub async fn assert_transfer_rejected_by(
+    context: &mut ProgramTestContext,
+    asset: Pubkey,
+    collection: Option<Pubkey>,
+    plugin_type: PluginType,
+) {
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset)
+        .collection(collection)
+        .payer(context.payer.pubkey())
+        .new_owner(Keypair::new().pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_rejected_by(context, tx, plugin_type).await;
+}
+
+/// Burn an asset owned by the context payer, asserting that the given plugin rejected it.
+pub async fn assert_burn_rejected_by(
+    context: &mut ProgramTestContext,
+    asset: Pubkey,
+    collection: Option<Pubkey>,
+    plugin_type: PluginType,
+) {
+    let burn_ix = BurnV1Builder::new()
+        .asset(asset)
+        .collection(collection)
+        .payer(context.payer.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_rejected_by(context, tx, plugin_type).await;
+}
+
+/// Process a transaction that is expected to fail with the given program error.
+pub async fn assert_program_error(
+    context: &mut ProgramTestContext,
+    tx: Transaction,
+    error: MplCoreError,
+) {
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect_err("transaction was expected to fail")
+        .unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(error as u32))
+    );
+}
+
+/// Fetch and deserialize an asset with its plugins.
+pub async fn get_asset(context: &mut ProgramTestContext, asset: Pubkey) -> Asset {
+    let asset_account = context
+        .banks_client
+        .get_account(asset)
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+
+    Asset::from_bytes(&asset_account.data).unwrap()
+}
+
+/// Fetch and deserialize a collection with its plugins.
+pub async fn get_collection(context: &mut ProgramTestContext, collection: Pubkey) -> Collection {
+    let collection_account = context
+        .banks_client
+        .get_account(collection)
+        .await
+        .expect("get_account")
+        .expect("collection account not found");
+
+    Collection::from_bytes(&collection_account.data).unwrap()
+}
+
+/// The compression proof of an asset with the default name and uri and the given plugins.
+pub fn compression_proof(
+    owner: Pubkey,
+    update_authority: UpdateAuthority,
+    plugins: Vec<HashablePluginSchema>,
+) -> CompressionProof {
+    CompressionProof {
+        owner,
+        update_authority,
+        name: DEFAULT_ASSET_NAME.to_owned(),
+        uri: DEFAULT_ASSET_URI.to_owned(),
+        seq: 0,
+        plugins,
+    }
+}
+
+/// Write a compressed asset with the given compression proof into account state.  Compressing
+/// an asset is not available, so compressed assets are set up directly.
+pub async fn create_hashed_asset(
+    context: &mut ProgramTestContext,
+    asset: Pubkey,
+    compression_proof: &CompressionProof,
+) {
+    let data = HashedAssetV1 {
+        key: Key::HashedAssetV1,
+        hash: compression_proof.hash().unwrap(),
+    }
+    .try_to_vec()
+    .unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    context.set_account(
+        &asset,
+        &Account {
+            lamports: rent.minimum_balance(data.len()),
+            data,
+            owner: mpl_core::ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+}
+
+/// Read the external plugin records in the plugin registry of an asset or collection.
+pub async fn get_external_plugin_records(
+    context: &mut ProgramTestContext,
+    address: Pubkey,
+) -> Vec<ExternalPluginRecord> {
+    let account = context
+        .banks_client
+        .get_account(address)
+        .await
+        .expect("get_account")
+        .expect("account not found");
+
+    external_plugin_records(&account.data)
+}
+
+/// Read the data of the external plugin of an asset or collection with the given type and
+/// authority.
+pub async fn get_external_plugin_data(
+    context: &mut ProgramTestContext,
+    address: Pubkey,
+    plugin_type: ExternalPluginType,
+    authority: PluginAuthority,
+) -> Vec<u8> {
+    let account = context
+        .banks_client
+        .get_account(address)
+        .await
+        .expect("get_account")
+        .expect("account not found");
+
+    let record = external_plugin_records(&account.data)
+        .into_iter()
+        .find(|record| record.plugin_type == plugin_type && record.authority == authority)
+        .expect("external plugin not found");
+    let offset = record.offset as usize;
+    account.data[offset..offset + record.data_len as usize].to_vec()
+}
+
+fn external_plugin_records(data: &[u8]) -> Vec<ExternalPluginRecord> {
+    let header_offset = match Key::try_from_slice(&data[..1]).unwrap() {
+        Key::AssetV1 => BaseAssetV1::from_bytes(data).unwrap().get_size(),
+        Key::CollectionV1 => BaseCollectionV1::from_bytes(data).unwrap().get_size(),
+        key => panic!("unexpected account key {:?}", key),
+    };
+    let header = PluginHeaderV1::from_bytes(&data[header_offset..]).unwrap();
+    PluginRegistryV1::from_bytes(&data[header.plugin_registry_offset as usize..])
+        .unwrap()
+        .external_plugins
+}
+
+/// The SPL Token program.
+pub const SPL_TOKEN_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// The Token Metadata program.
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// Find the address of the Token Metadata metadata account of a mint.
+pub fn find_token_metadata_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"metadata",
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            mint.as_ref(),
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    )
+}
+
+/// The SPL Token-2022 program.
+pub const SPL_TOKEN_2022_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// The SPL Associated Token Account program.
+pub const SPL_ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+/// Find the address of the associated token account of `owner` for `mint`.
+pub fn find_associated_token_address(
+    owner: &Pubkey,
+    token_program: &Pubkey,
+    mint: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &SPL_ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Write an initialized token account of `mint` held by `owner` into account state, so that
+/// tests do not need to load the token program.
+pub async fn set_token_account(
+    context: &mut ProgramTestContext,
+    address: Pubkey,
+    token_program: Pubkey,
+    mint: Pubkey,
+    owner: Pubkey,
+    amount: u64,
+) {
+    // The mint, the owner, the amount, then the state after the delegate.
+    let mut data = vec![0; 165];
+    data[0..32].copy_from_slice(mint.as_ref());
+    data[32..64].copy_from_slice(owner.as_ref());
+    data[64..72].copy_from_slice(&amount.to_le_bytes());
+    data[108] = 1;
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    context.set_account(
+        &address,
+        &Account {
+            lamports: rent.minimum_balance(data.len()),
+            data,
+            owner: token_program,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+}
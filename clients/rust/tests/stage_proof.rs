@@ -0,0 +1,149 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    accounts::{HashedAssetV1, ProofCacheV1},
+    errors::MplCoreError,
+    instructions::{StageProofV1Builder, TransferCompressedV1Builder},
+    types::{
+        Attributes, CompressionProof, HashablePluginSchema, Key, Plugin, PluginAuthority,
+        UpdateAuthority,
+    },
+};
+pub use setup::*;
+
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+fn find_proof_cache_address(asset: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"proof_cache", asset.as_ref()], &mpl_core::ID).0
+}
+
+/// The compression proof of an asset owned by the context payer with an `Attributes` plugin.
+fn asset_compression_proof(context: &ProgramTestContext) -> CompressionProof {
+    compression_proof(
+        context.payer.pubkey(),
+        UpdateAuthority::Address(context.payer.pubkey()),
+        vec![HashablePluginSchema {
+            index: 0,
+            authority: PluginAuthority::UpdateAuthority,
+            plugin: Plugin::Attributes(Attributes {
+                attribute_list: vec![],
+            }),
+        }],
+    )
+}
+
+fn stage_proof_tx(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    proof_cache: Pubkey,
+    compression_proof: CompressionProof,
+) -> Transaction {
+    let stage_proof_ix = StageProofV1Builder::new()
+        .asset(asset)
+        .proof_cache(proof_cache)
+        .payer(context.payer.pubkey())
+        .compression_proof(compression_proof)
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[stage_proof_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn staged_proof_is_used_for_compressed_transfer() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new().pubkey();
+    let compression_proof = asset_compression_proof(&context);
+    create_hashed_asset(&mut context, asset, &compression_proof).await;
+
+    let proof_cache = find_proof_cache_address(&asset);
+    let tx = stage_proof_tx(&context, asset, proof_cache, compression_proof.clone());
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let proof_cache_account = context
+        .banks_client
+        .get_account(proof_cache)
+        .await
+        .expect("get_account")
+        .expect("proof cache account not found");
+    let staged = ProofCacheV1::from_bytes(&proof_cache_account.data).unwrap();
+    assert_eq!(staged.key, Key::ProofCacheV1);
+    assert_eq!(staged.asset, asset);
+    assert_eq!(staged.hash, compression_proof.hash().unwrap());
+    assert_eq!(staged.compression_proof, compression_proof);
+
+    // The transfer references the staged proof instead of passing it.
+    let new_owner = Keypair::new().pubkey();
+    let transfer_ix = TransferCompressedV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner)
+        .add_remaining_account(AccountMeta::new_readonly(proof_cache, false))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset)
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let hashed_asset = HashedAssetV1::from_bytes(&asset_account.data).unwrap();
+    let transferred_proof = CompressionProof {
+        owner: new_owner,
+        seq: 1,
+        ..compression_proof
+    };
+    assert_eq!(hashed_asset.hash, transferred_proof.hash().unwrap());
+}
+
+#[tokio::test]
+async fn cannot_stage_incorrect_proof() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new().pubkey();
+    let compression_proof = asset_compression_proof(&context);
+    create_hashed_asset(&mut context, asset, &compression_proof).await;
+
+    let incorrect_proof = CompressionProof {
+        owner: Keypair::new().pubkey(),
+        ..compression_proof
+    };
+    let tx = stage_proof_tx(
+        &context,
+        asset,
+        find_proof_cache_address(&asset),
+        incorrect_proof,
+    );
+    assert_program_error(&mut context, tx, MplCoreError::IncorrectAssetHash).await;
+}
+
+#[tokio::test]
+async fn cannot_stage_proof_to_another_proof_cache() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new().pubkey();
+    let compression_proof = asset_compression_proof(&context);
+    create_hashed_asset(&mut context, asset, &compression_proof).await;
+
+    let tx = stage_proof_tx(
+        &context,
+        asset,
+        find_proof_cache_address(&Keypair::new().pubkey()),
+        compression_proof,
+    );
+    assert_program_error(&mut context, tx, MplCoreError::InvalidProofCache).await;
+}
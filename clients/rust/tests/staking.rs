@@ -0,0 +1,141 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    instructions::{TransferV1Builder, UpdatePluginV1Builder},
+    types::{Plugin, PluginAuthorityPair, PluginType, Staking},
+};
+pub use setup::*;
+
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset owned by the context payer with an unstaked `Staking` plugin.
+async fn create_stakeable_asset(context: &mut ProgramTestContext, asset: &Keypair) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Staking(Staking {
+                    staked: false,
+                    staked_at: 0,
+                    accumulated_staked_time: 0,
+                }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// Stake or unstake an asset owned by the context payer.
+async fn set_staked(context: &mut ProgramTestContext, asset: &Keypair, staked: bool) {
+    // The timestamps are maintained by the program, so only `staked` is taken from the update.
+    let update_plugin_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin(Plugin::Staking(Staking {
+            staked,
+            staked_at: 0,
+            accumulated_staked_time: 0,
+        }))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[update_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn cannot_transfer_or_burn_staked_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_stakeable_asset(&mut context, &asset).await;
+    set_staked(&mut context, &asset, true).await;
+
+    let staking = get_asset(&mut context, asset.pubkey())
+        .await
+        .plugin_list
+        .staking
+        .unwrap()
+        .staking;
+    assert!(staking.staked);
+    assert!(staking.staked_at > 0);
+
+    assert_transfer_rejected_by(&mut context, asset.pubkey(), None, PluginType::Staking).await;
+    assert_burn_rejected_by(&mut context, asset.pubkey(), None, PluginType::Staking).await;
+}
+
+#[tokio::test]
+async fn transfer_unstaked_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_stakeable_asset(&mut context, &asset).await;
+    set_staked(&mut context, &asset, true).await;
+    set_staked(&mut context, &asset, false).await;
+
+    let staking = get_asset(&mut context, asset.pubkey())
+        .await
+        .plugin_list
+        .staking
+        .unwrap()
+        .staking;
+    assert!(!staking.staked);
+
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(Keypair::new().pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn cannot_create_asset_already_staked() {
+    let mut context = program_test().start_with_context().await;
+
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &Keypair::new(),
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Staking(Staking {
+                    staked: true,
+                    staked_at: 0,
+                    accumulated_staked_time: 0,
+                }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap_err();
+}
@@ -0,0 +1,126 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::UpdatePluginV1Builder,
+    types::{Plugin, PluginAuthority, PluginAuthorityPair, PluginType, Ticket, TicketStatus},
+};
+pub use setup::*;
+
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create a valid ticket owned by the context payer with its status managed by `authority`.
+async fn create_ticket(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    authority: Option<PluginAuthority>,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Ticket(Ticket {
+                    status: TicketStatus::Valid,
+                }),
+                authority,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn update_ticket_status_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    authority: &Keypair,
+    status: TicketStatus,
+) -> Transaction {
+    let update_plugin_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .plugin(Plugin::Ticket(Ticket { status }))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[update_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn venue_delegate_checks_in_ticket() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let venue = Keypair::new();
+    create_ticket(
+        &mut context,
+        &asset,
+        Some(PluginAuthority::Address {
+            address: venue.pubkey(),
+        }),
+    )
+    .await;
+
+    let tx = update_ticket_status_tx(&context, &asset, &venue, TicketStatus::CheckedIn);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        fetched.plugin_list.ticket.unwrap().ticket,
+        Ticket {
+            status: TicketStatus::CheckedIn,
+        }
+    );
+
+    // A checked in ticket can no longer be resold.
+    assert_transfer_rejected_by(&mut context, asset.pubkey(), None, PluginType::Ticket).await;
+}
+
+#[tokio::test]
+async fn cannot_check_in_ticket_without_venue_delegate() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_ticket(&mut context, &asset, None).await;
+
+    let update_authority = context.payer.insecure_clone();
+    let tx = update_ticket_status_tx(&context, &asset, &update_authority, TicketStatus::CheckedIn);
+    assert_rejected_by(&mut context, tx, PluginType::Ticket).await;
+}
+
+#[tokio::test]
+async fn cannot_restore_voided_ticket() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let venue = Keypair::new();
+    create_ticket(
+        &mut context,
+        &asset,
+        Some(PluginAuthority::Address {
+            address: venue.pubkey(),
+        }),
+    )
+    .await;
+
+    let tx = update_ticket_status_tx(&context, &asset, &venue, TicketStatus::Void);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = update_ticket_status_tx(&context, &asset, &venue, TicketStatus::Valid);
+    assert_program_error(&mut context, tx, MplCoreError::InvalidPluginSetting).await;
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    instructions::BurnV1Builder,
+    types::{Plugin, PluginAuthorityPair, PluginType, TimeLock},
+};
+pub use setup::*;
+
+use solana_program::clock::Clock;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset owned by the context payer that is locked for the next day, returning the
+/// unlock time.
+async fn create_locked_asset(context: &mut ProgramTestContext, asset: &Keypair) -> i64 {
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let unlock_time = clock.unix_timestamp + 86_400;
+
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::TimeLock(TimeLock { unlock_time }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+
+    unlock_time
+}
+
+#[tokio::test]
+async fn cannot_transfer_or_burn_locked_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_locked_asset(&mut context, &asset).await;
+
+    assert_transfer_rejected_by(&mut context, asset.pubkey(), None, PluginType::TimeLock).await;
+    assert_burn_rejected_by(&mut context, asset.pubkey(), None, PluginType::TimeLock).await;
+}
+
+#[tokio::test]
+async fn burn_asset_after_unlock_time() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let unlock_time = create_locked_asset(&mut context, &asset).await;
+
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = unlock_time;
+    context.set_sysvar(&clock);
+
+    let burn_ix = BurnV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
@@ -0,0 +1,126 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::TransferV1Builder,
+    types::{Plugin, PluginAuthorityPair, TokenLink},
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::AccountMeta, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+/// Create an asset owned by the context payer backed by 100 shares of `mint` in `escrow`.
+async fn create_linked_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    mint: Pubkey,
+    escrow: Pubkey,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::TokenLink(TokenLink {
+                    mint,
+                    escrow,
+                    amount: 100,
+                }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn transfer_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    escrow: Option<Pubkey>,
+) -> Transaction {
+    let mut transfer = TransferV1Builder::new();
+    transfer
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(Keypair::new().pubkey());
+    if let Some(escrow) = escrow {
+        transfer.add_remaining_account(AccountMeta::new_readonly(escrow, false));
+    }
+
+    Transaction::new_signed_with_payer(
+        &[transfer.instruction()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn transfer_asset_backed_by_escrow() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let escrow = Pubkey::new_unique();
+    create_linked_asset(&mut context, &asset, mint, escrow).await;
+    set_token_account(
+        &mut context,
+        escrow,
+        SPL_TOKEN_2022_PROGRAM_ID,
+        mint,
+        Pubkey::new_unique(),
+        100,
+    )
+    .await;
+
+    let tx = transfer_tx(&context, &asset, Some(escrow));
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn cannot_transfer_asset_without_escrow() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let escrow = Pubkey::new_unique();
+    create_linked_asset(&mut context, &asset, mint, escrow).await;
+
+    let tx = transfer_tx(&context, &asset, None);
+    assert_program_error(&mut context, tx, MplCoreError::InvalidTokenLinkEscrow).await;
+}
+
+#[tokio::test]
+async fn cannot_transfer_asset_with_underfunded_escrow() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let escrow = Pubkey::new_unique();
+    create_linked_asset(&mut context, &asset, mint, escrow).await;
+    set_token_account(
+        &mut context,
+        escrow,
+        SPL_TOKEN_2022_PROGRAM_ID,
+        mint,
+        Pubkey::new_unique(),
+        99,
+    )
+    .await;
+
+    let tx = transfer_tx(&context, &asset, Some(escrow));
+    assert_program_error(&mut context, tx, MplCoreError::InvalidTokenLinkEscrow).await;
+}
@@ -2,7 +2,10 @@
 pub mod setup;
 use mpl_core::{
     instructions::TransferV1Builder,
-    types::{Creator, Plugin, PluginAuthorityPair, Royalties, RuleSet, UpdateAuthority},
+    types::{
+        Creator, FreezeDelegate, Plugin, PluginAuthorityPair, PluginType, Royalties, RuleSet,
+        UpdateAuthority,
+    },
 };
 pub use setup::*;
 
@@ -136,3 +139,38 @@ async fn transfer_asset_with_royalties() {
     )
     .await;
 }
+
+#[tokio::test]
+async fn cannot_transfer_frozen_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                authority: None,
+                plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: true }),
+            }],
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_transfer_rejected_by(
+        &mut context,
+        asset.pubkey(),
+        None,
+        PluginType::FreezeDelegate,
+    )
+    .await;
+}
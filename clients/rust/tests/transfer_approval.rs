@@ -0,0 +1,181 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{ApproveTransferV1Builder, RevokeTransferApprovalV1Builder, TransferV1Builder},
+};
+pub use setup::*;
+
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset owned by the context payer and approve `delegate` to transfer it once to
+/// `destination`.
+async fn create_approved_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    delegate: Pubkey,
+    destination: Pubkey,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let approve_transfer_ix = ApproveTransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .delegate(delegate)
+        .destination(destination)
+        .expiry_slot(u64::MAX)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[approve_transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+fn transfer_tx(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    authority: &Keypair,
+    new_owner: Pubkey,
+) -> Transaction {
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .new_owner(new_owner)
+        .system_program(Some(system_program::ID))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn delegate_transfers_approved_asset_once() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    let destination = Keypair::new().pubkey();
+    create_approved_asset(&mut context, &asset, delegate.pubkey(), destination).await;
+
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert!(fetched.plugin_list.transfer_approval.is_some());
+
+    let tx = transfer_tx(&context, asset.pubkey(), &delegate, destination);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The approval is consumed by the transfer.
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(fetched.base.owner, destination);
+    assert!(fetched.plugin_list.transfer_approval.is_none());
+}
+
+#[tokio::test]
+async fn cannot_transfer_approved_asset_to_another_destination() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_approved_asset(
+        &mut context,
+        &asset,
+        delegate.pubkey(),
+        Keypair::new().pubkey(),
+    )
+    .await;
+
+    let tx = transfer_tx(&context, asset.pubkey(), &delegate, Keypair::new().pubkey());
+    assert_program_error(&mut context, tx, MplCoreError::NoApprovals).await;
+}
+
+#[tokio::test]
+async fn cannot_transfer_after_approval_is_revoked() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    let destination = Keypair::new().pubkey();
+    create_approved_asset(&mut context, &asset, delegate.pubkey(), destination).await;
+
+    let revoke_ix = RevokeTransferApprovalV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[revoke_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert!(fetched.plugin_list.transfer_approval.is_none());
+
+    let tx = transfer_tx(&context, asset.pubkey(), &delegate, destination);
+    assert_program_error(&mut context, tx, MplCoreError::NoApprovals).await;
+}
+
+#[tokio::test]
+async fn cannot_approve_transfer_without_owner() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: Some(Keypair::new().pubkey()),
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let approve_transfer_ix = ApproveTransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .delegate(Keypair::new().pubkey())
+        .expiry_slot(u64::MAX)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[approve_transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+}
@@ -0,0 +1,124 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    accounts::HashedAssetV1,
+    errors::MplCoreError,
+    instructions::TransferCompressedV1Builder,
+    types::{
+        CompressionProof, FreezeDelegate, HashablePluginSchema, Plugin, PluginAuthority,
+        PluginType, UpdateAuthority,
+    },
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// The compression proof of an asset owned by the context payer with a freeze delegate.
+fn freezable_compression_proof(context: &ProgramTestContext, frozen: bool) -> CompressionProof {
+    compression_proof(
+        context.payer.pubkey(),
+        UpdateAuthority::Address(context.payer.pubkey()),
+        vec![HashablePluginSchema {
+            index: 0,
+            authority: PluginAuthority::Owner,
+            plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen }),
+        }],
+    )
+}
+
+fn transfer_compressed_tx(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    authority: &Keypair,
+    new_owner: Pubkey,
+    compression_proof: CompressionProof,
+) -> Transaction {
+    let transfer_ix = TransferCompressedV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .new_owner(new_owner)
+        .compression_proof(compression_proof)
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn transfer_compressed_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new().pubkey();
+    let compression_proof = freezable_compression_proof(&context, false);
+    create_hashed_asset(&mut context, asset, &compression_proof).await;
+
+    let owner = context.payer.insecure_clone();
+    let new_owner = Keypair::new().pubkey();
+    let tx = transfer_compressed_tx(
+        &context,
+        asset,
+        &owner,
+        new_owner,
+        compression_proof.clone(),
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The asset stays compressed, with the hash of the proof for the new owner.
+    let asset_account = context
+        .banks_client
+        .get_account(asset)
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let hashed_asset = HashedAssetV1::from_bytes(&asset_account.data).unwrap();
+    let transferred_proof = CompressionProof {
+        owner: new_owner,
+        seq: 1,
+        ..compression_proof
+    };
+    assert_eq!(hashed_asset.hash, transferred_proof.hash().unwrap());
+}
+
+#[tokio::test]
+async fn cannot_transfer_compressed_asset_without_owner() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new().pubkey();
+    let compression_proof = freezable_compression_proof(&context, false);
+    create_hashed_asset(&mut context, asset, &compression_proof).await;
+
+    let tx = transfer_compressed_tx(
+        &context,
+        asset,
+        &Keypair::new(),
+        Keypair::new().pubkey(),
+        compression_proof,
+    );
+    assert_program_error(&mut context, tx, MplCoreError::NoApprovals).await;
+}
+
+#[tokio::test]
+async fn cannot_transfer_frozen_compressed_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new().pubkey();
+    let compression_proof = freezable_compression_proof(&context, true);
+    create_hashed_asset(&mut context, asset, &compression_proof).await;
+
+    let owner = context.payer.insecure_clone();
+    let tx = transfer_compressed_tx(
+        &context,
+        asset,
+        &owner,
+        Keypair::new().pubkey(),
+        compression_proof,
+    );
+    assert_rejected_by(&mut context, tx, PluginType::FreezeDelegate).await;
+}
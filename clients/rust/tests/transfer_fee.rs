@@ -0,0 +1,100 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::TransferV1Builder,
+    types::{Plugin, PluginAuthorityPair, TransferFee},
+};
+pub use setup::*;
+
+use solana_program::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::AccountMeta, signature::Keypair, signer::Signer, system_program,
+    transaction::Transaction,
+};
+
+async fn create_asset_with_fee(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    recipient: Pubkey,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::TransferFee(TransferFee {
+                    fee: LAMPORTS_PER_SOL,
+                    recipient,
+                }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// Transfer an asset owned by the context payer, passing the given fee recipients.
+fn transfer_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    recipients: &[Pubkey],
+) -> Transaction {
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(Keypair::new().pubkey())
+        .system_program(Some(system_program::ID))
+        .add_remaining_accounts(
+            &recipients
+                .iter()
+                .map(|recipient| AccountMeta::new(*recipient, false))
+                .collect::<Vec<_>>(),
+        )
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn transfer_pays_fee_to_recipient() {
+    let mut context = program_test().start_with_context().await;
+
+    let recipient = Keypair::new().pubkey();
+    let asset = Keypair::new();
+    create_asset_with_fee(&mut context, &asset, recipient).await;
+
+    let tx = transfer_tx(&context, &asset, &[recipient]);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        context.banks_client.get_balance(recipient).await.unwrap(),
+        LAMPORTS_PER_SOL
+    );
+}
+
+#[tokio::test]
+async fn cannot_transfer_without_fee_recipient() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset_with_fee(&mut context, &asset, Keypair::new().pubkey()).await;
+
+    let tx = transfer_tx(&context, &asset, &[]);
+    assert_program_error(&mut context, tx, MplCoreError::MissingTransferFeeRecipient).await;
+}
@@ -0,0 +1,148 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use borsh::BorshSerialize;
+use mpl_core::{
+    instructions::{AddExternalPluginV1Builder, TransferV1Builder},
+    types::{
+        ExternalPluginInitInfo, HookableLifecycleEvent, LifecycleHook, UpdateAuthority,
+        ValidationResult,
+    },
+};
+pub use setup::*;
+
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program::set_return_data, pubkey::Pubkey,
+};
+use solana_program_test::{processor, tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::AccountMeta, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+/// A hooked program that abstains from every lifecycle event.
+fn pass_hook(_program_id: &Pubkey, _accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    Ok(())
+}
+
+/// A hooked program that rejects every lifecycle event.
+fn reject_hook(_program_id: &Pubkey, _accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    set_return_data(&ValidationResult::Rejected.try_to_vec().unwrap());
+    Ok(())
+}
+
+/// Create an asset with a transfer lifecycle hook on `hooked_program`.
+async fn create_hooked_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    hooked_program: Pubkey,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let add_external_plugin_ix = AddExternalPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .init_info(ExternalPluginInitInfo::LifecycleHook(LifecycleHook {
+            hooked_program,
+            events: vec![HookableLifecycleEvent::Transfer],
+        }))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_external_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn transfer_asset_with_lifecycle_hook() {
+    let hooked_program = Pubkey::new_unique();
+    let mut program_test = program_test();
+    program_test.add_program("pass_hook", hooked_program, processor!(pass_hook));
+    let mut context = program_test.start_with_context().await;
+
+    let asset = Keypair::new();
+    create_hooked_asset(&mut context, &asset, hooked_program).await;
+
+    // The hooked program is passed in the remaining accounts, and is not a dependent account.
+    let new_owner = Keypair::new();
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner.pubkey())
+        .add_remaining_account(AccountMeta::new_readonly(hooked_program, false))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let update_authority = context.payer.pubkey();
+    assert_asset(
+        &mut context,
+        AssertAssetHelperArgs {
+            asset: asset.pubkey(),
+            owner: new_owner.pubkey(),
+            update_authority: Some(UpdateAuthority::Address(update_authority)),
+            name: None,
+            uri: None,
+            plugins: vec![],
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn cannot_transfer_asset_rejected_by_lifecycle_hook() {
+    let hooked_program = Pubkey::new_unique();
+    let mut program_test = program_test();
+    program_test.add_program("reject_hook", hooked_program, processor!(reject_hook));
+    let mut context = program_test.start_with_context().await;
+
+    let asset = Keypair::new();
+    create_hooked_asset(&mut context, &asset, hooked_program).await;
+
+    let new_owner = Keypair::new();
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner.pubkey())
+        .add_remaining_account(AccountMeta::new_readonly(hooked_program, false))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::TransferV1Builder,
+    types::{Plugin, PluginAuthorityPair, TxGuard},
+};
+pub use setup::*;
+
+use solana_program::sysvar;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    signature::Keypair,
+    signer::Signer,
+    system_instruction, system_program,
+    transaction::Transaction,
+};
+
+/// Create an asset owned by the context payer that cannot be transferred in the same
+/// transaction as a system program instruction.
+async fn create_guarded_asset(context: &mut ProgramTestContext, asset: &Keypair) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::TxGuard(TxGuard {
+                    blocked_programs: vec![system_program::ID],
+                }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// Transfer an asset owned by the context payer, followed by the given instructions.
+fn transfer_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    remaining_accounts: &[AccountMeta],
+    instructions: &[Instruction],
+) -> Transaction {
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(Keypair::new().pubkey())
+        .add_remaining_accounts(remaining_accounts)
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[&[transfer_ix], instructions].concat(),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn transfer_without_blocked_programs() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_guarded_asset(&mut context, &asset).await;
+
+    let tx = transfer_tx(
+        &context,
+        &asset,
+        &[AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+        &[],
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn cannot_transfer_alongside_blocked_program() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_guarded_asset(&mut context, &asset).await;
+
+    let system_transfer_ix = system_instruction::transfer(
+        &context.payer.pubkey(),
+        &Keypair::new().pubkey(),
+        1_000_000_000,
+    );
+    let tx = transfer_tx(
+        &context,
+        &asset,
+        &[AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+        &[system_transfer_ix],
+    );
+    assert_program_error(&mut context, tx, MplCoreError::BlockedProgram).await;
+}
+
+#[tokio::test]
+async fn cannot_transfer_without_instructions_sysvar() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_guarded_asset(&mut context, &asset).await;
+
+    let tx = transfer_tx(&context, &asset, &[], &[]);
+    assert_program_error(&mut context, tx, MplCoreError::MissingInstructionsSysvar).await;
+}
@@ -0,0 +1,160 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{UpdatePluginV1Builder, UpdateV1Builder},
+    types::{Plugin, PluginAuthorityPair, PluginType, UpdateApproval, UpdateApprover},
+};
+pub use setup::*;
+
+use solana_program::{keccak, pubkey::Pubkey};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+const NEW_NAME: &str = "Approved Asset";
+const NEW_URI: &str = "https://example.com/approved";
+
+/// The hash of a proposed name and uri, as computed by the program.
+fn hash_update(name: &str, uri: &str) -> [u8; 32] {
+    keccak::hashv(&[name.as_bytes(), &[0], uri.as_bytes()]).to_bytes()
+}
+
+/// The update approval of two approvers of equal weight, both of whom must approve the update
+/// to `NEW_NAME` and `NEW_URI`.
+fn update_approval(approvers: &[Pubkey], approvals: Vec<Pubkey>) -> UpdateApproval {
+    UpdateApproval {
+        approvers: approvers
+            .iter()
+            .map(|address| UpdateApprover {
+                address: *address,
+                weight: 1,
+            })
+            .collect(),
+        threshold: 2,
+        pending_update_hash: hash_update(NEW_NAME, NEW_URI),
+        approvals,
+    }
+}
+
+async fn create_asset_with_approvers(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    approvers: &[Pubkey],
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::UpdateApproval(update_approval(approvers, vec![])),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn approve_update_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    approver: &Keypair,
+    update_approval: UpdateApproval,
+) -> Transaction {
+    let update_plugin_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(approver.pubkey()))
+        .plugin(Plugin::UpdateApproval(update_approval))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[update_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, approver],
+        context.last_blockhash,
+    )
+}
+
+fn update_tx(context: &ProgramTestContext, asset: &Keypair) -> Transaction {
+    let update_ix = UpdateV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_name(NEW_NAME.to_owned())
+        .new_uri(NEW_URI.to_owned())
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn update_once_approvers_reach_threshold() {
+    let mut context = program_test().start_with_context().await;
+
+    let first = Keypair::new();
+    let second = Keypair::new();
+    let approvers = [first.pubkey(), second.pubkey()];
+    let asset = Keypair::new();
+    create_asset_with_approvers(&mut context, &asset, &approvers).await;
+
+    let tx = approve_update_tx(
+        &context,
+        &asset,
+        &first,
+        update_approval(&approvers, vec![first.pubkey()]),
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The update is rejected until the threshold is reached.
+    let tx = update_tx(&context, &asset);
+    assert_program_error(&mut context, tx, MplCoreError::InvalidAuthority).await;
+
+    let tx = approve_update_tx(
+        &context,
+        &asset,
+        &second,
+        update_approval(&approvers, vec![first.pubkey(), second.pubkey()]),
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // A new blockhash keeps the retried update from being a duplicate of the rejected one.
+    context.last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let tx = update_tx(&context, &asset);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(asset.base.name, NEW_NAME);
+    assert_eq!(asset.base.uri, NEW_URI);
+}
+
+#[tokio::test]
+async fn cannot_approve_update_for_another_approver() {
+    let mut context = program_test().start_with_context().await;
+
+    let first = Keypair::new();
+    let second = Keypair::new();
+    let approvers = [first.pubkey(), second.pubkey()];
+    let asset = Keypair::new();
+    create_asset_with_approvers(&mut context, &asset, &approvers).await;
+
+    let tx = approve_update_tx(
+        &context,
+        &asset,
+        &first,
+        update_approval(&approvers, vec![first.pubkey(), second.pubkey()]),
+    );
+    assert_rejected_by(&mut context, tx, PluginType::UpdateApproval).await;
+}
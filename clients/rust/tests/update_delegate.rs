@@ -0,0 +1,199 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    instructions::UpdatePluginV1Builder,
+    types::{
+        Attribute, Attributes, FreezeDelegate, Plugin, PluginAuthority, PluginAuthorityPair,
+        UpdateDelegate,
+    },
+    Asset,
+};
+pub use setup::*;
+
+use solana_program_test::tokio;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+#[tokio::test]
+async fn update_delegate_can_update_update_authority_plugin() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![
+                PluginAuthorityPair {
+                    plugin: Plugin::UpdateDelegate(UpdateDelegate {
+                        additional_delegates: vec![],
+                    }),
+                    authority: Some(PluginAuthority::Address {
+                        address: delegate.pubkey(),
+                    }),
+                },
+                PluginAuthorityPair {
+                    plugin: Plugin::Attributes(Attributes {
+                        attribute_list: vec![],
+                    }),
+                    authority: None,
+                },
+            ],
+        },
+    )
+    .await
+    .unwrap();
+
+    let attributes = Attributes {
+        attribute_list: vec![Attribute {
+            key: "level".to_owned(),
+            value: "2".to_owned(),
+        }],
+    };
+    let update_plugin_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .plugin(Plugin::Attributes(attributes.clone()))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset = Asset::from_bytes(&asset_account.data).unwrap();
+    assert_eq!(asset.plugin_list.attributes.unwrap().attributes, attributes);
+}
+
+#[tokio::test]
+async fn update_delegate_cannot_update_owner_plugin() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![
+                PluginAuthorityPair {
+                    plugin: Plugin::UpdateDelegate(UpdateDelegate {
+                        additional_delegates: vec![],
+                    }),
+                    authority: Some(PluginAuthority::Address {
+                        address: delegate.pubkey(),
+                    }),
+                },
+                PluginAuthorityPair {
+                    plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+                    authority: None,
+                },
+            ],
+        },
+    )
+    .await
+    .unwrap();
+
+    let update_plugin_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: true }))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap_err();
+}
+
+#[tokio::test]
+async fn plugin_authority_cannot_update_another_plugin() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let freezer = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![
+                PluginAuthorityPair {
+                    plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+                    authority: Some(PluginAuthority::Address {
+                        address: freezer.pubkey(),
+                    }),
+                },
+                PluginAuthorityPair {
+                    plugin: Plugin::Attributes(Attributes {
+                        attribute_list: vec![],
+                    }),
+                    authority: None,
+                },
+            ],
+        },
+    )
+    .await
+    .unwrap();
+
+    // The freeze delegate may update its own plugin, but not the attributes.
+    let update_plugin_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(freezer.pubkey()))
+        .plugin(Plugin::Attributes(Attributes {
+            attribute_list: vec![Attribute {
+                key: "level".to_owned(),
+                value: "99".to_owned(),
+            }],
+        }))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &freezer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap_err();
+}
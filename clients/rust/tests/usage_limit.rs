@@ -0,0 +1,123 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::UpdatePluginV1Builder,
+    types::{Plugin, PluginAuthority, PluginAuthorityPair, PluginType, UsageLimit},
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset whose single use is recorded by `delegate`.
+async fn create_single_use_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    delegate: Pubkey,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::UsageLimit(UsageLimit {
+                    max_uses: 1,
+                    remaining: 1,
+                }),
+                authority: Some(PluginAuthority::Address { address: delegate }),
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn set_remaining_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    authority: &Keypair,
+    remaining: u32,
+) -> Transaction {
+    let update_plugin_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .plugin(Plugin::UsageLimit(UsageLimit {
+            max_uses: 1,
+            remaining,
+        }))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[update_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn cannot_use_asset_once_exhausted() {
+    let mut context = program_test().start_with_context().await;
+
+    let delegate = Keypair::new();
+    let asset = Keypair::new();
+    create_single_use_asset(&mut context, &asset, delegate.pubkey()).await;
+
+    let tx = set_remaining_tx(&context, &asset, &delegate, 0);
+    context.banks_client.process_transaction(tx).await.unwrap();
+    assert_eq!(
+        get_asset(&mut context, asset.pubkey())
+            .await
+            .plugin_list
+            .usage_limit
+            .unwrap()
+            .usage_limit,
+        UsageLimit {
+            max_uses: 1,
+            remaining: 0,
+        }
+    );
+
+    // A new blockhash keeps the second use from being a duplicate of the first.
+    context.last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let tx = set_remaining_tx(&context, &asset, &delegate, 0);
+    assert_rejected_by(&mut context, tx, PluginType::UsageLimit).await;
+}
+
+#[tokio::test]
+async fn cannot_restore_uses() {
+    let mut context = program_test().start_with_context().await;
+
+    let delegate = Keypair::new();
+    let asset = Keypair::new();
+    create_single_use_asset(&mut context, &asset, delegate.pubkey()).await;
+
+    let tx = set_remaining_tx(&context, &asset, &delegate, 0);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = set_remaining_tx(&context, &asset, &delegate, 1);
+    assert_program_error(&mut context, tx, MplCoreError::InvalidPluginSetting).await;
+}
+
+#[tokio::test]
+async fn only_delegate_records_uses() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_single_use_asset(&mut context, &asset, Keypair::new().pubkey()).await;
+
+    let owner = context.payer.insecure_clone();
+    let tx = set_remaining_tx(&context, &asset, &owner, 0);
+    assert_program_error(&mut context, tx, MplCoreError::NoApprovals).await;
+}
@@ -0,0 +1,134 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use borsh::BorshDeserialize;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::ValidateLifecycleV1Builder,
+    types::{
+        FreezeDelegate, HookableLifecycleEvent, LifecycleValidation, Plugin, PluginAuthorityPair,
+        PluginType, ValidationResult,
+    },
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+async fn create_freezable_asset(context: &mut ProgramTestContext, asset: &Keypair, frozen: bool) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+fn validate_transfer_tx(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    new_owner: Option<Pubkey>,
+) -> Transaction {
+    let validate_ix = ValidateLifecycleV1Builder::new()
+        .asset(asset)
+        .authority(context.payer.pubkey())
+        .new_owner(new_owner)
+        .event(HookableLifecycleEvent::Transfer)
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[validate_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+/// Process a transaction, returning the lifecycle validation it set as return data.
+async fn process_validation(
+    context: &mut ProgramTestContext,
+    tx: Transaction,
+) -> LifecycleValidation {
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .expect("process_transaction_with_metadata");
+    result.result.expect("validate lifecycle failed");
+
+    let return_data = result
+        .metadata
+        .expect("transaction metadata")
+        .return_data
+        .expect("return data");
+    assert_eq!(return_data.program_id, mpl_core::ID);
+    LifecycleValidation::try_from_slice(&return_data.data).unwrap()
+}
+
+#[tokio::test]
+async fn validate_transfer_by_owner() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_freezable_asset(&mut context, &asset, false).await;
+
+    let tx = validate_transfer_tx(&context, asset.pubkey(), Some(Keypair::new().pubkey()));
+    let validation = process_validation(&mut context, tx).await;
+    assert_eq!(
+        validation,
+        LifecycleValidation {
+            result: ValidationResult::Approved,
+            deciding_plugin: None,
+        }
+    );
+}
+
+#[tokio::test]
+async fn validate_transfer_of_frozen_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_freezable_asset(&mut context, &asset, true).await;
+
+    // The rejection is reported rather than failing the instruction.
+    let tx = validate_transfer_tx(&context, asset.pubkey(), Some(Keypair::new().pubkey()));
+    let validation = process_validation(&mut context, tx).await;
+    assert_eq!(
+        validation,
+        LifecycleValidation {
+            result: ValidationResult::Rejected,
+            deciding_plugin: Some(PluginType::FreezeDelegate),
+        }
+    );
+
+    let fetched = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        fetched.plugin_list.freeze_delegate.unwrap().freeze_delegate,
+        FreezeDelegate { frozen: true }
+    );
+}
+
+#[tokio::test]
+async fn cannot_validate_transfer_without_new_owner() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_freezable_asset(&mut context, &asset, false).await;
+
+    let tx = validate_transfer_tx(&context, asset.pubkey(), None);
+    assert_program_error(&mut context, tx, MplCoreError::MissingNewOwner).await;
+}
@@ -0,0 +1,126 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    instructions::UpdatePluginV1Builder,
+    types::{Plugin, PluginAuthorityPair, PluginType, VerifiedCreators, VerifiedCreatorsSignature},
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+/// Create an asset whose update authority is the context payer, listing the given creators.
+async fn create_asset_with_creators(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    signatures: Vec<VerifiedCreatorsSignature>,
+) -> Result<(), BanksClientError> {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::VerifiedCreators(VerifiedCreators { signatures }),
+                authority: None,
+            }],
+        },
+    )
+    .await
+}
+
+fn update_creators_tx(
+    context: &ProgramTestContext,
+    asset: &Keypair,
+    authority: &Keypair,
+    signatures: Vec<VerifiedCreatorsSignature>,
+) -> Transaction {
+    let update_plugin_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .plugin(Plugin::VerifiedCreators(VerifiedCreators { signatures }))
+        .instruction();
+
+    Transaction::new_signed_with_payer(
+        &[update_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    )
+}
+
+fn creator(address: Pubkey, verified: bool) -> VerifiedCreatorsSignature {
+    VerifiedCreatorsSignature { address, verified }
+}
+
+#[tokio::test]
+async fn creator_verifies_themselves() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator_signer = Keypair::new();
+    let asset = Keypair::new();
+    create_asset_with_creators(
+        &mut context,
+        &asset,
+        vec![creator(creator_signer.pubkey(), false)],
+    )
+    .await
+    .unwrap();
+
+    let signatures = vec![creator(creator_signer.pubkey(), true)];
+    let tx = update_creators_tx(&context, &asset, &creator_signer, signatures.clone());
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset = get_asset(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        asset
+            .plugin_list
+            .verified_creators
+            .unwrap()
+            .verified_creators,
+        VerifiedCreators { signatures }
+    );
+}
+
+#[tokio::test]
+async fn update_authority_cannot_verify_creator() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let creator_address = Keypair::new().pubkey();
+    create_asset_with_creators(&mut context, &asset, vec![creator(creator_address, false)])
+        .await
+        .unwrap();
+
+    let update_authority = context.payer.insecure_clone();
+    let tx = update_creators_tx(
+        &context,
+        &asset,
+        &update_authority,
+        vec![creator(creator_address, true)],
+    );
+    assert_rejected_by(&mut context, tx, PluginType::VerifiedCreators).await;
+}
+
+#[tokio::test]
+async fn cannot_create_asset_with_verified_creator() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset_with_creators(
+        &mut context,
+        &asset,
+        vec![creator(Keypair::new().pubkey(), true)],
+    )
+    .await
+    .unwrap_err();
+}
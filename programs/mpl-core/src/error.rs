@@ -1,4 +1,5 @@
 use num_derive::FromPrimitive;
+use num_traits::FromPrimitive as _;
 use solana_program::{
     decode_error::DecodeError,
     msg,
@@ -132,6 +133,105 @@ pub enum MplCoreError {
     /// 30 - Invalid Log Wrapper Program
     #[error("Invalid Log Wrapper Program")]
     InvalidLogWrapperProgram,
+
+    /// 31 - Dependent account is not held by the asset signer
+    #[error("Dependent account is not held by the asset signer")]
+    InvalidDependentAccount,
+
+    /// 32 - Asset is not owned by the expected owner
+    #[error("Asset is not owned by the expected owner")]
+    InvalidOwner,
+
+    /// 33 - Asset cannot be unbound early
+    #[error("Asset cannot be unbound early")]
+    CannotUnbind,
+
+    /// 34 - Transfer fee recipient is missing
+    #[error("Transfer fee recipient is missing")]
+    MissingTransferFeeRecipient,
+
+    /// 35 - Burn rent destination is missing
+    #[error("Burn rent destination is missing")]
+    MissingBurnRentDestination,
+
+    /// 36 - Missing instructions sysvar
+    #[error("Missing instructions sysvar")]
+    MissingInstructionsSysvar,
+
+    /// 37 - Transaction contains an instruction from a blocked program
+    #[error("Transaction contains an instruction from a blocked program")]
+    BlockedProgram,
+
+    /// 38 - Linked partner asset is not transferred to the same owner
+    #[error("Linked partner asset is not transferred to the same owner")]
+    LinkedPairNotTransferred,
+
+    /// 39 - Hooked program of a lifecycle hook is missing
+    #[error("Hooked program of a lifecycle hook is missing")]
+    MissingHookedProgram,
+
+    /// 40 - Invalid proof cache
+    #[error("Invalid proof cache")]
+    InvalidProofCache,
+
+    /// 41 - Proof cache is stale
+    #[error("Proof cache is stale")]
+    StaleProofCache,
+
+    /// 42 - Seller of a listed asset is missing
+    #[error("Seller of a listed asset is missing")]
+    MissingListingSeller,
+
+    /// 43 - Data offset is outside of the data section
+    #[error("Data offset is outside of the data section")]
+    InvalidDataOffset,
+
+    /// 44 - Linked token escrow is missing or does not back the asset
+    #[error("Linked token escrow is missing or does not back the asset")]
+    InvalidTokenLinkEscrow,
+
+    /// 45 - Fractions of the asset are still outstanding
+    #[error("Fractions of the asset are still outstanding")]
+    FractionsOutstanding,
+
+    /// 46 - Maximum supply of the collection has been reached
+    #[error("Maximum supply of the collection has been reached")]
+    MintLimitReached,
+
+    /// 47 - Collection still has assets
+    #[error("Collection still has assets")]
+    CollectionNotEmpty,
+
+    /// 48 - Invalid fee config account
+    #[error("Invalid fee config account")]
+    InvalidFeeConfig,
+
+    /// 49 - Invalid Token Metadata account
+    #[error("Invalid Token Metadata account")]
+    InvalidTokenMetadata,
+
+    /// 50 - Account cannot grow by more than 10KB in one instruction
+    #[error("Account cannot grow by more than 10KB in one instruction")]
+    AccountGrowthLimitExceeded,
+
+    /// 51 - A plugin rejected the operation
+    #[error("A plugin rejected the operation")]
+    PluginRejected,
+
+    /// 52 - Account key does not match the expected account type
+    #[error("Account key does not match the expected account type")]
+    IncorrectAccountKey,
+}
+
+impl MplCoreError {
+    /// Map a `ProgramError::Custom` returned by the program back to its error, so that it can be
+    /// rendered.  Returns `None` for any other program error or an unknown code.
+    pub fn from_program_error(error: &ProgramError) -> Option<Self> {
+        match error {
+            ProgramError::Custom(code) => Self::from_u32(*code),
+            _ => None,
+        }
+    }
 }
 
 impl PrintProgramError for MplCoreError {
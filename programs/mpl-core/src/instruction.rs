@@ -3,12 +3,22 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use shank::{ShankContext, ShankInstruction};
 
 use crate::processor::{
-    AddCollectionPluginV1Args, AddPluginV1Args, ApproveCollectionPluginAuthorityV1Args,
-    ApprovePluginAuthorityV1Args, BurnCollectionV1Args, BurnV1Args, CompressV1Args,
-    CreateCollectionV1Args, CreateV1Args, DecompressV1Args, RemoveCollectionPluginV1Args,
-    RemovePluginV1Args, RevokeCollectionPluginAuthorityV1Args, RevokePluginAuthorityV1Args,
-    TransferV1Args, UpdateCollectionPluginV1Args, UpdateCollectionV1Args, UpdatePluginV1Args,
-    UpdateV1Args,
+    AddCollectionExternalPluginV1Args, AddCollectionPluginV1Args, AddExternalPluginV1Args,
+    AddPluginV1Args, AddPluginV2Args, AirdropV1Args, ApproveCollectionPluginAuthorityV1Args,
+    ApprovePluginAuthorityV1Args, ApproveTransferV1Args, AssertOwnershipV1Args,
+    BurnCollectionV1Args, BurnCompressedV1Args, BurnV1Args, CompressV1Args, CreateBatchV1Args,
+    CreateCollectionV1Args, CreateV1Args, CreateV2Args, DecompressV1Args, ExecuteV1Args,
+    ExportToTokenMetadataV1Args, RemoveCollectionExternalPluginV1Args,
+    RemoveCollectionPluginV1Args, RemoveExternalPluginV1Args, RemovePluginV1Args,
+    RepairRegistryV1Args, ReserveV1Args, RevokeCollectionPluginAuthorityV1Args,
+    RevokePluginAuthorityV1Args, SetFeeConfigV1Args, StageProofV1Args, TransferBatchV1Args,
+    TransferCompressedV1Args, TransferV1Args, TruncateCollectionExternalPluginDataV1Args,
+    TruncateExternalPluginDataV1Args, UpdateCollectionExternalPluginV1Args,
+    UpdateCollectionPluginV1Args, UpdateCollectionV1Args, UpdateExternalPluginV1Args,
+    UpdatePluginV1Args, UpdateV1Args, UpdateV2Args, ValidateLifecycleV1Args, VerifyGroupingV1Args,
+    WithdrawFeesV1Args, WriteAppDataChunkV1Args, WriteAppDataV1Args, WriteCollectionAppDataV1Args,
+    WriteCollectionExternalPluginDataV1Args, WriteExternalPluginDataV1Args, WriteInscriptionV1Args,
+    WriteLinkedAppDataV1Args,
 };
 
 /// Instructions supported by the mpl-core program.
@@ -17,6 +27,7 @@ use crate::processor::{
 pub(crate) enum MplAssetInstruction {
     /// Create a new mpl-core Asset.
     /// This function creates the initial Asset, with or without plugins.
+    /// An optional referrer can be passed as the first remaining account to record the referral.
     #[account(0, writable, signer, name="asset", desc = "The address of the new asset")]
     #[account(1, optional, writable, name="collection", desc = "The collection to which the asset belongs")]
     #[account(2, optional, signer, name="authority", desc = "The authority signing for creation")]
@@ -184,8 +195,442 @@ pub(crate) enum MplAssetInstruction {
     DecompressV1(DecompressV1Args),
 
     /// Collect
-    /// This function creates the initial mpl-core
-    #[account(0, writable, name="recipient1", desc = "The address of the recipient 1")]
-    #[account(1, writable, name="recipient2", desc = "The address of the recipient 2")]
+    /// Sweep the legacy creation fees of the remaining accounts into the fee config.
+    #[account(0, writable, name="fee_config", desc = "The fee config receiving the fees")]
     Collect,
+
+    /// Verify or unverify a grouping of an asset with a collection.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, name="group", desc = "The collection the asset is grouped with")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The update authority of the group collection")]
+    #[account(4, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    VerifyGroupingV1(VerifyGroupingV1Args),
+
+    /// Approve a request for an asset to join a collection.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, writable, name="collection", desc = "The collection the asset requested to join")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The update authority of the collection")]
+    #[account(4, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    ApproveCollectionJoinV1,
+
+    /// Assert that an asset is owned by the given owner, and optionally that it is not frozen.
+    #[account(0, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, name="owner", desc = "The expected owner of the asset")]
+    AssertOwnershipV1(AssertOwnershipV1Args),
+
+    /// Rebuild the plugin registry offsets of an mpl-core Asset.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The update authority of the asset")]
+    #[account(4, name="system_program", desc = "The system program")]
+    #[account(5, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    RepairRegistryV1(RepairRegistryV1Args),
+
+    /// Distribute lamports or SPL tokens from a vault to the owners of the assets in a collection.
+    /// The remaining accounts are pairs of assets and their owners, followed by the associated
+    /// token account of the owner when tokens are distributed.
+    /// The status of each asset is set as return data.
+    #[account(0, name="collection", desc = "The collection the assets belong to")]
+    #[account(1, writable, signer, name="vault", desc = "The account funding the airdrop")]
+    #[account(2, optional, signer, name="authority", desc = "The update authority of the collection")]
+    #[account(3, name="system_program", desc = "The system program")]
+    #[account(4, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    #[account(5, optional, name="mint", desc = "The mint of the tokens to distribute instead of lamports")]
+    #[account(6, optional, writable, name="vault_token_account", desc = "The token account of the vault holding the tokens")]
+    #[account(7, optional, name="token_program", desc = "The SPL Token or Token-2022 program")]
+    AirdropV1(AirdropV1Args),
+
+    /// Pay the unbind fee of a bound asset to release it before its unlock time.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The owner of the asset paying the unbind fee")]
+    #[account(3, writable, name="fee_recipient", desc = "The account receiving the unbind fee")]
+    #[account(4, name="system_program", desc = "The system program")]
+    #[account(5, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    UnbindV1,
+
+    /// List the authorities of every plugin on an asset and its collection as return data.
+    #[account(0, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, name="collection", desc = "The collection to which the asset belongs")]
+    GetDelegatesV1,
+
+    /// Burn an expired asset of a collection with the Reaper plugin, pooling its rent in the collection.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, writable, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, signer, name="payer", desc = "The account reaping the asset")]
+    #[account(3, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    ReapV1,
+
+    /// Create a new mpl-core Asset with the rent shared between the payer and other funders.
+    /// The funders are passed as the first remaining accounts, one for each rent contribution,
+    /// followed by the optional referrer. Contributions that are not spent are refunded.
+    /// An optional policy preset adds a vetted bundle of plugins to the asset.
+    #[account(0, writable, signer, name="asset", desc = "The address of the new asset")]
+    #[account(1, optional, writable, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, optional, signer, name="authority", desc = "The authority signing for creation")]
+    #[account(3, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(4, optional, name="owner", desc = "The owner of the new asset. Defaults to the authority if not present.")]
+    #[account(5, optional, name="update_authority", desc = "The authority on the new asset")]
+    #[account(6, name="system_program", desc = "The system program")]
+    #[account(7, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    CreateV2(CreateV2Args),
+
+    /// Add a plugin to an mpl-core with the rent shared between the payer and other funders.
+    /// The funders are passed as the remaining accounts, one for each rent contribution.
+    /// Contributions that are not spent are refunded.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, writable, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The owner or delegate of the asset")]
+    #[account(4, name="system_program", desc = "The system program")]
+    #[account(5, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    AddPluginV2(AddPluginV2Args),
+
+    /// Add an external plugin to an mpl-core.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, writable, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The update authority of the asset")]
+    #[account(4, name="system_program", desc = "The system program")]
+    #[account(5, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    AddExternalPluginV1(AddExternalPluginV1Args),
+
+    /// Add an external plugin to an mpl-core Collection.
+    #[account(0, writable, name="collection", desc = "The address of the collection")]
+    #[account(1, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(2, optional, signer, name="authority", desc = "The update authority of the collection")]
+    #[account(3, name="system_program", desc = "The system program")]
+    #[account(4, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    AddCollectionExternalPluginV1(AddCollectionExternalPluginV1Args),
+
+    /// Verify the compression proof of a compressed asset and stage it in its proof cache.
+    /// Compressed operations in the same epoch can pass the proof cache in the remaining
+    /// accounts instead of the proof, for as long as the asset is unchanged.
+    #[account(0, name="asset", desc = "The address of the compressed asset")]
+    #[account(1, writable, name="proof_cache", desc = "The proof cache PDA of the asset")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, name="system_program", desc = "The system program")]
+    StageProofV1(StageProofV1Args),
+
+    /// Write the data of an app data external plugin of an mpl-core.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The data authority of the app data")]
+    #[account(4, name="system_program", desc = "The system program")]
+    #[account(5, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    WriteAppDataV1(WriteAppDataV1Args),
+
+    /// Write the data of an app data external plugin of an mpl-core Collection.
+    #[account(0, writable, name="collection", desc = "The address of the collection")]
+    #[account(1, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(2, optional, signer, name="authority", desc = "The data authority of the app data")]
+    #[account(3, name="system_program", desc = "The system program")]
+    #[account(4, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    WriteCollectionAppDataV1(WriteCollectionAppDataV1Args),
+
+    /// Write the data of an asset for a linked app data external plugin of its collection.
+    /// The data is stored in the linked data section PDA of the asset, which is created on the
+    /// first write.
+    #[account(0, name="asset", desc = "The address of the asset")]
+    #[account(1, name="collection", desc = "The collection with the linked app data")]
+    #[account(2, writable, name="linked_data_section", desc = "The linked data section PDA of the asset")]
+    #[account(3, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(4, optional, signer, name="authority", desc = "The data authority of the linked app data")]
+    #[account(5, name="system_program", desc = "The system program")]
+    WriteLinkedAppDataV1(WriteLinkedAppDataV1Args),
+
+    /// Redeem an asset with the Redemption plugin, burning it and emitting a redemption record
+    /// through the spl-noop program for the issuer to fulfill the reward.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, writable, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the transaction")]
+    #[account(3, optional, signer, name="authority", desc = "The owner of the asset")]
+    #[account(4, name="log_wrapper", desc = "The SPL Noop Program")]
+    RedeemV1,
+
+    /// Write a chunk of the data of an inscription external plugin of an mpl-core, optionally
+    /// sealing the inscription to make it immutable.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The inscription authority of the inscription")]
+    #[account(4, name="system_program", desc = "The system program")]
+    #[account(5, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    WriteInscriptionV1(WriteInscriptionV1Args),
+
+    /// Execute an instruction of another program signed by the asset signer PDA of an mpl-core
+    /// Asset, so that the owner of the asset controls any accounts held by the asset signer.
+    /// The remaining accounts are the accounts of the instruction to execute.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, name="asset_signer", desc = "The signer PDA of the asset")]
+    #[account(3, writable, signer, name="payer", desc = "The account paying for the transaction")]
+    #[account(4, optional, signer, name="authority", desc = "The owner of the asset")]
+    #[account(5, name="system_program", desc = "The system program")]
+    #[account(6, name="program_id", desc = "The program to execute")]
+    ExecuteV1(ExecuteV1Args),
+
+    /// Update an mpl-core, optionally moving it into, out of or between collections.
+    /// Moving an asset requires the update authority of the collection it leaves and the update
+    /// authority of the collection it joins.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, writable, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The update authority or update authority delegate of the asset")]
+    #[account(4, optional, writable, name="new_collection", desc = "The collection the asset is moved into")]
+    #[account(5, optional, signer, name="new_collection_authority", desc = "The update authority of the new collection. Defaults to the authority if not present.")]
+    #[account(6, name="system_program", desc = "The system program")]
+    #[account(7, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    UpdateV2(UpdateV2Args),
+
+    /// Transfer several assets to the same new owner.
+    /// The remaining accounts start with pairs of assets and their collections, with the program
+    /// id in place of the collection of an asset that is not in one, followed by the accounts
+    /// shared by every transfer.  The status of each asset is set as return data.
+    #[account(0, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(1, optional, signer, name="authority", desc = "The owner or delegate of the assets")]
+    #[account(2, name="new_owner", desc = "The new owner to which to transfer the assets")]
+    #[account(3, optional, name="system_program", desc = "The system program")]
+    #[account(4, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    TransferBatchV1(TransferBatchV1Args),
+
+    /// Create several mpl-core Assets in the same collection from a shared plugin template.
+    /// The remaining accounts are the addresses of the new assets, one for each item.
+    /// The status of each asset is set as return data.
+    #[account(0, writable, name="collection", desc = "The collection to which the assets belong")]
+    #[account(1, optional, signer, name="authority", desc = "The authority signing for creation")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, name="owner", desc = "The owner of the new assets. Defaults to the authority if not present.")]
+    #[account(4, name="system_program", desc = "The system program")]
+    #[account(5, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    CreateBatchV1(CreateBatchV1Args),
+
+    /// Update an external plugin of an mpl-core.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The update authority of the asset")]
+    #[account(4, name="system_program", desc = "The system program")]
+    #[account(5, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    UpdateExternalPluginV1(UpdateExternalPluginV1Args),
+
+    /// Update an external plugin of an mpl-core Collection.
+    #[account(0, writable, name="collection", desc = "The address of the collection")]
+    #[account(1, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(2, optional, signer, name="authority", desc = "The update authority of the collection")]
+    #[account(3, name="system_program", desc = "The system program")]
+    #[account(4, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    UpdateCollectionExternalPluginV1(UpdateCollectionExternalPluginV1Args),
+
+    /// Remove an external plugin and its data from an mpl-core.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The update authority of the asset")]
+    #[account(4, name="system_program", desc = "The system program")]
+    #[account(5, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    RemoveExternalPluginV1(RemoveExternalPluginV1Args),
+
+    /// Remove an external plugin and its data from an mpl-core Collection.
+    /// The linked data sections of a removed linked app data are left for their assets.
+    #[account(0, writable, name="collection", desc = "The address of the collection")]
+    #[account(1, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(2, optional, signer, name="authority", desc = "The update authority of the collection")]
+    #[account(3, name="system_program", desc = "The system program")]
+    #[account(4, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    RemoveCollectionExternalPluginV1(RemoveCollectionExternalPluginV1Args),
+
+    /// Replace or append to the data of an app data or inscription external plugin of an
+    /// mpl-core, resizing the account to fit.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The data authority of the external plugin")]
+    #[account(4, name="system_program", desc = "The system program")]
+    #[account(5, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    WriteExternalPluginDataV1(WriteExternalPluginDataV1Args),
+
+    /// Replace or append to the data of an app data external plugin of an mpl-core Collection,
+    /// resizing the account to fit.
+    #[account(0, writable, name="collection", desc = "The address of the collection")]
+    #[account(1, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(2, optional, signer, name="authority", desc = "The data authority of the external plugin")]
+    #[account(3, name="system_program", desc = "The system program")]
+    #[account(4, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    WriteCollectionExternalPluginDataV1(WriteCollectionExternalPluginDataV1Args),
+
+    /// Truncate the data of an app data or inscription external plugin of an mpl-core, shrinking
+    /// the account and refunding the rent to the payer.  A length of zero clears the data.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account receiving the freed rent")]
+    #[account(3, optional, signer, name="authority", desc = "The data authority of the external plugin")]
+    #[account(4, name="system_program", desc = "The system program")]
+    #[account(5, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    TruncateExternalPluginDataV1(TruncateExternalPluginDataV1Args),
+
+    /// Truncate the data of an app data external plugin of an mpl-core Collection, shrinking the
+    /// account and refunding the rent to the payer.  A length of zero clears the data.
+    #[account(0, writable, name="collection", desc = "The address of the collection")]
+    #[account(1, writable, signer, name="payer", desc = "The account receiving the freed rent")]
+    #[account(2, optional, signer, name="authority", desc = "The data authority of the external plugin")]
+    #[account(3, name="system_program", desc = "The system program")]
+    #[account(4, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    TruncateCollectionExternalPluginDataV1(TruncateCollectionExternalPluginDataV1Args),
+
+    /// Close an mpl-core Collection without any assets, refunding its rent to the update
+    /// authority.
+    #[account(0, writable, name="collection", desc = "The address of the collection")]
+    #[account(1, writable, signer, name="payer", desc = "The account paying for the transaction")]
+    #[account(2, optional, writable, signer, name="authority", desc = "The update authority of the collection")]
+    #[account(3, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    CloseCollectionV1,
+
+    /// Revoke every delegated plugin authority on an asset, resetting each to its default.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The owner or delegate of the asset")]
+    #[account(4, name="system_program", desc = "The system program")]
+    #[account(5, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    RevokeAllV1,
+
+    /// Fund the rent of an asset up to a target size ahead of time, so later plugin additions do
+    /// not need to transfer lamports.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, writable, signer, name="payer", desc = "The account paying for the reserved rent")]
+    #[account(2, name="system_program", desc = "The system program")]
+    ReserveV1(ReserveV1Args),
+
+    /// Run the validations of a lifecycle event on an asset without performing it, and return
+    /// the result and the deciding plugin as return data.
+    #[account(0, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, name="authority", desc = "The authority to validate the lifecycle event for")]
+    #[account(3, optional, name="new_owner", desc = "The new owner, for transfers")]
+    ValidateLifecycleV1(ValidateLifecycleV1Args),
+
+    /// Initialize or change the protocol fees charged on creation.
+    #[account(0, writable, name="fee_config", desc = "The fee config of the program")]
+    #[account(1, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(2, optional, signer, name="authority", desc = "The authority of the fee config")]
+    #[account(3, name="system_program", desc = "The system program")]
+    SetFeeConfigV1(SetFeeConfigV1Args),
+
+    /// Withdraw the protocol fees collected in the fee config.
+    #[account(0, writable, name="fee_config", desc = "The fee config of the program")]
+    #[account(1, signer, name="authority", desc = "The authority of the fee config")]
+    #[account(2, writable, name="recipient", desc = "The account receiving the fees")]
+    WithdrawFeesV1(WithdrawFeesV1Args),
+
+    /// Print the next edition of a master edition collection, named after the master edition.
+    #[account(0, writable, signer, name="asset", desc = "The address of the new edition")]
+    #[account(1, writable, name="collection", desc = "The master edition collection")]
+    #[account(2, optional, signer, name="authority", desc = "The authority signing for creation")]
+    #[account(3, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(4, optional, name="owner", desc = "The owner of the new edition. Defaults to the authority if not present.")]
+    #[account(5, name="system_program", desc = "The system program")]
+    #[account(6, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    CreateEditionV1,
+
+    /// Migrate a Token Metadata NFT to a new mpl-core Asset.  The NFT is burned, and the asset
+    /// keeps its name, uri, update authority, royalties and collection.
+    #[account(0, writable, signer, name="asset", desc = "The address of the new asset")]
+    #[account(1, optional, writable, name="collection", desc = "The collection to migrate the asset into")]
+    #[account(2, writable, signer, name="owner", desc = "The holder of the NFT and owner of the new asset")]
+    #[account(3, name="update_authority", desc = "The update authority of the NFT, signing when migrating into a collection")]
+    #[account(4, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(5, name="metadata", desc = "The Token Metadata account of the NFT")]
+    #[account(6, writable, name="mint", desc = "The mint of the NFT")]
+    #[account(7, writable, name="token_account", desc = "The token account of the owner holding the NFT")]
+    #[account(8, name="token_program", desc = "The SPL Token program")]
+    #[account(9, name="system_program", desc = "The system program")]
+    #[account(10, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    MigrateFromTokenMetadataV1,
+
+    /// Export an mpl-core Asset to a Token Metadata NFT.  The asset is burned, and the NFT keeps
+    /// its name, uri, update authority and royalties.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, writable, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="owner", desc = "The owner of the asset and of the new NFT")]
+    #[account(3, signer, name="update_authority", desc = "The update authority of the asset")]
+    #[account(4, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(5, writable, signer, name="mint", desc = "The new mint of the NFT")]
+    #[account(6, writable, name="metadata", desc = "The Token Metadata account of the NFT")]
+    #[account(7, writable, name="master_edition", desc = "The Token Metadata master edition of the NFT")]
+    #[account(8, writable, name="token_account", desc = "The associated token account of the owner for the NFT")]
+    #[account(9, name="token_metadata_program", desc = "The Token Metadata program")]
+    #[account(10, name="token_program", desc = "The SPL Token program")]
+    #[account(11, name="associated_token_program", desc = "The SPL Associated Token Account program")]
+    #[account(12, name="sysvar_instructions", desc = "The instructions sysvar")]
+    #[account(13, name="system_program", desc = "The system program")]
+    ExportToTokenMetadataV1(ExportToTokenMetadataV1Args),
+
+    /// Transfer a compressed mpl-core Asset without decompressing it.  The owner in the verified
+    /// compression proof is updated and the new hash is written back to the hashed asset.
+    #[account(0, writable, name="asset", desc = "The address of the compressed asset")]
+    #[account(1, optional, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the transfer fees")]
+    #[account(3, optional, signer, name="authority", desc = "The owner or delegate of the asset")]
+    #[account(4, name="new_owner", desc = "The new owner to which to transfer the asset")]
+    #[account(5, optional, name="system_program", desc = "The system program")]
+    #[account(6, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    TransferCompressedV1(TransferCompressedV1Args),
+
+    /// Burn a compressed mpl-core Asset without decompressing it.  The burn is validated against
+    /// the plugins of the verified compression proof.
+    #[account(0, writable, name="asset", desc = "The address of the compressed asset")]
+    #[account(1, optional, writable, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the transaction fees")]
+    #[account(3, optional, signer, name="authority", desc = "The owner or delegate of the asset")]
+    #[account(4, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    BurnCompressedV1(BurnCompressedV1Args),
+
+    /// Approve a delegate to transfer an mpl-core Asset once, optionally only to a given
+    /// destination, until an expiry slot.  The approval replaces any previous one.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(2, optional, signer, name="authority", desc = "The owner of the asset")]
+    #[account(3, name="system_program", desc = "The system program")]
+    #[account(4, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    ApproveTransferV1(ApproveTransferV1Args),
+
+    /// Revoke the transfer approval of an mpl-core Asset.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, writable, signer, name="payer", desc = "The account receiving the storage fees")]
+    #[account(2, optional, signer, name="authority", desc = "The owner of the asset or the approved delegate")]
+    #[account(3, name="system_program", desc = "The system program")]
+    #[account(4, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    RevokeTransferApprovalV1,
+
+    /// Freeze an mpl-core Asset through its freeze delegate, or its permanent freeze delegate.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the transaction fees")]
+    #[account(3, optional, signer, name="authority", desc = "The authority of the freeze plugin")]
+    #[account(4, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    FreezeAssetV1,
+
+    /// Thaw an mpl-core Asset through its freeze delegate, or its permanent freeze delegate.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the transaction fees")]
+    #[account(3, optional, signer, name="authority", desc = "The authority of the freeze plugin")]
+    #[account(4, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    ThawAssetV1,
+
+    /// Write a chunk of the data of an app data external plugin of an mpl-core Asset at an
+    /// offset, growing the data when written past its end.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The data authority of the app data")]
+    #[account(4, name="system_program", desc = "The system program")]
+    #[account(5, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    WriteAppDataChunkV1(WriteAppDataChunkV1Args),
 }
@@ -12,6 +12,15 @@
 //! it could even allow users to contribute third party plugins after the core
 //! program is made immutable.
 
+/// Log a message only when the `debug-logs` feature is enabled.  Approvals of lifecycle events
+/// are logged this way, since every log costs compute units on the successful path.
+macro_rules! debug_msg {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "debug-logs")]
+        solana_program::msg!($($arg)*);
+    };
+}
+
 /// Standard Solana entrypoint.
 pub mod entrypoint;
 /// Error types for MPL Core.
@@ -0,0 +1,19 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::state::Authority;
+
+use super::ExternalPluginSchema;
+
+/// The app data external plugin reserves a data section on the asset that a data authority,
+/// such as a game or app, can write arbitrary bytes into.  The data authority is not granted any
+/// lifecycle permissions over the asset.  The data starts empty and is written with the
+/// `WriteAppDataV1` instruction.  Linked app data on a collection instead keeps the data of each
+/// asset in a linked data section PDA, written with the `WriteLinkedAppDataV1` instruction.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct AppDataInitInfo {
+    /// The authority who can write the data.
+    pub data_authority: Authority, // Variable
+    /// The format of the data, for clients to decode it.
+    pub schema: ExternalPluginSchema, // 1
+}
@@ -33,7 +33,11 @@ impl DataBlob for Attributes {
     }
 
     fn get_size(&self) -> usize {
-        4 // TODO: Implement this.
+        4 + self
+            .attribute_list
+            .iter()
+            .map(|attribute| 4 + attribute.key.len() + 4 + attribute.value.len())
+            .sum::<usize>()
     }
 }
 
@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{error::MplCoreError, state::DataBlob};
+
+use super::{Plugin, PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The maximum length of an autograph message.
+pub const MAX_AUTOGRAPH_MESSAGE_LENGTH: usize = 200;
+
+/// A signature on an asset and its accompanying message.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct AutographSignature {
+    /// The address of the signer.
+    pub address: Pubkey, // 32
+    /// The message left by the signer.
+    pub message: String, // 4
+}
+
+/// The autograph plugin allows anyone to sign the asset with a message.
+/// Signatures can only be added by the signer themselves, and only removed by the owner.
+/// The default authority for this plugin is the owner.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct Autograph {
+    /// A list of signatures on the asset.
+    pub signatures: Vec<AutographSignature>, // 4
+}
+
+impl Autograph {
+    /// Initialize the Autograph plugin with no signatures.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl DataBlob for Autograph {
+    fn get_initial_size() -> usize {
+        4
+    }
+
+    fn get_size(&self) -> usize {
+        4 + self
+            .signatures
+            .iter()
+            .map(|signature| 32 + 4 + signature.message.len())
+            .sum::<usize>()
+    }
+}
+
+/// Validate the signatures of the plugin, rejecting any signature that was not added by the
+/// current authority.
+fn validate_autograph(
+    current: Option<&Autograph>,
+    new: &Autograph,
+    ctx: &PluginValidationContext,
+) -> Result<ValidationResult, ProgramError> {
+    // Each signer can only sign once.
+    let mut seen_addresses = HashSet::new();
+    if !new.signatures.iter().all(|signature| {
+        signature.message.len() <= MAX_AUTOGRAPH_MESSAGE_LENGTH
+            && seen_addresses.insert(signature.address)
+    }) {
+        return Err(MplCoreError::InvalidPluginSetting.into());
+    }
+
+    let is_new = |signature: &AutographSignature| match current {
+        Some(current) => !current.signatures.contains(signature),
+        None => true,
+    };
+    let mut added = new.signatures.iter().filter(|signature| is_new(signature));
+    if added.any(|signature| signature.address != *ctx.authority_info.key) {
+        solana_program::msg!("Autograph: Rejected");
+        return Ok(ValidationResult::Rejected);
+    }
+
+    match current {
+        // Signing only appends to the list, which anyone can do.
+        Some(current)
+            if current
+                .signatures
+                .iter()
+                .all(|signature| new.signatures.contains(signature)) =>
+        {
+            debug_msg!("Autograph: Approved");
+            Ok(ValidationResult::Approved)
+        }
+        // Removing signatures requires the authority of the plugin.
+        Some(_) => match ctx.resolved_authorities {
+            Some(resolved_authorities) if resolved_authorities.contains(ctx.self_authority) => {
+                Ok(ValidationResult::Pass)
+            }
+            _ => {
+                solana_program::msg!("Autograph: Rejected");
+                Ok(ValidationResult::Rejected)
+            }
+        },
+        None => Ok(ValidationResult::Pass),
+    }
+}
+
+impl PluginValidation for Autograph {
+    fn validate_create(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        validate_autograph(None, self, ctx)
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Autograph(autograph)) => validate_autograph(None, autograph, ctx),
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Autograph(autograph)) => validate_autograph(Some(self), autograph, ctx),
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+}
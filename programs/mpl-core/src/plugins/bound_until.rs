@@ -0,0 +1,57 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{clock::Clock, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar};
+
+use crate::state::DataBlob;
+
+use super::{PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The bound until plugin makes an asset non-transferable until a point in time, for
+/// example to vest team allocations or quest rewards. The owner may release the asset
+/// early by paying the unbind fee, if one is set.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct BoundUntil {
+    /// The unix timestamp after which the asset can be transferred.
+    pub unlock_at: i64, // 8
+    /// The lamports the owner must pay to unbind the asset early, zero if not allowed.
+    pub unbind_fee: u64, // 8
+    /// The account receiving the unbind fee.
+    pub fee_recipient: Pubkey, // 32
+}
+
+impl BoundUntil {
+    /// Check whether the asset is still bound at the current time.
+    pub fn is_bound(&self) -> Result<bool, ProgramError> {
+        Ok(Clock::get()?.unix_timestamp < self.unlock_at)
+    }
+
+    /// Release the asset, allowing it to be transferred immediately.
+    pub fn unbind(&mut self) {
+        self.unlock_at = 0;
+    }
+}
+
+impl DataBlob for BoundUntil {
+    fn get_initial_size() -> usize {
+        48
+    }
+
+    fn get_size(&self) -> usize {
+        48
+    }
+}
+
+impl PluginValidation for BoundUntil {
+    fn validate_transfer(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.is_bound()? {
+            solana_program::msg!("BoundUntil: Rejected");
+            Ok(ValidationResult::Rejected)
+        } else {
+            Ok(ValidationResult::Pass)
+        }
+    }
+}
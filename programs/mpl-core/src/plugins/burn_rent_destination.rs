@@ -0,0 +1,29 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::state::DataBlob;
+
+use super::PluginValidation;
+
+/// The burn rent destination plugin sets where the rent of burned member assets of a collection
+/// is returned, such as a DAO treasury, regardless of which authority performs the burn.
+/// The destination must be passed in the remaining accounts of the burn.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct BurnRentDestination {
+    /// The account receiving the rent of burned assets.
+    pub destination: Pubkey, // 32
+}
+
+impl DataBlob for BurnRentDestination {
+    fn get_initial_size() -> usize {
+        32
+    }
+
+    fn get_size(&self) -> usize {
+        32
+    }
+}
+
+impl PluginValidation for BurnRentDestination {}
@@ -0,0 +1,95 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::state::DataBlob;
+
+use super::{Plugin, PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The CollectionJoinRequest plugin records the request of an asset's update authority to join
+/// a collection. Once the update authority of the collection approves the request with
+/// `ApproveCollectionJoin`, the asset is moved into the collection and the plugin is kept as a
+/// record of the consent of both parties.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct CollectionJoinRequest {
+    /// The collection the asset requests to join.
+    pub collection: Pubkey, // 32
+    /// The update authority of the asset that signed the request.
+    pub requester: Pubkey, // 32
+    /// The update authority of the collection that approved the request, or the default
+    /// pubkey while the request is pending.
+    pub approver: Pubkey, // 32
+}
+
+impl CollectionJoinRequest {
+    /// Create a pending request to join the given collection.
+    pub fn new(collection: Pubkey, requester: Pubkey) -> Self {
+        Self {
+            collection,
+            requester,
+            approver: Pubkey::default(),
+        }
+    }
+
+    /// Whether or not the request has been approved by the collection.
+    pub fn is_approved(&self) -> bool {
+        self.approver != Pubkey::default()
+    }
+}
+
+impl DataBlob for CollectionJoinRequest {
+    fn get_initial_size() -> usize {
+        96
+    }
+
+    fn get_size(&self) -> usize {
+        96
+    }
+}
+
+/// A request must be signed by the requester and cannot be approved up front.
+fn validate_request(
+    request: &CollectionJoinRequest,
+    ctx: &PluginValidationContext,
+) -> Result<ValidationResult, ProgramError> {
+    if request.requester != *ctx.authority_info.key || request.is_approved() {
+        solana_program::msg!("CollectionJoinRequest: Rejected");
+        Ok(ValidationResult::Rejected)
+    } else {
+        Ok(ValidationResult::Pass)
+    }
+}
+
+impl PluginValidation for CollectionJoinRequest {
+    fn validate_create(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        validate_request(self, ctx)
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::CollectionJoinRequest(request)) => validate_request(request, ctx),
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        // The request can only be approved through `ApproveCollectionJoin`, a new request requires
+        // removing the old one first.
+        match ctx.target_plugin {
+            Some(Plugin::CollectionJoinRequest(_)) => {
+                solana_program::msg!("CollectionJoinRequest: Rejected");
+                Ok(ValidationResult::Rejected)
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+}
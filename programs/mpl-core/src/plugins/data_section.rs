@@ -0,0 +1,184 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
+};
+
+use crate::{error::MplCoreError, state::Authority, utils::resize_or_reallocate_account};
+
+use super::{ExternalPluginType, PluginHeaderV1, PluginRegistryV1};
+
+/// A variable-length region of raw external plugin data in an account, located by its record in
+/// the external plugins of the registry.  A data section grows and shrinks independently of the
+/// plugins around it, which are moved and have their offsets updated in the registry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DataSection {
+    /// The index of the external plugin record of the data section.
+    index: usize,
+    /// The offset of the data in the account.
+    offset: usize,
+    /// The length of the data.
+    len: usize,
+}
+
+impl DataSection {
+    /// Find the data section of an external plugin by its type and authority.
+    pub fn find(
+        plugin_registry: &PluginRegistryV1,
+        plugin_type: ExternalPluginType,
+        authority: &Authority,
+    ) -> Result<Self, MplCoreError> {
+        plugin_registry
+            .external_plugins
+            .iter()
+            .enumerate()
+            .find(|(_, record)| record.plugin_type == plugin_type && record.authority == *authority)
+            .map(|(index, record)| Self {
+                index,
+                offset: record.offset,
+                len: record.data_len,
+            })
+            .ok_or(MplCoreError::PluginNotFound)
+    }
+
+    /// The data section of the external plugin record at an index of the registry.
+    pub fn at(plugin_registry: &PluginRegistryV1, index: usize) -> Result<Self, MplCoreError> {
+        plugin_registry
+            .external_plugins
+            .get(index)
+            .map(|record| Self {
+                index,
+                offset: record.offset,
+                len: record.data_len,
+            })
+            .ok_or(MplCoreError::PluginNotFound)
+    }
+
+    /// The length of the data.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the data is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Replace the data of the section, resizing the account and moving the data after the
+    /// section.  The caller saves the header and the registry, which does not change size.
+    pub fn write<'a>(
+        &mut self,
+        data: &[u8],
+        plugin_header: &mut PluginHeaderV1,
+        plugin_registry: &mut PluginRegistryV1,
+        account: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        self.resize(
+            data.len(),
+            plugin_header,
+            plugin_registry,
+            account,
+            payer,
+            system_program,
+        )?;
+        self.write_at(0, data, account)
+    }
+
+    /// Write data into the section at an offset, which must be within the section.
+    pub fn write_at(&self, offset: usize, data: &[u8], account: &AccountInfo) -> ProgramResult {
+        if offset
+            .checked_add(data.len())
+            .ok_or(MplCoreError::NumericalOverflow)?
+            > self.len
+        {
+            return Err(MplCoreError::InvalidDataOffset.into());
+        }
+
+        sol_memcpy(
+            &mut account.data.borrow_mut()[(self.offset + offset)..],
+            data,
+            data.len(),
+        );
+
+        Ok(())
+    }
+
+    /// Resize the section, resizing the account and moving the data after the section.  Data
+    /// added at the end of the section is left zeroed or stale for the caller to write.  The
+    /// caller saves the header and the registry, which does not change size.
+    pub fn resize<'a>(
+        &mut self,
+        new_len: usize,
+        plugin_header: &mut PluginHeaderV1,
+        plugin_registry: &mut PluginRegistryV1,
+        account: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        // The difference in size between the new and old data.
+        let size_diff = (new_len as isize)
+            .checked_sub(self.len as isize)
+            .ok_or(MplCoreError::NumericalOverflow)?;
+
+        let new_size = (account.data_len() as isize)
+            .checked_add(size_diff)
+            .ok_or(MplCoreError::NumericalOverflow)?;
+
+        let registry_offset = plugin_header.plugin_registry_offset;
+        let new_registry_offset = (registry_offset as isize)
+            .checked_add(size_diff)
+            .ok_or(MplCoreError::NumericalOverflow)?;
+        plugin_header.plugin_registry_offset = new_registry_offset as usize;
+
+        let next_plugin_offset = self
+            .offset
+            .checked_add(self.len)
+            .ok_or(MplCoreError::NumericalOverflow)?;
+        let new_next_plugin_offset = (next_plugin_offset as isize)
+            .checked_add(size_diff)
+            .ok_or(MplCoreError::NumericalOverflow)?;
+
+        // Shift the data after the section in place.  When shrinking, the data must be moved
+        // down before the account is resized; when growing, after.
+        let plugin_data_range = next_plugin_offset..registry_offset;
+        if size_diff < 0 {
+            account
+                .data
+                .borrow_mut()
+                .copy_within(plugin_data_range, new_next_plugin_offset as usize);
+            resize_or_reallocate_account(account, payer, system_program, new_size as usize)?;
+        } else {
+            resize_or_reallocate_account(account, payer, system_program, new_size as usize)?;
+            account
+                .data
+                .borrow_mut()
+                .copy_within(plugin_data_range, new_next_plugin_offset as usize);
+        }
+
+        // Move the offsets of everything after the section.  Empty sections share their offset
+        // with whatever was added after them, so only those added later are moved.
+        for record in &mut plugin_registry.registry {
+            if record.offset >= next_plugin_offset {
+                record.offset = (record.offset as isize)
+                    .checked_add(size_diff)
+                    .ok_or(MplCoreError::NumericalOverflow)?
+                    as usize;
+            }
+        }
+        for (index, record) in plugin_registry.external_plugins.iter_mut().enumerate() {
+            if index != self.index
+                && (record.offset > self.offset
+                    || (record.offset == self.offset && index > self.index))
+            {
+                record.offset = (record.offset as isize)
+                    .checked_add(size_diff)
+                    .ok_or(MplCoreError::NumericalOverflow)?
+                    as usize;
+            }
+        }
+        plugin_registry.external_plugins[self.index].data_len = new_len;
+        self.len = new_len;
+
+        Ok(())
+    }
+}
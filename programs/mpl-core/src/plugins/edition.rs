@@ -1,17 +1,29 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::program_error::ProgramError;
 
-use crate::state::Authority;
+use crate::state::{Authority, DataBlob};
 
 use super::{PluginType, PluginValidation, PluginValidationContext, ValidationResult};
 
 /// The edition plugin allows the creator to set an edition number on the asset
 /// The default authority for this plugin is the creator.
+/// The edition number can only be set at creation time, and is made immutable by setting the
+/// authority of the plugin to `None`.
 #[repr(C)]
 #[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
 pub struct Edition {
     /// The edition number.
-    pub number: u32,
+    pub number: u32, // 4
+}
+
+impl DataBlob for Edition {
+    fn get_initial_size() -> usize {
+        4
+    }
+
+    fn get_size(&self) -> usize {
+        4
+    }
 }
 
 impl PluginValidation for Edition {
@@ -46,6 +58,7 @@ impl PluginValidation for Edition {
             Ok(ValidationResult::Pass)
         }
     }
+
     /// Validate the revoke plugin authority lifecycle action.
     fn validate_revoke_plugin_authority(
         &self,
@@ -58,7 +71,7 @@ impl PluginValidation for Edition {
             && ctx.target_plugin.is_some()
             && PluginType::from(ctx.target_plugin.unwrap()) == PluginType::Edition
         {
-            solana_program::msg!("Edition: Approved");
+            debug_msg!("Edition: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
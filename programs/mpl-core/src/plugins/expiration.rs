@@ -0,0 +1,49 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{clock::Clock, program_error::ProgramError, sysvar::Sysvar};
+
+use crate::state::DataBlob;
+
+use super::{PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The expiration plugin allows the plugin authority to burn an asset without the owner once
+/// it has expired, so temporary passes and trials can be cleaned up.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct Expiration {
+    /// The unix timestamp after which the asset can be burned by the plugin authority.
+    pub expires_at: i64, // 8
+}
+
+impl Expiration {
+    /// Check whether the asset has expired at the current time.
+    pub fn is_expired(&self) -> Result<bool, ProgramError> {
+        Ok(Clock::get()?.unix_timestamp >= self.expires_at)
+    }
+}
+
+impl DataBlob for Expiration {
+    fn get_initial_size() -> usize {
+        8
+    }
+
+    fn get_size(&self) -> usize {
+        8
+    }
+}
+
+impl PluginValidation for Expiration {
+    fn validate_burn(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if let Some(resolved_authorities) = ctx.resolved_authorities {
+            if resolved_authorities.contains(ctx.self_authority) && self.is_expired()? {
+                debug_msg!("Expiration: ForceApproved");
+                return Ok(ValidationResult::ForceApproved);
+            }
+        }
+
+        Ok(ValidationResult::Pass)
+    }
+}
@@ -0,0 +1,85 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    error::MplCoreError,
+    state::{DataBlob, SPL_TOKEN_2022_PROGRAM_ID, SPL_TOKEN_PROGRAM_ID},
+};
+
+use super::{Plugin, PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The fractionalized plugin marks an asset as locked in a fractionalization vault, recording
+/// the vault and the mint of its fractions.  The asset cannot be transferred or burned while
+/// fractionalized, and the plugin can only be removed once the whole supply of fractions has
+/// been burned, so the fraction mint must be passed in the remaining accounts.
+/// The default authority for this plugin is the owner.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct Fractionalized {
+    /// The vault holding the asset for the fraction holders.
+    pub vault: Pubkey, // 32
+    /// The mint of the fractions.
+    pub fraction_mint: Pubkey, // 32
+}
+
+impl Fractionalized {
+    /// Check that the fraction mint has no supply left.
+    pub fn check_fractions_burned(&self, fraction_mint_info: &AccountInfo) -> ProgramResult {
+        // A mint stores the optional mint authority, then the supply.
+        let data = fraction_mint_info.data.borrow();
+        let burned = fraction_mint_info.key == &self.fraction_mint
+            && (fraction_mint_info.owner == &SPL_TOKEN_PROGRAM_ID
+                || fraction_mint_info.owner == &SPL_TOKEN_2022_PROGRAM_ID)
+            && data.get(36..44) == Some(&[0; 8]);
+
+        if !burned {
+            msg!("Fractionalized: Rejected");
+            return Err(MplCoreError::FractionsOutstanding.into());
+        }
+
+        Ok(())
+    }
+}
+
+impl DataBlob for Fractionalized {
+    fn get_initial_size() -> usize {
+        64
+    }
+
+    fn get_size(&self) -> usize {
+        64
+    }
+}
+
+impl PluginValidation for Fractionalized {
+    fn validate_burn(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        solana_program::msg!("Fractionalized: Rejected");
+        Ok(ValidationResult::Rejected)
+    }
+
+    fn validate_transfer(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        solana_program::msg!("Fractionalized: Rejected");
+        Ok(ValidationResult::Rejected)
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Fractionalized(fractionalized)) if fractionalized != self => {
+                Err(MplCoreError::InvalidPluginSetting.into())
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+}
@@ -62,6 +62,18 @@ impl PluginValidation for FreezeDelegate {
         }
     }
 
+    fn validate_execute(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.frozen {
+            solana_program::msg!("FreezeDelegate: Rejected");
+            Ok(ValidationResult::Rejected)
+        } else {
+            Ok(ValidationResult::Pass)
+        }
+    }
+
     fn validate_approve_plugin_authority(
         &self,
         ctx: &PluginValidationContext,
@@ -89,7 +101,7 @@ impl PluginValidation for FreezeDelegate {
                     address: *ctx.authority_info.key,
                 })
             {
-                solana_program::msg!("FreezeDelegate: Approved");
+                debug_msg!("FreezeDelegate: Approved");
                 return Ok(ValidationResult::Approved);
             }
         }
@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{error::MplCoreError, state::DataBlob};
+
+use super::{Plugin, PluginValidation, PluginValidationContext, ValidationResult};
+
+/// A secondary collection that an asset is tagged with.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct Grouping {
+    /// The address of the collection.
+    pub collection: Pubkey, // 32
+    /// Whether or not the update authority of the collection has verified the grouping.
+    pub verified: bool, // 1
+}
+
+/// The Groupings plugin allows an asset to be tagged with additional collections beyond the
+/// collection that acts as its update authority. The groupings are non-authoritative and are
+/// only verified once the update authority of the grouped collection signs off on them.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq, Default)]
+pub struct Groupings {
+    /// A vector of collections the asset is grouped with.
+    pub groupings: Vec<Grouping>, // 4
+}
+
+impl Groupings {
+    /// Initialize the Groupings plugin with no groupings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl DataBlob for Groupings {
+    fn get_initial_size() -> usize {
+        4
+    }
+
+    fn get_size(&self) -> usize {
+        4 + self.groupings.len() * 33
+    }
+}
+
+/// Validate new plugin data, only allowing verified groupings that were already verified.
+fn validate_groupings(
+    current: Option<&Groupings>,
+    new: &Groupings,
+) -> Result<ValidationResult, ProgramError> {
+    // Check unique collections.
+    let mut seen_collections = HashSet::new();
+    if !new
+        .groupings
+        .iter()
+        .all(|grouping| seen_collections.insert(grouping.collection))
+    {
+        return Err(MplCoreError::InvalidPluginSetting.into());
+    }
+
+    // Groupings can only be verified by the collection's update authority through `VerifyGrouping`.
+    let previously_verified = |grouping: &Grouping| match current {
+        Some(current) => current.groupings.contains(grouping),
+        None => false,
+    };
+    if new
+        .groupings
+        .iter()
+        .any(|grouping| grouping.verified && !previously_verified(grouping))
+    {
+        solana_program::msg!("Groupings: Rejected");
+        return Ok(ValidationResult::Rejected);
+    }
+
+    Ok(ValidationResult::Pass)
+}
+
+impl PluginValidation for Groupings {
+    fn validate_create(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        validate_groupings(None, self)
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Groupings(groupings)) => validate_groupings(None, groupings),
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Groupings(groupings)) => validate_groupings(Some(self), groupings),
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::state::Authority;
+
+use super::ExternalPluginSchema;
+
+/// The inscription external plugin stores media such as an image, SVG or JSON document directly
+/// in the asset account.  The data starts empty and is written in chunks with the
+/// `WriteInscriptionV1` instruction, across as many transactions as needed, until the
+/// inscription authority seals it.  Sealing sets the authority to `None`, after which the data is
+/// immutable.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct InscriptionInitInfo {
+    /// The authority who can write the data until it is sealed.
+    pub inscription_authority: Authority, // Variable
+    /// The format of the data, for clients to decode it.
+    pub schema: ExternalPluginSchema, // 1
+}
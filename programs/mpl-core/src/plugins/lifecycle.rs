@@ -1,10 +1,10 @@
-use std::collections::BTreeMap;
-
+use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use strum::EnumCount;
 
 use crate::{
     error::MplCoreError,
-    state::{Authority, Key},
+    state::{Authority, Key, UpdateAuthority},
 };
 
 use super::{Plugin, PluginType, RegistryRecord};
@@ -84,6 +84,22 @@ impl PluginType {
         match plugin_type {
             PluginType::Royalties => CheckResult::CanReject,
             PluginType::UpdateDelegate => CheckResult::CanApprove,
+            PluginType::Groupings => CheckResult::CanReject,
+            PluginType::CollectionJoinRequest => CheckResult::CanReject,
+            PluginType::Autograph => CheckResult::CanReject,
+            PluginType::VerifiedCreators => CheckResult::CanReject,
+            PluginType::Staking => CheckResult::CanReject,
+            PluginType::UpdateApproval => CheckResult::CanReject,
+            PluginType::Reaper => CheckResult::CanReject,
+            PluginType::UsageLimit => CheckResult::CanReject,
+            PluginType::Multisig => CheckResult::CanReject,
+            PluginType::PhysicalClaim => CheckResult::CanReject,
+            PluginType::Ticket => CheckResult::CanReject,
+            PluginType::Progression => CheckResult::CanReject,
+            PluginType::Score => CheckResult::CanReject,
+            PluginType::MintLimit => CheckResult::CanReject,
+            PluginType::Provenance => CheckResult::CanReject,
+            PluginType::Paused => CheckResult::CanReject,
             _ => CheckResult::None,
         }
     }
@@ -105,10 +121,25 @@ impl PluginType {
             PluginType::BurnDelegate => CheckResult::CanApprove,
             PluginType::PermanentFreezeDelegate => CheckResult::CanReject,
             PluginType::PermanentBurnDelegate => CheckResult::CanApprove,
+            PluginType::Paused => CheckResult::CanReject,
+            PluginType::Staking => CheckResult::CanReject,
+            PluginType::Rental => CheckResult::CanReject,
+            PluginType::TimeLock => CheckResult::CanReject,
+            PluginType::Expiration => CheckResult::CanApprove,
+            PluginType::Fractionalized => CheckResult::CanReject,
             _ => CheckResult::None,
         }
     }
 
+    /// Check if a plugin is permitted to approve or deny a redeem action, which is a burn by
+    /// the owner that the Redemption plugin can force approve.
+    pub fn check_redeem(plugin_type: &PluginType) -> CheckResult {
+        match plugin_type {
+            PluginType::Redemption => CheckResult::CanApprove,
+            _ => Self::check_burn(plugin_type),
+        }
+    }
+
     /// Check if a plugin is permitted to approve or deny a transfer action.
     pub fn check_transfer(plugin_type: &PluginType) -> CheckResult {
         match plugin_type {
@@ -117,6 +148,17 @@ impl PluginType {
             PluginType::TransferDelegate => CheckResult::CanApprove,
             PluginType::PermanentFreezeDelegate => CheckResult::CanReject,
             PluginType::PermanentTransferDelegate => CheckResult::CanApprove,
+            PluginType::Paused => CheckResult::CanReject,
+            PluginType::BoundUntil => CheckResult::CanReject,
+            PluginType::Staking => CheckResult::CanReject,
+            PluginType::Rental => CheckResult::CanReject,
+            PluginType::TimeLock => CheckResult::CanReject,
+            PluginType::OwnerAllowList => CheckResult::CanReject,
+            PluginType::PhysicalClaim => CheckResult::CanReject,
+            PluginType::Listing => CheckResult::CanApprove,
+            PluginType::Ticket => CheckResult::CanReject,
+            PluginType::Fractionalized => CheckResult::CanReject,
+            PluginType::TransferApproval => CheckResult::CanApprove,
             _ => CheckResult::None,
         }
     }
@@ -136,6 +178,15 @@ impl PluginType {
             _ => CheckResult::None,
         }
     }
+
+    /// Check if a plugin is permitted to approve or deny an execute action.
+    pub fn check_execute(plugin_type: &PluginType) -> CheckResult {
+        match plugin_type {
+            PluginType::FreezeDelegate => CheckResult::CanReject,
+            PluginType::PermanentFreezeDelegate => CheckResult::CanReject,
+            _ => CheckResult::None,
+        }
+    }
 }
 
 impl Plugin {
@@ -144,29 +195,7 @@ impl Plugin {
         plugin: &Plugin,
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
-        match plugin {
-            Plugin::Royalties(royalties) => royalties.validate_add_plugin(ctx),
-            Plugin::FreezeDelegate(freeze) => freeze.validate_add_plugin(ctx),
-            Plugin::BurnDelegate(burn) => burn.validate_add_plugin(ctx),
-            Plugin::TransferDelegate(transfer) => transfer.validate_add_plugin(ctx),
-            Plugin::UpdateDelegate(update_delegate) => update_delegate.validate_add_plugin(ctx),
-            Plugin::PermanentFreezeDelegate(permanent_freeze) => {
-                permanent_freeze.validate_add_plugin(ctx)
-            }
-            Plugin::Attributes(attributes) => attributes.validate_add_plugin(ctx),
-            Plugin::PermanentTransferDelegate(permanent_transfer) => {
-                permanent_transfer.validate_add_plugin(ctx)
-            }
-            Plugin::PermanentBurnDelegate(permanent_burn) => {
-                permanent_burn.validate_add_plugin(ctx)
-            }
-            Plugin::Edition(edition) => edition.validate_add_plugin(ctx),
-            Plugin::MasterEdition(master_edition) => master_edition.validate_add_plugin(ctx),
-            Plugin::AddBlocker(add_blocker) => add_blocker.validate_add_plugin(ctx),
-            Plugin::ImmutableMetadata(immutable_metadata) => {
-                immutable_metadata.validate_add_plugin(ctx)
-            }
-        }
+        with_plugin!(plugin, |inner| inner.validate_add_plugin(ctx))
     }
 
     /// Validate the remove plugin lifecycle event.
@@ -182,29 +211,7 @@ impl Plugin {
             return Ok(ValidationResult::Rejected);
         }
 
-        match plugin {
-            Plugin::Royalties(royalties) => royalties.validate_remove_plugin(ctx),
-            Plugin::FreezeDelegate(freeze) => freeze.validate_remove_plugin(ctx),
-            Plugin::BurnDelegate(burn) => burn.validate_remove_plugin(ctx),
-            Plugin::TransferDelegate(transfer) => transfer.validate_remove_plugin(ctx),
-            Plugin::UpdateDelegate(update_delegate) => update_delegate.validate_remove_plugin(ctx),
-            Plugin::PermanentFreezeDelegate(permanent_freeze) => {
-                permanent_freeze.validate_remove_plugin(ctx)
-            }
-            Plugin::Attributes(attributes) => attributes.validate_remove_plugin(ctx),
-            Plugin::PermanentTransferDelegate(permanent_transfer) => {
-                permanent_transfer.validate_remove_plugin(ctx)
-            }
-            Plugin::PermanentBurnDelegate(permanent_burn) => {
-                permanent_burn.validate_remove_plugin(ctx)
-            }
-            Plugin::Edition(edition) => edition.validate_remove_plugin(ctx),
-            Plugin::MasterEdition(master_edition) => master_edition.validate_remove_plugin(ctx),
-            Plugin::AddBlocker(add_blocker) => add_blocker.validate_remove_plugin(ctx),
-            Plugin::ImmutableMetadata(immutable_metadata) => {
-                immutable_metadata.validate_remove_plugin(ctx)
-            }
-        }
+        with_plugin!(plugin, |inner| inner.validate_remove_plugin(ctx))
     }
 
     /// Validate the approve plugin authority lifecycle event.
@@ -222,33 +229,7 @@ impl Plugin {
             return Err(MplCoreError::InvalidPlugin.into());
         }
 
-        match plugin {
-            Plugin::Royalties(royalties) => royalties.validate_approve_plugin_authority(ctx),
-            Plugin::FreezeDelegate(freeze) => freeze.validate_approve_plugin_authority(ctx),
-            Plugin::BurnDelegate(burn) => burn.validate_approve_plugin_authority(ctx),
-            Plugin::TransferDelegate(transfer) => transfer.validate_approve_plugin_authority(ctx),
-            Plugin::UpdateDelegate(update_delegate) => {
-                update_delegate.validate_approve_plugin_authority(ctx)
-            }
-            Plugin::PermanentFreezeDelegate(permanent_freeze) => {
-                permanent_freeze.validate_approve_plugin_authority(ctx)
-            }
-            Plugin::Attributes(attributes) => attributes.validate_approve_plugin_authority(ctx),
-            Plugin::PermanentTransferDelegate(permanent_transfer) => {
-                permanent_transfer.validate_approve_plugin_authority(ctx)
-            }
-            Plugin::PermanentBurnDelegate(permanent_burn) => {
-                permanent_burn.validate_approve_plugin_authority(ctx)
-            }
-            Plugin::Edition(edition) => edition.validate_approve_plugin_authority(ctx),
-            Plugin::MasterEdition(master_edition) => {
-                master_edition.validate_approve_plugin_authority(ctx)
-            }
-            Plugin::AddBlocker(add_blocker) => add_blocker.validate_approve_plugin_authority(ctx),
-            Plugin::ImmutableMetadata(immutable_metadata) => {
-                immutable_metadata.validate_approve_plugin_authority(ctx)
-            }
-        }
+        with_plugin!(plugin, |inner| inner.validate_approve_plugin_authority(ctx))
     }
 
     /// Validate the revoke plugin authority lifecycle event.
@@ -265,33 +246,7 @@ impl Plugin {
             return Ok(ValidationResult::Rejected);
         }
 
-        match plugin {
-            Plugin::Royalties(royalties) => royalties.validate_revoke_plugin_authority(ctx),
-            Plugin::FreezeDelegate(freeze) => freeze.validate_revoke_plugin_authority(ctx),
-            Plugin::BurnDelegate(burn) => burn.validate_revoke_plugin_authority(ctx),
-            Plugin::TransferDelegate(transfer) => transfer.validate_revoke_plugin_authority(ctx),
-            Plugin::UpdateDelegate(update_delegate) => {
-                update_delegate.validate_revoke_plugin_authority(ctx)
-            }
-            Plugin::PermanentFreezeDelegate(permanent_freeze) => {
-                permanent_freeze.validate_revoke_plugin_authority(ctx)
-            }
-            Plugin::Attributes(attributes) => attributes.validate_revoke_plugin_authority(ctx),
-            Plugin::PermanentTransferDelegate(permanent_transfer) => {
-                permanent_transfer.validate_revoke_plugin_authority(ctx)
-            }
-            Plugin::PermanentBurnDelegate(permanent_burn) => {
-                permanent_burn.validate_revoke_plugin_authority(ctx)
-            }
-            Plugin::Edition(edition) => edition.validate_revoke_plugin_authority(ctx),
-            Plugin::MasterEdition(master_edition) => {
-                master_edition.validate_revoke_plugin_authority(ctx)
-            }
-            Plugin::AddBlocker(add_blocker) => add_blocker.validate_revoke_plugin_authority(ctx),
-            Plugin::ImmutableMetadata(immutable_metadata) => {
-                immutable_metadata.validate_revoke_plugin_authority(ctx)
-            }
-        }
+        with_plugin!(plugin, |inner| inner.validate_revoke_plugin_authority(ctx))
     }
 
     /// Route the validation of the create action to the appropriate plugin.
@@ -299,27 +254,7 @@ impl Plugin {
         plugin: &Plugin,
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
-        match plugin {
-            Plugin::Royalties(royalties) => royalties.validate_create(ctx),
-            Plugin::FreezeDelegate(freeze) => freeze.validate_create(ctx),
-            Plugin::BurnDelegate(burn) => burn.validate_create(ctx),
-            Plugin::TransferDelegate(transfer) => transfer.validate_create(ctx),
-            Plugin::UpdateDelegate(update_delegate) => update_delegate.validate_create(ctx),
-            Plugin::PermanentFreezeDelegate(permanent_freeze) => {
-                permanent_freeze.validate_create(ctx)
-            }
-            Plugin::Attributes(attributes) => attributes.validate_create(ctx),
-            Plugin::PermanentTransferDelegate(permanent_transfer) => {
-                permanent_transfer.validate_create(ctx)
-            }
-            Plugin::PermanentBurnDelegate(permanent_burn) => permanent_burn.validate_create(ctx),
-            Plugin::Edition(edition) => edition.validate_create(ctx),
-            Plugin::MasterEdition(master_edition) => master_edition.validate_create(ctx),
-            Plugin::AddBlocker(add_blocker) => add_blocker.validate_create(ctx),
-            Plugin::ImmutableMetadata(immutable_metadata) => {
-                immutable_metadata.validate_create(ctx)
-            }
-        }
+        with_plugin!(plugin, |inner| inner.validate_create(ctx))
     }
 
     /// Route the validation of the update action to the appropriate plugin.
@@ -327,27 +262,7 @@ impl Plugin {
         plugin: &Plugin,
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
-        match plugin {
-            Plugin::Royalties(royalties) => royalties.validate_update(ctx),
-            Plugin::FreezeDelegate(freeze) => freeze.validate_update(ctx),
-            Plugin::BurnDelegate(burn) => burn.validate_update(ctx),
-            Plugin::TransferDelegate(transfer) => transfer.validate_update(ctx),
-            Plugin::UpdateDelegate(update_delegate) => update_delegate.validate_update(ctx),
-            Plugin::PermanentFreezeDelegate(permanent_freeze) => {
-                permanent_freeze.validate_update(ctx)
-            }
-            Plugin::Attributes(attributes) => attributes.validate_update(ctx),
-            Plugin::PermanentTransferDelegate(permanent_transfer) => {
-                permanent_transfer.validate_update(ctx)
-            }
-            Plugin::PermanentBurnDelegate(permanent_burn) => permanent_burn.validate_update(ctx),
-            Plugin::Edition(edition) => edition.validate_update(ctx),
-            Plugin::MasterEdition(master_edition) => master_edition.validate_update(ctx),
-            Plugin::AddBlocker(add_blocker) => add_blocker.validate_update(ctx),
-            Plugin::ImmutableMetadata(immutable_metadata) => {
-                immutable_metadata.validate_update(ctx)
-            }
-        }
+        with_plugin!(plugin, |inner| inner.validate_update(ctx))
     }
 
     /// Route the validation of the update_plugin action to the appropriate plugin.
@@ -359,36 +274,19 @@ impl Plugin {
         let resolved_authorities = ctx
             .resolved_authorities
             .ok_or(MplCoreError::InvalidAuthority)?;
-        let base_result = if resolved_authorities.contains(ctx.self_authority) {
-            solana_program::msg!("Base: Approved");
+        // Only the authority of the plugin being updated may approve the change, otherwise the
+        // holder of any plugin authority (e.g. a freeze delegate) could update every plugin.
+        let base_result = if resolved_authorities.contains(ctx.self_authority)
+            && ctx.target_plugin.is_some()
+            && PluginType::from(ctx.target_plugin.unwrap()) == PluginType::from(plugin)
+        {
+            debug_msg!("Base: Approved");
             ValidationResult::Approved
         } else {
             ValidationResult::Pass
         };
 
-        let result = match plugin {
-            Plugin::Royalties(royalties) => royalties.validate_update_plugin(ctx),
-            Plugin::FreezeDelegate(freeze) => freeze.validate_update_plugin(ctx),
-            Plugin::BurnDelegate(burn) => burn.validate_update_plugin(ctx),
-            Plugin::TransferDelegate(transfer) => transfer.validate_update_plugin(ctx),
-            Plugin::UpdateDelegate(update_delegate) => update_delegate.validate_update_plugin(ctx),
-            Plugin::PermanentFreezeDelegate(permanent_freeze) => {
-                permanent_freeze.validate_update_plugin(ctx)
-            }
-            Plugin::Attributes(attributes) => attributes.validate_update_plugin(ctx),
-            Plugin::PermanentTransferDelegate(permanent_transfer) => {
-                permanent_transfer.validate_update_plugin(ctx)
-            }
-            Plugin::PermanentBurnDelegate(permanent_burn) => {
-                permanent_burn.validate_update_plugin(ctx)
-            }
-            Plugin::Edition(edition) => edition.validate_update_plugin(ctx),
-            Plugin::MasterEdition(master_edition) => master_edition.validate_update_plugin(ctx),
-            Plugin::AddBlocker(add_blocker) => add_blocker.validate_update_plugin(ctx),
-            Plugin::ImmutableMetadata(immutable_metadata) => {
-                immutable_metadata.validate_update_plugin(ctx)
-            }
-        }?;
+        let result = with_plugin!(plugin, |inner| inner.validate_update_plugin(ctx))?;
 
         match (&base_result, &result) {
             (ValidationResult::Approved, ValidationResult::Approved) => {
@@ -414,25 +312,18 @@ impl Plugin {
     pub(crate) fn validate_burn(
         plugin: &Plugin,
         ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        with_plugin!(plugin, |inner| inner.validate_burn(ctx))
+    }
+
+    /// Route the validation of the redeem action to the appropriate plugin.
+    pub(crate) fn validate_redeem(
+        plugin: &Plugin,
+        ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
         match plugin {
-            Plugin::Royalties(royalties) => royalties.validate_burn(ctx),
-            Plugin::FreezeDelegate(freeze) => freeze.validate_burn(ctx),
-            Plugin::BurnDelegate(burn) => burn.validate_burn(ctx),
-            Plugin::TransferDelegate(transfer) => transfer.validate_burn(ctx),
-            Plugin::UpdateDelegate(update_delegate) => update_delegate.validate_burn(ctx),
-            Plugin::PermanentFreezeDelegate(permanent_freeze) => {
-                permanent_freeze.validate_burn(ctx)
-            }
-            Plugin::Attributes(attributes) => attributes.validate_burn(ctx),
-            Plugin::PermanentTransferDelegate(permanent_transfer) => {
-                permanent_transfer.validate_burn(ctx)
-            }
-            Plugin::PermanentBurnDelegate(permanent_burn) => permanent_burn.validate_burn(ctx),
-            Plugin::Edition(edition) => edition.validate_burn(ctx),
-            Plugin::MasterEdition(master_edition) => master_edition.validate_burn(ctx),
-            Plugin::AddBlocker(add_blocker) => add_blocker.validate_burn(ctx),
-            Plugin::ImmutableMetadata(immutable_metadata) => immutable_metadata.validate_burn(ctx),
+            Plugin::Redemption(redemption) => redemption.validate_redeem(ctx),
+            _ => Self::validate_burn(plugin, ctx),
         }
     }
 
@@ -441,27 +332,7 @@ impl Plugin {
         plugin: &Plugin,
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
-        match plugin {
-            Plugin::Royalties(royalties) => royalties.validate_transfer(ctx),
-            Plugin::FreezeDelegate(freeze) => freeze.validate_transfer(ctx),
-            Plugin::BurnDelegate(burn) => burn.validate_transfer(ctx),
-            Plugin::TransferDelegate(transfer) => transfer.validate_transfer(ctx),
-            Plugin::UpdateDelegate(update_delegate) => update_delegate.validate_transfer(ctx),
-            Plugin::PermanentFreezeDelegate(permanent_freeze) => {
-                permanent_freeze.validate_transfer(ctx)
-            }
-            Plugin::PermanentTransferDelegate(permanent_transfer) => {
-                permanent_transfer.validate_transfer(ctx)
-            }
-            Plugin::Attributes(attributes_transfer) => attributes_transfer.validate_transfer(ctx),
-            Plugin::PermanentBurnDelegate(burn_transfer) => burn_transfer.validate_transfer(ctx),
-            Plugin::Edition(edition) => edition.validate_transfer(ctx),
-            Plugin::MasterEdition(master_edition) => master_edition.validate_transfer(ctx),
-            Plugin::AddBlocker(add_blocker) => add_blocker.validate_transfer(ctx),
-            Plugin::ImmutableMetadata(immutable_metadata) => {
-                immutable_metadata.validate_transfer(ctx)
-            }
-        }
+        with_plugin!(plugin, |inner| inner.validate_transfer(ctx))
     }
 
     /// Route the validation of the compress action to the appropriate plugin.
@@ -469,27 +340,7 @@ impl Plugin {
         plugin: &Plugin,
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
-        match plugin {
-            Plugin::Royalties(royalties) => royalties.validate_compress(ctx),
-            Plugin::FreezeDelegate(freeze) => freeze.validate_compress(ctx),
-            Plugin::BurnDelegate(burn) => burn.validate_compress(ctx),
-            Plugin::TransferDelegate(transfer) => transfer.validate_compress(ctx),
-            Plugin::UpdateDelegate(update_delegate) => update_delegate.validate_compress(ctx),
-            Plugin::PermanentFreezeDelegate(permanent_freeze) => {
-                permanent_freeze.validate_compress(ctx)
-            }
-            Plugin::Attributes(attributes) => attributes.validate_compress(ctx),
-            Plugin::PermanentTransferDelegate(permanent_transfer) => {
-                permanent_transfer.validate_compress(ctx)
-            }
-            Plugin::PermanentBurnDelegate(burn_transfer) => burn_transfer.validate_compress(ctx),
-            Plugin::Edition(edition) => edition.validate_compress(ctx),
-            Plugin::MasterEdition(master_edition) => master_edition.validate_compress(ctx),
-            Plugin::AddBlocker(add_blocker) => add_blocker.validate_compress(ctx),
-            Plugin::ImmutableMetadata(immutable_metadata) => {
-                immutable_metadata.validate_compress(ctx)
-            }
-        }
+        with_plugin!(plugin, |inner| inner.validate_compress(ctx))
     }
 
     /// Route the validation of the decompress action to the appropriate plugin.
@@ -497,35 +348,21 @@ impl Plugin {
         plugin: &Plugin,
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
-        match plugin {
-            Plugin::Royalties(royalties) => royalties.validate_decompress(ctx),
-            Plugin::FreezeDelegate(freeze) => freeze.validate_decompress(ctx),
-            Plugin::BurnDelegate(burn) => burn.validate_decompress(ctx),
-            Plugin::TransferDelegate(transfer) => transfer.validate_decompress(ctx),
-            Plugin::UpdateDelegate(update_delegate) => update_delegate.validate_decompress(ctx),
-            Plugin::PermanentFreezeDelegate(permanent_freeze) => {
-                permanent_freeze.validate_decompress(ctx)
-            }
-            Plugin::Attributes(attributes) => attributes.validate_decompress(ctx),
-            Plugin::PermanentTransferDelegate(permanent_transfer) => {
-                permanent_transfer.validate_decompress(ctx)
-            }
-            Plugin::PermanentBurnDelegate(permanent_burn) => {
-                permanent_burn.validate_decompress(ctx)
-            }
-            Plugin::Edition(edition) => edition.validate_decompress(ctx),
-            Plugin::MasterEdition(master_edition) => master_edition.validate_decompress(ctx),
-            Plugin::AddBlocker(add_blocker) => add_blocker.validate_decompress(ctx),
-            Plugin::ImmutableMetadata(immutable_metadata) => {
-                immutable_metadata.validate_decompress(ctx)
-            }
-        }
+        with_plugin!(plugin, |inner| inner.validate_decompress(ctx))
+    }
+
+    /// Route the validation of the execute action to the appropriate plugin.
+    pub(crate) fn validate_execute(
+        plugin: &Plugin,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        with_plugin!(plugin, |inner| inner.validate_execute(ctx))
     }
 }
 
 /// Lifecycle validations
 /// Plugins utilize this to indicate whether they approve or reject a lifecycle action.
-#[derive(Eq, PartialEq, Debug)]
+#[derive(BorshSerialize, BorshDeserialize, Eq, PartialEq, Debug)]
 pub enum ValidationResult {
     /// The plugin approves the lifecycle action.
     Approved,
@@ -537,6 +374,17 @@ pub enum ValidationResult {
     ForceApproved,
 }
 
+/// The outcome of lifecycle validations, as returned by the `ValidateLifecycleV1` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Eq, PartialEq, Debug)]
+pub struct LifecycleValidation {
+    /// The combined result of the validations.
+    pub result: ValidationResult,
+    /// The plugin that decided the result, or `None` if it was decided by the asset, the
+    /// collection or a lifecycle hook.
+    pub deciding_plugin: Option<PluginType>,
+}
+
 /// The required context for a plugin validation.
 #[allow(dead_code)]
 pub(crate) struct PluginValidationContext<'a, 'b> {
@@ -550,6 +398,20 @@ pub(crate) struct PluginValidationContext<'a, 'b> {
     pub new_owner: Option<&'a AccountInfo<'a>>,
     /// The new plugin.
     pub target_plugin: Option<&'b Plugin>,
+    /// The fields changed by an update.
+    pub update_info: Option<&'b UpdateInfo<'b>>,
+}
+
+/// The fields changed by an update, each of which is `None` when it is left unchanged.
+#[allow(dead_code)]
+#[derive(Debug, Default)]
+pub(crate) struct UpdateInfo<'b> {
+    /// The new name.
+    pub new_name: Option<&'b str>,
+    /// The new uri.
+    pub new_uri: Option<&'b str>,
+    /// The new update authority.
+    pub new_update_authority: Option<&'b UpdateAuthority>,
 }
 
 /// Plugin validation trait which is implemented by each plugin.
@@ -642,6 +504,14 @@ pub(crate) trait PluginValidation {
         Ok(ValidationResult::Pass)
     }
 
+    /// Validate the execute lifecycle action.
+    fn validate_execute(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        Ok(ValidationResult::Pass)
+    }
+
     /// Validate the add_authority lifecycle action.
     fn validate_add_authority(
         &self,
@@ -659,23 +529,57 @@ pub(crate) trait PluginValidation {
     }
 }
 
+/// The lifecycle checks of the plugins of an asset and its collection, indexed by plugin type.
+/// The checks borrow their registry records, which keeps them small enough for the stack.
+pub(crate) struct PluginChecks<'r>(
+    [Option<(Key, CheckResult, &'r RegistryRecord)>; PluginType::COUNT],
+);
+
+impl<'r> PluginChecks<'r> {
+    /// Create an empty set of checks.
+    pub(crate) fn new() -> Self {
+        Self([None; PluginType::COUNT])
+    }
+
+    /// Set the check of a plugin type, replacing any previous check of the same type.
+    pub(crate) fn insert(
+        &mut self,
+        plugin_type: PluginType,
+        check: (Key, CheckResult, &'r RegistryRecord),
+    ) {
+        self.0[plugin_type as usize] = Some(check);
+    }
+
+    /// Clear the check of a plugin type.
+    pub(crate) fn remove(&mut self, plugin_type: PluginType) {
+        self.0[plugin_type as usize] = None;
+    }
+
+    /// The checks, in plugin type order.
+    pub(crate) fn values(&self) -> impl Iterator<Item = &(Key, CheckResult, &'r RegistryRecord)> {
+        self.0.iter().flatten()
+    }
+}
+
 /// This function iterates through all plugin checks passed in and performs the validation
 /// by deserializing and calling validate on the plugin.
-/// The STRONGEST result is returned.
+/// The STRONGEST result is returned, along with the plugin that decided it: the first one to
+/// force approve, reject or approve, in that order of precedence.
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
-pub(crate) fn validate_plugin_checks<'a>(
+pub(crate) fn evaluate_plugin_checks<'a>(
     key: Key,
-    checks: &BTreeMap<PluginType, (Key, CheckResult, RegistryRecord)>,
+    checks: &PluginChecks,
     authority: &'a AccountInfo<'a>,
     new_owner: Option<&'a AccountInfo<'a>>,
     new_plugin: Option<&Plugin>,
+    update_info: Option<&UpdateInfo>,
     asset: Option<&AccountInfo<'a>>,
     collection: Option<&AccountInfo<'a>>,
     resolved_authorities: &[Authority],
     validate_fp: fn(&Plugin, &PluginValidationContext) -> Result<ValidationResult, ProgramError>,
-) -> Result<ValidationResult, ProgramError> {
-    let mut approved = false;
-    let mut rejected = false;
+) -> Result<LifecycleValidation, ProgramError> {
+    let mut approved = None;
+    let mut rejected = None;
     for (check_key, check_result, registry_record) in checks.values() {
         if *check_key == key
             && matches!(
@@ -689,29 +593,46 @@ pub(crate) fn validate_plugin_checks<'a>(
                 _ => unreachable!(),
             };
 
+            // Expired authorities fall back to the plugin's manager.
+            let self_authority = registry_record
+                .authority
+                .active(registry_record.plugin_type.manager())?;
             let ctx = PluginValidationContext {
-                self_authority: &registry_record.authority,
+                self_authority: &self_authority,
                 authority_info: authority,
                 resolved_authorities: Some(resolved_authorities),
                 new_owner,
                 target_plugin: new_plugin,
+                update_info,
             };
 
             let result = validate_fp(&Plugin::load(account, registry_record.offset)?, &ctx)?;
             match result {
-                ValidationResult::Rejected => rejected = true,
-                ValidationResult::Approved => approved = true,
+                ValidationResult::Rejected => {
+                    rejected.get_or_insert(registry_record.plugin_type);
+                }
+                ValidationResult::Approved => {
+                    approved.get_or_insert(registry_record.plugin_type);
+                }
                 ValidationResult::Pass => continue,
-                ValidationResult::ForceApproved => return Ok(ValidationResult::ForceApproved),
+                ValidationResult::ForceApproved => {
+                    return Ok(LifecycleValidation {
+                        result: ValidationResult::ForceApproved,
+                        deciding_plugin: Some(registry_record.plugin_type),
+                    })
+                }
             }
         }
     }
 
-    if rejected {
-        Ok(ValidationResult::Rejected)
-    } else if approved {
-        Ok(ValidationResult::Approved)
-    } else {
-        Ok(ValidationResult::Pass)
-    }
+    let (result, deciding_plugin) = match (rejected, approved) {
+        (Some(plugin_type), _) => (ValidationResult::Rejected, Some(plugin_type)),
+        (None, Some(plugin_type)) => (ValidationResult::Approved, Some(plugin_type)),
+        (None, None) => (ValidationResult::Pass, None),
+    };
+
+    Ok(LifecycleValidation {
+        result,
+        deciding_plugin,
+    })
 }
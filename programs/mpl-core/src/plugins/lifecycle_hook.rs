@@ -0,0 +1,87 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction},
+    program::{get_return_data, invoke},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use super::ValidationResult;
+
+/// A lifecycle event that can be hooked by a third party program.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub enum HookableLifecycleEvent {
+    /// The asset is transferred.
+    Transfer,
+    /// The asset is burned.
+    Burn,
+    /// The asset is updated.
+    Update,
+}
+
+/// The arguments the hooked program is invoked with.  The asset and, if any, its collection are
+/// passed as read-only accounts.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct LifecycleHookArgs {
+    /// The lifecycle event being validated.
+    pub event: HookableLifecycleEvent,
+    /// The authority performing the lifecycle event.
+    pub authority: Pubkey,
+    /// The new owner of the asset, for transfers.
+    pub new_owner: Option<Pubkey>,
+}
+
+/// The lifecycle hook external plugin invokes a third party program during the selected
+/// lifecycle events of an asset, which can approve or reject the event by setting a
+/// `ValidationResult` as its return data.  A hooked program cannot force approve an event.
+/// The hooked program must be passed in the remaining accounts.
+/// The authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct LifecycleHook {
+    /// The program invoked during the lifecycle events.
+    pub hooked_program: Pubkey, // 32
+    /// The lifecycle events the program is invoked for.
+    pub events: Vec<HookableLifecycleEvent>, // 4
+}
+
+impl LifecycleHook {
+    /// Invoke the hooked program for a lifecycle event and return its validation result.
+    pub(crate) fn invoke<'a>(
+        &self,
+        hooked_program_info: &AccountInfo<'a>,
+        asset_info: &AccountInfo<'a>,
+        collection_info: Option<&AccountInfo<'a>>,
+        args: &LifecycleHookArgs,
+    ) -> Result<ValidationResult, ProgramError> {
+        let mut accounts = vec![AccountMeta::new_readonly(*asset_info.key, false)];
+        let mut account_infos = vec![asset_info.clone()];
+        if let Some(collection_info) = collection_info {
+            accounts.push(AccountMeta::new_readonly(*collection_info.key, false));
+            account_infos.push(collection_info.clone());
+        }
+        account_infos.push(hooked_program_info.clone());
+
+        invoke(
+            &Instruction {
+                program_id: self.hooked_program,
+                accounts,
+                data: args.try_to_vec()?,
+            },
+            &account_infos,
+        )?;
+
+        match get_return_data() {
+            Some((program_id, data)) if program_id == self.hooked_program => {
+                match ValidationResult::try_from_slice(&data)? {
+                    ValidationResult::ForceApproved => Ok(ValidationResult::Approved),
+                    result => Ok(result),
+                }
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+}
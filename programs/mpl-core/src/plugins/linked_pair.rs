@@ -0,0 +1,68 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, sysvar::instructions::load_instruction_at_checked,
+};
+
+use crate::{error::MplCoreError, instruction::MplAssetInstruction, state::DataBlob};
+
+use super::PluginValidation;
+
+/// The linked pair plugin binds an asset to a partner asset, such as a key and its lock, so
+/// that it can only be transferred in a transaction that also transfers the partner to the same
+/// new owner.  The instructions sysvar must be passed in the remaining accounts.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct LinkedPair {
+    /// The address of the partner asset.
+    pub partner: Pubkey, // 32
+}
+
+impl LinkedPair {
+    /// Check that the transaction also transfers the partner asset to the new owner.
+    pub fn check_transaction(
+        &self,
+        instructions_sysvar: &AccountInfo,
+        new_owner: &Pubkey,
+    ) -> ProgramResult {
+        let mut index = 0;
+        loop {
+            let instruction = match load_instruction_at_checked(index, instructions_sysvar) {
+                Ok(instruction) => instruction,
+                // Reading past the last instruction of the transaction.
+                Err(ProgramError::InvalidArgument) => break,
+                Err(err) => return Err(err),
+            };
+
+            // The asset is the first account and the new owner the fifth account of a transfer.
+            if instruction.program_id == crate::ID
+                && matches!(
+                    MplAssetInstruction::try_from_slice(&instruction.data),
+                    Ok(MplAssetInstruction::TransferV1(_))
+                )
+                && instruction.accounts.first().map(|meta| meta.pubkey) == Some(self.partner)
+                && instruction.accounts.get(4).map(|meta| meta.pubkey) == Some(*new_owner)
+            {
+                return Ok(());
+            }
+
+            index += 1;
+        }
+
+        msg!("LinkedPair: Rejected");
+        Err(MplCoreError::LinkedPairNotTransferred.into())
+    }
+}
+
+impl DataBlob for LinkedPair {
+    fn get_initial_size() -> usize {
+        32
+    }
+
+    fn get_size(&self) -> usize {
+        32
+    }
+}
+
+impl PluginValidation for LinkedPair {}
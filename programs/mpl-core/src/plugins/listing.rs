@@ -0,0 +1,70 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::state::{Authority, DataBlob};
+
+use super::{PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The seed prefix used by a marketplace program when deriving its listing authority.
+pub const LISTING_AUTHORITY_PREFIX: &[u8] = b"mpl-core-listing";
+
+/// Find the listing authority of a marketplace program, the PDA with which the marketplace
+/// signs the transfers of the assets it sells.
+pub fn find_listing_authority_address(marketplace: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LISTING_AUTHORITY_PREFIX], marketplace)
+}
+
+/// The listing plugin lists an asset for sale on a marketplace without moving it into escrow.
+/// The owner sets the plugin authority to the listing authority of the marketplace, which can
+/// then transfer the asset to a buyer, who pays the price to the owner as part of the transfer.
+/// Like other owner-managed plugins, the listing lapses when the asset is transferred.
+/// The default authority for this plugin is the owner.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct Listing {
+    /// The price of the asset in lamports.
+    pub price: u64, // 8
+    /// The marketplace program authorized to sell the asset.
+    pub marketplace: Pubkey, // 32
+}
+
+impl Listing {
+    /// Check whether the listing authority of the marketplace is the authority of the listing.
+    pub fn is_active(&self, authority: &Authority) -> bool {
+        let (listing_authority, _) = find_listing_authority_address(&self.marketplace);
+        authority
+            == &Authority::Address {
+                address: listing_authority,
+            }
+    }
+}
+
+impl DataBlob for Listing {
+    fn get_initial_size() -> usize {
+        40
+    }
+
+    fn get_size(&self) -> usize {
+        40
+    }
+}
+
+impl PluginValidation for Listing {
+    fn validate_transfer(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        // The payment of the price is made by the transfer itself.
+        if self.is_active(ctx.self_authority)
+            && ctx.self_authority
+                == &(Authority::Address {
+                    address: *ctx.authority_info.key,
+                })
+        {
+            debug_msg!("Listing: Approved");
+            Ok(ValidationResult::Approved)
+        } else {
+            Ok(ValidationResult::Pass)
+        }
+    }
+}
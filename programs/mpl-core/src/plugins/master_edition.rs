@@ -1,5 +1,7 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 
+use crate::state::DataBlob;
+
 use super::PluginValidation;
 
 /// The master edition plugin allows the creator to specify details on the master edition including max supply, name, and uri.
@@ -15,4 +17,18 @@ pub struct MasterEdition {
     pub uri: Option<String>,
 }
 
+impl DataBlob for MasterEdition {
+    fn get_initial_size() -> usize {
+        1 + 1 + 1
+    }
+
+    fn get_size(&self) -> usize {
+        1 + self.max_supply.map_or(0, |_| 4)
+            + 1
+            + self.name.as_ref().map_or(0, |name| 4 + name.len())
+            + 1
+            + self.uri.as_ref().map_or(0, |uri| 4 + uri.len())
+    }
+}
+
 impl PluginValidation for MasterEdition {}
@@ -0,0 +1,80 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{entrypoint::ProgramResult, program_error::ProgramError};
+
+use crate::{error::MplCoreError, state::DataBlob};
+
+use super::{Plugin, PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The mint limit plugin caps the number of assets that can be created into a collection.
+/// Every asset created into the collection after the plugin is added is counted, and creation
+/// is rejected once the maximum supply is reached.
+/// The minted count is maintained by the program and cannot be set by the authority.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct MintLimit {
+    /// The maximum number of assets that can be created into the collection.
+    pub max_supply: u32, // 4
+    /// The number of assets created into the collection.
+    pub minted: u32, // 4
+}
+
+impl MintLimit {
+    /// Count a new mint, failing if the maximum supply has been reached.
+    pub(crate) fn record_mint(&mut self) -> ProgramResult {
+        if self.minted >= self.max_supply {
+            solana_program::msg!("MintLimit: Rejected");
+            return Err(MplCoreError::MintLimitReached.into());
+        }
+        self.minted += 1;
+        Ok(())
+    }
+}
+
+impl DataBlob for MintLimit {
+    fn get_initial_size() -> usize {
+        8
+    }
+
+    fn get_size(&self) -> usize {
+        8
+    }
+}
+
+impl PluginValidation for MintLimit {
+    fn validate_create(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.minted != 0 {
+            return Err(MplCoreError::InvalidPluginSetting.into());
+        }
+        Ok(ValidationResult::Pass)
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::MintLimit(mint_limit)) if mint_limit.minted != 0 => {
+                Err(MplCoreError::InvalidPluginSetting.into())
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::MintLimit(mint_limit))
+                if mint_limit.minted != self.minted || mint_limit.max_supply < self.minted =>
+            {
+                Err(MplCoreError::InvalidPluginSetting.into())
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+}
@@ -1,39 +1,197 @@
+/// Expand a callback macro with the name of every plugin variant.  A new plugin is registered
+/// by adding its variant here, next to the `Plugin` and `PluginType` enums, and implementing
+/// `PluginValidation` (whose lifecycle methods all pass by default) and `DataBlob` for it.  The
+/// lifecycle dispatch, size and type of every plugin are then matched from this list, and a
+/// plugin missing from it fails to compile as a non-exhaustive match.
+macro_rules! plugin_variants {
+    ($callback:ident!($($args:tt)*)) => {
+        $callback!(($($args)*)
+            Royalties
+            FreezeDelegate
+            BurnDelegate
+            TransferDelegate
+            UpdateDelegate
+            PermanentFreezeDelegate
+            Attributes
+            PermanentTransferDelegate
+            PermanentBurnDelegate
+            Edition
+            MasterEdition
+            AddBlocker
+            ImmutableMetadata
+            Groupings
+            CollectionJoinRequest
+            Paused
+            Autograph
+            BoundUntil
+            VerifiedCreators
+            Staking
+            Rental
+            UpdateApproval
+            Reaper
+            TimeLock
+            UsageLimit
+            Expiration
+            TransferFee
+            BurnRentDestination
+            TxGuard
+            OwnerAllowList
+            Multisig
+            LinkedPair
+            Redemption
+            PhysicalClaim
+            Listing
+            Ticket
+            Progression
+            TokenLink
+            Fractionalized
+            Score
+            MintLimit
+            TransferApproval
+            Provenance
+        )
+    };
+}
+
+/// Match a plugin, binding the inner plugin data of whichever variant it is, e.g.
+/// `with_plugin!(plugin, |inner| inner.get_size())`.
+macro_rules! with_plugin {
+    ($($args:tt)*) => {
+        plugin_variants!(match_plugin_variants!($($args)*))
+    };
+}
+
+/// The match expanded by `with_plugin!`.
+macro_rules! match_plugin_variants {
+    (($plugin:expr, |$inner:ident| $body:expr) $($variant:ident)*) => {
+        match $plugin {
+            $($crate::plugins::Plugin::$variant($inner) => $body,)*
+        }
+    };
+}
+
+/// The type of a plugin.
+macro_rules! plugin_type_of {
+    ($plugin:expr) => {
+        plugin_variants!(match_plugin_type!($plugin))
+    };
+}
+
+/// The match expanded by `plugin_type_of!`.
+macro_rules! match_plugin_type {
+    (($plugin:expr) $($variant:ident)*) => {
+        match $plugin {
+            $($crate::plugins::Plugin::$variant(_) => $crate::plugins::PluginType::$variant,)*
+        }
+    };
+}
+
 mod add_blocker;
+mod app_data;
 mod attributes;
+mod autograph;
+mod bound_until;
 mod burn_delegate;
+mod burn_rent_destination;
+mod collection_join_request;
+mod data_section;
 mod edition;
+mod expiration;
+mod fractionalized;
 mod freeze_delegate;
+mod groupings;
 mod immutable_metadata;
+mod inscription;
 mod lifecycle;
+mod lifecycle_hook;
+mod linked_pair;
+mod listing;
 mod master_edition;
+mod mint_limit;
+mod multisig;
+mod owner_allow_list;
+mod paused;
 mod permanent_burn_delegate;
 mod permanent_freeze_delegate;
 mod permanent_transfer_delegate;
+mod physical_claim;
 mod plugin_header;
 mod plugin_registry;
+mod policy_preset;
+mod provenance;
+mod progression;
+mod reaper;
+mod redemption;
+mod rental;
 mod royalties;
+mod score;
+mod staking;
+mod ticket;
+mod time_lock;
+mod token_link;
 mod transfer;
+mod transfer_approval;
+mod transfer_fee;
+mod tx_guard;
+mod update_approval;
 mod update_delegate;
+mod usage_limit;
 mod utils;
+mod verified_creators;
 
 pub use add_blocker::*;
+pub use app_data::*;
 pub use attributes::*;
+pub use autograph::*;
+pub use bound_until::*;
 pub use burn_delegate::*;
+pub use burn_rent_destination::*;
+pub use collection_join_request::*;
+pub use data_section::*;
 pub use edition::*;
+pub use expiration::*;
+pub use fractionalized::*;
 pub use freeze_delegate::*;
+pub use groupings::*;
 pub use immutable_metadata::*;
+pub use inscription::*;
 pub use lifecycle::*;
+pub use lifecycle_hook::*;
+pub use linked_pair::*;
+pub use listing::*;
 pub use master_edition::*;
+pub use mint_limit::*;
+pub use multisig::*;
 use num_derive::ToPrimitive;
+pub use owner_allow_list::*;
+pub use paused::*;
 pub use permanent_burn_delegate::*;
 pub use permanent_freeze_delegate::*;
 pub use permanent_transfer_delegate::*;
+pub use physical_claim::*;
 pub use plugin_header::*;
 pub use plugin_registry::*;
+pub use policy_preset::*;
+pub use provenance::*;
+pub use progression::*;
+pub use reaper::*;
+pub use redemption::*;
+pub use rental::*;
 pub use royalties::*;
+pub use score::*;
+pub use staking::*;
+pub use ticket::*;
+pub use time_lock::*;
+pub use token_link::*;
 pub use transfer::*;
+pub use transfer_approval::*;
+pub use transfer_fee::*;
+pub use tx_guard::*;
+pub use update_approval::*;
 pub use update_delegate::*;
+pub use usage_limit::*;
 pub use utils::*;
+pub use verified_creators::*;
 
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
@@ -77,6 +235,66 @@ pub enum Plugin {
     AddBlocker(AddBlocker),
     /// ImmutableMetadata plugin. Makes metadata of the asset immutable.
     ImmutableMetadata(ImmutableMetadata),
+    /// Groupings plugin allows an asset to be tagged with additional collections.
+    Groupings(Groupings),
+    /// CollectionJoinRequest plugin records a request for the asset to join a collection.
+    CollectionJoinRequest(CollectionJoinRequest),
+    /// Paused plugin. Pauses lifecycle events of the collection's assets.
+    Paused(Paused),
+    /// Autograph plugin. Allows signers to leave signed messages on the asset.
+    Autograph(Autograph),
+    /// Bound until plugin.
+    BoundUntil(BoundUntil),
+    /// Verified creators plugin.
+    VerifiedCreators(VerifiedCreators),
+    /// Staking plugin.
+    Staking(Staking),
+    /// Rental plugin.
+    Rental(Rental),
+    /// Update approval plugin.
+    UpdateApproval(UpdateApproval),
+    /// Reaper plugin.
+    Reaper(Reaper),
+    /// Time lock plugin.
+    TimeLock(TimeLock),
+    /// Usage limit plugin.
+    UsageLimit(UsageLimit),
+    /// Expiration plugin.
+    Expiration(Expiration),
+    /// Transfer fee plugin.
+    TransferFee(TransferFee),
+    /// Burn rent destination plugin.
+    BurnRentDestination(BurnRentDestination),
+    /// Transaction guard plugin.
+    TxGuard(TxGuard),
+    /// Owner allow list plugin.
+    OwnerAllowList(OwnerAllowList),
+    /// Multisig plugin.
+    Multisig(Multisig),
+    /// Linked pair plugin.
+    LinkedPair(LinkedPair),
+    /// Redemption plugin.
+    Redemption(Redemption),
+    /// Physical claim plugin.
+    PhysicalClaim(PhysicalClaim),
+    /// Listing plugin.
+    Listing(Listing),
+    /// Ticket plugin.
+    Ticket(Ticket),
+    /// Progression plugin.
+    Progression(Progression),
+    /// Token link plugin.
+    TokenLink(TokenLink),
+    /// Fractionalized plugin.
+    Fractionalized(Fractionalized),
+    /// Score plugin.
+    Score(Score),
+    /// Mint limit plugin.
+    MintLimit(MintLimit),
+    /// Transfer approval plugin.
+    TransferApproval(TransferApproval),
+    /// Provenance plugin.
+    Provenance(Provenance),
 }
 
 impl Plugin {
@@ -105,6 +323,18 @@ impl Plugin {
 
 impl Compressible for Plugin {}
 
+impl DataBlob for Plugin {
+    fn get_initial_size() -> usize {
+        1
+    }
+
+    /// The exact serialized size of the plugin, so that it can be measured without serializing
+    /// it.
+    fn get_size(&self) -> usize {
+        1 + with_plugin!(self, |inner| inner.get_size())
+    }
+}
+
 /// List of First Party Plugin types.
 #[repr(C)]
 #[derive(
@@ -147,35 +377,81 @@ pub enum PluginType {
     AddBlocker,
     /// ImmutableMetadata plugin.
     ImmutableMetadata,
+    /// The Groupings plugin.
+    Groupings,
+    /// The CollectionJoinRequest plugin.
+    CollectionJoinRequest,
+    /// The Paused plugin.
+    Paused,
+    /// The Autograph plugin.
+    Autograph,
+    /// Bound until plugin.
+    BoundUntil,
+    /// Verified creators plugin.
+    VerifiedCreators,
+    /// Staking plugin.
+    Staking,
+    /// Rental plugin.
+    Rental,
+    /// Update approval plugin.
+    UpdateApproval,
+    /// Reaper plugin.
+    Reaper,
+    /// Time lock plugin.
+    TimeLock,
+    /// Usage limit plugin.
+    UsageLimit,
+    /// Expiration plugin.
+    Expiration,
+    /// Transfer fee plugin.
+    TransferFee,
+    /// Burn rent destination plugin.
+    BurnRentDestination,
+    /// Transaction guard plugin.
+    TxGuard,
+    /// Owner allow list plugin.
+    OwnerAllowList,
+    /// Multisig plugin.
+    Multisig,
+    /// Linked pair plugin.
+    LinkedPair,
+    /// Redemption plugin.
+    Redemption,
+    /// Physical claim plugin.
+    PhysicalClaim,
+    /// Listing plugin.
+    Listing,
+    /// Ticket plugin.
+    Ticket,
+    /// Progression plugin.
+    Progression,
+    /// Token link plugin.
+    TokenLink,
+    /// Fractionalized plugin.
+    Fractionalized,
+    /// Score plugin.
+    Score,
+    /// Mint limit plugin.
+    MintLimit,
+    /// Transfer approval plugin.
+    TransferApproval,
+    /// Provenance plugin.
+    Provenance,
 }
 
 impl DataBlob for PluginType {
     fn get_initial_size() -> usize {
-        2
+        1
     }
 
     fn get_size(&self) -> usize {
-        2
+        1
     }
 }
 
 impl From<&Plugin> for PluginType {
     fn from(plugin: &Plugin) -> Self {
-        match plugin {
-            Plugin::AddBlocker(_) => PluginType::AddBlocker,
-            Plugin::ImmutableMetadata(_) => PluginType::ImmutableMetadata,
-            Plugin::Royalties(_) => PluginType::Royalties,
-            Plugin::FreezeDelegate(_) => PluginType::FreezeDelegate,
-            Plugin::BurnDelegate(_) => PluginType::BurnDelegate,
-            Plugin::TransferDelegate(_) => PluginType::TransferDelegate,
-            Plugin::UpdateDelegate(_) => PluginType::UpdateDelegate,
-            Plugin::PermanentFreezeDelegate(_) => PluginType::PermanentFreezeDelegate,
-            Plugin::Attributes(_) => PluginType::Attributes,
-            Plugin::PermanentTransferDelegate(_) => PluginType::PermanentTransferDelegate,
-            Plugin::PermanentBurnDelegate(_) => PluginType::PermanentBurnDelegate,
-            Plugin::Edition(_) => PluginType::Edition,
-            Plugin::MasterEdition(_) => PluginType::MasterEdition,
-        }
+        plugin_type_of!(plugin)
     }
 }
 
@@ -185,6 +461,36 @@ impl PluginType {
         match self {
             PluginType::AddBlocker => Authority::UpdateAuthority,
             PluginType::ImmutableMetadata => Authority::UpdateAuthority,
+            PluginType::Groupings => Authority::UpdateAuthority,
+            PluginType::CollectionJoinRequest => Authority::UpdateAuthority,
+            PluginType::Paused => Authority::UpdateAuthority,
+            PluginType::Autograph => Authority::Owner,
+            PluginType::BoundUntil => Authority::UpdateAuthority,
+            PluginType::VerifiedCreators => Authority::UpdateAuthority,
+            PluginType::Staking => Authority::Owner,
+            PluginType::Rental => Authority::Owner,
+            PluginType::UpdateApproval => Authority::UpdateAuthority,
+            PluginType::Reaper => Authority::UpdateAuthority,
+            PluginType::TimeLock => Authority::UpdateAuthority,
+            PluginType::UsageLimit => Authority::UpdateAuthority,
+            PluginType::Expiration => Authority::UpdateAuthority,
+            PluginType::TransferFee => Authority::UpdateAuthority,
+            PluginType::BurnRentDestination => Authority::UpdateAuthority,
+            PluginType::TxGuard => Authority::UpdateAuthority,
+            PluginType::OwnerAllowList => Authority::UpdateAuthority,
+            PluginType::Multisig => Authority::UpdateAuthority,
+            PluginType::LinkedPair => Authority::UpdateAuthority,
+            PluginType::Redemption => Authority::UpdateAuthority,
+            PluginType::PhysicalClaim => Authority::UpdateAuthority,
+            PluginType::Listing => Authority::Owner,
+            PluginType::Ticket => Authority::UpdateAuthority,
+            PluginType::Progression => Authority::UpdateAuthority,
+            PluginType::TokenLink => Authority::UpdateAuthority,
+            PluginType::Fractionalized => Authority::Owner,
+            PluginType::Score => Authority::UpdateAuthority,
+            PluginType::MintLimit => Authority::UpdateAuthority,
+            PluginType::TransferApproval => Authority::Owner,
+            PluginType::Provenance => Authority::UpdateAuthority,
             PluginType::Royalties => Authority::UpdateAuthority,
             PluginType::FreezeDelegate => Authority::Owner,
             PluginType::BurnDelegate => Authority::Owner,
@@ -204,6 +510,9 @@ impl PluginType {
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub(crate) struct PluginAuthorityPair {
+    /// The plugin to create.
     pub(crate) plugin: Plugin,
+    /// The authority the plugin is created with, so that a delegate can be set up at creation
+    /// without a separate approval.  Defaults to the manager of the plugin when `None`.
     pub(crate) authority: Option<Authority>,
 }
@@ -0,0 +1,103 @@
+use std::collections::HashSet;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{error::MplCoreError, state::DataBlob, utils::cmp_pubkeys};
+
+use super::{Plugin, PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The seed prefix used when deriving the address of a multisig.
+pub const MULTISIG_PREFIX: &[u8] = b"multisig";
+
+/// Find the address identifying the multisig of an asset or collection. Plugins whose authority
+/// is this address can be managed by the multisig once enough of its signers have signed.
+pub fn find_multisig_address(core_address: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MULTISIG_PREFIX, core_address.as_ref()], &crate::ID)
+}
+
+/// The multisig plugin defines an m-of-n set of signers for an asset or collection, so that the
+/// authority over its plugins does not rest on a single key.  The co-signers are passed as
+/// signers in the remaining accounts.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct Multisig {
+    /// The number of signers required.
+    pub threshold: u8, // 1
+    /// The addresses able to sign for the multisig.
+    pub signers: Vec<Pubkey>, // 4
+}
+
+impl Multisig {
+    /// Check whether enough of the signers of the multisig have signed the transaction.
+    pub fn is_signed(&self, authority_info: &AccountInfo, signers: &[AccountInfo]) -> bool {
+        let signed = self
+            .signers
+            .iter()
+            .filter(|signer| {
+                (authority_info.is_signer && cmp_pubkeys(authority_info.key, signer))
+                    || signers.iter().any(|account_info| {
+                        account_info.is_signer && cmp_pubkeys(account_info.key, signer)
+                    })
+            })
+            .count();
+
+        signed >= self.threshold as usize
+    }
+}
+
+impl DataBlob for Multisig {
+    fn get_initial_size() -> usize {
+        5
+    }
+
+    fn get_size(&self) -> usize {
+        5 + self.signers.len() * 32
+    }
+}
+
+/// Validate the threshold and signers of the plugin.
+fn validate_multisig(multisig: &Multisig) -> Result<ValidationResult, ProgramError> {
+    let mut seen_signers = HashSet::new();
+    if multisig.threshold == 0
+        || multisig.threshold as usize > multisig.signers.len()
+        || !multisig
+            .signers
+            .iter()
+            .all(|signer| seen_signers.insert(*signer))
+    {
+        return Err(MplCoreError::InvalidPluginSetting.into());
+    }
+
+    Ok(ValidationResult::Pass)
+}
+
+impl PluginValidation for Multisig {
+    fn validate_create(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        validate_multisig(self)
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Multisig(multisig)) => validate_multisig(multisig),
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Multisig(multisig)) => validate_multisig(multisig),
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+}
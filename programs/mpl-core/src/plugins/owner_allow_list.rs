@@ -0,0 +1,42 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{error::MplCoreError, state::DataBlob};
+
+use super::{PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The owner allow list plugin restricts who an asset can be transferred to, for example for
+/// regulated assets.  The new owner must either be in the list, or be an account owned by a
+/// program in the list.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct OwnerAllowList {
+    /// The addresses, or programs owning the addresses, allowed to own the asset.
+    pub allowed: Vec<Pubkey>, // 4
+}
+
+impl DataBlob for OwnerAllowList {
+    fn get_initial_size() -> usize {
+        4
+    }
+
+    fn get_size(&self) -> usize {
+        4 + self.allowed.len() * 32
+    }
+}
+
+impl PluginValidation for OwnerAllowList {
+    fn validate_transfer(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        let new_owner = ctx.new_owner.ok_or(MplCoreError::MissingNewOwner)?;
+        if self.allowed.contains(new_owner.key) || self.allowed.contains(new_owner.owner) {
+            Ok(ValidationResult::Pass)
+        } else {
+            solana_program::msg!("OwnerAllowList: Rejected");
+            Ok(ValidationResult::Rejected)
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::program_error::ProgramError;
+
+use crate::state::DataBlob;
+
+use super::{PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The paused plugin is a kill switch for the update authority of a collection.
+/// While paused, member assets cannot be minted, transferred, or burned.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct Paused {
+    /// Whether or not lifecycle events are currently paused.
+    pub paused: bool, // 1
+}
+
+impl Paused {
+    /// Initialize the Paused plugin, unpaused by default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl DataBlob for Paused {
+    fn get_initial_size() -> usize {
+        1
+    }
+
+    fn get_size(&self) -> usize {
+        1
+    }
+}
+
+impl PluginValidation for Paused {
+    fn validate_create(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.paused {
+            solana_program::msg!("Paused: Rejected");
+            Ok(ValidationResult::Rejected)
+        } else {
+            Ok(ValidationResult::Pass)
+        }
+    }
+
+    fn validate_burn(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.paused {
+            solana_program::msg!("Paused: Rejected");
+            Ok(ValidationResult::Rejected)
+        } else {
+            Ok(ValidationResult::Pass)
+        }
+    }
+
+    fn validate_transfer(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.paused {
+            solana_program::msg!("Paused: Rejected");
+            Ok(ValidationResult::Rejected)
+        } else {
+            Ok(ValidationResult::Pass)
+        }
+    }
+}
@@ -65,6 +65,18 @@ impl PluginValidation for PermanentFreezeDelegate {
         }
     }
 
+    fn validate_execute(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.frozen {
+            solana_program::msg!("PermanentFreezeDelegate: Rejected");
+            Ok(ValidationResult::Rejected)
+        } else {
+            Ok(ValidationResult::Pass)
+        }
+    }
+
     fn validate_add_plugin(
         &self,
         ctx: &PluginValidationContext,
@@ -93,7 +105,7 @@ impl PluginValidation for PermanentFreezeDelegate {
             && ctx.target_plugin.is_some()
             && PluginType::from(ctx.target_plugin.unwrap()) == PluginType::PermanentFreezeDelegate
         {
-            solana_program::msg!("PermanentFreezeDelegate: Approved");
+            debug_msg!("PermanentFreezeDelegate: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
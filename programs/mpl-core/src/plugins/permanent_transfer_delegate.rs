@@ -1,23 +1,38 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::program_error::ProgramError;
+use solana_program::{clock::Clock, program_error::ProgramError, sysvar::Sysvar};
 
 use crate::state::DataBlob;
 
-use super::{PluginType, PluginValidation, PluginValidationContext, ValidationResult};
+use super::{Plugin, PluginType, PluginValidation, PluginValidationContext, ValidationResult};
 
 /// The permanent transfer plugin allows any authority to transfer the asset.
+/// The power of the delegate lapses at the optional sunset, which can be brought forward but
+/// never pushed back or removed.
 /// The default authority for this plugin is the update authority.
 #[repr(C)]
 #[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
-pub struct PermanentTransferDelegate {}
+pub struct PermanentTransferDelegate {
+    /// The optional unix timestamp after which the delegate can no longer force the transfer.
+    pub sunset: Option<i64>, // 1 + 8
+}
+
+impl PermanentTransferDelegate {
+    /// Check whether the power of the delegate has lapsed at the current time.
+    pub fn is_sunset(&self) -> Result<bool, ProgramError> {
+        match self.sunset {
+            Some(sunset) => Ok(Clock::get()?.unix_timestamp >= sunset),
+            None => Ok(false),
+        }
+    }
+}
 
 impl DataBlob for PermanentTransferDelegate {
     fn get_initial_size() -> usize {
-        0
+        1
     }
 
     fn get_size(&self) -> usize {
-        0
+        1 + self.sunset.map_or(0, |_| 8)
     }
 }
 
@@ -38,11 +53,30 @@ impl PluginValidation for PermanentTransferDelegate {
         }
     }
 
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if let (Some(Plugin::PermanentTransferDelegate(new)), Some(sunset)) =
+            (ctx.target_plugin, self.sunset)
+        {
+            match new.sunset {
+                Some(new_sunset) if new_sunset <= sunset => (),
+                _ => {
+                    solana_program::msg!("PermanentTransferDelegate: Rejected");
+                    return Ok(ValidationResult::Rejected);
+                }
+            }
+        }
+
+        Ok(ValidationResult::Pass)
+    }
+
     fn validate_revoke_plugin_authority(
         &self,
         _ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
-        solana_program::msg!("PermanentTransferDelegate: Approved");
+        debug_msg!("PermanentTransferDelegate: Approved");
         Ok(ValidationResult::Approved)
     }
 
@@ -51,8 +85,8 @@ impl PluginValidation for PermanentTransferDelegate {
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
         if let Some(resolved_authorities) = ctx.resolved_authorities {
-            if resolved_authorities.contains(ctx.self_authority) {
-                solana_program::msg!("PermanentTransferDelegate: ForceApproved");
+            if resolved_authorities.contains(ctx.self_authority) && !self.is_sunset()? {
+                debug_msg!("PermanentTransferDelegate: ForceApproved");
                 return Ok(ValidationResult::ForceApproved);
             }
         }
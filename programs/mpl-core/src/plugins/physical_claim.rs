@@ -0,0 +1,115 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::program_error::ProgramError;
+
+use crate::{
+    error::MplCoreError,
+    state::{Authority, DataBlob},
+};
+
+use super::{Plugin, PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The status of the claim of the physical item backing an asset.
+#[repr(C)]
+#[derive(
+    Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq, PartialOrd,
+)]
+pub enum ClaimStatus {
+    /// The physical item has not been claimed.
+    #[default]
+    Unclaimed,
+    /// The physical item has been shipped to the owner.
+    Shipped,
+    /// The physical item has been delivered to the owner.
+    Delivered,
+}
+
+/// The physical claim plugin tracks the fulfillment of the physical item backing a phygital
+/// asset.  The status only moves forward and can only be changed by a fulfillment delegate,
+/// i.e. once the plugin authority is set to an address.  Transfers can optionally be frozen
+/// while the item is shipped.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct PhysicalClaim {
+    /// The status of the claim.
+    pub status: ClaimStatus, // 1
+    /// Whether the asset cannot be transferred while the item is shipped.
+    pub freeze_in_flight: bool, // 1
+}
+
+impl PhysicalClaim {
+    // New claims always start unclaimed.
+    fn validate_initial(&self) -> Result<ValidationResult, ProgramError> {
+        if self.status != ClaimStatus::Unclaimed {
+            return Err(MplCoreError::InvalidPluginSetting.into());
+        }
+        Ok(ValidationResult::Pass)
+    }
+}
+
+impl DataBlob for PhysicalClaim {
+    fn get_initial_size() -> usize {
+        2
+    }
+
+    fn get_size(&self) -> usize {
+        2
+    }
+}
+
+impl PluginValidation for PhysicalClaim {
+    fn validate_create(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_initial()
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::PhysicalClaim(physical_claim)) => physical_claim.validate_initial(),
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::PhysicalClaim(physical_claim)) => {
+                // Only the status can change, and only forward.
+                if physical_claim.freeze_in_flight != self.freeze_in_flight
+                    || physical_claim.status < self.status
+                {
+                    return Err(MplCoreError::InvalidPluginSetting.into());
+                }
+
+                if physical_claim.status != self.status
+                    && !matches!(ctx.self_authority, Authority::Address { .. })
+                {
+                    solana_program::msg!("PhysicalClaim: Rejected");
+                    return Ok(ValidationResult::Rejected);
+                }
+
+                Ok(ValidationResult::Pass)
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_transfer(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.freeze_in_flight && self.status == ClaimStatus::Shipped {
+            solana_program::msg!("PhysicalClaim: Rejected");
+            Ok(ValidationResult::Rejected)
+        } else {
+            Ok(ValidationResult::Pass)
+        }
+    }
+}
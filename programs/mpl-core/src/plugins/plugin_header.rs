@@ -5,6 +5,7 @@ use shank::ShankAccount;
 /// The plugin header is the first part of the plugin metadata.
 /// This field stores the Key
 /// And a pointer to the Plugin Registry stored at the end of the account.
+/// Like the asset and the registry records, its layout must not change for existing accounts.
 #[repr(C)]
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
 pub struct PluginHeaderV1 {
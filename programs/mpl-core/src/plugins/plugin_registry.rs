@@ -1,10 +1,20 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankAccount;
-use std::{cmp::Ordering, collections::BTreeMap};
+use solana_program::{
+    account_info::AccountInfo, keccak, msg, program_error::ProgramError, pubkey::Pubkey,
+};
+use std::cmp::Ordering;
 
-use crate::state::{Authority, DataBlob, Key, SolanaAccount};
+use crate::{
+    error::MplCoreError,
+    state::{Authority, DataBlob, Key, SolanaAccount},
+    utils::load_key,
+};
 
-use super::{CheckResult, PluginType};
+use super::{
+    AppDataInitInfo, CheckResult, HookableLifecycleEvent, InscriptionInitInfo, LifecycleHook,
+    PluginChecks, PluginType,
+};
 
 /// The Plugin Registry stores a record of all plugins, their location, and their authorities.
 #[repr(C)]
@@ -20,19 +30,96 @@ pub struct PluginRegistryV1 {
 
 impl PluginRegistryV1 {
     /// Evaluate checks for all plugins in the registry.
-    pub(crate) fn check_registry(
-        &self,
+    pub(crate) fn check_registry<'r>(
+        &'r self,
         key: Key,
         check_fp: fn(&PluginType) -> CheckResult,
-        result: &mut BTreeMap<PluginType, (Key, CheckResult, RegistryRecord)>,
+        result: &mut PluginChecks<'r>,
     ) {
         for record in &self.registry {
             result.insert(
                 record.plugin_type,
-                (key, check_fp(&record.plugin_type), record.clone()),
+                (key, check_fp(&record.plugin_type), record),
             );
         }
     }
+
+    /// Find the index of the record of a plugin type.  The registry is sorted by plugin type when
+    /// it is loaded, so the record is binary searched.
+    pub(crate) fn find_index(&self, plugin_type: PluginType) -> Option<usize> {
+        self.registry
+            .binary_search_by_key(&plugin_type, |record| record.plugin_type)
+            .ok()
+    }
+
+    /// Find the record of a plugin type.
+    pub(crate) fn find(&self, plugin_type: PluginType) -> Option<&RegistryRecord> {
+        self.find_index(plugin_type)
+            .map(|index| &self.registry[index])
+    }
+
+    /// Find the record of a plugin type to update it.
+    pub(crate) fn find_mut(&mut self, plugin_type: PluginType) -> Option<&mut RegistryRecord> {
+        self.find_index(plugin_type)
+            .map(|index| &mut self.registry[index])
+    }
+
+    /// Insert a record at its position in the registry.
+    pub(crate) fn insert_sorted(&mut self, record: RegistryRecord) {
+        let index = self
+            .registry
+            .partition_point(|existing| existing.plugin_type < record.plugin_type);
+        self.registry.insert(index, record);
+    }
+
+    /// Move the data sections of the external plugins at or after an offset, after the data
+    /// before them has changed size.
+    pub(crate) fn shift_external_plugins(
+        &mut self,
+        from_offset: usize,
+        size_diff: isize,
+    ) -> Result<(), MplCoreError> {
+        for record in &mut self.external_plugins {
+            if record.offset >= from_offset {
+                record.offset = (record.offset as isize)
+                    .checked_add(size_diff)
+                    .ok_or(MplCoreError::NumericalOverflow)?
+                    as usize;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Find the index of the record of an external plugin by its key.
+    pub(crate) fn find_external_plugin(
+        &self,
+        key: &ExternalPluginKey,
+        account: &AccountInfo,
+    ) -> Result<usize, ProgramError> {
+        for (index, record) in self.external_plugins.iter().enumerate() {
+            if record.plugin_type != key.plugin_type() {
+                continue;
+            }
+
+            let matches = match key {
+                // Lifecycle hooks all have the update authority, so they are identified by the
+                // program they hook.
+                ExternalPluginKey::LifecycleHook(hooked_program) => {
+                    LifecycleHook::try_from_slice(&record.load_data(account)?)?.hooked_program
+                        == *hooked_program
+                }
+                ExternalPluginKey::AppData(authority)
+                | ExternalPluginKey::LinkedAppData(authority)
+                | ExternalPluginKey::Inscription(authority) => record.authority == *authority,
+            };
+            if matches {
+                return Ok(index);
+            }
+        }
+
+        Err(MplCoreError::PluginNotFound.into())
+    }
 }
 
 impl DataBlob for PluginRegistryV1 {
@@ -49,14 +136,40 @@ impl SolanaAccount for PluginRegistryV1 {
     fn key() -> Key {
         Key::PluginRegistryV1
     }
+
+    /// Load the registry, sorting the records of a registry written before they were kept sorted
+    /// by plugin type.  Sorting an already sorted registry is a single pass.
+    fn load(account: &AccountInfo, offset: usize) -> Result<Self, ProgramError> {
+        let key = load_key(account, offset)?;
+        if key != Self::key() {
+            msg!(
+                "Error: Account {} is a {:?}, expected a {:?}",
+                account.key,
+                key,
+                Self::key()
+            );
+            return Err(MplCoreError::IncorrectAccountKey.into());
+        }
+
+        let mut bytes: &[u8] = &(*account.data).borrow()[offset..];
+        let mut plugin_registry = Self::deserialize(&mut bytes).map_err(|error| {
+            msg!("Error: {}", error);
+            MplCoreError::DeserializationError
+        })?;
+        plugin_registry
+            .registry
+            .sort_by_key(|record| record.plugin_type);
+
+        Ok(plugin_registry)
+    }
 }
 
 /// A simple type to store the mapping of Plugin type to Plugin data.
 #[repr(C)]
-#[derive(Clone, BorshSerialize, BorshDeserialize, Debug)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
 pub struct RegistryRecord {
     /// The type of plugin.
-    pub plugin_type: PluginType, // 2
+    pub plugin_type: PluginType, // 1
     /// The authority who has permission to utilize a plugin.
     pub authority: Authority, // Variable
     /// The offset to the plugin in the account.
@@ -70,12 +183,214 @@ impl RegistryRecord {
     }
 }
 
+impl DataBlob for RegistryRecord {
+    fn get_initial_size() -> usize {
+        1 + 1 + 8
+    }
+
+    fn get_size(&self) -> usize {
+        self.plugin_type.get_size() + self.authority.get_size() + 8
+    }
+}
+
+/// An authority over a plugin of an asset, as returned by the `GetDelegatesV1` instruction.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct DelegateRecord {
+    /// The type of plugin, which determines what the authority is able to do.
+    pub plugin_type: PluginType, // 2
+    /// The authority who has permission to utilize the plugin.
+    pub authority: Authority, // Variable
+    /// Whether the plugin is inherited from the collection of the asset.
+    pub collection_level: bool, // 1
+}
+
+/// The format of the data stored by an external plugin.  The program treats the data as opaque
+/// bytes, the schema only tells clients which codec to use to decode it.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub enum ExternalPluginSchema {
+    /// Raw bytes, or Borsh if the application does not specify otherwise.
+    #[default]
+    Binary,
+    /// UTF-8 encoded JSON.
+    Json,
+    /// MessagePack encoded data.
+    MsgPack,
+}
+
+/// The type of an external plugin, which determines how the program uses its data.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub enum ExternalPluginType {
+    /// A hook into a third party program, whose data is the `LifecycleHook` configuration.
+    LifecycleHook,
+    /// Data written by a third party data authority, whose data is opaque to the program.
+    AppData,
+    /// Data written by a third party data authority for each asset of a collection, stored in a
+    /// linked data section PDA per asset rather than in the collection.
+    LinkedAppData,
+    /// Media written in chunks by an inscription authority until sealed, whose data is opaque to
+    /// the program.
+    Inscription,
+}
+
+/// The configuration of a new external plugin.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub enum ExternalPluginInitInfo {
+    /// Add a `LifecycleHook` external plugin.
+    LifecycleHook(LifecycleHook),
+    /// Add an `AppData` external plugin.
+    AppData(AppDataInitInfo),
+    /// Add a `LinkedAppData` external plugin, only to collections.
+    LinkedAppData(AppDataInitInfo),
+    /// Add an `Inscription` external plugin, only to assets.
+    Inscription(InscriptionInitInfo),
+}
+
+impl ExternalPluginInitInfo {
+    /// The type of the external plugin.
+    pub fn plugin_type(&self) -> ExternalPluginType {
+        match self {
+            ExternalPluginInitInfo::LifecycleHook(_) => ExternalPluginType::LifecycleHook,
+            ExternalPluginInitInfo::AppData(_) => ExternalPluginType::AppData,
+            ExternalPluginInitInfo::LinkedAppData(_) => ExternalPluginType::LinkedAppData,
+            ExternalPluginInitInfo::Inscription(_) => ExternalPluginType::Inscription,
+        }
+    }
+
+    /// The authority of the external plugin.
+    pub fn authority(&self) -> Authority {
+        match self {
+            ExternalPluginInitInfo::LifecycleHook(_) => Authority::UpdateAuthority,
+            ExternalPluginInitInfo::AppData(app_data)
+            | ExternalPluginInitInfo::LinkedAppData(app_data) => app_data.data_authority,
+            ExternalPluginInitInfo::Inscription(inscription) => inscription.inscription_authority,
+        }
+    }
+
+    /// The format of the data stored by the external plugin.
+    pub fn schema(&self) -> ExternalPluginSchema {
+        match self {
+            ExternalPluginInitInfo::LifecycleHook(_) => ExternalPluginSchema::Binary,
+            ExternalPluginInitInfo::AppData(app_data)
+            | ExternalPluginInitInfo::LinkedAppData(app_data) => app_data.schema,
+            ExternalPluginInitInfo::Inscription(inscription) => inscription.schema,
+        }
+    }
+
+    /// The initial data stored by the external plugin.
+    pub fn data(&self) -> Result<Vec<u8>, ProgramError> {
+        match self {
+            ExternalPluginInitInfo::LifecycleHook(lifecycle_hook) => {
+                Ok(lifecycle_hook.try_to_vec()?)
+            }
+            ExternalPluginInitInfo::AppData(_)
+            | ExternalPluginInitInfo::LinkedAppData(_)
+            | ExternalPluginInitInfo::Inscription(_) => Ok(vec![]),
+        }
+    }
+}
+
+/// The key identifying an existing external plugin.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub enum ExternalPluginKey {
+    /// A `LifecycleHook` external plugin, by its hooked program.
+    LifecycleHook(Pubkey),
+    /// An `AppData` external plugin, by its data authority.
+    AppData(Authority),
+    /// A `LinkedAppData` external plugin, by its data authority.
+    LinkedAppData(Authority),
+    /// An `Inscription` external plugin, by its inscription authority.
+    Inscription(Authority),
+}
+
+impl ExternalPluginKey {
+    /// The type of the external plugin.
+    pub fn plugin_type(&self) -> ExternalPluginType {
+        match self {
+            ExternalPluginKey::LifecycleHook(_) => ExternalPluginType::LifecycleHook,
+            ExternalPluginKey::AppData(_) => ExternalPluginType::AppData,
+            ExternalPluginKey::LinkedAppData(_) => ExternalPluginType::LinkedAppData,
+            ExternalPluginKey::Inscription(_) => ExternalPluginType::Inscription,
+        }
+    }
+}
+
+/// The new configuration of an existing external plugin.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub enum ExternalPluginUpdateInfo {
+    /// Replace the lifecycle events a `LifecycleHook` is invoked for.
+    LifecycleHook {
+        /// The new lifecycle events.
+        events: Vec<HookableLifecycleEvent>,
+    },
+    /// Change the schema of the data of an `AppData` external plugin.
+    AppData {
+        /// The new schema.
+        schema: ExternalPluginSchema,
+    },
+    /// Change the schema of the data of a `LinkedAppData` external plugin.
+    LinkedAppData {
+        /// The new schema.
+        schema: ExternalPluginSchema,
+    },
+    /// Change the schema of the data of an unsealed `Inscription` external plugin.
+    Inscription {
+        /// The new schema.
+        schema: ExternalPluginSchema,
+    },
+}
+
+impl ExternalPluginUpdateInfo {
+    /// The type of the external plugin.
+    pub fn plugin_type(&self) -> ExternalPluginType {
+        match self {
+            ExternalPluginUpdateInfo::LifecycleHook { .. } => ExternalPluginType::LifecycleHook,
+            ExternalPluginUpdateInfo::AppData { .. } => ExternalPluginType::AppData,
+            ExternalPluginUpdateInfo::LinkedAppData { .. } => ExternalPluginType::LinkedAppData,
+            ExternalPluginUpdateInfo::Inscription { .. } => ExternalPluginType::Inscription,
+        }
+    }
+}
+
 /// A simple type to store the mapping of external Plugin authority to Plugin data.
 #[repr(C)]
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug)]
 pub struct ExternalPluginRecord {
+    /// The type of external plugin.
+    pub plugin_type: ExternalPluginType, // 1
     /// The authority of the external plugin.
     pub authority: Authority,
     /// The offset to the plugin in the account.
     pub offset: usize,
+    /// The format of the data stored by the external plugin.
+    pub schema: ExternalPluginSchema, // 1
+    /// The length of the raw data stored by the external plugin.
+    pub data_len: usize, // 8
+}
+
+impl ExternalPluginRecord {
+    /// Load the raw data of the external plugin without interpreting it.
+    pub fn load_data(&self, account: &AccountInfo) -> Result<Vec<u8>, ProgramError> {
+        let end = self
+            .offset
+            .checked_add(self.data_len)
+            .ok_or(MplCoreError::NumericalOverflow)?;
+
+        account
+            .data
+            .borrow()
+            .get(self.offset..end)
+            .map(|data| data.to_vec())
+            .ok_or(MplCoreError::DeserializationError.into())
+    }
+
+    /// Hash the raw data of the external plugin, used to include it in compressed state.
+    pub fn hash_data(&self, account: &AccountInfo) -> Result<[u8; 32], ProgramError> {
+        Ok(keccak::hash(&self.load_data(account)?).to_bytes())
+    }
 }
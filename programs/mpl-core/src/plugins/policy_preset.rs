@@ -0,0 +1,83 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::state::Authority;
+
+use super::{
+    Attributes, Creator, PermanentBurnDelegate, PermanentFreezeDelegate, Plugin,
+    PluginAuthorityPair, Royalties, RuleSet,
+};
+
+/// A named bundle of plugins that can be requested when creating an asset, so integrators do not
+/// have to assemble common plugin sets by hand.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub enum PolicyPreset {
+    /// The asset can never be transferred or burned.
+    Soulbound,
+    /// The asset pays royalties to its creators and can only be moved by the allowed programs.
+    /// Note that wallet to wallet transfers require the System Program to be allowed.
+    RoyaltyEnforced {
+        /// The percentage of royalties to be paid to the creators.
+        basis_points: u16,
+        /// A list of creators to receive royalties.
+        creators: Vec<Creator>,
+        /// The programs allowed to transfer, receive, or send the asset.
+        allowed_programs: Vec<Pubkey>,
+    },
+    /// The asset carries attributes managed by the update authority, which can also lock the
+    /// asset while it is in use.
+    GameItem,
+    /// The asset can never be transferred, but can be revoked by the update authority.
+    Credential,
+}
+
+impl PolicyPreset {
+    /// Expand the preset into the plugins it is made of.
+    pub(crate) fn plugins(&self) -> Vec<PluginAuthorityPair> {
+        match self {
+            PolicyPreset::Soulbound => vec![PluginAuthorityPair {
+                plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate { frozen: true }),
+                authority: Some(Authority::None),
+            }],
+            PolicyPreset::RoyaltyEnforced {
+                basis_points,
+                creators,
+                allowed_programs,
+            } => vec![PluginAuthorityPair {
+                plugin: Plugin::Royalties(Royalties {
+                    basis_points: *basis_points,
+                    creators: creators.clone(),
+                    rule_set: RuleSet::ProgramAllowList(allowed_programs.clone()),
+                }),
+                authority: Some(Authority::UpdateAuthority),
+            }],
+            PolicyPreset::GameItem => vec![
+                PluginAuthorityPair {
+                    plugin: Plugin::Attributes(Attributes {
+                        attribute_list: vec![],
+                    }),
+                    authority: Some(Authority::UpdateAuthority),
+                },
+                PluginAuthorityPair {
+                    plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate {
+                        frozen: false,
+                    }),
+                    authority: Some(Authority::UpdateAuthority),
+                },
+            ],
+            PolicyPreset::Credential => vec![
+                PluginAuthorityPair {
+                    plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate {
+                        frozen: true,
+                    }),
+                    authority: Some(Authority::None),
+                },
+                PluginAuthorityPair {
+                    plugin: Plugin::PermanentBurnDelegate(PermanentBurnDelegate { sunset: None }),
+                    authority: Some(Authority::UpdateAuthority),
+                },
+            ],
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::program_error::ProgramError;
+
+use crate::{error::MplCoreError, state::DataBlob};
+
+use super::{Plugin, PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The progression plugin records the experience and level of a game asset.  The plugin
+/// authority, typically a game delegate, increments them through an update, up to the caps set
+/// when the plugin is added.  Progress can never be lowered and the caps cannot be changed.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct Progression {
+    /// The experience of the asset.
+    pub experience: u64, // 8
+    /// The level of the asset.
+    pub level: u32, // 4
+    /// The maximum experience of the asset.
+    pub max_experience: u64, // 8
+    /// The maximum level of the asset.
+    pub max_level: u32, // 4
+}
+
+impl Progression {
+    fn validate(&self) -> Result<ValidationResult, ProgramError> {
+        if self.experience > self.max_experience || self.level > self.max_level {
+            return Err(MplCoreError::InvalidPluginSetting.into());
+        }
+        Ok(ValidationResult::Pass)
+    }
+}
+
+impl DataBlob for Progression {
+    fn get_initial_size() -> usize {
+        24
+    }
+
+    fn get_size(&self) -> usize {
+        24
+    }
+}
+
+impl PluginValidation for Progression {
+    fn validate_create(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate()
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Progression(progression)) => progression.validate(),
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Progression(progression)) => {
+                // Progress can only be incremented, within the original caps.
+                if progression.max_experience != self.max_experience
+                    || progression.max_level != self.max_level
+                    || progression.experience < self.experience
+                    || progression.level < self.level
+                {
+                    return Err(MplCoreError::InvalidPluginSetting.into());
+                }
+
+                progression.validate()
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::program_error::ProgramError;
+
+use crate::{
+    error::MplCoreError,
+    state::{Authority, DataBlob},
+};
+
+use super::{Plugin, PluginType, PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The provenance plugin records which owner-managed plugins were added to an asset by the update
+/// authority at mint, so the owner cannot strip them while genuinely owner-added plugins remain
+/// removable by the owner.  It is written by the program when an asset is created with
+/// owner-managed plugins and cannot be supplied, added or updated directly.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct Provenance {
+    /// A bitmask of the `PluginType`s added by the update authority at mint.
+    pub mint_plugins: [u8; 32], // 32
+}
+
+impl Provenance {
+    /// Check whether a plugin of the given type was added by the update authority at mint.
+    pub fn contains(&self, plugin_type: PluginType) -> bool {
+        let index = plugin_type as usize;
+        self.mint_plugins[index / 8] & (1 << (index % 8)) != 0
+    }
+
+    /// Record a plugin of the given type as added by the update authority at mint.
+    pub fn insert(&mut self, plugin_type: PluginType) {
+        let index = plugin_type as usize;
+        self.mint_plugins[index / 8] |= 1 << (index % 8);
+    }
+
+    /// Forget a plugin of the given type once it has been removed from the asset.
+    pub fn remove(&mut self, plugin_type: PluginType) {
+        let index = plugin_type as usize;
+        self.mint_plugins[index / 8] &= !(1 << (index % 8));
+    }
+}
+
+impl DataBlob for Provenance {
+    fn get_initial_size() -> usize {
+        32
+    }
+
+    fn get_size(&self) -> usize {
+        32
+    }
+}
+
+impl PluginValidation for Provenance {
+    fn validate_create(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        Err(MplCoreError::InvalidPlugin.into())
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Provenance(_)) => Err(MplCoreError::InvalidPlugin.into()),
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Provenance(_)) => Err(MplCoreError::InvalidPlugin.into()),
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_remove_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        let resolved_authorities = ctx
+            .resolved_authorities
+            .ok_or(MplCoreError::InvalidAuthority)?;
+
+        match ctx.target_plugin {
+            Some(plugin)
+                if self.contains(PluginType::from(plugin))
+                    && resolved_authorities.contains(&Authority::Owner)
+                    && !resolved_authorities.contains(&Authority::UpdateAuthority) =>
+            {
+                solana_program::msg!("Provenance: Rejected");
+                Ok(ValidationResult::Rejected)
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{clock::Clock, program_error::ProgramError, sysvar::Sysvar};
+
+use crate::{error::MplCoreError, state::DataBlob};
+
+use super::{Plugin, PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The reaper plugin lets anyone burn the assets of a collection once they have expired.
+/// The rent of reaped assets is pooled in the collection account and used to subsidize the
+/// rent of future mints into the collection.
+/// The pool balance is maintained by the program and cannot be set by the authority.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct Reaper {
+    /// The unix timestamp after which the assets of the collection can be reaped.
+    pub expires_at: i64, // 8
+    /// The maximum lamports paid back to the payer of each new mint from the pool.
+    pub subsidy_per_mint: u64, // 8
+    /// The lamports currently held by the collection account for subsidies.
+    pub pool_balance: u64, // 8
+}
+
+impl Reaper {
+    /// Check whether the assets of the collection can be reaped.
+    pub fn is_expired(&self) -> Result<bool, ProgramError> {
+        Ok(Clock::get()?.unix_timestamp >= self.expires_at)
+    }
+
+    /// Take the subsidy for a new mint out of the pool, returning the lamports to pay back.
+    pub(crate) fn take_subsidy(&mut self) -> u64 {
+        let subsidy = self.subsidy_per_mint.min(self.pool_balance);
+        self.pool_balance -= subsidy;
+        subsidy
+    }
+}
+
+impl DataBlob for Reaper {
+    fn get_initial_size() -> usize {
+        24
+    }
+
+    fn get_size(&self) -> usize {
+        24
+    }
+}
+
+impl PluginValidation for Reaper {
+    fn validate_create(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.pool_balance != 0 {
+            return Err(MplCoreError::InvalidPluginSetting.into());
+        }
+        Ok(ValidationResult::Pass)
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Reaper(reaper)) if reaper.pool_balance != 0 => {
+                Err(MplCoreError::InvalidPluginSetting.into())
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Reaper(reaper)) if reaper.pool_balance != self.pool_balance => {
+                Err(MplCoreError::InvalidPluginSetting.into())
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_remove_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        // Removing the plugin would strand the pooled lamports in the collection account.
+        match ctx.target_plugin {
+            Some(Plugin::Reaper(_)) if self.pool_balance != 0 => {
+                solana_program::msg!("Reaper: Rejected");
+                Ok(ValidationResult::Rejected)
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::state::{Authority, DataBlob};
+
+use super::{PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The redemption plugin marks an asset as redeemable for a reward, which is fulfilled by the
+/// issuer once the owner redeems the asset.  Redeeming burns the asset and emits a
+/// `RedemptionRecord` event through the spl-noop program.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct Redemption {
+    /// The issuer fulfilling the reward.
+    pub issuer: Pubkey, // 32
+    /// An identifier of the reward for the fulfillment system of the issuer.
+    pub reward: String, // 4 + len
+}
+
+impl Redemption {
+    /// Validate the redeem action, force approving the burn when the owner redeems the asset.
+    pub(crate) fn validate_redeem(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if let Some(resolved_authorities) = ctx.resolved_authorities {
+            if resolved_authorities.contains(&Authority::Owner) {
+                debug_msg!("Redemption: ForceApproved");
+                return Ok(ValidationResult::ForceApproved);
+            }
+        }
+
+        Ok(ValidationResult::Pass)
+    }
+}
+
+impl DataBlob for Redemption {
+    fn get_initial_size() -> usize {
+        36
+    }
+
+    fn get_size(&self) -> usize {
+        36 + self.reward.len()
+    }
+}
+
+impl PluginValidation for Redemption {}
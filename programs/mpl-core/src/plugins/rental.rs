@@ -0,0 +1,95 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{clock::Clock, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar};
+
+use crate::{state::DataBlob, utils::cmp_pubkeys};
+
+use super::{Plugin, PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The rental plugin lends the use of an asset to a renter until an expiry time, without
+/// moving the asset into escrow. While the rental is active the owner cannot transfer or burn
+/// the asset, and only the renter can end or change the rental early.
+/// The default authority for this plugin is the owner.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct Rental {
+    /// The account renting the asset.
+    pub renter: Pubkey, // 32
+    /// The unix timestamp at which the rental expires.
+    pub expires_at: i64, // 8
+}
+
+impl Rental {
+    /// Check whether the rental has not yet expired.
+    pub fn is_active(&self) -> Result<bool, ProgramError> {
+        Ok(Clock::get()?.unix_timestamp < self.expires_at)
+    }
+
+    // While active, changes to the rental require the renter to sign.
+    fn validate_change(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Rental(_)) if self.is_active()? => {
+                if cmp_pubkeys(ctx.authority_info.key, &self.renter) {
+                    debug_msg!("Rental: Approved");
+                    Ok(ValidationResult::Approved)
+                } else {
+                    solana_program::msg!("Rental: Rejected");
+                    Ok(ValidationResult::Rejected)
+                }
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+}
+
+impl DataBlob for Rental {
+    fn get_initial_size() -> usize {
+        40
+    }
+
+    fn get_size(&self) -> usize {
+        40
+    }
+}
+
+impl PluginValidation for Rental {
+    fn validate_burn(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.is_active()? {
+            solana_program::msg!("Rental: Rejected");
+            Ok(ValidationResult::Rejected)
+        } else {
+            Ok(ValidationResult::Pass)
+        }
+    }
+
+    fn validate_transfer(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.is_active()? {
+            solana_program::msg!("Rental: Rejected");
+            Ok(ValidationResult::Rejected)
+        } else {
+            Ok(ValidationResult::Pass)
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_change(ctx)
+    }
+
+    fn validate_remove_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_change(ctx)
+    }
+}
@@ -3,15 +3,21 @@ use std::collections::HashSet;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 
-use crate::{error::MplCoreError, plugins::PluginType, state::Authority};
+use crate::{
+    error::MplCoreError,
+    plugins::PluginType,
+    state::{Authority, DataBlob},
+};
 
 use super::{Plugin, PluginValidation, PluginValidationContext, ValidationResult};
 
-/// The creator on an asset and whether or not they are verified.
+/// A creator on an asset and their share of the royalties.
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
 pub struct Creator {
-    address: Pubkey,
-    percentage: u8,
+    /// The address of the creator.
+    pub address: Pubkey,
+    /// The percentage share of the royalties owed to the creator.
+    pub percentage: u8,
 }
 
 /// The rule set for an asset indicating where it is allowed to be transferred.
@@ -29,11 +35,28 @@ pub enum RuleSet {
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug, Eq, PartialEq)]
 pub struct Royalties {
     /// The percentage of royalties to be paid to the creators.
-    basis_points: u16,
+    pub basis_points: u16,
     /// A list of creators to receive royalties.
-    creators: Vec<Creator>,
+    pub creators: Vec<Creator>,
     /// The rule set for the asset to enforce royalties.
-    rule_set: RuleSet,
+    pub rule_set: RuleSet,
+}
+
+impl DataBlob for Royalties {
+    fn get_initial_size() -> usize {
+        2 + 4 + 1
+    }
+
+    fn get_size(&self) -> usize {
+        let rule_set_size = match &self.rule_set {
+            RuleSet::None => 1,
+            RuleSet::ProgramAllowList(programs) | RuleSet::ProgramDenyList(programs) => {
+                1 + 4 + programs.len() * 32
+            }
+        };
+
+        2 + 4 + self.creators.len() * 33 + rule_set_size
+    }
 }
 
 fn validate_royalties(royalties: &Royalties) -> Result<ValidationResult, ProgramError> {
@@ -142,7 +165,7 @@ impl PluginValidation for Royalties {
             && ctx.target_plugin.is_some()
             && PluginType::from(ctx.target_plugin.unwrap()) == PluginType::Royalties
         {
-            solana_program::msg!("Royalties: Approved");
+            debug_msg!("Royalties: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
@@ -0,0 +1,158 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{error::MplCoreError, state::DataBlob};
+
+use super::{Plugin, PluginValidation, PluginValidationContext, ValidationResult};
+
+/// How the value of a score may change.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub enum ScoreMode {
+    /// The value can be set to anything.
+    Unrestricted,
+    /// The value can only increase.
+    MonotonicIncrease,
+    /// The value must stay within a range.
+    Bounded {
+        /// The minimum value, inclusive.
+        min: i64,
+        /// The maximum value, inclusive.
+        max: i64,
+    },
+}
+
+/// A named numeric score.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct NamedScore {
+    /// The name of the score.
+    pub name: String, // 4 + len
+    /// The value of the score.
+    pub value: i64, // 8
+    /// How the value of the score may change.
+    pub mode: ScoreMode, // 1 + 16
+}
+
+impl NamedScore {
+    fn validate(&self) -> Result<(), ProgramError> {
+        match self.mode {
+            ScoreMode::Bounded { min, max } if self.value < min || self.value > max => {
+                Err(MplCoreError::InvalidPluginSetting.into())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The score plugin attaches named scores, such as reputation, to an asset.  The values can only
+/// be updated by the writers, within the mode of each score, while the writers and the scores
+/// themselves are managed by the plugin authority, who cannot change the values.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct Score {
+    /// The keys that can update the values of the scores.
+    pub writers: Vec<Pubkey>, // 4 + 32 * len
+    /// The scores.
+    pub scores: Vec<NamedScore>, // 4 + len
+}
+
+impl Score {
+    fn validate(&self) -> Result<ValidationResult, ProgramError> {
+        for score in &self.scores {
+            score.validate()?;
+        }
+        Ok(ValidationResult::Pass)
+    }
+
+    fn find(&self, name: &str) -> Option<&NamedScore> {
+        self.scores.iter().find(|score| score.name == name)
+    }
+}
+
+impl DataBlob for Score {
+    fn get_initial_size() -> usize {
+        8
+    }
+
+    fn get_size(&self) -> usize {
+        8 + self.writers.len() * 32
+            + self
+                .scores
+                .iter()
+                .map(|score| {
+                    let mode_size = match score.mode {
+                        ScoreMode::Bounded { .. } => 17,
+                        _ => 1,
+                    };
+                    4 + score.name.len() + 8 + mode_size
+                })
+                .sum::<usize>()
+    }
+}
+
+impl PluginValidation for Score {
+    fn validate_create(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate()
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Score(score)) => score.validate(),
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        let Some(Plugin::Score(score)) = ctx.target_plugin else {
+            return Ok(ValidationResult::Pass);
+        };
+        score.validate()?;
+
+        let definitions_changed = score.writers != self.writers
+            || score.scores.len() != self.scores.len()
+            || score
+                .scores
+                .iter()
+                .zip(&self.scores)
+                .any(|(new, old)| new.name != old.name || new.mode != old.mode);
+
+        if definitions_changed {
+            // The plugin authority, approved by the base validation, cannot change the values of
+            // the scores it keeps.
+            if score.scores.iter().any(|new| {
+                self.find(&new.name)
+                    .is_some_and(|old| old.value != new.value)
+            }) {
+                solana_program::msg!("Score: Rejected");
+                return Ok(ValidationResult::Rejected);
+            }
+            return Ok(ValidationResult::Pass);
+        }
+
+        // Only the writers can change the values, within the mode of each score.
+        if !self.writers.contains(ctx.authority_info.key) {
+            solana_program::msg!("Score: Rejected");
+            return Ok(ValidationResult::Rejected);
+        }
+
+        for (new, old) in score.scores.iter().zip(&self.scores) {
+            if old.mode == ScoreMode::MonotonicIncrease && new.value < old.value {
+                return Err(MplCoreError::InvalidPluginSetting.into());
+            }
+        }
+
+        debug_msg!("Score: Approved");
+        Ok(ValidationResult::Approved)
+    }
+}
@@ -0,0 +1,146 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{clock::Clock, program_error::ProgramError, sysvar::Sysvar};
+
+use crate::{error::MplCoreError, state::DataBlob};
+
+use super::{Plugin, PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The staking plugin records the stake state of an asset so it can be staked without leaving
+/// the owner's wallet. While staked, the asset cannot be transferred or burned.
+/// The timestamps are maintained by the program, updates only set whether the asset is staked.
+/// The default authority for this plugin is the owner.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct Staking {
+    /// Whether or not the asset is currently staked.
+    pub staked: bool, // 1
+    /// The unix timestamp at which the asset was last staked.
+    pub staked_at: i64, // 8
+    /// The total number of seconds the asset was staked, excluding the current stake.
+    pub accumulated_staked_time: i64, // 8
+}
+
+impl Staking {
+    /// Initialize the Staking plugin, unstaked by default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply a requested change of stake state, stamping the timestamps with the current time.
+    pub(crate) fn apply(&self, staked: bool) -> Result<Self, ProgramError> {
+        let now = Clock::get()?.unix_timestamp;
+        let mut new = *self;
+        match (self.staked, staked) {
+            (false, true) => {
+                new.staked = true;
+                new.staked_at = now;
+            }
+            (true, false) => {
+                new.staked = false;
+                new.accumulated_staked_time = now
+                    .checked_sub(self.staked_at)
+                    .and_then(|elapsed| self.accumulated_staked_time.checked_add(elapsed))
+                    .ok_or(MplCoreError::NumericalOverflow)?;
+            }
+            _ => (),
+        }
+        Ok(new)
+    }
+}
+
+impl DataBlob for Staking {
+    fn get_initial_size() -> usize {
+        17
+    }
+
+    fn get_size(&self) -> usize {
+        17
+    }
+}
+
+impl PluginValidation for Staking {
+    fn validate_create(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if *self != Staking::new() {
+            return Err(MplCoreError::InvalidPluginSetting.into());
+        }
+        Ok(ValidationResult::Pass)
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Staking(staking)) if *staking != Staking::new() => {
+                Err(MplCoreError::InvalidPluginSetting.into())
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_burn(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.staked {
+            solana_program::msg!("Staking: Rejected");
+            Ok(ValidationResult::Rejected)
+        } else {
+            Ok(ValidationResult::Pass)
+        }
+    }
+
+    fn validate_transfer(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.staked {
+            solana_program::msg!("Staking: Rejected");
+            Ok(ValidationResult::Rejected)
+        } else {
+            Ok(ValidationResult::Pass)
+        }
+    }
+
+    fn validate_approve_plugin_authority(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Staking(staking)) if staking.staked => {
+                solana_program::msg!("Staking: Rejected");
+                Ok(ValidationResult::Rejected)
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_revoke_plugin_authority(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Staking(staking)) if staking.staked => {
+                solana_program::msg!("Staking: Rejected");
+                Ok(ValidationResult::Rejected)
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_remove_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Staking(_)) if self.staked => {
+                solana_program::msg!("Staking: Rejected");
+                Ok(ValidationResult::Rejected)
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::program_error::ProgramError;
+
+use crate::{
+    error::MplCoreError,
+    state::{Authority, DataBlob},
+};
+
+use super::{Plugin, PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The status of a ticket.
+#[repr(C)]
+#[derive(
+    Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq, PartialOrd,
+)]
+pub enum TicketStatus {
+    /// The ticket is valid for entry.
+    #[default]
+    Valid,
+    /// The ticket has been checked in at the venue.
+    CheckedIn,
+    /// The ticket has been voided.
+    Void,
+}
+
+/// The ticket plugin tracks the check-in of an event ticket.  The status only moves forward and
+/// can only be changed by a venue delegate, i.e. once the plugin authority is set to an address.
+/// The ticket cannot be transferred once it has been checked in or voided.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct Ticket {
+    /// The status of the ticket.
+    pub status: TicketStatus, // 1
+}
+
+impl Ticket {
+    // New tickets are always valid.
+    fn validate_initial(&self) -> Result<ValidationResult, ProgramError> {
+        if self.status != TicketStatus::Valid {
+            return Err(MplCoreError::InvalidPluginSetting.into());
+        }
+        Ok(ValidationResult::Pass)
+    }
+}
+
+impl DataBlob for Ticket {
+    fn get_initial_size() -> usize {
+        1
+    }
+
+    fn get_size(&self) -> usize {
+        1
+    }
+}
+
+impl PluginValidation for Ticket {
+    fn validate_create(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_initial()
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Ticket(ticket)) => ticket.validate_initial(),
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Ticket(ticket)) => {
+                if ticket.status < self.status {
+                    return Err(MplCoreError::InvalidPluginSetting.into());
+                }
+
+                if ticket.status != self.status
+                    && !matches!(ctx.self_authority, Authority::Address { .. })
+                {
+                    solana_program::msg!("Ticket: Rejected");
+                    return Ok(ValidationResult::Rejected);
+                }
+
+                Ok(ValidationResult::Pass)
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_transfer(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.status != TicketStatus::Valid {
+            solana_program::msg!("Ticket: Rejected");
+            Ok(ValidationResult::Rejected)
+        } else {
+            Ok(ValidationResult::Pass)
+        }
+    }
+}
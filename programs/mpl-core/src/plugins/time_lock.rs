@@ -0,0 +1,59 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{clock::Clock, program_error::ProgramError, sysvar::Sysvar};
+
+use crate::state::DataBlob;
+
+use super::{PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The time lock plugin prevents an asset from being transferred or burned until a point in
+/// time, for example so vested assets cannot be sold before the vesting cliff.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct TimeLock {
+    /// The unix timestamp after which the asset can be transferred or burned.
+    pub unlock_time: i64, // 8
+}
+
+impl TimeLock {
+    /// Check whether the asset is still locked at the current time.
+    pub fn is_locked(&self) -> Result<bool, ProgramError> {
+        Ok(Clock::get()?.unix_timestamp < self.unlock_time)
+    }
+}
+
+impl DataBlob for TimeLock {
+    fn get_initial_size() -> usize {
+        8
+    }
+
+    fn get_size(&self) -> usize {
+        8
+    }
+}
+
+impl PluginValidation for TimeLock {
+    fn validate_burn(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.is_locked()? {
+            solana_program::msg!("TimeLock: Rejected");
+            Ok(ValidationResult::Rejected)
+        } else {
+            Ok(ValidationResult::Pass)
+        }
+    }
+
+    fn validate_transfer(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.is_locked()? {
+            solana_program::msg!("TimeLock: Rejected");
+            Ok(ValidationResult::Rejected)
+        } else {
+            Ok(ValidationResult::Pass)
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
+
+use crate::{
+    error::MplCoreError,
+    state::{DataBlob, SPL_TOKEN_2022_PROGRAM_ID},
+};
+
+use super::PluginValidation;
+
+/// The token link plugin binds an asset to the fungible shares of a Token-2022 mint held in an
+/// escrow, for hybrid protocols that swap assets for shares.  The asset can only be transferred
+/// while the escrow backs it with at least the linked amount of shares, so the escrow token
+/// account must be passed in the remaining accounts.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct TokenLink {
+    /// The Token-2022 mint of the shares.
+    pub mint: Pubkey, // 32
+    /// The token account holding the shares backing the asset.
+    pub escrow: Pubkey, // 32
+    /// The amount of shares backing the asset.
+    pub amount: u64, // 8
+}
+
+impl TokenLink {
+    /// Check that the escrow is an initialized token account of the mint holding enough shares.
+    pub fn check_escrow(&self, escrow_info: &AccountInfo) -> ProgramResult {
+        // A token account stores the mint, then the owner, the amount and, after the delegate,
+        // the state.
+        let data = escrow_info.data.borrow();
+        let backed = escrow_info.key == &self.escrow
+            && escrow_info.owner == &SPL_TOKEN_2022_PROGRAM_ID
+            && data.get(0..32) == Some(self.mint.as_ref())
+            && data
+                .get(64..72)
+                .and_then(|amount| amount.try_into().ok())
+                .map(u64::from_le_bytes)
+                .unwrap_or_default()
+                >= self.amount
+            && data.get(108) == Some(&1);
+
+        if !backed {
+            msg!("TokenLink: Rejected");
+            return Err(MplCoreError::InvalidTokenLinkEscrow.into());
+        }
+
+        Ok(())
+    }
+}
+
+impl DataBlob for TokenLink {
+    fn get_initial_size() -> usize {
+        72
+    }
+
+    fn get_size(&self) -> usize {
+        72
+    }
+}
+
+impl PluginValidation for TokenLink {}
@@ -47,7 +47,7 @@ impl PluginValidation for TransferDelegate {
                 address: *ctx.authority_info.key,
             })
         {
-            solana_program::msg!("TransferDelegate: Approved");
+            debug_msg!("TransferDelegate: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
@@ -63,7 +63,7 @@ impl PluginValidation for TransferDelegate {
                 address: *ctx.authority_info.key,
             })
         {
-            solana_program::msg!("TransferDelegate: Approved");
+            debug_msg!("TransferDelegate: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
@@ -82,7 +82,7 @@ impl PluginValidation for TransferDelegate {
             && ctx.target_plugin.is_some()
             && PluginType::from(ctx.target_plugin.unwrap()) == PluginType::TransferDelegate
         {
-            solana_program::msg!("TransferDelegate: Approved");
+            debug_msg!("TransferDelegate: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
@@ -0,0 +1,61 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{clock::Clock, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar};
+
+use crate::{state::DataBlob, utils::cmp_pubkeys};
+
+use super::{PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The transfer approval plugin allows a delegate to transfer an asset once, optionally only to
+/// a given destination, until an expiry slot.  The approval is consumed by the transfer, so the
+/// owner can approve a transfer in one transaction and the delegate execute it in another.
+/// The default authority for this plugin is the owner.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct TransferApproval {
+    /// The address approved to transfer the asset.
+    pub delegate: Pubkey, // 32
+    /// The only new owner the asset can be transferred to, if any.
+    pub destination: Option<Pubkey>, // 1 + 32
+    /// The last slot in which the approval can be used.
+    pub expiry_slot: u64, // 8
+}
+
+impl TransferApproval {
+    /// Check whether the approval can no longer be used at the current slot.
+    pub fn is_expired(&self) -> Result<bool, ProgramError> {
+        Ok(Clock::get()?.slot > self.expiry_slot)
+    }
+}
+
+impl DataBlob for TransferApproval {
+    fn get_initial_size() -> usize {
+        41
+    }
+
+    fn get_size(&self) -> usize {
+        41 + self.destination.map_or(0, |_| 32)
+    }
+}
+
+impl PluginValidation for TransferApproval {
+    fn validate_transfer(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        let to_destination = match (self.destination, ctx.new_owner) {
+            (None, _) => true,
+            (Some(destination), Some(new_owner)) => cmp_pubkeys(new_owner.key, &destination),
+            (Some(_), None) => false,
+        };
+
+        if cmp_pubkeys(ctx.authority_info.key, &self.delegate)
+            && to_destination
+            && !self.is_expired()?
+        {
+            debug_msg!("TransferApproval: Approved");
+            Ok(ValidationResult::Approved)
+        } else {
+            Ok(ValidationResult::Pass)
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::state::DataBlob;
+
+use super::PluginValidation;
+
+/// The transfer fee plugin charges a fixed fee in lamports on every transfer of an asset,
+/// paid by the payer of the transfer to the recipient. The transfer is rejected unless the
+/// recipient is passed in the remaining accounts of the transfer.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct TransferFee {
+    /// The fee in lamports charged on each transfer.
+    pub fee: u64, // 8
+    /// The account receiving the fee.
+    pub recipient: Pubkey, // 32
+}
+
+impl DataBlob for TransferFee {
+    fn get_initial_size() -> usize {
+        40
+    }
+
+    fn get_size(&self) -> usize {
+        40
+    }
+}
+
+impl PluginValidation for TransferFee {}
@@ -0,0 +1,54 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, sysvar::instructions::load_instruction_at_checked,
+};
+
+use crate::{error::MplCoreError, state::DataBlob};
+
+use super::PluginValidation;
+
+/// The transaction guard plugin rejects transfers, burns, and updates of an asset when the
+/// transaction also contains an instruction from one of the blocked programs, such as known
+/// exploit routers.  The instructions sysvar must be passed in the remaining accounts.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct TxGuard {
+    /// The programs which cannot be invoked in the same transaction.
+    pub blocked_programs: Vec<Pubkey>, // 4
+}
+
+impl TxGuard {
+    /// Check every instruction of the transaction against the blocked programs.
+    pub fn check_transaction(&self, instructions_sysvar: &AccountInfo) -> ProgramResult {
+        let mut index = 0;
+        loop {
+            let instruction = match load_instruction_at_checked(index, instructions_sysvar) {
+                Ok(instruction) => instruction,
+                // Reading past the last instruction of the transaction.
+                Err(ProgramError::InvalidArgument) => return Ok(()),
+                Err(err) => return Err(err),
+            };
+
+            if self.blocked_programs.contains(&instruction.program_id) {
+                msg!("TxGuard: Rejected");
+                return Err(MplCoreError::BlockedProgram.into());
+            }
+
+            index += 1;
+        }
+    }
+}
+
+impl DataBlob for TxGuard {
+    fn get_initial_size() -> usize {
+        4
+    }
+
+    fn get_size(&self) -> usize {
+        4 + self.blocked_programs.len() * 32
+    }
+}
+
+impl PluginValidation for TxGuard {}
@@ -0,0 +1,165 @@
+use std::collections::HashSet;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{keccak, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{error::MplCoreError, state::DataBlob};
+
+use super::{Plugin, PluginValidation, PluginValidationContext, ValidationResult};
+
+/// A creator who can approve metadata updates, along with the weight of their approval.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct UpdateApprover {
+    /// The address of the approver.
+    pub address: Pubkey, // 32
+    /// The weight the approval counts for towards the threshold.
+    pub weight: u8, // 1
+}
+
+/// The update approval plugin requires the listed approvers to co-sign changes to the name and
+/// uri of an asset. The update authority proposes an update by setting its hash, after which
+/// approvers add their approval in their own transactions. The update can be applied once the
+/// weight of the approvals reaches the threshold.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct UpdateApproval {
+    /// The accounts that can approve updates.
+    pub approvers: Vec<UpdateApprover>, // 4
+    /// The total weight of approvals required to apply an update.
+    pub threshold: u16, // 2
+    /// The hash of the pending name and uri, see `UpdateApproval::hash_update`.
+    pub pending_update_hash: [u8; 32], // 32
+    /// The approvers who have approved the pending update.
+    pub approvals: Vec<Pubkey>, // 4
+}
+
+impl UpdateApproval {
+    /// Hash the name and uri of an update so it can be proposed.
+    pub fn hash_update(name: &str, uri: &str) -> [u8; 32] {
+        keccak::hashv(&[name.as_bytes(), &[0], uri.as_bytes()]).to_bytes()
+    }
+
+    /// Check whether an update to the given name and uri has been approved.
+    pub fn is_approved(&self, name: &str, uri: &str) -> bool {
+        self.pending_update_hash == Self::hash_update(name, uri)
+            && self.approved_weight() >= self.threshold as u64
+    }
+
+    fn approved_weight(&self) -> u64 {
+        self.approvers
+            .iter()
+            .filter(|approver| self.approvals.contains(&approver.address))
+            .map(|approver| approver.weight as u64)
+            .sum()
+    }
+}
+
+impl DataBlob for UpdateApproval {
+    fn get_initial_size() -> usize {
+        4 + 2 + 32 + 4
+    }
+
+    fn get_size(&self) -> usize {
+        4 + self.approvers.len() * 33 + 2 + 32 + 4 + self.approvals.len() * 32
+    }
+}
+
+/// Validate the approvers and approvals of the plugin, rejecting any approval that was not
+/// added by the approver themselves.
+fn validate_update_approval(
+    current: Option<&UpdateApproval>,
+    new: &UpdateApproval,
+    ctx: &PluginValidationContext,
+) -> Result<ValidationResult, ProgramError> {
+    // Each approver can only be listed and approve once, and the threshold must be reachable.
+    let mut seen_approvers = HashSet::new();
+    let mut seen_approvals = HashSet::new();
+    let total_weight: u64 = new
+        .approvers
+        .iter()
+        .map(|approver| approver.weight as u64)
+        .sum();
+    if new.threshold == 0
+        || new.threshold as u64 > total_weight
+        || !new
+            .approvers
+            .iter()
+            .all(|approver| seen_approvers.insert(approver.address))
+        || !new
+            .approvals
+            .iter()
+            .all(|approval| seen_approvers.contains(approval) && seen_approvals.insert(*approval))
+    {
+        return Err(MplCoreError::InvalidPluginSetting.into());
+    }
+
+    let is_new = |approval: &Pubkey| match current {
+        Some(current) => !current.approvals.contains(approval),
+        None => true,
+    };
+    let mut added = new.approvals.iter().filter(|approval| is_new(approval));
+    if added.any(|approval| approval != ctx.authority_info.key) {
+        solana_program::msg!("UpdateApproval: Rejected");
+        return Ok(ValidationResult::Rejected);
+    }
+
+    match current {
+        // Approving only adds the approver to the approvals of the pending update.
+        Some(current)
+            if current.approvers == new.approvers
+                && current.threshold == new.threshold
+                && current.pending_update_hash == new.pending_update_hash
+                && current
+                    .approvals
+                    .iter()
+                    .all(|approval| new.approvals.contains(approval)) =>
+        {
+            debug_msg!("UpdateApproval: Approved");
+            Ok(ValidationResult::Approved)
+        }
+        // Proposing a new update discards the approvals of the previous one.
+        Some(current)
+            if current.pending_update_hash != new.pending_update_hash
+                && !new.approvals.iter().all(is_new) =>
+        {
+            solana_program::msg!("UpdateApproval: Rejected");
+            Ok(ValidationResult::Rejected)
+        }
+        _ => Ok(ValidationResult::Pass),
+    }
+}
+
+impl PluginValidation for UpdateApproval {
+    fn validate_create(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        validate_update_approval(None, self, ctx)
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::UpdateApproval(update_approval)) => {
+                validate_update_approval(None, update_approval, ctx)
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::UpdateApproval(update_approval)) => {
+                validate_update_approval(Some(self), update_approval, ctx)
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+}
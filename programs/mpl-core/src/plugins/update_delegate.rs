@@ -9,8 +9,10 @@ use crate::{
 
 use super::{Plugin, PluginValidation, PluginValidationContext, ValidationResult};
 
-/// This plugin manages additional permissions to burn.
-/// Any authorities approved are given permission to burn the asset on behalf of the owner.
+/// This plugin manages additional permissions to update.
+/// Any authorities approved are given permission to update the asset and its update authority
+/// managed plugins on behalf of the update authority, without being able to change the update
+/// authority itself.
 #[repr(C)]
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
 pub struct UpdateDelegate {
@@ -35,11 +37,11 @@ impl Default for UpdateDelegate {
 
 impl DataBlob for UpdateDelegate {
     fn get_initial_size() -> usize {
-        0
+        4
     }
 
     fn get_size(&self) -> usize {
-        0
+        4 + self.additional_delegates.len() * 32
     }
 }
 
@@ -71,7 +73,7 @@ impl PluginValidation for UpdateDelegate {
                 })
                 && new_plugin.manager() == Authority::UpdateAuthority
             {
-                solana_program::msg!("UpdateDelegate: Approved");
+                debug_msg!("UpdateDelegate: Approved");
                 Ok(ValidationResult::Approved)
             } else {
                 Ok(ValidationResult::Pass)
@@ -92,7 +94,7 @@ impl PluginValidation for UpdateDelegate {
                 })
                 && plugin_to_remove.manager() == Authority::UpdateAuthority
             {
-                solana_program::msg!("UpdateDelegate: Approved");
+                debug_msg!("UpdateDelegate: Approved");
                 Ok(ValidationResult::Approved)
             } else {
                 Ok(ValidationResult::Pass)
@@ -114,7 +116,7 @@ impl PluginValidation for UpdateDelegate {
             && ctx.target_plugin.is_some()
             && PluginType::from(ctx.target_plugin.unwrap()) == PluginType::UpdateDelegate
         {
-            solana_program::msg!("UpdateDelegate: Approved");
+            debug_msg!("UpdateDelegate: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
@@ -125,12 +127,20 @@ impl PluginValidation for UpdateDelegate {
         &self,
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
+        // Delegates cannot hand over the update authority.
+        if ctx
+            .update_info
+            .is_some_and(|update_info| update_info.new_update_authority.is_some())
+        {
+            return Ok(ValidationResult::Pass);
+        }
+
         if ctx.self_authority
             == (&Authority::Address {
                 address: *ctx.authority_info.key,
             })
         {
-            solana_program::msg!("UpdateDelegate: Approved");
+            debug_msg!("UpdateDelegate: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
@@ -148,6 +158,16 @@ impl PluginValidation for UpdateDelegate {
             }
         }
 
-        Ok(ValidationResult::Pass)
+        if ctx.self_authority
+            == (&Authority::Address {
+                address: *ctx.authority_info.key,
+            })
+            && plugin_to_update.manager() == Authority::UpdateAuthority
+        {
+            debug_msg!("UpdateDelegate: Approved");
+            Ok(ValidationResult::Approved)
+        } else {
+            Ok(ValidationResult::Pass)
+        }
     }
 }
@@ -0,0 +1,87 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::program_error::ProgramError;
+
+use crate::{error::MplCoreError, state::DataBlob};
+
+use super::{Plugin, PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The usage limit plugin counts the uses of an asset, such as the entries of a ticket or the
+/// charges of a consumable item. Each use is recorded by the plugin authority decrementing
+/// `remaining` through an update, and no further uses can be recorded once it reaches zero.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct UsageLimit {
+    /// The total number of uses of the asset.
+    pub max_uses: u32, // 4
+    /// The number of uses left.
+    pub remaining: u32, // 4
+}
+
+impl UsageLimit {
+    /// Initialize the UsageLimit plugin with all uses remaining.
+    pub fn new(max_uses: u32) -> Self {
+        Self {
+            max_uses,
+            remaining: max_uses,
+        }
+    }
+
+    fn validate(&self) -> Result<ValidationResult, ProgramError> {
+        if self.remaining > self.max_uses {
+            return Err(MplCoreError::InvalidPluginSetting.into());
+        }
+        Ok(ValidationResult::Pass)
+    }
+}
+
+impl DataBlob for UsageLimit {
+    fn get_initial_size() -> usize {
+        8
+    }
+
+    fn get_size(&self) -> usize {
+        8
+    }
+}
+
+impl PluginValidation for UsageLimit {
+    fn validate_create(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate()
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::UsageLimit(usage_limit)) => usage_limit.validate(),
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::UsageLimit(usage_limit)) => {
+                // Uses can only be consumed, never restored.
+                if usage_limit.max_uses != self.max_uses || usage_limit.remaining > self.remaining {
+                    return Err(MplCoreError::InvalidPluginSetting.into());
+                }
+
+                if self.remaining == 0 {
+                    solana_program::msg!("UsageLimit: Rejected");
+                    return Ok(ValidationResult::Rejected);
+                }
+
+                Ok(ValidationResult::Pass)
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+}
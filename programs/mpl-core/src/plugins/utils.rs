@@ -1,4 +1,5 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use num_traits::ToPrimitive;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
     program_memory::sol_memcpy,
@@ -10,7 +11,11 @@ use crate::{
     utils::resize_or_reallocate_account,
 };
 
-use super::{Plugin, PluginHeaderV1, PluginRegistryV1, PluginType, RegistryRecord};
+use super::{
+    DataSection, ExternalPluginKey, ExternalPluginRecord, ExternalPluginSchema, ExternalPluginType,
+    ExternalPluginUpdateInfo, LifecycleHook, Plugin, PluginHeaderV1, PluginRegistryV1, PluginType,
+    RegistryRecord,
+};
 
 /// Create plugin header and registry if it doesn't exist
 pub fn create_meta_idempotent<'a, T: SolanaAccount + DataBlob>(
@@ -99,31 +104,29 @@ pub fn assert_plugins_initialized(account: &AccountInfo) -> ProgramResult {
     Ok(())
 }
 
-/// Fetch the plugin from the registry.
+/// Fetch the plugin from the registry.  Only the requested plugin is deserialized, after checking
+/// the variant it is stored as.
 pub fn fetch_plugin<T: DataBlob + SolanaAccount, U: BorshDeserialize>(
     account: &AccountInfo,
     plugin_type: PluginType,
 ) -> Result<(Authority, U, usize), ProgramError> {
-    let asset = T::load(account, 0)?;
+    let size = T::load_size(account)?;
 
-    if asset.get_size() == account.data_len() {
+    if size == account.data_len() {
         return Err(MplCoreError::PluginNotFound.into());
     }
 
-    let header = PluginHeaderV1::load(account, asset.get_size())?;
-    let PluginRegistryV1 { registry, .. } =
-        PluginRegistryV1::load(account, header.plugin_registry_offset)?;
+    let header = PluginHeaderV1::load(account, size)?;
+    let plugin_registry = PluginRegistryV1::load(account, header.plugin_registry_offset)?;
 
     // Find the plugin in the registry.
-    let registry_record = registry
-        .iter()
-        .find(|record| record.plugin_type == plugin_type)
+    let registry_record = plugin_registry
+        .find(plugin_type)
         .ok_or(MplCoreError::PluginNotFound)?;
 
-    // Deserialize the plugin.
-    let plugin = Plugin::deserialize(&mut &(*account.data).borrow()[registry_record.offset..])?;
-
-    if PluginType::from(&plugin) != plugin_type {
+    // The variants of `Plugin` and `PluginType` are in the same order, so the stored variant is
+    // checked without deserializing the plugin twice.
+    if account.data.borrow().get(registry_record.offset).copied() != plugin_type.to_u8() {
         return Err(MplCoreError::PluginNotFound.into());
     }
 
@@ -147,24 +150,22 @@ pub fn fetch_wrapped_plugin<T: DataBlob + SolanaAccount>(
     let size = match core {
         Some(core) => core.get_size(),
         None => {
-            let asset = T::load(account, 0)?;
+            let size = T::load_size(account)?;
 
-            if asset.get_size() == account.data_len() {
+            if size == account.data_len() {
                 return Err(MplCoreError::PluginNotFound.into());
             }
 
-            asset.get_size()
+            size
         }
     };
 
     let header = PluginHeaderV1::load(account, size)?;
-    let PluginRegistryV1 { registry, .. } =
-        PluginRegistryV1::load(account, header.plugin_registry_offset)?;
+    let plugin_registry = PluginRegistryV1::load(account, header.plugin_registry_offset)?;
 
     // Find the plugin in the registry.
-    let registry_record = registry
-        .iter()
-        .find(|record| record.plugin_type == plugin_type)
+    let registry_record = plugin_registry
+        .find(plugin_type)
         .ok_or(MplCoreError::PluginNotFound)?;
 
     // Deserialize the plugin.
@@ -176,13 +177,13 @@ pub fn fetch_wrapped_plugin<T: DataBlob + SolanaAccount>(
 
 /// Fetch the plugin registry.
 pub fn fetch_plugins(account: &AccountInfo) -> Result<Vec<RegistryRecord>, ProgramError> {
-    let asset = AssetV1::load(account, 0)?;
+    let size = AssetV1::load_size(account)?;
 
-    if asset.get_size() == account.data_len() {
+    if size == account.data_len() {
         return Err(MplCoreError::PluginNotFound.into());
     }
 
-    let header = PluginHeaderV1::load(account, asset.get_size())?;
+    let header = PluginHeaderV1::load(account, size)?;
     let PluginRegistryV1 { registry, .. } =
         PluginRegistryV1::load(account, header.plugin_registry_offset)?;
 
@@ -191,13 +192,13 @@ pub fn fetch_plugins(account: &AccountInfo) -> Result<Vec<RegistryRecord>, Progr
 
 /// List all plugins in an account.
 pub fn list_plugins(account: &AccountInfo) -> Result<Vec<PluginType>, ProgramError> {
-    let asset = AssetV1::load(account, 0)?;
+    let size = AssetV1::load_size(account)?;
 
-    if asset.get_size() == account.data_len() {
+    if size == account.data_len() {
         return Err(MplCoreError::PluginNotFound.into());
     }
 
-    let header = PluginHeaderV1::load(account, asset.get_size())?;
+    let header = PluginHeaderV1::load(account, size)?;
     let PluginRegistryV1 { registry, .. } =
         PluginRegistryV1::load(account, header.plugin_registry_offset)?;
 
@@ -217,18 +218,12 @@ pub fn initialize_plugin<'a, T: DataBlob + SolanaAccount>(
     payer: &AccountInfo<'a>,
     system_program: &AccountInfo<'a>,
 ) -> ProgramResult {
-    let core = T::load(account, 0)?;
-    let header_offset = core.get_size();
+    let header_offset = T::load_size(account)?;
     let plugin_type = plugin.into();
-    let plugin_data = plugin.try_to_vec()?;
-    let plugin_size = plugin_data.len();
+    let plugin_size = plugin.get_size();
 
     // You cannot add a duplicate plugin.
-    if plugin_registry
-        .registry
-        .iter_mut()
-        .any(|record| record.plugin_type == plugin_type)
-    {
+    if plugin_registry.find(plugin_type).is_some() {
         return Err(MplCoreError::PluginAlreadyExists.into());
     }
 
@@ -241,7 +236,7 @@ pub fn initialize_plugin<'a, T: DataBlob + SolanaAccount>(
     };
 
     let size_increase = plugin_size
-        .checked_add(new_registry_record.try_to_vec()?.len())
+        .checked_add(new_registry_record.get_size())
         .ok_or(MplCoreError::NumericalOverflow)?;
 
     let new_registry_offset = plugin_header
@@ -251,7 +246,7 @@ pub fn initialize_plugin<'a, T: DataBlob + SolanaAccount>(
 
     plugin_header.plugin_registry_offset = new_registry_offset;
 
-    plugin_registry.registry.push(new_registry_record);
+    plugin_registry.insert_sorted(new_registry_record);
 
     let new_size = account
         .data_len()
@@ -266,6 +261,192 @@ pub fn initialize_plugin<'a, T: DataBlob + SolanaAccount>(
     Ok(())
 }
 
+/// Add an external plugin and its data to the account, after the existing plugins.
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_external_plugin<'a, T: DataBlob + SolanaAccount>(
+    plugin_type: ExternalPluginType,
+    authority: &Authority,
+    schema: ExternalPluginSchema,
+    data: &[u8],
+    plugin_header: &mut PluginHeaderV1,
+    plugin_registry: &mut PluginRegistryV1,
+    account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    let header_offset = T::load_size(account)?;
+
+    let old_registry_offset = plugin_header.plugin_registry_offset;
+
+    let new_external_record = ExternalPluginRecord {
+        plugin_type,
+        authority: *authority,
+        offset: old_registry_offset,
+        schema,
+        data_len: data.len(),
+    };
+
+    let size_increase = data
+        .len()
+        .checked_add(new_external_record.try_to_vec()?.len())
+        .ok_or(MplCoreError::NumericalOverflow)?;
+
+    let new_registry_offset = plugin_header
+        .plugin_registry_offset
+        .checked_add(data.len())
+        .ok_or(MplCoreError::NumericalOverflow)?;
+
+    plugin_header.plugin_registry_offset = new_registry_offset;
+
+    plugin_registry.external_plugins.push(new_external_record);
+
+    let new_size = account
+        .data_len()
+        .checked_add(size_increase)
+        .ok_or(MplCoreError::NumericalOverflow)?;
+
+    resize_or_reallocate_account(account, payer, system_program, new_size)?;
+    plugin_header.save(account, header_offset)?;
+    sol_memcpy(
+        &mut account.data.borrow_mut()[old_registry_offset..],
+        data,
+        data.len(),
+    );
+    plugin_registry.save(account, new_registry_offset)?;
+
+    Ok(())
+}
+
+/// Replace the data of an external plugin, resizing its data section to fit the new data.
+pub fn write_external_plugin_data<'a, T: DataBlob + SolanaAccount>(
+    plugin_type: ExternalPluginType,
+    authority: &Authority,
+    data: &[u8],
+    account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    let header_offset = T::load_size(account)?;
+    if header_offset == account.data_len() {
+        return Err(MplCoreError::PluginNotFound.into());
+    }
+
+    let mut plugin_header = PluginHeaderV1::load(account, header_offset)?;
+    let mut plugin_registry =
+        PluginRegistryV1::load(account, plugin_header.plugin_registry_offset)?;
+
+    let mut data_section = DataSection::find(&plugin_registry, plugin_type, authority)?;
+    data_section.write(
+        data,
+        &mut plugin_header,
+        &mut plugin_registry,
+        account,
+        payer,
+        system_program,
+    )?;
+
+    plugin_header.save(account, header_offset)?;
+    plugin_registry.save(account, plugin_header.plugin_registry_offset)?;
+
+    Ok(())
+}
+
+/// Apply a new configuration to an external plugin, resizing its data section if its data
+/// changes size.
+pub fn update_external_plugin_config<'a, T: DataBlob + SolanaAccount>(
+    key: &ExternalPluginKey,
+    update_info: &ExternalPluginUpdateInfo,
+    account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    if key.plugin_type() != update_info.plugin_type() {
+        return Err(MplCoreError::InvalidPlugin.into());
+    }
+
+    let header_offset = T::load_size(account)?;
+    if header_offset == account.data_len() {
+        return Err(MplCoreError::PluginNotFound.into());
+    }
+
+    let mut plugin_header = PluginHeaderV1::load(account, header_offset)?;
+    let mut plugin_registry =
+        PluginRegistryV1::load(account, plugin_header.plugin_registry_offset)?;
+    let index = plugin_registry.find_external_plugin(key, account)?;
+
+    match update_info {
+        ExternalPluginUpdateInfo::LifecycleHook { events } => {
+            let mut lifecycle_hook = LifecycleHook::try_from_slice(
+                &plugin_registry.external_plugins[index].load_data(account)?,
+            )?;
+            lifecycle_hook.events = events.clone();
+
+            let mut data_section = DataSection::at(&plugin_registry, index)?;
+            data_section.write(
+                &lifecycle_hook.try_to_vec()?,
+                &mut plugin_header,
+                &mut plugin_registry,
+                account,
+                payer,
+                system_program,
+            )?;
+        }
+        ExternalPluginUpdateInfo::AppData { schema }
+        | ExternalPluginUpdateInfo::LinkedAppData { schema }
+        | ExternalPluginUpdateInfo::Inscription { schema } => {
+            // A sealed inscription cannot be changed.
+            if plugin_registry.external_plugins[index].authority == Authority::None {
+                return Err(MplCoreError::InvalidAuthority.into());
+            }
+            plugin_registry.external_plugins[index].schema = *schema;
+        }
+    }
+
+    plugin_header.save(account, header_offset)?;
+    plugin_registry.save(account, plugin_header.plugin_registry_offset)?;
+
+    Ok(())
+}
+
+/// Remove an external plugin from the registry and delete its data.
+pub fn delete_external_plugin<'a, T: DataBlob + SolanaAccount>(
+    key: &ExternalPluginKey,
+    account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    let header_offset = T::load_size(account)?;
+    if header_offset == account.data_len() {
+        return Err(MplCoreError::PluginNotFound.into());
+    }
+
+    let mut plugin_header = PluginHeaderV1::load(account, header_offset)?;
+    let mut plugin_registry =
+        PluginRegistryV1::load(account, plugin_header.plugin_registry_offset)?;
+    let index = plugin_registry.find_external_plugin(key, account)?;
+
+    // Empty the data section, which moves everything after it, then drop its record.
+    let mut data_section = DataSection::at(&plugin_registry, index)?;
+    data_section.resize(
+        0,
+        &mut plugin_header,
+        &mut plugin_registry,
+        account,
+        payer,
+        system_program,
+    )?;
+    let record = plugin_registry.external_plugins.remove(index);
+
+    let new_size = account
+        .data_len()
+        .checked_sub(record.try_to_vec()?.len())
+        .ok_or(MplCoreError::NumericalOverflow)?;
+
+    plugin_header.save(account, header_offset)?;
+    plugin_registry.save(account, plugin_header.plugin_registry_offset)?;
+    resize_or_reallocate_account(account, payer, system_program, new_size)
+}
+
 /// Remove a plugin from the registry and delete it.
 pub fn delete_plugin<'a, T: DataBlob>(
     plugin_type: &PluginType,
@@ -282,35 +463,30 @@ pub fn delete_plugin<'a, T: DataBlob>(
     let mut header = PluginHeaderV1::load(account, asset.get_size())?;
     let mut plugin_registry = PluginRegistryV1::load(account, header.plugin_registry_offset)?;
 
-    if let Some(index) = plugin_registry
-        .registry
-        .iter_mut()
-        .position(|record| record.plugin_type == *plugin_type)
-    {
+    if let Some(index) = plugin_registry.find_index(*plugin_type) {
         let registry_record = plugin_registry.registry.remove(index);
-        let serialized_registry_record = registry_record.try_to_vec()?;
+        let registry_record_size = registry_record.get_size();
 
         // Fetch the offset of the plugin to be removed.
         let plugin_offset = registry_record.offset;
-        let plugin = Plugin::load(account, plugin_offset)?;
-        let serialized_plugin = plugin.try_to_vec()?;
+        let plugin_size = Plugin::load(account, plugin_offset)?.get_size();
 
         // Get the offset of the plugin after the one being removed.
         let next_plugin_offset = plugin_offset
-            .checked_add(serialized_plugin.len())
+            .checked_add(plugin_size)
             .ok_or(MplCoreError::NumericalOverflow)?;
 
         // Calculate the new size of the account.
         let new_size = account
             .data_len()
-            .checked_sub(serialized_registry_record.len())
+            .checked_sub(registry_record_size)
             .ok_or(MplCoreError::NumericalOverflow)?
-            .checked_sub(serialized_plugin.len())
+            .checked_sub(plugin_size)
             .ok_or(MplCoreError::NumericalOverflow)?;
 
         let new_registry_offset = header
             .plugin_registry_offset
-            .checked_sub(serialized_plugin.len())
+            .checked_sub(plugin_size)
             .ok_or(MplCoreError::NumericalOverflow)?;
 
         let data_to_move = header
@@ -332,9 +508,10 @@ pub fn delete_plugin<'a, T: DataBlob>(
         // Move offsets for existing registry records.
         for record in &mut plugin_registry.registry {
             if plugin_offset < record.offset {
-                record.offset -= serialized_plugin.len()
+                record.offset -= plugin_size
             }
         }
+        plugin_registry.shift_external_plugins(next_plugin_offset, -(plugin_size as isize))?;
 
         plugin_registry.save(account, new_registry_offset)?;
 
@@ -357,19 +534,15 @@ pub fn approve_authority_on_plugin<'a, T: CoreAsset>(
     payer: &AccountInfo<'a>,
     system_program: &AccountInfo<'a>,
 ) -> ProgramResult {
-    let registry_record = &mut plugin_registry
-        .registry
-        .iter_mut()
-        .find(|record| record.plugin_type == *plugin_type)
+    let registry_record = plugin_registry
+        .find_mut(*plugin_type)
         .ok_or(MplCoreError::PluginNotFound)?;
 
     registry_record.authority = *new_authority;
 
-    let authority_bytes = new_authority.try_to_vec()?;
-
     let new_size = account
         .data_len()
-        .checked_add(authority_bytes.len())
+        .checked_add(new_authority.get_size())
         .ok_or(MplCoreError::NumericalOverflow)?;
     resize_or_reallocate_account(account, payer, system_program, new_size)?;
 
@@ -388,18 +561,15 @@ pub fn revoke_authority_on_plugin<'a>(
     payer: &AccountInfo<'a>,
     system_program: &AccountInfo<'a>,
 ) -> ProgramResult {
-    let registry_record = &mut plugin_registry
-        .registry
-        .iter_mut()
-        .find(|record| record.plugin_type == *plugin_type)
+    let registry_record = plugin_registry
+        .find_mut(*plugin_type)
         .ok_or(MplCoreError::PluginNotFound)?;
 
-    let old_authority_bytes = registry_record.authority.try_to_vec()?;
+    let old_authority_size = registry_record.authority.get_size();
     registry_record.authority = registry_record.plugin_type.manager();
-    let new_authority_bytes = registry_record.authority.try_to_vec()?;
 
-    let size_diff = (new_authority_bytes.len() as isize)
-        .checked_sub(old_authority_bytes.len() as isize)
+    let size_diff = (registry_record.authority.get_size() as isize)
+        .checked_sub(old_authority_size as isize)
         .ok_or(MplCoreError::NumericalOverflow)?;
 
     let new_size = (account.data_len() as isize)
@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{error::MplCoreError, state::DataBlob};
+
+use super::{Plugin, PluginValidation, PluginValidationContext, ValidationResult};
+
+/// A creator of an asset and whether they have verified their attribution.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct VerifiedCreatorsSignature {
+    /// The address of the creator.
+    pub address: Pubkey, // 32
+    /// Whether or not the creator has verified the asset.
+    pub verified: bool, // 1
+}
+
+/// The verified creators plugin lists the creators of an asset.
+/// A creator can only be verified or unverified by the creator themselves, so the
+/// attribution of verified creators can be trusted.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct VerifiedCreators {
+    /// A list of creators of the asset.
+    pub signatures: Vec<VerifiedCreatorsSignature>, // 4
+}
+
+impl VerifiedCreators {
+    /// Initialize the VerifiedCreators plugin with no creators.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn is_verified(&self, address: &Pubkey) -> bool {
+        self.signatures
+            .iter()
+            .any(|signature| signature.address == *address && signature.verified)
+    }
+}
+
+impl DataBlob for VerifiedCreators {
+    fn get_initial_size() -> usize {
+        4
+    }
+
+    fn get_size(&self) -> usize {
+        4 + self.signatures.len() * 33
+    }
+}
+
+/// Validate the creators of the plugin, rejecting any change to the verification of a
+/// creator other than the current authority.
+fn validate_verified_creators(
+    current: Option<&VerifiedCreators>,
+    new: &VerifiedCreators,
+    ctx: &PluginValidationContext,
+) -> Result<ValidationResult, ProgramError> {
+    // Each creator can only be listed once.
+    let mut seen_addresses = HashSet::new();
+    if !new
+        .signatures
+        .iter()
+        .all(|signature| seen_addresses.insert(signature.address))
+    {
+        return Err(MplCoreError::InvalidPluginSetting.into());
+    }
+
+    let empty = VerifiedCreators::new();
+    let current = current.unwrap_or(&empty);
+    let signer = ctx.authority_info.key;
+
+    // Creators whose verification differs between the current and new plugin.
+    let mut changed = current
+        .signatures
+        .iter()
+        .chain(new.signatures.iter())
+        .filter(|signature| {
+            current.is_verified(&signature.address) != new.is_verified(&signature.address)
+        })
+        .map(|signature| signature.address);
+    if changed.any(|address| address != *signer) {
+        solana_program::msg!("VerifiedCreators: Rejected");
+        return Ok(ValidationResult::Rejected);
+    }
+
+    // A creator changing only their own verification does not need the plugin authority.
+    let same_creators = current.signatures.len() == new.signatures.len()
+        && current
+            .signatures
+            .iter()
+            .zip(new.signatures.iter())
+            .all(|(a, b)| {
+                a.address == b.address && (a.verified == b.verified || a.address == *signer)
+            });
+    if same_creators && current != new {
+        debug_msg!("VerifiedCreators: Approved");
+        Ok(ValidationResult::Approved)
+    } else {
+        Ok(ValidationResult::Pass)
+    }
+}
+
+impl PluginValidation for VerifiedCreators {
+    fn validate_create(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        validate_verified_creators(None, self, ctx)
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::VerifiedCreators(verified_creators)) => {
+                validate_verified_creators(None, verified_creators, ctx)
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::VerifiedCreators(verified_creators)) => {
+                validate_verified_creators(Some(self), verified_creators, ctx)
+            }
+            _ => Ok(ValidationResult::Pass),
+        }
+    }
+}
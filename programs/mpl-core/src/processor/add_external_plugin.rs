@@ -0,0 +1,173 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::{AddCollectionExternalPluginV1Accounts, AddExternalPluginV1Accounts},
+    plugins::{
+        create_meta_idempotent, initialize_external_plugin, ExternalPluginInitInfo,
+        ExternalPluginType,
+    },
+    state::{AssetV1, Authority, CollectionV1, DataBlob, Key, SolanaAccount},
+    utils::{fetch_core_data, load_key, resolve_authority, resolve_pubkey_to_authorities},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct AddExternalPluginV1Args {
+    init_info: ExternalPluginInitInfo,
+}
+
+pub(crate) fn add_external_plugin<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: AddExternalPluginV1Args,
+) -> ProgramResult {
+    let ctx = AddExternalPluginV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Adding external plugin to compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    validate_init_info(&args.init_info)?;
+
+    // Linked app data is stored per asset of a collection, so it can only be added to collections.
+    if let ExternalPluginInitInfo::LinkedAppData(_) = args.init_info {
+        return Err(MplCoreError::InvalidPlugin.into());
+    }
+
+    // External plugins can only be added by the update authority.
+    let (mut asset, _, _) = fetch_core_data::<AssetV1>(ctx.accounts.asset)?;
+    if !resolve_pubkey_to_authorities(authority, ctx.accounts.collection, &asset.header())?
+        .contains(&Authority::UpdateAuthority)
+    {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    // Increment sequence number and save only if it is `Some(_)`.
+    asset.increment_seq_and_save(ctx.accounts.asset)?;
+
+    process_add_external_plugin::<AssetV1>(
+        ctx.accounts.asset,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        &args.init_info,
+    )
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct AddCollectionExternalPluginV1Args {
+    init_info: ExternalPluginInitInfo,
+}
+
+pub(crate) fn add_collection_external_plugin<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: AddCollectionExternalPluginV1Args,
+) -> ProgramResult {
+    let ctx = AddCollectionExternalPluginV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    validate_init_info(&args.init_info)?;
+
+    // Inscriptions are stored in the asset account, so they can only be added to assets.
+    if let ExternalPluginInitInfo::Inscription(_) = args.init_info {
+        return Err(MplCoreError::InvalidPlugin.into());
+    }
+
+    // External plugins can only be added by the update authority.
+    let collection = CollectionV1::load(ctx.accounts.collection, 0)?;
+    if collection.update_authority != *authority.key {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    process_add_external_plugin::<CollectionV1>(
+        ctx.accounts.collection,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        &args.init_info,
+    )
+}
+
+fn validate_init_info(init_info: &ExternalPluginInitInfo) -> ProgramResult {
+    match init_info {
+        // The program cannot hook itself.
+        ExternalPluginInitInfo::LifecycleHook(lifecycle_hook) => {
+            if lifecycle_hook.hooked_program == crate::ID {
+                return Err(MplCoreError::InvalidPluginSetting.into());
+            }
+        }
+        // An inscription cannot be created already sealed.
+        ExternalPluginInitInfo::Inscription(inscription) => {
+            if inscription.inscription_authority == Authority::None {
+                return Err(MplCoreError::InvalidPluginSetting.into());
+            }
+        }
+        ExternalPluginInitInfo::AppData(_) | ExternalPluginInitInfo::LinkedAppData(_) => (),
+    }
+
+    Ok(())
+}
+
+fn process_add_external_plugin<'a, T: DataBlob + SolanaAccount>(
+    account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    init_info: &ExternalPluginInitInfo,
+) -> ProgramResult {
+    let (_, mut plugin_header, mut plugin_registry) =
+        create_meta_idempotent::<T>(account, payer, system_program)?;
+
+    // App data and inscriptions are identified by their authority, so each authority can only
+    // have one.
+    if matches!(
+        init_info.plugin_type(),
+        ExternalPluginType::AppData
+            | ExternalPluginType::LinkedAppData
+            | ExternalPluginType::Inscription
+    ) && plugin_registry.external_plugins.iter().any(|record| {
+        record.plugin_type == init_info.plugin_type() && record.authority == init_info.authority()
+    }) {
+        return Err(MplCoreError::PluginAlreadyExists.into());
+    }
+
+    initialize_external_plugin::<T>(
+        init_info.plugin_type(),
+        &init_info.authority(),
+        init_info.schema(),
+        &init_info.data()?,
+        &mut plugin_header,
+        &mut plugin_registry,
+        account,
+        payer,
+        system_program,
+    )
+}
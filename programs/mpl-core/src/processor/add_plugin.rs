@@ -1,17 +1,22 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_utils::assert_signer;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+};
 
 use crate::{
     error::MplCoreError,
-    instruction::accounts::{AddCollectionPluginV1Accounts, AddPluginV1Accounts},
+    instruction::accounts::{
+        AddCollectionPluginV1Accounts, AddPluginV1Accounts, AddPluginV2Accounts,
+    },
     plugins::{
         create_meta_idempotent, initialize_plugin, Plugin, PluginType, PluginValidationContext,
         ValidationResult,
     },
-    state::{AssetV1, Authority, CollectionV1, DataBlob, Key, SolanaAccount},
+    state::{AssetHeaderV1, AssetV1, Authority, CollectionV1, DataBlob, Key, SolanaAccount},
     utils::{
-        load_key, resolve_authority, validate_asset_permissions, validate_collection_permissions,
+        collect_rent_contributions, load_key, refund_rent_contributions, resolve_authority,
+        validate_asset_permissions, validate_collection_permissions,
     },
 };
 
@@ -60,6 +65,7 @@ pub(crate) fn add_plugin<'a>(
         resolved_authorities: None,
         new_owner: None,
         target_plugin: Some(&args.plugin),
+        update_info: None,
     };
     if Plugin::validate_add_plugin(&args.plugin, &validation_ctx)? == ValidationResult::Rejected {
         return Err(MplCoreError::InvalidAuthority.into());
@@ -68,14 +74,17 @@ pub(crate) fn add_plugin<'a>(
     // Validate asset permissions.
     let (mut asset, _, _) = validate_asset_permissions(
         authority,
+        ctx.remaining_accounts,
         ctx.accounts.asset,
         ctx.accounts.collection,
         None,
         Some(&args.plugin),
+        None,
+        None,
         AssetV1::check_add_plugin,
         CollectionV1::check_add_plugin,
         PluginType::check_add_plugin,
-        AssetV1::validate_add_plugin,
+        AssetHeaderV1::validate_add_plugin,
         CollectionV1::validate_add_plugin,
         Plugin::validate_add_plugin,
     )?;
@@ -92,6 +101,52 @@ pub(crate) fn add_plugin<'a>(
     )
 }
 
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct AddPluginV2Args {
+    plugin: Plugin,
+    init_authority: Option<Authority>,
+    rent_contributions: Vec<u64>,
+}
+
+pub(crate) fn add_plugin_v2<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: AddPluginV2Args,
+) -> ProgramResult {
+    let ctx = AddPluginV2Accounts::context(accounts)?;
+
+    // The funders are the first remaining accounts.
+    let funders = ctx
+        .remaining_accounts
+        .get(..args.rent_contributions.len())
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let starting_lamports = collect_rent_contributions(
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ctx.accounts.asset,
+        funders,
+        &args.rent_contributions,
+    )?;
+
+    add_plugin(
+        accounts,
+        AddPluginV1Args {
+            plugin: args.plugin,
+            init_authority: args.init_authority,
+        },
+    )?;
+
+    refund_rent_contributions(
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ctx.accounts.asset,
+        funders,
+        &args.rent_contributions,
+        starting_lamports,
+    )
+}
+
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub(crate) struct AddCollectionPluginV1Args {
@@ -125,6 +180,7 @@ pub(crate) fn add_collection_plugin<'a>(
         resolved_authorities: None,
         new_owner: None,
         target_plugin: Some(&args.plugin),
+        update_info: None,
     };
     if Plugin::validate_add_plugin(&args.plugin, &validation_context)? == ValidationResult::Rejected
     {
@@ -139,8 +195,10 @@ pub(crate) fn add_collection_plugin<'a>(
     // Validate collection permissions.
     let _ = validate_collection_permissions(
         authority,
+        ctx.remaining_accounts,
         ctx.accounts.collection,
         Some(&args.plugin),
+        None,
         CollectionV1::check_add_plugin,
         PluginType::check_add_plugin,
         CollectionV1::validate_add_plugin,
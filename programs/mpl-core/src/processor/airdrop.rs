@@ -0,0 +1,201 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    msg,
+    program::invoke,
+    pubkey::Pubkey,
+    system_instruction, system_program,
+};
+use std::collections::BTreeSet;
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::AirdropV1Accounts,
+    state::{
+        AssetV1, BatchItemResult, BatchItemStatus, CollectionV1, Key, SolanaAccount,
+        UpdateAuthority, SPL_ASSOCIATED_TOKEN_PROGRAM_ID, SPL_TOKEN_2022_PROGRAM_ID,
+        SPL_TOKEN_PROGRAM_ID,
+    },
+    utils::{emit_batch_results, load_key, resolve_authority},
+};
+
+/// The SPL Token `TransferChecked` instruction.
+const SPL_TOKEN_TRANSFER_CHECKED: u8 = 12;
+
+/// The offset of the decimals in an SPL Token mint, after its optional mint authority and supply.
+const MINT_DECIMALS_OFFSET: usize = 44;
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct AirdropV1Args {
+    pub amount_per_asset: u64,
+}
+
+pub(crate) fn airdrop<'a>(accounts: &'a [AccountInfo<'a>], args: AirdropV1Args) -> ProgramResult {
+    // Accounts.
+    let ctx = AirdropV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.vault)?;
+    let authority = resolve_authority(ctx.accounts.vault, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if ctx.accounts.collection.owner != &crate::ID {
+        return Err(MplCoreError::InvalidCollection.into());
+    }
+    let collection = CollectionV1::load(ctx.accounts.collection, 0)?;
+    if authority.key != &collection.update_authority {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    // Tokens are distributed instead of lamports when a mint is passed, together with the vault
+    // token account holding them and the token program owning the mint.
+    let token_accounts = match (
+        ctx.accounts.mint,
+        ctx.accounts.vault_token_account,
+        ctx.accounts.token_program,
+    ) {
+        (None, None, None) => None,
+        (Some(mint), Some(vault_token_account), Some(token_program)) => {
+            if token_program.key != &SPL_TOKEN_PROGRAM_ID
+                && token_program.key != &SPL_TOKEN_2022_PROGRAM_ID
+            {
+                return Err(MplCoreError::IncorrectAccount.into());
+            }
+
+            if mint.owner != token_program.key {
+                return Err(MplCoreError::IncorrectAccount.into());
+            }
+
+            let decimals = *mint
+                .data
+                .borrow()
+                .get(MINT_DECIMALS_OFFSET)
+                .ok_or(MplCoreError::IncorrectAccount)?;
+            Some((mint, vault_token_account, token_program, decimals))
+        }
+        _ => return Err(MplCoreError::IncorrectAccount.into()),
+    };
+
+    // The remaining accounts are pairs of member assets and their current owners, each followed by
+    // the associated token account of the owner when tokens are distributed, so owners holding
+    // several assets receive a share for each of them.
+    let item_len = if token_accounts.is_some() { 3 } else { 2 };
+    if ctx.remaining_accounts.len() % item_len != 0 {
+        return Err(MplCoreError::IncorrectAccount.into());
+    }
+
+    let mut seen_assets = BTreeSet::new();
+    let mut results = Vec::with_capacity(ctx.remaining_accounts.len() / item_len);
+    for (index, item) in ctx.remaining_accounts.chunks(item_len).enumerate() {
+        let (asset_info, owner_info) = (&item[0], &item[1]);
+
+        if !seen_assets.insert(asset_info.key) {
+            return Err(MplCoreError::IncorrectAccount.into());
+        }
+
+        // Assets burned or reaped since the list of assets was taken are skipped.
+        if asset_info.data_is_empty()
+            || (asset_info.owner == &crate::ID && load_key(asset_info, 0)? == Key::Uninitialized)
+        {
+            results.push(BatchItemResult {
+                index: index as u32,
+                asset: *asset_info.key,
+                status: BatchItemStatus::Skipped,
+            });
+            continue;
+        }
+
+        if asset_info.owner != &crate::ID {
+            return Err(MplCoreError::IncorrectAccount.into());
+        }
+
+        if load_key(asset_info, 0)? != Key::AssetV1 {
+            msg!("Error: Airdrop to compressed is not available");
+            return Err(MplCoreError::NotAvailable.into());
+        }
+
+        let asset = AssetV1::load(asset_info, 0)?;
+        if asset.update_authority != UpdateAuthority::Collection(*ctx.accounts.collection.key) {
+            return Err(MplCoreError::InvalidCollection.into());
+        }
+
+        if asset.owner != *owner_info.key {
+            return Err(MplCoreError::InvalidOwner.into());
+        }
+
+        match token_accounts {
+            Some((mint, vault_token_account, token_program, decimals)) => {
+                let owner_token_account = &item[2];
+                let (expected_token_account, _) = Pubkey::find_program_address(
+                    &[
+                        owner_info.key.as_ref(),
+                        token_program.key.as_ref(),
+                        mint.key.as_ref(),
+                    ],
+                    &SPL_ASSOCIATED_TOKEN_PROGRAM_ID,
+                );
+                if owner_token_account.key != &expected_token_account {
+                    return Err(MplCoreError::IncorrectAccount.into());
+                }
+
+                let mut data = vec![SPL_TOKEN_TRANSFER_CHECKED];
+                data.extend_from_slice(&args.amount_per_asset.to_le_bytes());
+                data.push(decimals);
+
+                invoke(
+                    &Instruction {
+                        program_id: *token_program.key,
+                        accounts: vec![
+                            AccountMeta::new(*vault_token_account.key, false),
+                            AccountMeta::new_readonly(*mint.key, false),
+                            AccountMeta::new(*owner_token_account.key, false),
+                            AccountMeta::new_readonly(*ctx.accounts.vault.key, true),
+                        ],
+                        data,
+                    },
+                    &[
+                        vault_token_account.clone(),
+                        mint.clone(),
+                        owner_token_account.clone(),
+                        ctx.accounts.vault.clone(),
+                        token_program.clone(),
+                    ],
+                )?;
+            }
+            None => invoke(
+                &system_instruction::transfer(
+                    ctx.accounts.vault.key,
+                    owner_info.key,
+                    args.amount_per_asset,
+                ),
+                &[
+                    ctx.accounts.vault.clone(),
+                    owner_info.clone(),
+                    ctx.accounts.system_program.clone(),
+                ],
+            )?,
+        }
+
+        results.push(BatchItemResult {
+            index: index as u32,
+            asset: *asset_info.key,
+            status: BatchItemStatus::Success,
+        });
+    }
+
+    // The airdrop is all or nothing, so every item that is reported succeeded or was skipped.
+    emit_batch_results(&results, ctx.accounts.log_wrapper)
+}
@@ -0,0 +1,81 @@
+use mpl_utils::assert_signer;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::ApproveCollectionJoinV1Accounts,
+    plugins::{fetch_plugin, CollectionJoinRequest, MintLimit, Plugin, PluginType},
+    state::{AssetV1, CollectionV1, Key, SolanaAccount, UpdateAuthority},
+    utils::{load_key, resolve_authority},
+};
+
+pub(crate) fn approve_collection_join<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = ApproveCollectionJoinV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Approve collection join for compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    if ctx.accounts.collection.owner != &crate::ID {
+        return Err(MplCoreError::InvalidCollection.into());
+    }
+    let mut collection = CollectionV1::load(ctx.accounts.collection, 0)?;
+    if authority.key != &collection.update_authority {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    let mut asset = AssetV1::load(ctx.accounts.asset, 0)?;
+    let (_, mut request, offset) = fetch_plugin::<AssetV1, CollectionJoinRequest>(
+        ctx.accounts.asset,
+        PluginType::CollectionJoinRequest,
+    )?;
+
+    if request.collection != *ctx.accounts.collection.key || request.is_approved() {
+        return Err(MplCoreError::InvalidCollection.into());
+    }
+
+    // The request is only valid as long as the requester is still the update authority.
+    match asset.update_authority {
+        UpdateAuthority::Address(address) if address == request.requester => (),
+        _ => return Err(MplCoreError::InvalidAuthority.into()),
+    }
+
+    // Record the approval, the request does not change size so it can be saved in place.
+    request.approver = *authority.key;
+    Plugin::CollectionJoinRequest(request).save(ctx.accounts.asset, offset)?;
+
+    // Both update authority variants are the same size so the asset can be saved in place.
+    asset.update_authority = UpdateAuthority::Collection(*ctx.accounts.collection.key);
+    if let Some(seq) = &mut asset.seq {
+        *seq = seq.saturating_add(1);
+    }
+    asset.save(ctx.accounts.asset, 0)?;
+
+    collection.current_size = collection
+        .current_size
+        .checked_add(1)
+        .ok_or(MplCoreError::NumericalOverflowError)?;
+    collection.save(ctx.accounts.collection, 0)?;
+
+    // Count the joining asset against the maximum supply of the collection.
+    if let Ok((_, mut mint_limit, offset)) =
+        fetch_plugin::<CollectionV1, MintLimit>(ctx.accounts.collection, PluginType::MintLimit)
+    {
+        mint_limit.record_mint()?;
+        Plugin::MintLimit(mint_limit).save(ctx.accounts.collection, offset)?;
+    }
+
+    Ok(())
+}
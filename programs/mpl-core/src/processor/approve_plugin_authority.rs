@@ -8,9 +8,11 @@ use crate::{
         ApproveCollectionPluginAuthorityV1Accounts, ApprovePluginAuthorityV1Accounts,
     },
     plugins::{approve_authority_on_plugin, fetch_wrapped_plugin, Plugin, PluginType},
-    state::{AssetV1, Authority, CollectionV1, CoreAsset, DataBlob, Key, SolanaAccount},
+    state::{
+        AssetHeaderV1, AssetV1, Authority, CollectionV1, CoreAsset, DataBlob, Key, SolanaAccount,
+    },
     utils::{
-        fetch_core_data, load_key, resolve_authority, validate_asset_permissions,
+        fetch_core_registry, load_key, resolve_authority, validate_asset_permissions,
         validate_collection_permissions,
     },
 };
@@ -52,14 +54,17 @@ pub(crate) fn approve_plugin_authority<'a>(
     // Validate asset permissions.
     let (mut asset, _, _) = validate_asset_permissions(
         authority,
+        ctx.remaining_accounts,
         ctx.accounts.asset,
         ctx.accounts.collection,
         None,
         Some(&plugin),
+        None,
+        None,
         AssetV1::check_approve_plugin_authority,
         CollectionV1::check_approve_plugin_authority,
         PluginType::check_approve_plugin_authority,
-        AssetV1::validate_approve_plugin_authority,
+        AssetHeaderV1::validate_approve_plugin_authority,
         CollectionV1::validate_approve_plugin_authority,
         Plugin::validate_approve_plugin_authority,
     )?;
@@ -109,8 +114,10 @@ pub(crate) fn approve_collection_plugin_authority<'a>(
     // Validate collection permissions.
     let _ = validate_collection_permissions(
         authority,
+        ctx.remaining_accounts,
         ctx.accounts.collection,
         Some(&plugin),
+        None,
         CollectionV1::check_approve_plugin_authority,
         PluginType::check_approve_plugin_authority,
         CollectionV1::validate_approve_plugin_authority,
@@ -133,7 +140,7 @@ fn process_approve_plugin_authority<'a, T: CoreAsset + DataBlob + SolanaAccount>
     plugin_type: &PluginType,
     new_authority: &Authority,
 ) -> ProgramResult {
-    let (_, plugin_header, plugin_registry) = fetch_core_data::<T>(core_info)?;
+    let (plugin_header, plugin_registry) = fetch_core_registry::<T>(core_info)?;
 
     let plugin_header = match plugin_header {
         Some(header) => header,
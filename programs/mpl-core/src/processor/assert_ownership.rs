@@ -0,0 +1,94 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::AssertOwnershipV1Accounts,
+    plugins::{Plugin, PluginType},
+    state::{AssetV1, CollectionV1, DataBlob, Key, SolanaAccount, UpdateAuthority},
+    utils::{fetch_core_registry, load_key},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct AssertOwnershipV1Args {
+    pub require_unfrozen: bool,
+}
+
+pub(crate) fn assert_ownership<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: AssertOwnershipV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = AssertOwnershipV1Accounts::context(accounts)?;
+
+    // Guards.
+    if ctx.accounts.asset.owner != &crate::ID {
+        return Err(MplCoreError::IncorrectAccount.into());
+    }
+
+    match load_key(ctx.accounts.asset, 0)? {
+        Key::AssetV1 => (),
+        Key::HashedAssetV1 => {
+            msg!("Error: Assert ownership for compressed is not available");
+            return Err(MplCoreError::NotAvailable.into());
+        }
+        _ => return Err(MplCoreError::IncorrectAccount.into()),
+    }
+
+    let asset = AssetV1::load(ctx.accounts.asset, 0)?;
+    if asset.owner != *ctx.accounts.owner.key {
+        return Err(MplCoreError::InvalidOwner.into());
+    }
+
+    if !args.require_unfrozen {
+        return Ok(());
+    }
+
+    if is_frozen::<AssetV1>(ctx.accounts.asset)? {
+        return Err(MplCoreError::AssetIsFrozen.into());
+    }
+
+    // Freeze plugins on the collection apply to the asset as well.
+    if let UpdateAuthority::Collection(collection_address) = asset.update_authority {
+        let collection = ctx
+            .accounts
+            .collection
+            .ok_or(MplCoreError::MissingCollection)?;
+        if collection.key != &collection_address {
+            return Err(MplCoreError::InvalidCollection.into());
+        }
+
+        if is_frozen::<CollectionV1>(collection)? {
+            return Err(MplCoreError::AssetIsFrozen.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Check the freeze plugins of an account without validating any other plugins.
+fn is_frozen<T: DataBlob + SolanaAccount>(account: &AccountInfo) -> Result<bool, ProgramError> {
+    let (_, plugin_registry) = fetch_core_registry::<T>(account)?;
+    let registry = match plugin_registry {
+        Some(plugin_registry) => plugin_registry.registry,
+        None => return Ok(false),
+    };
+
+    for record in registry.iter().filter(|record| {
+        matches!(
+            record.plugin_type,
+            PluginType::FreezeDelegate | PluginType::PermanentFreezeDelegate
+        )
+    }) {
+        match Plugin::load(account, record.offset)? {
+            Plugin::FreezeDelegate(freeze) if freeze.frozen => return Ok(true),
+            Plugin::PermanentFreezeDelegate(freeze) if freeze.frozen => return Ok(true),
+            _ => (),
+        }
+    }
+
+    Ok(false)
+}
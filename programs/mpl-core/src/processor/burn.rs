@@ -1,15 +1,20 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_utils::assert_signer;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+};
 
 use crate::{
     error::MplCoreError,
     instruction::accounts::{BurnCollectionV1Accounts, BurnV1Accounts},
-    plugins::{Plugin, PluginType},
-    state::{AssetV1, CollectionV1, CompressionProof, Key, SolanaAccount, Wrappable},
+    plugins::{fetch_plugin, BurnRentDestination, HookableLifecycleEvent, Plugin, PluginType},
+    state::{
+        AssetHeaderV1, AssetV1, CollectionV1, CompressionProof, Key, SolanaAccount, Wrappable,
+    },
     utils::{
         close_program_account, load_key, rebuild_account_state_from_proof_data, resolve_authority,
-        validate_asset_permissions, validate_collection_permissions, verify_proof,
+        resolve_compression_proof, validate_asset_permissions, validate_collection_permissions,
+        validate_tx_guard,
     },
 };
 
@@ -45,22 +50,22 @@ pub(crate) fn burn<'a>(accounts: &'a [AccountInfo<'a>], args: BurnV1Args) -> Pro
 
     match load_key(ctx.accounts.asset, 0)? {
         Key::HashedAssetV1 => {
-            let mut compression_proof = args
-                .compression_proof
-                .ok_or(MplCoreError::MissingCompressionProof)?;
-
             let system_program = ctx
                 .accounts
                 .system_program
                 .ok_or(MplCoreError::MissingSystemProgram)?;
 
-            // Verify the proof and rebuild Asset struct in account space.
-            let (asset, plugins) = verify_proof(ctx.accounts.asset, &compression_proof)?;
+            // Verify the proof, or use the staged proof, and rebuild Asset struct in account space.
+            let (asset, plugins) = resolve_compression_proof(
+                ctx.accounts.asset,
+                args.compression_proof,
+                ctx.remaining_accounts,
+            )?;
 
             // Use the data from the compression proof to rebuild the account.  Only needed for validation.
             rebuild_account_state_from_proof_data(
-                asset,
-                plugins,
+                &asset,
+                &plugins,
                 ctx.accounts.asset,
                 ctx.accounts.payer,
                 system_program,
@@ -69,10 +74,10 @@ pub(crate) fn burn<'a>(accounts: &'a [AccountInfo<'a>], args: BurnV1Args) -> Pro
             // Increment sequence number for the spl-noop event.  Note we don't care about the
             // sequence number in account state because we are closing the account later in this
             // instruction.
-            compression_proof.seq = compression_proof.seq.saturating_add(1);
+            let seq = asset.seq.unwrap_or(0).saturating_add(1);
 
             // Send the spl-noop event for indexing the compressed asset.
-            compression_proof.wrap()?;
+            CompressionProof::new(asset, seq, plugins).wrap()?;
 
             // TODO Enable compressed burn.
             msg!("Error: Burning compressed is currently not available");
@@ -85,19 +90,31 @@ pub(crate) fn burn<'a>(accounts: &'a [AccountInfo<'a>], args: BurnV1Args) -> Pro
     // Validate asset permissions.
     let _ = validate_asset_permissions(
         authority,
+        ctx.remaining_accounts,
         ctx.accounts.asset,
         ctx.accounts.collection,
         None,
         None,
+        None,
+        Some(HookableLifecycleEvent::Burn),
         AssetV1::check_burn,
         CollectionV1::check_burn,
         PluginType::check_burn,
-        AssetV1::validate_burn,
+        AssetHeaderV1::validate_burn,
         CollectionV1::validate_burn,
         Plugin::validate_burn,
     )?;
 
-    process_burn(ctx.accounts.asset, authority)?;
+    validate_tx_guard(
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        ctx.remaining_accounts,
+    )?;
+
+    let rent_destination =
+        resolve_burn_rent_destination(authority, ctx.accounts.collection, ctx.remaining_accounts)?;
+
+    process_burn(ctx.accounts.asset, rent_destination)?;
     if let Some(mut collection) = collection {
         collection.decrement()?;
         collection.save(ctx.accounts.collection.unwrap(), 0)?;
@@ -130,8 +147,10 @@ pub(crate) fn burn_collection<'a>(
     // Validate collection permissions.
     let _ = validate_collection_permissions(
         authority,
+        ctx.remaining_accounts,
         ctx.accounts.collection,
         None,
+        None,
         CollectionV1::check_burn,
         PluginType::check_burn,
         CollectionV1::validate_burn,
@@ -141,6 +160,30 @@ pub(crate) fn burn_collection<'a>(
     process_burn(ctx.accounts.collection, authority)
 }
 
-fn process_burn<'a>(core_info: &AccountInfo<'a>, authority: &AccountInfo<'a>) -> ProgramResult {
-    close_program_account(core_info, authority)
+/// The collection may require the rent to be returned to a fixed destination, which must be
+/// passed in the remaining accounts.  Otherwise the rent is returned to the authority.
+pub(crate) fn resolve_burn_rent_destination<'a, 'b>(
+    authority: &'b AccountInfo<'a>,
+    collection: Option<&AccountInfo<'a>>,
+    remaining_accounts: &'b [AccountInfo<'a>],
+) -> Result<&'b AccountInfo<'a>, ProgramError> {
+    match collection.map(|collection| {
+        fetch_plugin::<CollectionV1, BurnRentDestination>(
+            collection,
+            PluginType::BurnRentDestination,
+        )
+    }) {
+        Some(Ok((_, burn_rent_destination, _))) => Ok(remaining_accounts
+            .iter()
+            .find(|account_info| account_info.key == &burn_rent_destination.destination)
+            .ok_or(MplCoreError::MissingBurnRentDestination)?),
+        _ => Ok(authority),
+    }
+}
+
+pub(crate) fn process_burn<'a>(
+    core_info: &AccountInfo<'a>,
+    rent_destination: &AccountInfo<'a>,
+) -> ProgramResult {
+    close_program_account(core_info, rent_destination)
 }
@@ -0,0 +1,111 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, sysvar};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::BurnCompressedV1Accounts,
+    plugins::{HookableLifecycleEvent, Plugin, PluginType},
+    state::{
+        AssetHeaderV1, AssetV1, CollectionV1, CompressionProof, Key, SolanaAccount, Wrappable,
+    },
+    utils::{
+        load_key, resolve_authority, resolve_compression_proof,
+        validate_compressed_asset_permissions, validate_tx_guard,
+    },
+};
+
+use super::{process_burn, resolve_burn_rent_destination};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct BurnCompressedV1Args {
+    /// The compression proof of the asset, or `None` to use its staged proof.
+    pub compression_proof: Option<CompressionProof>,
+}
+
+pub(crate) fn burn_compressed<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: BurnCompressedV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = BurnCompressedV1Accounts::context(accounts)?;
+    let collection = ctx
+        .accounts
+        .collection
+        .map(|collection_info| CollectionV1::load(collection_info, 0))
+        .transpose()?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if load_key(ctx.accounts.asset, 0)? != Key::HashedAssetV1 {
+        return Err(MplCoreError::IncorrectAccount.into());
+    }
+
+    // Verify the proof, or use the staged proof.
+    let (asset, plugins) = resolve_compression_proof(
+        ctx.accounts.asset,
+        args.compression_proof,
+        ctx.remaining_accounts,
+    )?;
+
+    validate_compressed_asset_permissions(
+        authority,
+        ctx.remaining_accounts,
+        ctx.accounts.asset,
+        &asset,
+        &plugins,
+        ctx.accounts.collection,
+        None,
+        Some(HookableLifecycleEvent::Burn),
+        AssetV1::check_burn,
+        CollectionV1::check_burn,
+        PluginType::check_burn,
+        AssetHeaderV1::validate_burn,
+        CollectionV1::validate_burn,
+        Plugin::validate_burn,
+    )?;
+
+    // The transaction guard of the asset is read from the proof, and that of its collection from
+    // account space.
+    validate_tx_guard(
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        ctx.remaining_accounts,
+    )?;
+    for plugin in &plugins {
+        if let Plugin::TxGuard(tx_guard) = &plugin.plugin {
+            let instructions_sysvar = ctx
+                .remaining_accounts
+                .iter()
+                .find(|account_info| account_info.key == &sysvar::instructions::ID)
+                .ok_or(MplCoreError::MissingInstructionsSysvar)?;
+            tx_guard.check_transaction(instructions_sysvar)?;
+        }
+    }
+
+    // Send the spl-noop event for indexing the burned compressed asset.
+    if ctx.accounts.log_wrapper.is_some() {
+        let seq = asset.seq.unwrap_or(0).saturating_add(1);
+        CompressionProof::new(asset, seq, plugins).wrap()?;
+    }
+
+    let rent_destination =
+        resolve_burn_rent_destination(authority, ctx.accounts.collection, ctx.remaining_accounts)?;
+
+    process_burn(ctx.accounts.asset, rent_destination)?;
+    if let (Some(mut collection), Some(collection_info)) = (collection, ctx.accounts.collection) {
+        collection.decrement()?;
+        collection.save(collection_info, 0)?;
+    }
+
+    Ok(())
+}
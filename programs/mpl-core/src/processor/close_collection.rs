@@ -0,0 +1,59 @@
+use mpl_utils::assert_signer;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, rent::Rent, sysvar::Sysvar,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::CloseCollectionV1Accounts,
+    state::{CollectionV1, SolanaAccount},
+    utils::{close_program_account, resolve_authority},
+};
+
+pub(crate) fn close_collection<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = CloseCollectionV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if ctx.accounts.collection.owner != &crate::ID {
+        return Err(MplCoreError::InvalidCollection.into());
+    }
+    let collection = CollectionV1::load(ctx.accounts.collection, 0)?;
+    if authority.key != &collection.update_authority {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    // Only a collection without any assets can be closed.
+    if collection.current_size != 0 {
+        msg!(
+            "Error: Collection still has {} assets",
+            collection.current_size
+        );
+        return Err(MplCoreError::CollectionNotEmpty.into());
+    }
+
+    close_program_account(ctx.accounts.collection, authority)?;
+
+    // Any lamports held beyond the rent, such as an unspent Reaper pool, are refunded as well.
+    let excess = ctx
+        .accounts
+        .collection
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(1));
+    **ctx.accounts.collection.try_borrow_mut_lamports()? -= excess;
+    **authority.try_borrow_mut_lamports()? = authority
+        .lamports()
+        .checked_add(excess)
+        .ok_or(MplCoreError::NumericalOverflowError)?;
+
+    Ok(())
+}
@@ -6,7 +6,7 @@ use crate::{
     error::MplCoreError,
     instruction::accounts::CompressV1Accounts,
     plugins::{Plugin, PluginType},
-    state::{AssetV1, CollectionV1, Key, Wrappable},
+    state::{AssetHeaderV1, AssetV1, CollectionV1, Key, Wrappable},
     utils::{
         compress_into_account_space, fetch_core_data, load_key, resolve_authority,
         validate_asset_permissions,
@@ -45,14 +45,17 @@ pub(crate) fn compress<'a>(
             // Validate asset permissions.
             let _ = validate_asset_permissions(
                 authority,
+                ctx.remaining_accounts,
                 ctx.accounts.asset,
                 ctx.accounts.collection,
                 None,
                 None,
+                None,
+                None,
                 AssetV1::check_compress,
                 CollectionV1::check_compress,
                 PluginType::check_compress,
-                AssetV1::validate_compress,
+                AssetHeaderV1::validate_compress,
                 CollectionV1::validate_compress,
                 Plugin::validate_compress,
             )?;
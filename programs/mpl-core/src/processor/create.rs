@@ -2,18 +2,26 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_utils::assert_signer;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program::invoke,
-    program_memory::sol_memcpy, rent::Rent, system_instruction, system_program, sysvar::Sysvar,
+    program_error::ProgramError, program_memory::sol_memcpy, rent::Rent, system_instruction,
+    system_program, sysvar::Sysvar,
 };
 
 use crate::{
     error::MplCoreError,
-    instruction::accounts::CreateV1Accounts,
+    instruction::accounts::{CreateV1Accounts, CreateV2Accounts},
     plugins::{
-        create_plugin_meta, initialize_plugin, CheckResult, Plugin, PluginAuthorityPair,
-        PluginType, PluginValidationContext, ValidationResult,
+        create_plugin_meta, fetch_plugin, initialize_plugin, CheckResult, MintLimit, Paused,
+        Plugin, PluginAuthorityPair, PluginType, PluginValidationContext, PolicyPreset, Provenance,
+        Reaper, ValidationResult,
+    },
+    state::{
+        AssetV1, Authority, CollectionV1, DataState, MintReferral, SolanaAccount, UpdateAuthority,
+        Wrappable, COLLECT_AMOUNT,
+    },
+    utils::{
+        collect_rent_contributions, fetch_fee_config, pay_fee, refund_rent_contributions,
+        rejection_error, resolve_authority, split_fee_config,
     },
-    state::{AssetV1, CollectionV1, DataState, SolanaAccount, UpdateAuthority, COLLECT_AMOUNT},
-    utils::resolve_authority,
 };
 
 #[repr(C)]
@@ -26,53 +34,154 @@ pub(crate) struct CreateV1Args {
 }
 
 pub(crate) fn create<'a>(accounts: &'a [AccountInfo<'a>], args: CreateV1Args) -> ProgramResult {
-    // Accounts.
     let ctx = CreateV1Accounts::context(accounts)?;
+
+    // The optional referrer is the first remaining account, and the fee config the last.
+    let (fee_config, remaining_accounts) = split_fee_config(ctx.remaining_accounts);
+    process_create(ctx.accounts, args, remaining_accounts.first(), fee_config)
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct CreateV2Args {
+    pub(crate) data_state: DataState,
+    pub(crate) name: String,
+    pub(crate) uri: String,
+    pub(crate) plugins: Option<Vec<PluginAuthorityPair>>,
+    pub(crate) rent_contributions: Vec<u64>,
+    pub(crate) preset: Option<PolicyPreset>,
+}
+
+pub(crate) fn create_v2<'a>(accounts: &'a [AccountInfo<'a>], args: CreateV2Args) -> ProgramResult {
+    let ctx = CreateV2Accounts::context(accounts)?;
+
+    // The funders are the first remaining accounts, followed by the optional referrer and the
+    // optional fee config.
+    let funder_count = args.rent_contributions.len();
+    if ctx.remaining_accounts.len() < funder_count {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (funders, remaining_accounts) = ctx.remaining_accounts.split_at(funder_count);
+    let (fee_config, remaining_accounts) = split_fee_config(remaining_accounts);
+
+    let starting_lamports = collect_rent_contributions(
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ctx.accounts.asset,
+        funders,
+        &args.rent_contributions,
+    )?;
+
+    // The plugins of the preset are created alongside any plugins given explicitly.
+    let plugins = match args.preset {
+        Some(preset) => Some(
+            args.plugins
+                .unwrap_or_default()
+                .into_iter()
+                .chain(preset.plugins())
+                .collect(),
+        ),
+        None => args.plugins,
+    };
+
+    process_create(
+        CreateV1Accounts {
+            asset: ctx.accounts.asset,
+            collection: ctx.accounts.collection,
+            authority: ctx.accounts.authority,
+            payer: ctx.accounts.payer,
+            owner: ctx.accounts.owner,
+            update_authority: ctx.accounts.update_authority,
+            system_program: ctx.accounts.system_program,
+            log_wrapper: ctx.accounts.log_wrapper,
+        },
+        CreateV1Args {
+            data_state: args.data_state,
+            name: args.name,
+            uri: args.uri,
+            plugins,
+        },
+        remaining_accounts.first(),
+        fee_config,
+    )?;
+
+    refund_rent_contributions(
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ctx.accounts.asset,
+        funders,
+        &args.rent_contributions,
+        starting_lamports,
+    )
+}
+
+pub(crate) fn process_create<'a>(
+    accounts: CreateV1Accounts<'a>,
+    args: CreateV1Args,
+    referrer: Option<&AccountInfo<'a>>,
+    fee_config: Option<&AccountInfo<'a>>,
+) -> ProgramResult {
     let rent = Rent::get()?;
 
     // Guards.
-    assert_signer(ctx.accounts.asset)?;
-    assert_signer(ctx.accounts.payer)?;
-    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+    assert_signer(accounts.asset)?;
+    assert_signer(accounts.payer)?;
+    let authority = resolve_authority(accounts.payer, accounts.authority)?;
 
-    if *ctx.accounts.system_program.key != system_program::ID {
+    if *accounts.system_program.key != system_program::ID {
         return Err(MplCoreError::InvalidSystemProgram.into());
     }
 
-    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+    if let Some(log_wrapper) = accounts.log_wrapper {
         if log_wrapper.key != &spl_noop::ID {
             return Err(MplCoreError::InvalidLogWrapperProgram.into());
         }
     }
 
-    if ctx.accounts.update_authority.is_some() && ctx.accounts.collection.is_some() {
+    if accounts.update_authority.is_some() && accounts.collection.is_some() {
         return Err(MplCoreError::ConflictingAuthority.into());
     }
 
-    let (update_authority, collection) = match ctx.accounts.collection {
+    let (update_authority, collection) = match accounts.collection {
         Some(collection) => (
             UpdateAuthority::Collection(*collection.key),
             Some(CollectionV1::load(collection, 0)?),
         ),
         None => (
-            UpdateAuthority::Address(
-                *ctx.accounts
-                    .update_authority
-                    .unwrap_or(ctx.accounts.payer)
-                    .key,
-            ),
+            UpdateAuthority::Address(*accounts.update_authority.unwrap_or(accounts.payer).key),
             None,
         ),
     };
 
-    if update_authority.validate_create(&ctx.accounts, &args)? == ValidationResult::Rejected {
+    if update_authority.validate_create(&accounts, &args)? == ValidationResult::Rejected {
         return Err(MplCoreError::InvalidAuthority.into());
     }
 
+    // No new assets can be minted into a paused collection.
+    if let Some(collection_info) = accounts.collection {
+        if let Ok((paused_authority, paused, _)) =
+            fetch_plugin::<CollectionV1, Paused>(collection_info, PluginType::Paused)
+        {
+            let validation_ctx = PluginValidationContext {
+                self_authority: &paused_authority,
+                authority_info: authority,
+                resolved_authorities: None,
+                new_owner: None,
+                target_plugin: None,
+                update_info: None,
+            };
+            if Plugin::validate_create(&Plugin::Paused(paused), &validation_ctx)?
+                == ValidationResult::Rejected
+            {
+                return Err(rejection_error(Some(PluginType::Paused)));
+            }
+        }
+    }
+
     let new_asset = AssetV1::new(
-        *ctx.accounts
+        *accounts
             .owner
-            .unwrap_or(ctx.accounts.update_authority.unwrap_or(ctx.accounts.payer))
+            .unwrap_or(accounts.update_authority.unwrap_or(accounts.payer))
             .key,
         update_authority,
         args.name.clone(),
@@ -90,26 +199,39 @@ pub(crate) fn create<'a>(accounts: &'a [AccountInfo<'a>], args: CreateV1Args) ->
         }
     };
 
-    let lamports = rent.minimum_balance(serialized_data.len()) + COLLECT_AMOUNT;
+    // The creation fee is paid into the fee config when it is passed, and otherwise the legacy
+    // fee is left on the asset for `Collect`.
+    let lamports = match fee_config {
+        Some(fee_config_info) => {
+            pay_fee(
+                accounts.payer,
+                fee_config_info,
+                accounts.system_program,
+                fetch_fee_config(fee_config_info)?.create_fee,
+            )?;
+            rent.minimum_balance(serialized_data.len())
+        }
+        None => rent.minimum_balance(serialized_data.len()) + COLLECT_AMOUNT,
+    };
 
     // CPI to the System Program.
     invoke(
         &system_instruction::create_account(
-            ctx.accounts.payer.key,
-            ctx.accounts.asset.key,
+            accounts.payer.key,
+            accounts.asset.key,
             lamports,
             serialized_data.len() as u64,
             &crate::ID,
         ),
         &[
-            ctx.accounts.payer.clone(),
-            ctx.accounts.asset.clone(),
-            ctx.accounts.system_program.clone(),
+            accounts.payer.clone(),
+            accounts.asset.clone(),
+            accounts.system_program.clone(),
         ],
     )?;
 
     sol_memcpy(
-        &mut ctx.accounts.asset.try_borrow_mut_data()?,
+        &mut accounts.asset.try_borrow_mut_data()?,
         &serialized_data,
         serialized_data.len(),
     );
@@ -118,9 +240,9 @@ pub(crate) fn create<'a>(accounts: &'a [AccountInfo<'a>], args: CreateV1Args) ->
         if !plugins.is_empty() {
             let (mut plugin_header, mut plugin_registry) = create_plugin_meta::<AssetV1>(
                 new_asset,
-                ctx.accounts.asset,
-                ctx.accounts.payer,
-                ctx.accounts.system_program,
+                accounts.asset,
+                accounts.payer,
+                accounts.system_program,
             )?;
             let mut approved = true;
             let mut force_approved = false;
@@ -138,6 +260,7 @@ pub(crate) fn create<'a>(accounts: &'a [AccountInfo<'a>], args: CreateV1Args) ->
                         resolved_authorities: None,
                         new_owner: None,
                         target_plugin: None,
+                        update_info: None,
                     };
                     match Plugin::validate_create(&plugin.plugin, &validation_ctx)? {
                         ValidationResult::Rejected => approved = false,
@@ -150,9 +273,28 @@ pub(crate) fn create<'a>(accounts: &'a [AccountInfo<'a>], args: CreateV1Args) ->
                     &plugin.authority.unwrap_or(plugin.plugin.manager()),
                     &mut plugin_header,
                     &mut plugin_registry,
-                    ctx.accounts.asset,
-                    ctx.accounts.payer,
-                    ctx.accounts.system_program,
+                    accounts.asset,
+                    accounts.payer,
+                    accounts.system_program,
+                )?;
+            }
+
+            // Record the owner-managed plugins added at mint so the owner cannot strip them.
+            let mut provenance = Provenance::default();
+            for plugin in &plugins {
+                if plugin.plugin.manager() == Authority::Owner {
+                    provenance.insert(PluginType::from(&plugin.plugin));
+                }
+            }
+            if provenance != Provenance::default() {
+                initialize_plugin::<AssetV1>(
+                    &Plugin::Provenance(provenance),
+                    &Authority::UpdateAuthority,
+                    &mut plugin_header,
+                    &mut plugin_registry,
+                    accounts.asset,
+                    accounts.payer,
+                    accounts.system_program,
                 )?;
             }
 
@@ -163,9 +305,41 @@ pub(crate) fn create<'a>(accounts: &'a [AccountInfo<'a>], args: CreateV1Args) ->
     }
 
     if let Some(mut collection) = collection {
+        let collection_info = accounts.collection.unwrap();
         collection.increment()?;
-        collection.save(ctx.accounts.collection.unwrap(), 0)?;
+        collection.save(collection_info, 0)?;
+
+        // Count the new asset against the maximum supply of the collection.
+        if let Ok((_, mut mint_limit, offset)) =
+            fetch_plugin::<CollectionV1, MintLimit>(collection_info, PluginType::MintLimit)
+        {
+            mint_limit.record_mint()?;
+            Plugin::MintLimit(mint_limit).save(collection_info, offset)?;
+        }
+
+        // Subsidize the rent of the new asset from the pool of reaped assets.
+        if let Ok((_, mut reaper, offset)) =
+            fetch_plugin::<CollectionV1, Reaper>(collection_info, PluginType::Reaper)
+        {
+            let subsidy = reaper.take_subsidy();
+            if subsidy > 0 {
+                Plugin::Reaper(reaper).save(collection_info, offset)?;
+                **collection_info.try_borrow_mut_lamports()? -= subsidy;
+                **accounts.payer.try_borrow_mut_lamports()? += subsidy;
+            }
+        }
     };
 
+    // The referral is recorded through the spl-noop program for indexing.
+    if let (Some(referrer), Some(_)) = (referrer, accounts.log_wrapper) {
+        MintReferral {
+            asset: *accounts.asset.key,
+            collection: accounts.collection.map(|collection| *collection.key),
+            referrer: *referrer.key,
+            payer: *accounts.payer.key,
+        }
+        .wrap()?;
+    }
+
     Ok(())
 }
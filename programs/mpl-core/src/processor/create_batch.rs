@@ -0,0 +1,81 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+use std::collections::BTreeSet;
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::{CreateBatchV1Accounts, CreateV1Accounts},
+    plugins::PluginAuthorityPair,
+    state::{BatchItemResult, BatchItemStatus, DataState},
+    utils::{emit_batch_results, split_fee_config},
+};
+
+use super::{process_create, CreateV1Args};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct CreateBatchV1Item {
+    pub(crate) name: String,
+    pub(crate) uri: String,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct CreateBatchV1Args {
+    pub(crate) assets: Vec<CreateBatchV1Item>,
+    pub(crate) plugins: Option<Vec<PluginAuthorityPair>>,
+}
+
+pub(crate) fn create_batch<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: CreateBatchV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = CreateBatchV1Accounts::context(accounts)?;
+
+    // The remaining accounts are the new assets, one for each item, followed by the optional
+    // fee config.
+    let (fee_config, asset_infos) = split_fee_config(ctx.remaining_accounts);
+    if asset_infos.len() != args.assets.len() {
+        return Err(MplCoreError::IncorrectAccount.into());
+    }
+
+    let mut seen_assets = BTreeSet::new();
+    let mut results = Vec::with_capacity(args.assets.len());
+    for (index, (asset_info, item)) in asset_infos.iter().zip(args.assets).enumerate() {
+        if !seen_assets.insert(asset_info.key) {
+            return Err(MplCoreError::IncorrectAccount.into());
+        }
+
+        // Every asset is created from the same plugin template.
+        process_create(
+            CreateV1Accounts {
+                asset: asset_info,
+                collection: Some(ctx.accounts.collection),
+                authority: ctx.accounts.authority,
+                payer: ctx.accounts.payer,
+                owner: ctx.accounts.owner,
+                update_authority: None,
+                system_program: ctx.accounts.system_program,
+                log_wrapper: ctx.accounts.log_wrapper,
+            },
+            CreateV1Args {
+                data_state: DataState::AccountState,
+                name: item.name,
+                uri: item.uri,
+                plugins: args.plugins.clone(),
+            },
+            None,
+            fee_config,
+        )?;
+
+        results.push(BatchItemResult {
+            index: index as u32,
+            asset: *asset_info.key,
+            status: BatchItemStatus::Success,
+        });
+    }
+
+    // The batch is all or nothing, so every item that is reported succeeded.
+    emit_batch_results(&results, ctx.accounts.log_wrapper)
+}
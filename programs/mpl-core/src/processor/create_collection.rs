@@ -13,6 +13,7 @@ use crate::{
         PluginType, PluginValidationContext, ValidationResult,
     },
     state::{Authority, CollectionV1, Key},
+    utils::{fetch_fee_config, pay_fee, split_fee_config},
 };
 
 #[repr(C)]
@@ -56,6 +57,17 @@ pub(crate) fn create_collection<'a>(
 
     let lamports = rent.minimum_balance(serialized_data.len());
 
+    // The creation fee is only charged when the fee config is passed as a remaining account.
+    let (fee_config, _) = split_fee_config(ctx.remaining_accounts);
+    if let Some(fee_config_info) = fee_config {
+        pay_fee(
+            ctx.accounts.payer,
+            fee_config_info,
+            ctx.accounts.system_program,
+            fetch_fee_config(fee_config_info)?.create_collection_fee,
+        )?;
+    }
+
     // CPI to the System Program.
     invoke(
         &system_instruction::create_account(
@@ -108,6 +120,7 @@ pub(crate) fn create_collection<'a>(
                         resolved_authorities: None,
                         new_owner: None,
                         target_plugin: None,
+                        update_info: None,
                     };
                     match Plugin::validate_create(&plugin.plugin, &validation_ctx)? {
                         ValidationResult::Rejected => approved = false,
@@ -0,0 +1,62 @@
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::{CreateEditionV1Accounts, CreateV1Accounts},
+    plugins::{fetch_plugin, Edition, MasterEdition, Plugin, PluginAuthorityPair, PluginType},
+    state::{Authority, CollectionV1, DataState, SolanaAccount},
+    utils::split_fee_config,
+};
+
+use super::{process_create, CreateV1Args};
+
+pub(crate) fn create_edition<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = CreateEditionV1Accounts::context(accounts)?;
+
+    let collection = CollectionV1::load(ctx.accounts.collection, 0)?;
+    let (_, master_edition, _) = fetch_plugin::<CollectionV1, MasterEdition>(
+        ctx.accounts.collection,
+        PluginType::MasterEdition,
+    )?;
+
+    // Editions are numbered in mint order, so the collection is the source of truth for the
+    // next number.
+    let number = collection
+        .num_minted
+        .checked_add(1)
+        .ok_or(MplCoreError::NumericalOverflow)?;
+    if let Some(max_supply) = master_edition.max_supply {
+        if number > max_supply {
+            msg!("Error: All editions of the master edition have been printed");
+            return Err(MplCoreError::MintLimitReached.into());
+        }
+    }
+
+    let (fee_config, _) = split_fee_config(ctx.remaining_accounts);
+
+    // The edition number is immutable once printed.
+    process_create(
+        CreateV1Accounts {
+            asset: ctx.accounts.asset,
+            collection: Some(ctx.accounts.collection),
+            authority: ctx.accounts.authority,
+            payer: ctx.accounts.payer,
+            owner: ctx.accounts.owner,
+            update_authority: None,
+            system_program: ctx.accounts.system_program,
+            log_wrapper: ctx.accounts.log_wrapper,
+        },
+        CreateV1Args {
+            data_state: DataState::AccountState,
+            name: master_edition.name.unwrap_or(collection.name),
+            uri: master_edition.uri.unwrap_or(collection.uri),
+            plugins: Some(vec![PluginAuthorityPair {
+                plugin: Plugin::Edition(Edition { number }),
+                authority: Some(Authority::None),
+            }]),
+        },
+        None,
+        fee_config,
+    )
+}
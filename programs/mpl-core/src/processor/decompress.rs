@@ -6,7 +6,7 @@ use crate::{
     error::MplCoreError,
     instruction::accounts::DecompressV1Accounts,
     plugins::{Plugin, PluginType},
-    state::{AssetV1, CollectionV1, CompressionProof, Key},
+    state::{AssetHeaderV1, AssetV1, CollectionV1, CompressionProof, Key},
     utils::{
         load_key, rebuild_account_state_from_proof_data, resolve_authority,
         validate_asset_permissions, verify_proof,
@@ -43,7 +43,7 @@ pub(crate) fn decompress<'a>(
     match load_key(ctx.accounts.asset, 0)? {
         Key::HashedAssetV1 => {
             // Verify the proof and rebuild `Asset`` struct in account space.
-            let (mut asset, plugins) = verify_proof(ctx.accounts.asset, &args.compression_proof)?;
+            let (mut asset, plugins) = verify_proof(ctx.accounts.asset, args.compression_proof)?;
 
             // Increment sequence number.  Note `Asset`` will always be `Some(_)`` here
             // after rebuilding from a compression proof.
@@ -51,8 +51,8 @@ pub(crate) fn decompress<'a>(
 
             // Use the data from the compression proof to rebuild the account.
             rebuild_account_state_from_proof_data(
-                asset,
-                plugins,
+                &asset,
+                &plugins,
                 ctx.accounts.asset,
                 ctx.accounts.payer,
                 ctx.accounts.system_program,
@@ -61,14 +61,17 @@ pub(crate) fn decompress<'a>(
             // Validate asset permissions.
             let _ = validate_asset_permissions(
                 authority,
+                ctx.remaining_accounts,
                 ctx.accounts.asset,
                 ctx.accounts.collection,
                 None,
                 None,
+                None,
+                None,
                 AssetV1::check_decompress,
                 CollectionV1::check_decompress,
                 PluginType::check_decompress,
-                AssetV1::validate_decompress,
+                AssetHeaderV1::validate_decompress,
                 CollectionV1::validate_decompress,
                 Plugin::validate_decompress,
             )?;
@@ -0,0 +1,101 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    msg,
+    program::invoke_signed,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::ExecuteV1Accounts,
+    plugins::{Plugin, PluginType},
+    state::{
+        find_asset_signer_address, AssetHeaderV1, AssetV1, CollectionV1, Key, ASSET_SIGNER_PREFIX,
+    },
+    utils::{load_key, resolve_authority, validate_asset_permissions},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct ExecuteV1Args {
+    instruction_data: Vec<u8>,
+}
+
+pub(crate) fn execute<'a>(accounts: &'a [AccountInfo<'a>], args: ExecuteV1Args) -> ProgramResult {
+    // Accounts.
+    let ctx = ExecuteV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    match load_key(ctx.accounts.asset, 0)? {
+        Key::HashedAssetV1 => {
+            msg!("Error: Execute for compressed is not available");
+            return Err(MplCoreError::NotAvailable.into());
+        }
+        Key::AssetV1 => (),
+        _ => return Err(MplCoreError::IncorrectAccount.into()),
+    }
+
+    let (asset_signer, bump) = find_asset_signer_address(ctx.accounts.asset.key);
+    if ctx.accounts.asset_signer.key != &asset_signer {
+        return Err(MplCoreError::IncorrectAccount.into());
+    }
+
+    // Validate asset permissions.
+    let _ = validate_asset_permissions(
+        authority,
+        ctx.remaining_accounts,
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        None,
+        None,
+        None,
+        None,
+        AssetV1::check_execute,
+        CollectionV1::check_execute,
+        PluginType::check_execute,
+        AssetHeaderV1::validate_execute,
+        CollectionV1::validate_execute,
+        Plugin::validate_execute,
+    )?;
+
+    // The remaining accounts are the accounts of the instruction, in order, with the asset signer
+    // signing wherever it appears.
+    let accounts = ctx
+        .remaining_accounts
+        .iter()
+        .map(|account_info| AccountMeta {
+            pubkey: *account_info.key,
+            is_signer: account_info.is_signer || account_info.key == &asset_signer,
+            is_writable: account_info.is_writable,
+        })
+        .collect();
+    let instruction = Instruction {
+        program_id: *ctx.accounts.program_id.key,
+        accounts,
+        data: args.instruction_data,
+    };
+
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(ctx.accounts.asset_signer.clone());
+    account_infos.push(ctx.accounts.program_id.clone());
+
+    invoke_signed(
+        &instruction,
+        &account_infos,
+        &[&[
+            ASSET_SIGNER_PREFIX,
+            ctx.accounts.asset.key.as_ref(),
+            &[bump],
+        ]],
+    )
+}
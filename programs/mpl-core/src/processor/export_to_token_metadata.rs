@@ -0,0 +1,242 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+    sysvar,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::ExportToTokenMetadataV1Accounts,
+    plugins::{fetch_plugin, HookableLifecycleEvent, Plugin, PluginType, Royalties},
+    state::{
+        find_token_metadata_address, find_token_metadata_master_edition_address, AssetHeaderV1,
+        AssetV1, CollectionV1, Key, SolanaAccount, TokenMetadataAssetData, TokenMetadataCreator,
+        UpdateAuthority, SPL_ASSOCIATED_TOKEN_PROGRAM_ID, SPL_TOKEN_PROGRAM_ID,
+        TOKEN_METADATA_PROGRAM_ID,
+    },
+    utils::{load_key, validate_asset_permissions, validate_tx_guard},
+};
+
+use super::{process_burn, resolve_burn_rent_destination};
+
+/// The Token Metadata `CreateV1` instruction.
+const TOKEN_METADATA_CREATE: u8 = 42;
+
+/// The Token Metadata `MintV1` instruction.
+const TOKEN_METADATA_MINT: u8 = 43;
+
+/// The `NonFungible` Token Metadata token standard.
+const TOKEN_STANDARD_NON_FUNGIBLE: u8 = 0;
+
+/// The `Zero` Token Metadata print supply, so that no editions can be printed.
+const PRINT_SUPPLY_ZERO: u8 = 0;
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct ExportToTokenMetadataV1Args {
+    /// The symbol of the NFT, which mpl-core assets do not have.
+    pub symbol: String,
+}
+
+pub(crate) fn export_to_token_metadata<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: ExportToTokenMetadataV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = ExportToTokenMetadataV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.owner)?;
+    assert_signer(ctx.accounts.update_authority)?;
+    assert_signer(ctx.accounts.payer)?;
+    assert_signer(ctx.accounts.mint)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if ctx.accounts.token_metadata_program.key != &TOKEN_METADATA_PROGRAM_ID
+        || ctx.accounts.token_program.key != &SPL_TOKEN_PROGRAM_ID
+        || ctx.accounts.associated_token_program.key != &SPL_ASSOCIATED_TOKEN_PROGRAM_ID
+        || ctx.accounts.sysvar_instructions.key != &sysvar::instructions::ID
+    {
+        return Err(MplCoreError::IncorrectAccount.into());
+    }
+
+    if ctx.accounts.metadata.key != &find_token_metadata_address(ctx.accounts.mint.key).0
+        || ctx.accounts.master_edition.key
+            != &find_token_metadata_master_edition_address(ctx.accounts.mint.key).0
+    {
+        return Err(MplCoreError::InvalidTokenMetadata.into());
+    }
+
+    if load_key(ctx.accounts.asset, 0)? != Key::AssetV1 {
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    // The NFT keeps the update authority of the asset, which has to sign for its creation.
+    let asset = AssetV1::load(ctx.accounts.asset, 0)?;
+    let collection = ctx
+        .accounts
+        .collection
+        .map(|collection_info| CollectionV1::load(collection_info, 0))
+        .transpose()?;
+    let update_authority = match (&asset.update_authority, &collection) {
+        (UpdateAuthority::Address(address), _) => *address,
+        (UpdateAuthority::Collection(_), Some(collection)) => collection.update_authority,
+        _ => return Err(MplCoreError::MissingCollection.into()),
+    };
+    if ctx.accounts.update_authority.key != &update_authority {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    // Exporting burns the asset, so the owner has to be allowed to burn it.
+    let _ = validate_asset_permissions(
+        ctx.accounts.owner,
+        ctx.remaining_accounts,
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        None,
+        None,
+        None,
+        Some(HookableLifecycleEvent::Burn),
+        AssetV1::check_burn,
+        CollectionV1::check_burn,
+        PluginType::check_burn,
+        AssetHeaderV1::validate_burn,
+        CollectionV1::validate_burn,
+        Plugin::validate_burn,
+    )?;
+
+    validate_tx_guard(
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        ctx.remaining_accounts,
+    )?;
+
+    // The royalties of the asset take precedence over those of its collection.
+    let royalties = fetch_plugin::<AssetV1, Royalties>(ctx.accounts.asset, PluginType::Royalties)
+        .or_else(|error| match ctx.accounts.collection {
+            Some(collection_info) => {
+                fetch_plugin::<CollectionV1, Royalties>(collection_info, PluginType::Royalties)
+            }
+            None => Err(error),
+        })
+        .ok()
+        .map(|(_, royalties, _)| royalties);
+
+    let asset_data = TokenMetadataAssetData {
+        name: asset.name,
+        symbol: args.symbol,
+        uri: asset.uri,
+        seller_fee_basis_points: royalties
+            .as_ref()
+            .map_or(0, |royalties| royalties.basis_points),
+        creators: royalties.map(|royalties| {
+            royalties
+                .creators
+                .into_iter()
+                .map(|creator| TokenMetadataCreator {
+                    address: creator.address,
+                    verified: false,
+                    share: creator.percentage,
+                })
+                .collect()
+        }),
+        primary_sale_happened: true,
+        is_mutable: true,
+        token_standard: TOKEN_STANDARD_NON_FUNGIBLE,
+        collection: None,
+    };
+
+    let rent_destination = resolve_burn_rent_destination(
+        ctx.accounts.owner,
+        ctx.accounts.collection,
+        ctx.remaining_accounts,
+    )?;
+    process_burn(ctx.accounts.asset, rent_destination)?;
+    if let (Some(mut collection), Some(collection_info)) = (collection, ctx.accounts.collection) {
+        collection.decrement()?;
+        collection.save(collection_info, 0)?;
+    }
+
+    // Create the metadata and master edition of the NFT, initializing its mint.
+    let mut create_data = vec![TOKEN_METADATA_CREATE, 0];
+    asset_data.serialize(&mut create_data)?;
+    // No uses, collection details or rule set, zero decimals and no prints.
+    create_data.extend_from_slice(&[0, 0, 0]);
+    (Some(0u8), Some(PRINT_SUPPLY_ZERO)).serialize(&mut create_data)?;
+    invoke(
+        &Instruction {
+            program_id: TOKEN_METADATA_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*ctx.accounts.metadata.key, false),
+                AccountMeta::new(*ctx.accounts.master_edition.key, false),
+                AccountMeta::new(*ctx.accounts.mint.key, true),
+                AccountMeta::new_readonly(*ctx.accounts.update_authority.key, true),
+                AccountMeta::new(*ctx.accounts.payer.key, true),
+                AccountMeta::new_readonly(*ctx.accounts.update_authority.key, true),
+                AccountMeta::new_readonly(*ctx.accounts.system_program.key, false),
+                AccountMeta::new_readonly(*ctx.accounts.sysvar_instructions.key, false),
+                AccountMeta::new_readonly(*ctx.accounts.token_program.key, false),
+            ],
+            data: create_data,
+        },
+        &[
+            ctx.accounts.metadata.clone(),
+            ctx.accounts.master_edition.clone(),
+            ctx.accounts.mint.clone(),
+            ctx.accounts.update_authority.clone(),
+            ctx.accounts.payer.clone(),
+            ctx.accounts.system_program.clone(),
+            ctx.accounts.sysvar_instructions.clone(),
+            ctx.accounts.token_program.clone(),
+        ],
+    )?;
+
+    // Mint the NFT to the owner.  Token Metadata stands in for the optional accounts that are not
+    // used by non-fungible assets.
+    let mut mint_data = vec![TOKEN_METADATA_MINT, 0];
+    (1u64, None::<u8>).serialize(&mut mint_data)?;
+    invoke(
+        &Instruction {
+            program_id: TOKEN_METADATA_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*ctx.accounts.token_account.key, false),
+                AccountMeta::new_readonly(*ctx.accounts.owner.key, false),
+                AccountMeta::new_readonly(*ctx.accounts.metadata.key, false),
+                AccountMeta::new(*ctx.accounts.master_edition.key, false),
+                AccountMeta::new_readonly(TOKEN_METADATA_PROGRAM_ID, false),
+                AccountMeta::new(*ctx.accounts.mint.key, false),
+                AccountMeta::new_readonly(*ctx.accounts.update_authority.key, true),
+                AccountMeta::new_readonly(TOKEN_METADATA_PROGRAM_ID, false),
+                AccountMeta::new(*ctx.accounts.payer.key, true),
+                AccountMeta::new_readonly(*ctx.accounts.system_program.key, false),
+                AccountMeta::new_readonly(*ctx.accounts.sysvar_instructions.key, false),
+                AccountMeta::new_readonly(*ctx.accounts.token_program.key, false),
+                AccountMeta::new_readonly(*ctx.accounts.associated_token_program.key, false),
+                AccountMeta::new_readonly(TOKEN_METADATA_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_METADATA_PROGRAM_ID, false),
+            ],
+            data: mint_data,
+        },
+        &[
+            ctx.accounts.token_account.clone(),
+            ctx.accounts.owner.clone(),
+            ctx.accounts.metadata.clone(),
+            ctx.accounts.master_edition.clone(),
+            ctx.accounts.mint.clone(),
+            ctx.accounts.update_authority.clone(),
+            ctx.accounts.payer.clone(),
+            ctx.accounts.system_program.clone(),
+            ctx.accounts.sysvar_instructions.clone(),
+            ctx.accounts.token_program.clone(),
+            ctx.accounts.associated_token_program.clone(),
+            ctx.accounts.token_metadata_program.clone(),
+        ],
+    )
+}
@@ -0,0 +1,120 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::{assert_signer, create_or_allocate_account_raw};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::{SetFeeConfigV1Accounts, WithdrawFeesV1Accounts},
+    state::{
+        find_fee_config_address, DataBlob, FeeConfigV1, Key, SolanaAccount, FEE_CONFIG_AUTHORITY,
+        FEE_CONFIG_PREFIX,
+    },
+    utils::{fetch_fee_config, resolve_authority},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct SetFeeConfigV1Args {
+    /// The fee, in lamports, charged when creating an asset.
+    pub create_fee: u64,
+    /// The fee, in lamports, charged when creating a collection.
+    pub create_collection_fee: u64,
+    /// The new authority of the fee config, if it changes.
+    pub new_authority: Option<Pubkey>,
+}
+
+pub(crate) fn set_fee_config<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: SetFeeConfigV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = SetFeeConfigV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    let (fee_config_address, bump) = find_fee_config_address();
+    if ctx.accounts.fee_config.key != &fee_config_address {
+        return Err(MplCoreError::InvalidFeeConfig.into());
+    }
+
+    // The fee config is initialized by the program authority, and then managed by its own.
+    let current_authority = if ctx.accounts.fee_config.data_is_empty() {
+        create_or_allocate_account_raw(
+            crate::ID,
+            ctx.accounts.fee_config,
+            ctx.accounts.system_program,
+            ctx.accounts.payer,
+            FeeConfigV1::get_initial_size(),
+            &[FEE_CONFIG_PREFIX, &[bump]],
+        )?;
+        FEE_CONFIG_AUTHORITY
+    } else {
+        fetch_fee_config(ctx.accounts.fee_config)?.authority
+    };
+
+    if authority.key != &current_authority {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    FeeConfigV1 {
+        key: Key::FeeConfigV1,
+        authority: args.new_authority.unwrap_or(current_authority),
+        create_fee: args.create_fee,
+        create_collection_fee: args.create_collection_fee,
+        bump,
+    }
+    .save(ctx.accounts.fee_config, 0)
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct WithdrawFeesV1Args {
+    /// The lamports to withdraw, or every collected fee if `None`.
+    pub amount: Option<u64>,
+}
+
+pub(crate) fn withdraw_fees<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: WithdrawFeesV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = WithdrawFeesV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.authority)?;
+
+    let fee_config = fetch_fee_config(ctx.accounts.fee_config)?;
+    if ctx.accounts.authority.key != &fee_config.authority {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    // Everything above the rent of the fee config is collected fees.
+    let collected = ctx
+        .accounts
+        .fee_config
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(ctx.accounts.fee_config.data_len()));
+    let amount = args.amount.unwrap_or(collected);
+    if amount > collected {
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    **ctx.accounts.fee_config.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.recipient.try_borrow_mut_lamports()? = ctx
+        .accounts
+        .recipient
+        .lamports()
+        .checked_add(amount)
+        .ok_or(MplCoreError::NumericalOverflow)?;
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use mpl_utils::assert_signer;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::FreezeAssetV1Accounts,
+    plugins::{fetch_plugin, FreezeDelegate, PermanentFreezeDelegate, Plugin, PluginType},
+    state::{AssetHeaderV1, AssetV1, CollectionV1, Key},
+    utils::{load_key, resolve_authority, validate_asset_permissions},
+};
+
+pub(crate) fn freeze_asset<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    process_set_frozen(accounts, true)
+}
+
+pub(crate) fn thaw_asset<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    process_set_frozen(accounts, false)
+}
+
+/// Freeze or thaw an asset through its freeze delegate, or its permanent freeze delegate if it
+/// has none, with the same permissions as updating the plugin.
+fn process_set_frozen<'a>(accounts: &'a [AccountInfo<'a>], frozen: bool) -> ProgramResult {
+    // Accounts.  Freezing and thawing share their accounts.
+    let ctx = FreezeAssetV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Freezing compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    let (new_plugin, offset) = match fetch_plugin::<AssetV1, FreezeDelegate>(
+        ctx.accounts.asset,
+        PluginType::FreezeDelegate,
+    ) {
+        Ok((_, _, offset)) => (Plugin::FreezeDelegate(FreezeDelegate { frozen }), offset),
+        Err(_) => {
+            let (_, _, offset) = fetch_plugin::<AssetV1, PermanentFreezeDelegate>(
+                ctx.accounts.asset,
+                PluginType::PermanentFreezeDelegate,
+            )?;
+            (
+                Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate { frozen }),
+                offset,
+            )
+        }
+    };
+
+    let (mut asset, _, _) = validate_asset_permissions(
+        authority,
+        ctx.remaining_accounts,
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        None,
+        Some(&new_plugin),
+        None,
+        None,
+        AssetV1::check_update_plugin,
+        CollectionV1::check_update_plugin,
+        PluginType::check_update_plugin,
+        AssetHeaderV1::validate_update_plugin,
+        CollectionV1::validate_update_plugin,
+        Plugin::validate_update_plugin,
+    )?;
+
+    // Increment sequence number and save only if it is `Some(_)`.
+    asset.increment_seq_and_save(ctx.accounts.asset)?;
+
+    // Both freeze plugins have a fixed size, so the plugin is updated in place.
+    new_plugin.save(ctx.accounts.asset, offset)
+}
@@ -0,0 +1,71 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::set_return_data,
+    program_error::ProgramError,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::GetDelegatesV1Accounts,
+    plugins::{DelegateRecord, PluginHeaderV1, PluginRegistryV1},
+    state::{AssetV1, Authority, CollectionV1, DataBlob, Key, SolanaAccount, UpdateAuthority},
+    utils::load_key,
+};
+
+pub(crate) fn get_delegates<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = GetDelegatesV1Accounts::context(accounts)?;
+
+    // Guards.
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Get delegates for compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    let asset = AssetV1::load(ctx.accounts.asset, 0)?;
+    let mut delegates = fetch_delegates(ctx.accounts.asset, &asset, false)?;
+
+    match (ctx.accounts.collection, &asset.update_authority) {
+        (Some(collection_info), UpdateAuthority::Collection(collection_address))
+            if collection_info.key == collection_address =>
+        {
+            let collection = CollectionV1::load(collection_info, 0)?;
+            delegates.extend(fetch_delegates(collection_info, &collection, true)?);
+        }
+        (None, _) => (),
+        _ => return Err(MplCoreError::InvalidCollection.into()),
+    }
+
+    set_return_data(&delegates.try_to_vec()?);
+
+    Ok(())
+}
+
+// List the plugins of an account along with their authorities, skipping plugins that nobody
+// has authority over, including those whose authority has expired.
+fn fetch_delegates<T: DataBlob + SolanaAccount>(
+    account: &AccountInfo,
+    core: &T,
+    collection_level: bool,
+) -> Result<Vec<DelegateRecord>, ProgramError> {
+    if core.get_size() == account.data_len() {
+        return Ok(vec![]);
+    }
+
+    let header = PluginHeaderV1::load(account, core.get_size())?;
+    let PluginRegistryV1 { registry, .. } =
+        PluginRegistryV1::load(account, header.plugin_registry_offset)?;
+
+    let mut delegates = vec![];
+    for record in registry {
+        if record.authority != Authority::None && !record.authority.is_expired()? {
+            delegates.push(DelegateRecord {
+                plugin_type: record.plugin_type,
+                authority: record.authority,
+                collection_level,
+            });
+        }
+    }
+
+    Ok(delegates)
+}
@@ -0,0 +1,154 @@
+use mpl_utils::assert_signer;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    msg,
+    program::invoke,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::{CreateV1Accounts, MigrateFromTokenMetadataV1Accounts},
+    plugins::{Creator, Plugin, PluginAuthorityPair, Royalties, RuleSet},
+    state::{trim_token_metadata_string, DataState, TokenMetadata, SPL_TOKEN_PROGRAM_ID},
+    utils::split_fee_config,
+};
+
+use super::{process_create, CreateV1Args};
+
+/// The SPL Token `Burn` instruction.
+const SPL_TOKEN_BURN: u8 = 8;
+
+/// The SPL Token `CloseAccount` instruction.
+const SPL_TOKEN_CLOSE_ACCOUNT: u8 = 9;
+
+pub(crate) fn migrate_from_token_metadata<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = MigrateFromTokenMetadataV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.owner)?;
+
+    if ctx.accounts.token_program.key != &SPL_TOKEN_PROGRAM_ID {
+        return Err(MplCoreError::IncorrectAccount.into());
+    }
+
+    let metadata = TokenMetadata::load(ctx.accounts.metadata, ctx.accounts.mint.key)?;
+    if ctx.accounts.update_authority.key != &metadata.update_authority {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    // Only a non-fungible mint whose one token is held by the owner can be migrated.  A token
+    // account stores the mint, the owner then the amount, and a mint stores the supply then the
+    // decimals after its optional mint authority.
+    {
+        let token = ctx.accounts.token_account.data.borrow();
+        let mint = ctx.accounts.mint.data.borrow();
+        let is_held_nft = ctx.accounts.token_account.owner == &SPL_TOKEN_PROGRAM_ID
+            && ctx.accounts.mint.owner == &SPL_TOKEN_PROGRAM_ID
+            && token.get(0..32) == Some(ctx.accounts.mint.key.as_ref())
+            && token.get(32..64) == Some(ctx.accounts.owner.key.as_ref())
+            && token.get(64..72) == Some(&1u64.to_le_bytes())
+            && mint.get(36..44) == Some(&1u64.to_le_bytes())
+            && mint.get(44) == Some(&0);
+        if !is_held_nft {
+            return Err(MplCoreError::InvalidTokenMetadata.into());
+        }
+    }
+
+    // A verified collection membership is carried over to the collection the NFT is migrated
+    // into, whose update authority has to sign for it.
+    let verified_collection = metadata
+        .collection
+        .as_ref()
+        .is_some_and(|collection| collection.verified);
+    if verified_collection && ctx.accounts.collection.is_none() {
+        msg!("Error: An NFT of a verified collection must be migrated into a collection");
+        return Err(MplCoreError::MissingCollection.into());
+    }
+
+    // Burn the NFT and close its token account, returning the rent to the owner.
+    invoke(
+        &Instruction {
+            program_id: SPL_TOKEN_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*ctx.accounts.token_account.key, false),
+                AccountMeta::new(*ctx.accounts.mint.key, false),
+                AccountMeta::new_readonly(*ctx.accounts.owner.key, true),
+            ],
+            data: [&[SPL_TOKEN_BURN][..], &1u64.to_le_bytes()].concat(),
+        },
+        &[
+            ctx.accounts.token_account.clone(),
+            ctx.accounts.mint.clone(),
+            ctx.accounts.owner.clone(),
+        ],
+    )?;
+    invoke(
+        &Instruction {
+            program_id: SPL_TOKEN_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*ctx.accounts.token_account.key, false),
+                AccountMeta::new(*ctx.accounts.owner.key, false),
+                AccountMeta::new_readonly(*ctx.accounts.owner.key, true),
+            ],
+            data: vec![SPL_TOKEN_CLOSE_ACCOUNT],
+        },
+        &[
+            ctx.accounts.token_account.clone(),
+            ctx.accounts.owner.clone(),
+        ],
+    )?;
+
+    // The royalties of the NFT are kept as a royalties plugin.
+    let plugins = metadata
+        .creators
+        .filter(|creators| !creators.is_empty())
+        .map(|creators| {
+            vec![PluginAuthorityPair {
+                plugin: Plugin::Royalties(Royalties {
+                    basis_points: metadata.seller_fee_basis_points,
+                    creators: creators
+                        .into_iter()
+                        .map(|creator| Creator {
+                            address: creator.address,
+                            percentage: creator.share,
+                        })
+                        .collect(),
+                    rule_set: RuleSet::None,
+                }),
+                authority: None,
+            }]
+        });
+
+    let (fee_config, _) = split_fee_config(ctx.remaining_accounts);
+
+    // The asset keeps the update authority of the NFT, either directly or through the
+    // collection.
+    let (authority, update_authority) = match ctx.accounts.collection {
+        Some(_) => (Some(ctx.accounts.update_authority), None),
+        None => (None, Some(ctx.accounts.update_authority)),
+    };
+
+    process_create(
+        CreateV1Accounts {
+            asset: ctx.accounts.asset,
+            collection: ctx.accounts.collection,
+            authority,
+            payer: ctx.accounts.payer,
+            owner: Some(ctx.accounts.owner),
+            update_authority,
+            system_program: ctx.accounts.system_program,
+            log_wrapper: ctx.accounts.log_wrapper,
+        },
+        CreateV1Args {
+            data_state: DataState::AccountState,
+            name: trim_token_metadata_string(&metadata.name),
+            uri: trim_token_metadata_string(&metadata.uri),
+            plugins,
+        },
+        None,
+        fee_config,
+    )
+}
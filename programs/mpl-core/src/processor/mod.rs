@@ -41,6 +41,99 @@ pub(crate) use update_plugin::*;
 mod collect;
 pub(crate) use collect::*;
 
+mod verify_grouping;
+pub(crate) use verify_grouping::*;
+
+mod approve_collection_join;
+pub(crate) use approve_collection_join::*;
+
+mod assert_ownership;
+pub(crate) use assert_ownership::*;
+
+mod repair_registry;
+pub(crate) use repair_registry::*;
+
+mod airdrop;
+pub(crate) use airdrop::*;
+
+mod unbind;
+pub(crate) use unbind::*;
+
+mod get_delegates;
+pub(crate) use get_delegates::*;
+
+mod reap;
+pub(crate) use reap::*;
+
+mod add_external_plugin;
+pub(crate) use add_external_plugin::*;
+
+mod update_external_plugin;
+pub(crate) use update_external_plugin::*;
+
+mod remove_external_plugin;
+pub(crate) use remove_external_plugin::*;
+
+mod write_external_plugin_data;
+pub(crate) use write_external_plugin_data::*;
+
+mod close_collection;
+pub(crate) use close_collection::*;
+
+mod revoke_all;
+pub(crate) use revoke_all::*;
+
+mod reserve;
+pub(crate) use reserve::*;
+
+mod validate_lifecycle;
+pub(crate) use validate_lifecycle::*;
+
+mod fee_config;
+pub(crate) use fee_config::*;
+
+mod create_edition;
+pub(crate) use create_edition::*;
+
+mod migrate_from_token_metadata;
+pub(crate) use migrate_from_token_metadata::*;
+
+mod export_to_token_metadata;
+pub(crate) use export_to_token_metadata::*;
+
+mod transfer_compressed;
+pub(crate) use transfer_compressed::*;
+
+mod burn_compressed;
+pub(crate) use burn_compressed::*;
+
+mod transfer_approval;
+pub(crate) use transfer_approval::*;
+
+mod freeze;
+pub(crate) use freeze::*;
+
+mod stage_proof;
+pub(crate) use stage_proof::*;
+
+mod write_app_data;
+pub(crate) use write_app_data::*;
+
+mod redeem;
+pub(crate) use redeem::*;
+
+mod write_inscription;
+pub(crate) use write_inscription::*;
+
+mod execute;
+pub(crate) use execute::*;
+
+mod transfer_batch;
+pub(crate) use transfer_batch::*;
+
+mod create_batch;
+pub(crate) use create_batch::*;
+
 /// Standard processor that deserializes and instruction and routes it to the appropriate handler.
 pub fn process_instruction<'a>(
     _program_id: &Pubkey,
@@ -127,5 +220,189 @@ pub fn process_instruction<'a>(
         }
 
         MplAssetInstruction::Collect => collect(accounts),
+        MplAssetInstruction::VerifyGroupingV1(args) => {
+            msg!("Instruction: VerifyGrouping");
+            verify_grouping(accounts, args)
+        }
+        MplAssetInstruction::ApproveCollectionJoinV1 => {
+            msg!("Instruction: ApproveCollectionJoin");
+            approve_collection_join(accounts)
+        }
+        MplAssetInstruction::AssertOwnershipV1(args) => {
+            msg!("Instruction: AssertOwnership");
+            assert_ownership(accounts, args)
+        }
+        MplAssetInstruction::RepairRegistryV1(args) => {
+            msg!("Instruction: RepairRegistry");
+            repair_registry(accounts, args)
+        }
+        MplAssetInstruction::AirdropV1(args) => {
+            msg!("Instruction: Airdrop");
+            airdrop(accounts, args)
+        }
+        MplAssetInstruction::UnbindV1 => {
+            msg!("Instruction: Unbind");
+            unbind(accounts)
+        }
+        MplAssetInstruction::GetDelegatesV1 => {
+            msg!("Instruction: GetDelegates");
+            get_delegates(accounts)
+        }
+        MplAssetInstruction::ReapV1 => {
+            msg!("Instruction: Reap");
+            reap(accounts)
+        }
+        MplAssetInstruction::CreateV2(args) => {
+            msg!("Instruction: CreateV2");
+            create_v2(accounts, args)
+        }
+        MplAssetInstruction::AddPluginV2(args) => {
+            msg!("Instruction: AddPluginV2");
+            add_plugin_v2(accounts, args)
+        }
+        MplAssetInstruction::AddExternalPluginV1(args) => {
+            msg!("Instruction: AddExternalPlugin");
+            add_external_plugin(accounts, args)
+        }
+        MplAssetInstruction::AddCollectionExternalPluginV1(args) => {
+            msg!("Instruction: AddCollectionExternalPlugin");
+            add_collection_external_plugin(accounts, args)
+        }
+        MplAssetInstruction::StageProofV1(args) => {
+            msg!("Instruction: StageProof");
+            stage_proof(accounts, args)
+        }
+        MplAssetInstruction::WriteAppDataV1(args) => {
+            msg!("Instruction: WriteAppData");
+            write_app_data(accounts, args)
+        }
+        MplAssetInstruction::WriteCollectionAppDataV1(args) => {
+            msg!("Instruction: WriteCollectionAppData");
+            write_collection_app_data(accounts, args)
+        }
+        MplAssetInstruction::WriteLinkedAppDataV1(args) => {
+            msg!("Instruction: WriteLinkedAppData");
+            write_linked_app_data(accounts, args)
+        }
+        MplAssetInstruction::RedeemV1 => {
+            msg!("Instruction: Redeem");
+            redeem(accounts)
+        }
+        MplAssetInstruction::WriteInscriptionV1(args) => {
+            msg!("Instruction: WriteInscription");
+            write_inscription(accounts, args)
+        }
+        MplAssetInstruction::ExecuteV1(args) => {
+            msg!("Instruction: Execute");
+            execute(accounts, args)
+        }
+        MplAssetInstruction::UpdateV2(args) => {
+            msg!("Instruction: UpdateV2");
+            update_v2(accounts, args)
+        }
+        MplAssetInstruction::TransferBatchV1(args) => {
+            msg!("Instruction: TransferBatch");
+            transfer_batch(accounts, args)
+        }
+        MplAssetInstruction::CreateBatchV1(args) => {
+            msg!("Instruction: CreateBatch");
+            create_batch(accounts, args)
+        }
+        MplAssetInstruction::UpdateExternalPluginV1(args) => {
+            msg!("Instruction: UpdateExternalPlugin");
+            update_external_plugin(accounts, args)
+        }
+        MplAssetInstruction::UpdateCollectionExternalPluginV1(args) => {
+            msg!("Instruction: UpdateCollectionExternalPlugin");
+            update_collection_external_plugin(accounts, args)
+        }
+        MplAssetInstruction::RemoveExternalPluginV1(args) => {
+            msg!("Instruction: RemoveExternalPlugin");
+            remove_external_plugin(accounts, args)
+        }
+        MplAssetInstruction::RemoveCollectionExternalPluginV1(args) => {
+            msg!("Instruction: RemoveCollectionExternalPlugin");
+            remove_collection_external_plugin(accounts, args)
+        }
+        MplAssetInstruction::WriteExternalPluginDataV1(args) => {
+            msg!("Instruction: WriteExternalPluginData");
+            write_external_plugin_data(accounts, args)
+        }
+        MplAssetInstruction::WriteCollectionExternalPluginDataV1(args) => {
+            msg!("Instruction: WriteCollectionExternalPluginData");
+            write_collection_external_plugin_data(accounts, args)
+        }
+        MplAssetInstruction::TruncateExternalPluginDataV1(args) => {
+            msg!("Instruction: TruncateExternalPluginData");
+            truncate_external_plugin_data(accounts, args)
+        }
+        MplAssetInstruction::TruncateCollectionExternalPluginDataV1(args) => {
+            msg!("Instruction: TruncateCollectionExternalPluginData");
+            truncate_collection_external_plugin_data(accounts, args)
+        }
+        MplAssetInstruction::CloseCollectionV1 => {
+            msg!("Instruction: CloseCollection");
+            close_collection(accounts)
+        }
+        MplAssetInstruction::RevokeAllV1 => {
+            msg!("Instruction: RevokeAll");
+            revoke_all(accounts)
+        }
+        MplAssetInstruction::ReserveV1(args) => {
+            msg!("Instruction: Reserve");
+            reserve(accounts, args)
+        }
+        MplAssetInstruction::ValidateLifecycleV1(args) => {
+            msg!("Instruction: ValidateLifecycle");
+            validate_lifecycle(accounts, args)
+        }
+        MplAssetInstruction::SetFeeConfigV1(args) => {
+            msg!("Instruction: SetFeeConfig");
+            set_fee_config(accounts, args)
+        }
+        MplAssetInstruction::WithdrawFeesV1(args) => {
+            msg!("Instruction: WithdrawFees");
+            withdraw_fees(accounts, args)
+        }
+        MplAssetInstruction::CreateEditionV1 => {
+            msg!("Instruction: CreateEdition");
+            create_edition(accounts)
+        }
+        MplAssetInstruction::MigrateFromTokenMetadataV1 => {
+            msg!("Instruction: MigrateFromTokenMetadata");
+            migrate_from_token_metadata(accounts)
+        }
+        MplAssetInstruction::ExportToTokenMetadataV1(args) => {
+            msg!("Instruction: ExportToTokenMetadata");
+            export_to_token_metadata(accounts, args)
+        }
+        MplAssetInstruction::TransferCompressedV1(args) => {
+            msg!("Instruction: TransferCompressed");
+            transfer_compressed(accounts, args)
+        }
+        MplAssetInstruction::BurnCompressedV1(args) => {
+            msg!("Instruction: BurnCompressed");
+            burn_compressed(accounts, args)
+        }
+        MplAssetInstruction::ApproveTransferV1(args) => {
+            msg!("Instruction: ApproveTransfer");
+            approve_transfer(accounts, args)
+        }
+        MplAssetInstruction::RevokeTransferApprovalV1 => {
+            msg!("Instruction: RevokeTransferApproval");
+            revoke_transfer_approval(accounts)
+        }
+        MplAssetInstruction::FreezeAssetV1 => {
+            msg!("Instruction: FreezeAsset");
+            freeze_asset(accounts)
+        }
+        MplAssetInstruction::ThawAssetV1 => {
+            msg!("Instruction: ThawAsset");
+            thaw_asset(accounts)
+        }
+        MplAssetInstruction::WriteAppDataChunkV1(args) => {
+            msg!("Instruction: WriteAppDataChunk");
+            write_app_data_chunk(accounts, args)
+        }
     }
 }
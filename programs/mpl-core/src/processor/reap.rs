@@ -0,0 +1,68 @@
+use mpl_utils::assert_signer;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::ReapV1Accounts,
+    plugins::{fetch_plugin, Plugin, PluginType, Reaper},
+    state::{AssetV1, CollectionV1, Key, SolanaAccount, UpdateAuthority},
+    utils::{close_program_account, load_key},
+};
+
+pub(crate) fn reap<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = ReapV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Reap for compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    if ctx.accounts.asset.owner != &crate::ID || ctx.accounts.collection.owner != &crate::ID {
+        return Err(MplCoreError::IncorrectAccount.into());
+    }
+
+    let asset = AssetV1::load(ctx.accounts.asset, 0)?;
+    if asset.update_authority != UpdateAuthority::Collection(*ctx.accounts.collection.key) {
+        return Err(MplCoreError::InvalidCollection.into());
+    }
+
+    let mut collection = CollectionV1::load(ctx.accounts.collection, 0)?;
+    let (_, mut reaper, offset) =
+        fetch_plugin::<CollectionV1, Reaper>(ctx.accounts.collection, PluginType::Reaper)?;
+
+    if !reaper.is_expired()? {
+        msg!("Reaper: Rejected");
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    // Anyone can reap an expired asset, the rent goes into the collection pool.
+    let starting_lamports = ctx.accounts.collection.lamports();
+    close_program_account(ctx.accounts.asset, ctx.accounts.collection)?;
+    let reaped_lamports = ctx
+        .accounts
+        .collection
+        .lamports()
+        .checked_sub(starting_lamports)
+        .ok_or(MplCoreError::NumericalOverflowError)?;
+
+    reaper.pool_balance = reaper
+        .pool_balance
+        .checked_add(reaped_lamports)
+        .ok_or(MplCoreError::NumericalOverflowError)?;
+
+    // The plugin does not change size so it can be saved in place.
+    Plugin::Reaper(reaper).save(ctx.accounts.collection, offset)?;
+
+    collection.decrement()?;
+    collection.save(ctx.accounts.collection, 0)
+}
@@ -0,0 +1,94 @@
+use mpl_utils::assert_signer;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::RedeemV1Accounts,
+    plugins::{fetch_plugin, HookableLifecycleEvent, Plugin, PluginType, Redemption},
+    state::{
+        AssetHeaderV1, AssetV1, CollectionV1, Key, RedemptionRecord, SolanaAccount, Wrappable,
+    },
+    utils::{load_key, resolve_authority, validate_asset_permissions, validate_tx_guard},
+};
+
+use super::{process_burn, resolve_burn_rent_destination};
+
+pub(crate) fn redeem<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = RedeemV1Accounts::context(accounts)?;
+    let collection = if let Some(collection) = ctx.accounts.collection {
+        Some(CollectionV1::load(collection, 0)?)
+    } else {
+        None
+    };
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    // The redemption record is the only notice the issuer gets, so the log wrapper is required.
+    if ctx.accounts.log_wrapper.key != &spl_noop::ID {
+        return Err(MplCoreError::InvalidLogWrapperProgram.into());
+    }
+
+    match load_key(ctx.accounts.asset, 0)? {
+        Key::HashedAssetV1 => {
+            msg!("Error: Redeem for compressed is not available");
+            return Err(MplCoreError::NotAvailable.into());
+        }
+        Key::AssetV1 => (),
+        _ => return Err(MplCoreError::IncorrectAccount.into()),
+    }
+
+    let (_, redemption, _) =
+        fetch_plugin::<AssetV1, Redemption>(ctx.accounts.asset, PluginType::Redemption)?;
+
+    // Validate asset permissions.  The Redemption plugin force approves the burn for the owner.
+    let (asset, _, _) = validate_asset_permissions(
+        authority,
+        ctx.remaining_accounts,
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        None,
+        None,
+        None,
+        Some(HookableLifecycleEvent::Burn),
+        AssetV1::check_burn,
+        CollectionV1::check_burn,
+        PluginType::check_redeem,
+        AssetHeaderV1::validate_burn,
+        CollectionV1::validate_burn,
+        Plugin::validate_redeem,
+    )?;
+
+    // Only the owner can redeem the asset.
+    if authority.key != &asset.owner {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    validate_tx_guard(
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        ctx.remaining_accounts,
+    )?;
+
+    // The redemption is recorded through the spl-noop program for the issuer to fulfill.
+    RedemptionRecord {
+        asset: *ctx.accounts.asset.key,
+        collection: ctx.accounts.collection.map(|collection| *collection.key),
+        owner: asset.owner,
+        issuer: redemption.issuer,
+        reward: redemption.reward,
+    }
+    .wrap()?;
+
+    let rent_destination =
+        resolve_burn_rent_destination(authority, ctx.accounts.collection, ctx.remaining_accounts)?;
+
+    process_burn(ctx.accounts.asset, rent_destination)?;
+    if let Some(mut collection) = collection {
+        collection.decrement()?;
+        collection.save(ctx.accounts.collection.unwrap(), 0)?;
+    };
+    Ok(())
+}
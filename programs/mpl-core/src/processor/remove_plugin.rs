@@ -5,8 +5,8 @@ use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
 use crate::{
     error::MplCoreError,
     instruction::accounts::{RemoveCollectionPluginV1Accounts, RemovePluginV1Accounts},
-    plugins::{delete_plugin, fetch_wrapped_plugin, Plugin, PluginType},
-    state::{AssetV1, CollectionV1, DataBlob, Key},
+    plugins::{delete_plugin, fetch_plugin, fetch_wrapped_plugin, Plugin, PluginType, Provenance},
+    state::{AssetHeaderV1, AssetV1, CollectionV1, DataBlob, Key},
     utils::{
         fetch_core_data, load_key, resolve_authority, validate_asset_permissions,
         validate_collection_permissions,
@@ -58,18 +58,34 @@ pub(crate) fn remove_plugin<'a>(
     // Validate asset permissions.
     let _ = validate_asset_permissions(
         authority,
+        ctx.remaining_accounts,
         ctx.accounts.asset,
         ctx.accounts.collection,
         None,
         Some(&plugin_to_remove),
+        None,
+        None,
         AssetV1::check_remove_plugin,
         CollectionV1::check_remove_plugin,
         PluginType::check_remove_plugin,
-        AssetV1::validate_remove_plugin,
+        AssetHeaderV1::validate_remove_plugin,
         CollectionV1::validate_remove_plugin,
         Plugin::validate_remove_plugin,
     )?;
 
+    // A fractionalized asset can only be recovered once all of its fractions are burned.
+    if let Plugin::Fractionalized(fractionalized) = &plugin_to_remove {
+        let fraction_mint = ctx
+            .remaining_accounts
+            .iter()
+            .find(|account_info| account_info.key == &fractionalized.fraction_mint)
+            .ok_or_else(|| {
+                msg!("Fractionalized: Rejected");
+                MplCoreError::FractionsOutstanding
+            })?;
+        fractionalized.check_fractions_burned(fraction_mint)?;
+    }
+
     // Increment sequence number and save only if it is `Some(_)`.
     asset.increment_seq_and_save(ctx.accounts.asset)?;
 
@@ -79,7 +95,19 @@ pub(crate) fn remove_plugin<'a>(
         ctx.accounts.asset,
         ctx.accounts.payer,
         ctx.accounts.system_program,
-    )
+    )?;
+
+    // Forget the provenance of the removed plugin so that it can be re-added by the owner.
+    if let Ok((_, mut provenance, offset)) =
+        fetch_plugin::<AssetV1, Provenance>(ctx.accounts.asset, PluginType::Provenance)
+    {
+        if provenance.contains(args.plugin_type) {
+            provenance.remove(args.plugin_type);
+            Plugin::Provenance(provenance).save(ctx.accounts.asset, offset)?;
+        }
+    }
+
+    Ok(())
 }
 
 #[repr(C)]
@@ -125,8 +153,10 @@ pub(crate) fn remove_collection_plugin<'a>(
     // Validate collection permissions.
     let _ = validate_collection_permissions(
         authority,
+        ctx.remaining_accounts,
         ctx.accounts.collection,
         Some(&plugin_to_remove),
+        None,
         CollectionV1::check_remove_plugin,
         PluginType::check_remove_plugin,
         CollectionV1::validate_remove_plugin,
@@ -0,0 +1,176 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, keccak, msg};
+use std::collections::BTreeSet;
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::RepairRegistryV1Accounts,
+    plugins::{Plugin, PluginHeaderV1, PluginRegistryV1, PluginType, RegistryRecord},
+    state::{AssetV1, CollectionV1, DataBlob, Key, SolanaAccount, UpdateAuthority},
+    utils::{load_key, resize_or_reallocate_account, resolve_authority},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct RepairRegistryV1Args {
+    pub plugin_types: Vec<PluginType>,
+    pub plugin_data_hash: [u8; 32],
+}
+
+pub(crate) fn repair_registry<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: RepairRegistryV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = RepairRegistryV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Repair registry for compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    // Only the core asset is trusted, the plugin metadata is what is being repaired so the
+    // lifecycle validations cannot be used.
+    let asset = AssetV1::load(ctx.accounts.asset, 0)?;
+    match asset.update_authority {
+        UpdateAuthority::Address(address) => {
+            if authority.key != &address {
+                return Err(MplCoreError::InvalidAuthority.into());
+            }
+        }
+        UpdateAuthority::Collection(collection_address) => {
+            let collection_info = ctx
+                .accounts
+                .collection
+                .ok_or(MplCoreError::MissingCollection)?;
+            if collection_info.key != &collection_address {
+                return Err(MplCoreError::InvalidCollection.into());
+            }
+
+            let collection = CollectionV1::load(collection_info, 0)?;
+            if authority.key != &collection.update_authority {
+                return Err(MplCoreError::InvalidAuthority.into());
+            }
+        }
+        UpdateAuthority::None => return Err(MplCoreError::InvalidAuthority.into()),
+    }
+
+    // Only the plugin header is trusted to say where the plugin data ends, the registry is
+    // rebuilt from the plugins actually stored before it.
+    let header_offset = asset.get_size();
+    let plugin_header = PluginHeaderV1::load(ctx.accounts.asset, header_offset)?;
+    let plugins_offset = header_offset
+        .checked_add(PluginHeaderV1::get_initial_size())
+        .ok_or(MplCoreError::NumericalOverflow)?;
+    let data_end = plugin_header.plugin_registry_offset;
+    if data_end < plugins_offset || data_end > ctx.accounts.asset.data_len() {
+        return Err(MplCoreError::InvalidPlugin.into());
+    }
+
+    // The authorities and external plugins of a registry that can still be read are kept, only
+    // the offsets of the plugins are rebuilt.
+    let (existing_registry, external_plugins) =
+        match PluginRegistryV1::load(ctx.accounts.asset, data_end) {
+            Ok(plugin_registry) => (plugin_registry.registry, plugin_registry.external_plugins),
+            Err(_) => (vec![], vec![]),
+        };
+
+    // The plugins must be laid out back to back after the header, around the data of any
+    // external plugins, and each must be of the type described.
+    let skip_external_data = |mut offset: usize| -> Result<usize, MplCoreError> {
+        while let Some(record) = external_plugins
+            .iter()
+            .find(|record| record.offset == offset && record.data_len > 0)
+        {
+            offset = offset
+                .checked_add(record.data_len)
+                .ok_or(MplCoreError::NumericalOverflow)?;
+        }
+        Ok(offset)
+    };
+
+    let mut next_offset = plugins_offset;
+    let mut seen_types = BTreeSet::new();
+    let mut registry = vec![];
+    for plugin_type in args.plugin_types {
+        next_offset = skip_external_data(next_offset)?;
+        if next_offset >= data_end {
+            return Err(MplCoreError::InvalidPlugin.into());
+        }
+
+        if !seen_types.insert(plugin_type) {
+            return Err(MplCoreError::PluginAlreadyExists.into());
+        }
+
+        let plugin = Plugin::load(ctx.accounts.asset, next_offset)?;
+        if PluginType::from(&plugin) != plugin_type {
+            return Err(MplCoreError::InvalidPlugin.into());
+        }
+
+        // The update authority cannot choose the authority of a plugin, it is either kept or
+        // reset to the manager of the plugin.
+        let authority = existing_registry
+            .iter()
+            .find(|record| record.plugin_type == plugin_type)
+            .map(|record| record.authority)
+            .unwrap_or(plugin.manager());
+        registry.push(RegistryRecord {
+            plugin_type,
+            authority,
+            offset: next_offset,
+        });
+
+        next_offset = next_offset
+            .checked_add(plugin.get_size())
+            .ok_or(MplCoreError::NumericalOverflow)?;
+    }
+
+    // Every plugin must be described, so none can be dropped from the registry.
+    if skip_external_data(next_offset)? != data_end {
+        return Err(MplCoreError::InvalidPlugin.into());
+    }
+
+    // The hash ensures the description was computed against the plugin data currently stored
+    // in the account.
+    let plugin_data_hash =
+        keccak::hash(&ctx.accounts.asset.data.borrow()[plugins_offset..data_end]).to_bytes();
+    if plugin_data_hash != args.plugin_data_hash {
+        return Err(MplCoreError::IncorrectAssetHash.into());
+    }
+
+    // The repaired registry is kept sorted by plugin type, like any other.
+    registry.sort_by_key(|record| record.plugin_type);
+
+    let plugin_registry = PluginRegistryV1 {
+        key: Key::PluginRegistryV1,
+        registry,
+        external_plugins,
+    };
+
+    let new_size = data_end
+        .checked_add(plugin_registry.try_to_vec()?.len())
+        .ok_or(MplCoreError::NumericalOverflow)?;
+    resize_or_reallocate_account(
+        ctx.accounts.asset,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        new_size,
+    )?;
+
+    plugin_registry.save(ctx.accounts.asset, data_end)
+}
@@ -0,0 +1,72 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke, rent::Rent,
+    system_instruction, system_instruction::MAX_PERMITTED_DATA_LENGTH, sysvar::Sysvar,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::ReserveV1Accounts,
+    state::{Key, COLLECT_AMOUNT},
+    utils::{holds_legacy_fee, load_key},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct ReserveV1Args {
+    /// The account size, in bytes, to fund rent for.
+    pub size: u64,
+}
+
+pub(crate) fn reserve<'a>(accounts: &'a [AccountInfo<'a>], args: ReserveV1Args) -> ProgramResult {
+    // Accounts.
+    let ctx = ReserveV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if ctx.accounts.asset.owner != &crate::ID {
+        return Err(MplCoreError::IncorrectAccount.into());
+    }
+
+    if load_key(ctx.accounts.asset, 0)? != Key::AssetV1 {
+        msg!("Error: Reserve is only available for assets");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    if args.size > MAX_PERMITTED_DATA_LENGTH {
+        return Err(MplCoreError::NumericalOverflow.into());
+    }
+
+    // The account keeps its size, the lamports are consumed by later reallocations instead.  The
+    // legacy fee of an asset created without a fee config stays on top for `Collect`.
+    let rent = Rent::get()?;
+    let mut target_balance = rent.minimum_balance(args.size as usize);
+    if holds_legacy_fee(ctx.accounts.asset, &rent) {
+        target_balance = target_balance
+            .checked_add(COLLECT_AMOUNT)
+            .ok_or(MplCoreError::NumericalOverflow)?;
+    }
+    let lamports_diff = target_balance.saturating_sub(ctx.accounts.asset.lamports());
+    if lamports_diff == 0 {
+        return Ok(());
+    }
+
+    invoke(
+        &system_instruction::transfer(
+            ctx.accounts.payer.key,
+            ctx.accounts.asset.key,
+            lamports_diff,
+        ),
+        &[
+            ctx.accounts.payer.clone(),
+            ctx.accounts.asset.clone(),
+            ctx.accounts.system_program.clone(),
+        ],
+    )
+}
@@ -0,0 +1,116 @@
+use mpl_utils::assert_signer;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::RevokeAllV1Accounts,
+    plugins::{fetch_wrapped_plugin, revoke_authority_on_plugin, Plugin, PluginType},
+    state::{AssetHeaderV1, AssetV1, Authority, CollectionV1, Key},
+    utils::{
+        fetch_core_data, fetch_core_registry, load_key, resolve_authority,
+        resolve_multisig_authority, resolve_pubkey_to_authorities, validate_asset_permissions,
+    },
+};
+
+pub(crate) fn revoke_all<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = RevokeAllV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Revoke all for compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    let (mut asset, _, plugin_registry) = fetch_core_data::<AssetV1>(ctx.accounts.asset)?;
+
+    // Only plugins delegated away from their manager have anything to revoke. Plugins with no
+    // authority are immutable and are left alone.
+    let delegated: Vec<PluginType> = plugin_registry
+        .map(|registry| {
+            registry
+                .registry
+                .into_iter()
+                .filter(|record| {
+                    record.authority != record.plugin_type.manager()
+                        && record.authority != Authority::None
+                })
+                .map(|record| record.plugin_type)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Every revoke has to be allowed, otherwise none of them happen.
+    let mut plugins = Vec::with_capacity(delegated.len());
+    for plugin_type in delegated {
+        let (_, plugin) =
+            fetch_wrapped_plugin::<AssetV1>(ctx.accounts.asset, Some(&asset), plugin_type)?;
+
+        let _ = validate_asset_permissions(
+            authority,
+            ctx.remaining_accounts,
+            ctx.accounts.asset,
+            ctx.accounts.collection,
+            None,
+            Some(&plugin),
+            None,
+            None,
+            AssetV1::check_revoke_plugin_authority,
+            CollectionV1::check_revoke_plugin_authority,
+            PluginType::check_revoke_plugin_authority,
+            AssetHeaderV1::validate_revoke_plugin_authority,
+            CollectionV1::validate_revoke_plugin_authority,
+            Plugin::validate_revoke_plugin_authority,
+        )?;
+
+        plugins.push((plugin_type, plugin));
+    }
+
+    // Increment sequence number and save only if it is `Some(_)`.
+    asset.increment_seq_and_save(ctx.accounts.asset)?;
+
+    let mut resolved_authorities =
+        resolve_pubkey_to_authorities(authority, ctx.accounts.collection, &asset.header())?;
+    resolved_authorities.extend(resolve_multisig_authority::<AssetV1>(
+        ctx.accounts.asset,
+        authority,
+        ctx.remaining_accounts,
+    ));
+
+    for (plugin_type, plugin) in plugins {
+        let payer = if resolved_authorities.contains(&plugin.manager()) {
+            ctx.accounts.payer
+        } else {
+            ctx.accounts.asset
+        };
+
+        // Each revoke moves the registry, so it is reloaded every time.
+        let (plugin_header, plugin_registry) = fetch_core_registry::<AssetV1>(ctx.accounts.asset)?;
+        let (plugin_header, mut plugin_registry) = plugin_header
+            .zip(plugin_registry)
+            .ok_or(MplCoreError::PluginsNotInitialized)?;
+
+        revoke_authority_on_plugin(
+            &plugin_type,
+            ctx.accounts.asset,
+            &plugin_header,
+            &mut plugin_registry,
+            payer,
+            ctx.accounts.system_program,
+        )?;
+    }
+
+    Ok(())
+}
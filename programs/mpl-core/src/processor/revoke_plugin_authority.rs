@@ -11,11 +11,11 @@ use crate::{
         fetch_wrapped_plugin, revoke_authority_on_plugin, Plugin, PluginHeaderV1, PluginRegistryV1,
         PluginType,
     },
-    state::{AssetV1, CollectionV1, Key},
+    state::{AssetHeaderV1, AssetV1, CollectionV1, Key},
     utils::{
-        fetch_core_data, load_key, resolve_authority, resolve_pubkey_to_authorities,
-        resolve_pubkey_to_authorities_collection, validate_asset_permissions,
-        validate_collection_permissions,
+        fetch_core_data, load_key, resolve_authority, resolve_multisig_authority,
+        resolve_pubkey_to_authorities, resolve_pubkey_to_authorities_collection,
+        validate_asset_permissions, validate_collection_permissions,
     },
 };
 
@@ -59,14 +59,17 @@ pub(crate) fn revoke_plugin_authority<'a>(
     // Validate asset permissions.
     let _ = validate_asset_permissions(
         authority,
+        ctx.remaining_accounts,
         ctx.accounts.asset,
         ctx.accounts.collection,
         None,
         Some(&plugin),
+        None,
+        None,
         AssetV1::check_revoke_plugin_authority,
         CollectionV1::check_revoke_plugin_authority,
         PluginType::check_revoke_plugin_authority,
-        AssetV1::validate_revoke_plugin_authority,
+        AssetHeaderV1::validate_revoke_plugin_authority,
         CollectionV1::validate_revoke_plugin_authority,
         Plugin::validate_revoke_plugin_authority,
     )?;
@@ -74,8 +77,13 @@ pub(crate) fn revoke_plugin_authority<'a>(
     // Increment sequence number and save only if it is `Some(_)`.
     asset.increment_seq_and_save(ctx.accounts.asset)?;
 
-    let resolved_authorities =
-        resolve_pubkey_to_authorities(authority, ctx.accounts.collection, &asset)?;
+    let mut resolved_authorities =
+        resolve_pubkey_to_authorities(authority, ctx.accounts.collection, &asset.header())?;
+    resolved_authorities.extend(resolve_multisig_authority::<AssetV1>(
+        ctx.accounts.asset,
+        authority,
+        ctx.remaining_accounts,
+    ));
     let payer = if resolved_authorities.contains(&plugin.manager()) {
         ctx.accounts.payer
     } else {
@@ -130,16 +138,23 @@ pub(crate) fn revoke_collection_plugin_authority<'a>(
     // Validate collection permissions.
     let _ = validate_collection_permissions(
         authority,
+        ctx.remaining_accounts,
         ctx.accounts.collection,
         Some(&plugin),
+        None,
         CollectionV1::check_revoke_plugin_authority,
         PluginType::check_revoke_plugin_authority,
         CollectionV1::validate_revoke_plugin_authority,
         Plugin::validate_revoke_plugin_authority,
     )?;
 
-    let resolved_authorities =
+    let mut resolved_authorities =
         resolve_pubkey_to_authorities_collection(authority, ctx.accounts.collection)?;
+    resolved_authorities.extend(resolve_multisig_authority::<CollectionV1>(
+        ctx.accounts.collection,
+        authority,
+        ctx.remaining_accounts,
+    ));
     let payer = if resolved_authorities.contains(&plugin.manager()) {
         ctx.accounts.payer
     } else {
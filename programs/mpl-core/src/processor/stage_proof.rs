@@ -0,0 +1,78 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::{assert_signer, create_or_allocate_account_raw};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, sysvar::Sysvar,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::StageProofV1Accounts,
+    state::{
+        find_proof_cache_address, CompressionProof, DataBlob, HashedAssetV1, Key, ProofCacheV1,
+        SolanaAccount, PROOF_CACHE_PREFIX,
+    },
+    utils::{load_key, resize_or_reallocate_account, verify_proof},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct StageProofV1Args {
+    compression_proof: CompressionProof,
+}
+
+pub(crate) fn stage_proof<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: StageProofV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = StageProofV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if load_key(ctx.accounts.asset, 0)? != Key::HashedAssetV1 {
+        msg!("Error: Only compressed assets have proofs to stage");
+        return Err(MplCoreError::IncorrectAccount.into());
+    }
+
+    let (proof_cache_address, bump) = find_proof_cache_address(ctx.accounts.asset.key);
+    if ctx.accounts.proof_cache.key != &proof_cache_address {
+        return Err(MplCoreError::InvalidProofCache.into());
+    }
+
+    // Verify the proof once, so that it can be referenced without verification this epoch.
+    let (asset, plugins) = verify_proof(ctx.accounts.asset, args.compression_proof)?;
+    let seq = asset.seq.unwrap_or(0);
+
+    let proof_cache = ProofCacheV1 {
+        key: Key::ProofCacheV1,
+        asset: *ctx.accounts.asset.key,
+        hash: HashedAssetV1::load(ctx.accounts.asset, 0)?.hash,
+        epoch: Clock::get()?.epoch,
+        compression_proof: CompressionProof::new(asset, seq, plugins),
+    };
+
+    if ctx.accounts.proof_cache.data_is_empty() {
+        create_or_allocate_account_raw(
+            crate::ID,
+            ctx.accounts.proof_cache,
+            ctx.accounts.system_program,
+            ctx.accounts.payer,
+            proof_cache.get_size(),
+            &[PROOF_CACHE_PREFIX, ctx.accounts.asset.key.as_ref(), &[bump]],
+        )?;
+    } else {
+        resize_or_reallocate_account(
+            ctx.accounts.proof_cache,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+            proof_cache.get_size(),
+        )?;
+    }
+
+    proof_cache.save(ctx.accounts.proof_cache, 0)
+}
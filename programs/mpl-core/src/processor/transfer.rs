@@ -1,15 +1,25 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_utils::assert_signer;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke,
+    program_error::ProgramError, pubkey::Pubkey, system_instruction, sysvar,
+};
 
 use crate::{
     error::MplCoreError,
     instruction::accounts::TransferV1Accounts,
-    plugins::{Plugin, PluginType},
-    state::{AssetV1, Authority, CollectionV1, CompressionProof, Key, SolanaAccount, Wrappable},
+    plugins::{
+        delete_plugin, fetch_plugin, HookableLifecycleEvent, LinkedPair, Listing, Plugin,
+        PluginType, TokenLink, TransferFee,
+    },
+    state::{
+        find_asset_signer_address, AssetHeaderV1, AssetV1, Authority, CollectionV1,
+        CompressionProof, Key, SolanaAccount, UpdateAuthority,
+    },
     utils::{
-        compress_into_account_space, load_key, rebuild_account_state_from_proof_data,
-        resolve_authority, validate_asset_permissions, verify_proof,
+        cmp_pubkeys, is_token_account, load_key, rebuild_account_state_from_proof_data,
+        resolve_authority, resolve_compression_proof, validate_asset_permissions,
+        validate_dependent_account, validate_tx_guard,
     },
 };
 
@@ -43,25 +53,25 @@ pub(crate) fn transfer<'a>(accounts: &'a [AccountInfo<'a>], args: TransferV1Args
 
     match key {
         Key::HashedAssetV1 => {
-            let compression_proof = args
-                .compression_proof
-                .ok_or(MplCoreError::MissingCompressionProof)?;
-
             let system_program = ctx
                 .accounts
                 .system_program
                 .ok_or(MplCoreError::MissingSystemProgram)?;
 
-            // Verify the proof and rebuild Asset struct in account space.
-            let (mut asset, plugins) = verify_proof(ctx.accounts.asset, &compression_proof)?;
+            // Verify the proof, or use the staged proof, and rebuild Asset struct in account space.
+            let (mut asset, plugins) = resolve_compression_proof(
+                ctx.accounts.asset,
+                args.compression_proof,
+                ctx.remaining_accounts,
+            )?;
 
             // Set the new owner.
             asset.owner = *ctx.accounts.new_owner.key;
 
             // Use the data from the compression proof to rebuild the account.
             rebuild_account_state_from_proof_data(
-                asset,
-                plugins,
+                &asset,
+                &plugins,
                 ctx.accounts.asset,
                 ctx.accounts.payer,
                 system_program,
@@ -75,21 +85,156 @@ pub(crate) fn transfer<'a>(accounts: &'a [AccountInfo<'a>], args: TransferV1Args
         _ => return Err(MplCoreError::IncorrectAccount.into()),
     }
 
-    // Validate asset permissions.
-    let (mut asset, plugin_header, plugin_registry) = validate_asset_permissions(
+    let checked_accounts = process_transfer(
         authority,
+        ctx.accounts.payer,
         ctx.accounts.asset,
         ctx.accounts.collection,
-        Some(ctx.accounts.new_owner),
+        ctx.accounts.new_owner,
+        ctx.accounts.system_program,
+        ctx.remaining_accounts,
+    )?;
+
+    // Any other token accounts passed are dependent accounts, such as vaults, and must be held by
+    // the asset signer, which guarantees that the new owner controls them as soon as the transfer
+    // completes.
+    if !ctx.remaining_accounts.is_empty() {
+        let (asset_signer, _) = find_asset_signer_address(ctx.accounts.asset.key);
+        for account_info in ctx.remaining_accounts {
+            if is_token_account(account_info) && !checked_accounts.contains(&account_info.key) {
+                validate_dependent_account(account_info, &asset_signer)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Transfer a non-compressed asset to a new owner, settling the payments required by its plugins.
+/// Returns the remaining accounts consumed by the plugins, which are not dependent accounts.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn process_transfer<'a>(
+    authority: &'a AccountInfo<'a>,
+    payer: &'a AccountInfo<'a>,
+    asset_info: &'a AccountInfo<'a>,
+    collection: Option<&'a AccountInfo<'a>>,
+    new_owner: &'a AccountInfo<'a>,
+    system_program: Option<&'a AccountInfo<'a>>,
+    remaining_accounts: &'a [AccountInfo<'a>],
+) -> Result<Vec<&'a Pubkey>, ProgramError> {
+    // Without plugins on the asset or its collection, only the owner can approve the transfer.
+    let asset_header = AssetV1::load_header(asset_info)?;
+    let has_plugins = asset_header.size != asset_info.data_len()
+        || match collection {
+            Some(collection_info) => {
+                CollectionV1::load_size(collection_info)? != collection_info.data_len()
+            }
+            None => false,
+        };
+    if !has_plugins {
+        return transfer_plain_asset(authority, asset_header, asset_info, collection, new_owner);
+    }
+
+    // Validate asset permissions.
+    let (mut asset, plugin_header, plugin_registry) = validate_asset_permissions(
+        authority,
+        remaining_accounts,
+        asset_info,
+        collection,
+        Some(new_owner),
+        None,
         None,
+        Some(HookableLifecycleEvent::Transfer),
         AssetV1::check_transfer,
         CollectionV1::check_transfer,
         PluginType::check_transfer,
-        AssetV1::validate_transfer,
+        AssetHeaderV1::validate_transfer,
         CollectionV1::validate_transfer,
         Plugin::validate_transfer,
     )?;
 
+    validate_tx_guard(asset_info, collection, remaining_accounts)?;
+
+    // A linked asset can only be transferred together with its partner.
+    if let Ok((_, linked_pair, _)) =
+        fetch_plugin::<AssetV1, LinkedPair>(asset_info, PluginType::LinkedPair)
+    {
+        let instructions_sysvar = remaining_accounts
+            .iter()
+            .find(|account_info| account_info.key == &sysvar::instructions::ID)
+            .ok_or(MplCoreError::MissingInstructionsSysvar)?;
+        linked_pair.check_transaction(instructions_sysvar, new_owner.key)?;
+    }
+
+    // An asset linked to fungible shares can only be transferred while the shares are escrowed.
+    let mut checked_accounts = vec![];
+    if let Ok((_, token_link, _)) =
+        fetch_plugin::<AssetV1, TokenLink>(asset_info, PluginType::TokenLink)
+    {
+        let escrow = remaining_accounts
+            .iter()
+            .find(|account_info| account_info.key == &token_link.escrow)
+            .ok_or_else(|| {
+                msg!("TokenLink: Rejected");
+                MplCoreError::InvalidTokenLinkEscrow
+            })?;
+        token_link.check_escrow(escrow)?;
+        checked_accounts.push(escrow.key);
+    }
+
+    // A sale of a listed asset by its marketplace pays the price to the owner, who must be passed
+    // in the remaining accounts.
+    if let Ok((listing_authority, listing, _)) =
+        fetch_plugin::<AssetV1, Listing>(asset_info, PluginType::Listing)
+    {
+        if listing.is_active(&listing_authority)
+            && listing_authority
+                == (Authority::Address {
+                    address: *authority.key,
+                })
+        {
+            let seller = remaining_accounts
+                .iter()
+                .find(|account_info| account_info.key == &asset.owner)
+                .ok_or_else(|| {
+                    msg!("Listing: Rejected");
+                    MplCoreError::MissingListingSeller
+                })?;
+            let system_program = system_program.ok_or(MplCoreError::MissingSystemProgram)?;
+
+            invoke(
+                &system_instruction::transfer(payer.key, seller.key, listing.price),
+                &[payer.clone(), seller.clone(), system_program.clone()],
+            )?;
+            checked_accounts.push(seller.key);
+        }
+    }
+
+    // Pay the transfer fees of the asset and its collection, whose recipients must be passed in
+    // the remaining accounts.
+    let transfer_fees = [
+        fetch_plugin::<AssetV1, TransferFee>(asset_info, PluginType::TransferFee).ok(),
+        collection.and_then(|collection| {
+            fetch_plugin::<CollectionV1, TransferFee>(collection, PluginType::TransferFee).ok()
+        }),
+    ];
+    for (_, transfer_fee, _) in transfer_fees.into_iter().flatten() {
+        let recipient = remaining_accounts
+            .iter()
+            .find(|account_info| account_info.key == &transfer_fee.recipient)
+            .ok_or_else(|| {
+                msg!("TransferFee: Rejected");
+                MplCoreError::MissingTransferFeeRecipient
+            })?;
+        let system_program = system_program.ok_or(MplCoreError::MissingSystemProgram)?;
+
+        invoke(
+            &system_instruction::transfer(payer.key, recipient.key, transfer_fee.fee),
+            &[payer.clone(), recipient.clone(), system_program.clone()],
+        )?;
+        checked_accounts.push(recipient.key);
+    }
+
     // Reset every owner-managed plugin in the registry.
     if let (Some(plugin_header), Some(mut plugin_registry)) =
         (plugin_header, plugin_registry.clone())
@@ -101,37 +246,66 @@ pub(crate) fn transfer<'a>(accounts: &'a [AccountInfo<'a>], args: TransferV1Args
         });
 
         // Save the plugin registry.
-        plugin_registry.save(ctx.accounts.asset, plugin_header.plugin_registry_offset)?;
+        plugin_registry.save(asset_info, plugin_header.plugin_registry_offset)?;
     }
 
     // Set the new owner.
-    asset.owner = *ctx.accounts.new_owner.key;
+    asset.owner = *new_owner.key;
 
-    // Reserialize the account into correct format.
-    match key {
-        Key::HashedAssetV1 => {
-            let system_program = ctx
-                .accounts
-                .system_program
-                .ok_or(MplCoreError::MissingSystemProgram)?;
+    // Increment sequence number only if it is `Some(_)`.
+    asset.seq = asset.seq.map(|seq| seq.saturating_add(1));
+    asset.save(asset_info)?;
 
-            // Compress the asset and plugin registry into account space.
-            let compression_proof = compress_into_account_space(
-                asset,
-                plugin_registry,
-                ctx.accounts.asset,
-                ctx.accounts.payer,
-                system_program,
-            )?;
+    // A transfer approval is single use, whoever transfers the asset.
+    if plugin_registry
+        .is_some_and(|plugin_registry| plugin_registry.find(PluginType::TransferApproval).is_some())
+    {
+        let system_program = system_program.ok_or(MplCoreError::MissingSystemProgram)?;
+        delete_plugin(
+            &PluginType::TransferApproval,
+            &AssetV1::load(asset_info, 0)?,
+            asset_info,
+            payer,
+            system_program,
+        )?;
+    }
+
+    Ok(checked_accounts)
+}
 
-            // Send the spl-noop event for indexing the compressed asset.
-            compression_proof.wrap()
+/// Transfer an asset that, like its collection, has no plugins.  No lifecycle checks apply, so
+/// the owner is verified directly, and the new owner is written in place.
+fn transfer_plain_asset<'a>(
+    authority: &AccountInfo,
+    mut asset: AssetHeaderV1,
+    asset_info: &AccountInfo,
+    collection: Option<&AccountInfo>,
+    new_owner: &AccountInfo,
+) -> Result<Vec<&'a Pubkey>, ProgramError> {
+    // If the asset is part of a collection, the collection must be passed in and it must be correct.
+    match (&asset.update_authority, collection) {
+        (UpdateAuthority::Collection(collection_address), Some(collection_info)) => {
+            if collection_info.key != collection_address {
+                return Err(MplCoreError::InvalidCollection.into());
+            }
         }
-        Key::AssetV1 => {
-            // Increment sequence number only if it is `Some(_)`.
-            asset.seq = asset.seq.map(|seq| seq.saturating_add(1));
-            asset.save(ctx.accounts.asset, 0)
+        (UpdateAuthority::Collection(_), None) => {
+            return Err(MplCoreError::MissingCollection.into())
         }
-        _ => unreachable!(),
+        (_, Some(_)) => return Err(MplCoreError::InvalidCollection.into()),
+        (_, None) => (),
+    }
+
+    if !cmp_pubkeys(authority.key, &asset.owner) {
+        return Err(MplCoreError::NoApprovals.into());
     }
+
+    // Set the new owner.
+    asset.owner = *new_owner.key;
+
+    // Increment sequence number only if it is `Some(_)`.
+    asset.seq = asset.seq.map(|seq| seq.saturating_add(1));
+    asset.save(asset_info)?;
+
+    Ok(vec![])
 }
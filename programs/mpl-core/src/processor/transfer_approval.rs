@@ -0,0 +1,146 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::{ApproveTransferV1Accounts, RevokeTransferApprovalV1Accounts},
+    plugins::{
+        create_meta_idempotent, delete_plugin, fetch_plugin, initialize_plugin, Plugin, PluginType,
+        TransferApproval,
+    },
+    state::{AssetV1, Authority, Key},
+    utils::{fetch_core_data, load_key, resolve_authority},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct ApproveTransferV1Args {
+    /// The address approved to transfer the asset.
+    pub delegate: Pubkey,
+    /// The only new owner the asset can be transferred to, if any.
+    pub destination: Option<Pubkey>,
+    /// The last slot in which the approval can be used.
+    pub expiry_slot: u64,
+}
+
+pub(crate) fn approve_transfer<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: ApproveTransferV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = ApproveTransferV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Approve transfer for compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    let (mut asset, _, _) = fetch_core_data::<AssetV1>(ctx.accounts.asset)?;
+    if authority.key != &asset.owner {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    if args.expiry_slot < Clock::get()?.slot {
+        msg!("Error: Transfer approval has already expired");
+        return Err(MplCoreError::InvalidPluginSetting.into());
+    }
+
+    // A new approval replaces the previous one.
+    if fetch_plugin::<AssetV1, TransferApproval>(ctx.accounts.asset, PluginType::TransferApproval)
+        .is_ok()
+    {
+        delete_plugin(
+            &PluginType::TransferApproval,
+            &asset,
+            ctx.accounts.asset,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+        )?;
+    }
+
+    // Increment sequence number and save only if it is `Some(_)`.
+    asset.increment_seq_and_save(ctx.accounts.asset)?;
+
+    let (_, mut plugin_header, mut plugin_registry) = create_meta_idempotent::<AssetV1>(
+        ctx.accounts.asset,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )?;
+    initialize_plugin::<AssetV1>(
+        &Plugin::TransferApproval(TransferApproval {
+            delegate: args.delegate,
+            destination: args.destination,
+            expiry_slot: args.expiry_slot,
+        }),
+        &Authority::Owner,
+        &mut plugin_header,
+        &mut plugin_registry,
+        ctx.accounts.asset,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )
+}
+
+pub(crate) fn revoke_transfer_approval<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = RevokeTransferApprovalV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Revoke transfer approval for compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    let (mut asset, _, _) = fetch_core_data::<AssetV1>(ctx.accounts.asset)?;
+    let (_, transfer_approval, _) = fetch_plugin::<AssetV1, TransferApproval>(
+        ctx.accounts.asset,
+        PluginType::TransferApproval,
+    )?;
+
+    // The delegate can give up the approval as well as the owner.
+    if authority.key != &asset.owner && authority.key != &transfer_approval.delegate {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    // Increment sequence number and save only if it is `Some(_)`.
+    asset.increment_seq_and_save(ctx.accounts.asset)?;
+
+    delete_plugin(
+        &PluginType::TransferApproval,
+        &asset,
+        ctx.accounts.asset,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )
+}
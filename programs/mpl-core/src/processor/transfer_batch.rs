@@ -0,0 +1,125 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use std::collections::BTreeSet;
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::TransferBatchV1Accounts,
+    state::{find_asset_signer_address, AssetV1, BatchItemResult, BatchItemStatus, Key},
+    utils::{
+        emit_batch_results, is_token_account, load_key, resolve_authority,
+        validate_dependent_account,
+    },
+};
+
+use super::process_transfer;
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct TransferBatchV1Args {
+    pub asset_count: u8,
+}
+
+pub(crate) fn transfer_batch<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: TransferBatchV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = TransferBatchV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if let Some(system_program) = ctx.accounts.system_program {
+        if system_program.key != &solana_program::system_program::ID {
+            return Err(MplCoreError::InvalidSystemProgram.into());
+        }
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    // The remaining accounts start with pairs of assets and their collections, where the program
+    // id stands in for the collection of an asset that is not in one.  The accounts that follow
+    // are shared by every transfer, e.g. the instructions sysvar and transfer fee recipients.
+    let pair_len = (args.asset_count as usize)
+        .checked_mul(2)
+        .ok_or(MplCoreError::NumericalOverflow)?;
+    if ctx.remaining_accounts.len() < pair_len {
+        return Err(MplCoreError::IncorrectAccount.into());
+    }
+    let (pairs, shared_accounts) = ctx.remaining_accounts.split_at(pair_len);
+
+    let mut seen_assets = BTreeSet::new();
+    let mut asset_signers = Vec::with_capacity(args.asset_count as usize);
+    let mut checked_accounts = vec![];
+    let mut results = Vec::with_capacity(args.asset_count as usize);
+    for (index, pair) in pairs.chunks(2).enumerate() {
+        let asset_info = &pair[0];
+        let collection_info = match &pair[1] {
+            collection_info if collection_info.key == &crate::ID => None,
+            collection_info => Some(collection_info),
+        };
+
+        if !seen_assets.insert(asset_info.key) {
+            return Err(MplCoreError::IncorrectAccount.into());
+        }
+
+        match load_key(asset_info, 0)? {
+            Key::HashedAssetV1 => {
+                msg!("Error: Transferring compressed is currently not available");
+                return Err(MplCoreError::NotAvailable.into());
+            }
+            Key::AssetV1 => (),
+            _ => return Err(MplCoreError::IncorrectAccount.into()),
+        }
+
+        // An asset already owned by the new owner, e.g. when a batch is retried, is left as is.
+        asset_signers.push(find_asset_signer_address(asset_info.key).0);
+        if AssetV1::load_header(asset_info)?.owner == *ctx.accounts.new_owner.key {
+            results.push(BatchItemResult {
+                index: index as u32,
+                asset: *asset_info.key,
+                status: BatchItemStatus::Skipped,
+            });
+            continue;
+        }
+
+        checked_accounts.extend(process_transfer(
+            authority,
+            ctx.accounts.payer,
+            asset_info,
+            collection_info,
+            ctx.accounts.new_owner,
+            ctx.accounts.system_program,
+            shared_accounts,
+        )?);
+
+        results.push(BatchItemResult {
+            index: index as u32,
+            asset: *asset_info.key,
+            status: BatchItemStatus::Success,
+        });
+    }
+
+    // Any other token accounts passed are dependent accounts and must be held by the signer of
+    // one of the assets.
+    for account_info in shared_accounts {
+        if is_token_account(account_info)
+            && !checked_accounts.contains(&account_info.key)
+            && !asset_signers
+                .iter()
+                .any(|asset_signer| validate_dependent_account(account_info, asset_signer).is_ok())
+        {
+            return Err(MplCoreError::InvalidDependentAccount.into());
+        }
+    }
+
+    // The batch is all or nothing, so every item that is reported succeeded or was skipped.
+    emit_batch_results(&results, ctx.accounts.log_wrapper)
+}
@@ -0,0 +1,167 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke, system_instruction,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::TransferCompressedV1Accounts,
+    plugins::{fetch_plugin, HookableLifecycleEvent, Plugin, PluginType, TransferFee},
+    state::{
+        find_asset_signer_address, AssetHeaderV1, AssetV1, Authority, CollectionV1,
+        CompressionProof, HashablePluginSchema, HashedAssetV1, Key, SolanaAccount, Wrappable,
+    },
+    utils::{
+        hash_compressed_asset, is_token_account, load_key, resolve_authority,
+        resolve_compression_proof, validate_compressed_asset_permissions,
+        validate_dependent_account, validate_tx_guard,
+    },
+};
+
+/// The plugins whose transfer does more than validate it, and which require the asset to be
+/// decompressed first.
+const DECOMPRESSED_TRANSFER_PLUGINS: [PluginType; 5] = [
+    PluginType::TransferFee,
+    PluginType::TxGuard,
+    PluginType::LinkedPair,
+    PluginType::Listing,
+    PluginType::TokenLink,
+];
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct TransferCompressedV1Args {
+    /// The compression proof of the asset, or `None` to use its staged proof.
+    pub compression_proof: Option<CompressionProof>,
+}
+
+pub(crate) fn transfer_compressed<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: TransferCompressedV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = TransferCompressedV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if let Some(system_program) = ctx.accounts.system_program {
+        if system_program.key != &solana_program::system_program::ID {
+            return Err(MplCoreError::InvalidSystemProgram.into());
+        }
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if load_key(ctx.accounts.asset, 0)? != Key::HashedAssetV1 {
+        return Err(MplCoreError::IncorrectAccount.into());
+    }
+
+    // Verify the proof, or use the staged proof.
+    let (mut asset, mut plugins) = resolve_compression_proof(
+        ctx.accounts.asset,
+        args.compression_proof,
+        ctx.remaining_accounts,
+    )?;
+
+    if let Some(plugin) = plugins
+        .iter()
+        .find(|plugin| DECOMPRESSED_TRANSFER_PLUGINS.contains(&PluginType::from(&plugin.plugin)))
+    {
+        msg!(
+            "Error: An asset with a {:?} plugin must be decompressed to be transferred",
+            PluginType::from(&plugin.plugin)
+        );
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    validate_compressed_asset_permissions(
+        authority,
+        ctx.remaining_accounts,
+        ctx.accounts.asset,
+        &asset,
+        &plugins,
+        ctx.accounts.collection,
+        Some(ctx.accounts.new_owner),
+        Some(HookableLifecycleEvent::Transfer),
+        AssetV1::check_transfer,
+        CollectionV1::check_transfer,
+        PluginType::check_transfer,
+        AssetHeaderV1::validate_transfer,
+        CollectionV1::validate_transfer,
+        Plugin::validate_transfer,
+    )?;
+
+    validate_tx_guard(
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        ctx.remaining_accounts,
+    )?;
+
+    // Pay the transfer fee of the collection, whose recipient must be passed in the remaining
+    // accounts.
+    let mut checked_accounts = vec![];
+    if let Some((_, transfer_fee, _)) = ctx.accounts.collection.and_then(|collection| {
+        fetch_plugin::<CollectionV1, TransferFee>(collection, PluginType::TransferFee).ok()
+    }) {
+        let recipient = ctx
+            .remaining_accounts
+            .iter()
+            .find(|account_info| account_info.key == &transfer_fee.recipient)
+            .ok_or_else(|| {
+                msg!("TransferFee: Rejected");
+                MplCoreError::MissingTransferFeeRecipient
+            })?;
+        let system_program = ctx
+            .accounts
+            .system_program
+            .ok_or(MplCoreError::MissingSystemProgram)?;
+
+        invoke(
+            &system_instruction::transfer(ctx.accounts.payer.key, recipient.key, transfer_fee.fee),
+            &[
+                ctx.accounts.payer.clone(),
+                recipient.clone(),
+                system_program.clone(),
+            ],
+        )?;
+        checked_accounts.push(recipient.key);
+    }
+
+    // Any other token accounts passed must be held by the asset signer, as for a regular transfer.
+    let (asset_signer, _) = find_asset_signer_address(ctx.accounts.asset.key);
+    for account_info in ctx.remaining_accounts {
+        if is_token_account(account_info) && !checked_accounts.contains(&account_info.key) {
+            validate_dependent_account(account_info, &asset_signer)?;
+        }
+    }
+
+    // A transfer approval is single use, and every other owner-managed plugin is reset.
+    plugins.retain(|plugin| PluginType::from(&plugin.plugin) != PluginType::TransferApproval);
+    for plugin in plugins.iter_mut() {
+        if PluginType::from(&plugin.plugin).manager() == Authority::Owner {
+            plugin.authority = Authority::Owner;
+        }
+    }
+
+    // Set the new owner and increment the sequence number.
+    asset.owner = *ctx.accounts.new_owner.key;
+    let seq = asset.seq.unwrap_or(0).saturating_add(1);
+    asset.seq = Some(seq);
+
+    // The new hash has the same size, so the account is updated in place.
+    plugins.sort_by(HashablePluginSchema::compare_indeces);
+    HashedAssetV1::new(hash_compressed_asset(&asset, &plugins)?).save(ctx.accounts.asset, 0)?;
+
+    if ctx.accounts.log_wrapper.is_some() {
+        CompressionProof::new(asset, seq, plugins).wrap()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use mpl_utils::assert_signer;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke, system_instruction,
+    system_program,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::UnbindV1Accounts,
+    plugins::{fetch_plugin, BoundUntil, Plugin, PluginType},
+    state::{AssetV1, Key, SolanaAccount},
+    utils::load_key,
+};
+
+pub(crate) fn unbind<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Accounts.
+    let ctx = UnbindV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+
+    if ctx.accounts.system_program.key != &system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Unbind for compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    let asset = AssetV1::load(ctx.accounts.asset, 0)?;
+    if asset.owner != *ctx.accounts.payer.key {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    let (_, mut bound_until, offset) =
+        fetch_plugin::<AssetV1, BoundUntil>(ctx.accounts.asset, PluginType::BoundUntil)?;
+
+    // Nothing to pay for once the unlock time has passed.
+    if !bound_until.is_bound()? {
+        return Ok(());
+    }
+
+    if bound_until.unbind_fee == 0 {
+        return Err(MplCoreError::CannotUnbind.into());
+    }
+
+    if bound_until.fee_recipient != *ctx.accounts.fee_recipient.key {
+        return Err(MplCoreError::IncorrectAccount.into());
+    }
+
+    invoke(
+        &system_instruction::transfer(
+            ctx.accounts.payer.key,
+            ctx.accounts.fee_recipient.key,
+            bound_until.unbind_fee,
+        ),
+        &[
+            ctx.accounts.payer.clone(),
+            ctx.accounts.fee_recipient.clone(),
+            ctx.accounts.system_program.clone(),
+        ],
+    )?;
+
+    // The plugin does not change size so it can be saved in place.
+    bound_until.unbind();
+    Plugin::BoundUntil(bound_until).save(ctx.accounts.asset, offset)
+}
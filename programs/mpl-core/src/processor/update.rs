@@ -1,17 +1,18 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_utils::assert_signer;
-use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_memory::sol_memcpy,
-};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
 
 use crate::{
     error::MplCoreError,
-    instruction::accounts::{UpdateCollectionV1Accounts, UpdateV1Accounts},
-    plugins::{Plugin, PluginHeaderV1, PluginRegistryV1, PluginType, RegistryRecord},
-    state::{AssetV1, CollectionV1, DataBlob, Key, SolanaAccount, UpdateAuthority},
+    instruction::accounts::{UpdateCollectionV1Accounts, UpdateV1Accounts, UpdateV2Accounts},
+    plugins::{
+        fetch_plugin, HookableLifecycleEvent, MintLimit, Paused, Plugin, PluginHeaderV1,
+        PluginRegistryV1, PluginType, RegistryRecord, UpdateApproval, UpdateInfo,
+    },
+    state::{AssetHeaderV1, AssetV1, CollectionV1, DataBlob, Key, SolanaAccount, UpdateAuthority},
     utils::{
-        load_key, resize_or_reallocate_account, resolve_authority, validate_asset_permissions,
-        validate_collection_permissions,
+        load_key, rejection_error, resize_or_reallocate_account, resolve_authority,
+        validate_asset_permissions, validate_collection_permissions, validate_tx_guard,
     },
 };
 
@@ -27,55 +28,213 @@ pub(crate) fn update<'a>(accounts: &'a [AccountInfo<'a>], args: UpdateV1Args) ->
     // Accounts.
     let ctx = UpdateV1Accounts::context(accounts)?;
 
+    update_asset(
+        UpdateAssetAccounts {
+            asset: ctx.accounts.asset,
+            collection: ctx.accounts.collection,
+            payer: ctx.accounts.payer,
+            authority: ctx.accounts.authority,
+            new_collection: None,
+            new_collection_authority: None,
+            system_program: ctx.accounts.system_program,
+            log_wrapper: ctx.accounts.log_wrapper,
+            remaining_accounts: ctx.remaining_accounts,
+        },
+        UpdateV2Args {
+            new_name: args.new_name,
+            new_uri: args.new_uri,
+            new_update_authority: args.new_update_authority,
+        },
+        false,
+    )
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct UpdateV2Args {
+    pub new_name: Option<String>,
+    pub new_uri: Option<String>,
+    pub new_update_authority: Option<UpdateAuthority>,
+}
+
+pub(crate) fn update_v2<'a>(accounts: &'a [AccountInfo<'a>], args: UpdateV2Args) -> ProgramResult {
+    // Accounts.
+    let ctx = UpdateV2Accounts::context(accounts)?;
+
+    update_asset(
+        UpdateAssetAccounts {
+            asset: ctx.accounts.asset,
+            collection: ctx.accounts.collection,
+            payer: ctx.accounts.payer,
+            authority: ctx.accounts.authority,
+            new_collection: ctx.accounts.new_collection,
+            new_collection_authority: ctx.accounts.new_collection_authority,
+            system_program: ctx.accounts.system_program,
+            log_wrapper: ctx.accounts.log_wrapper,
+            remaining_accounts: ctx.remaining_accounts,
+        },
+        args,
+        true,
+    )
+}
+
+struct UpdateAssetAccounts<'a> {
+    asset: &'a AccountInfo<'a>,
+    collection: Option<&'a AccountInfo<'a>>,
+    payer: &'a AccountInfo<'a>,
+    authority: Option<&'a AccountInfo<'a>>,
+    new_collection: Option<&'a AccountInfo<'a>>,
+    new_collection_authority: Option<&'a AccountInfo<'a>>,
+    system_program: &'a AccountInfo<'a>,
+    log_wrapper: Option<&'a AccountInfo<'a>>,
+    remaining_accounts: &'a [AccountInfo<'a>],
+}
+
+fn update_asset<'a>(
+    accounts: UpdateAssetAccounts<'a>,
+    args: UpdateV2Args,
+    allow_collection_change: bool,
+) -> ProgramResult {
     // Guards.
-    assert_signer(ctx.accounts.payer)?;
-    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+    assert_signer(accounts.payer)?;
+    let authority = resolve_authority(accounts.payer, accounts.authority)?;
 
-    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+    if accounts.system_program.key != &solana_program::system_program::ID {
         return Err(MplCoreError::InvalidSystemProgram.into());
     }
 
-    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+    if let Some(log_wrapper) = accounts.log_wrapper {
         if log_wrapper.key != &spl_noop::ID {
             return Err(MplCoreError::InvalidLogWrapperProgram.into());
         }
     }
 
-    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+    if let Key::HashedAssetV1 = load_key(accounts.asset, 0)? {
         msg!("Error: Update for compressed is not available");
         return Err(MplCoreError::NotAvailable.into());
     }
 
-    let (mut asset, plugin_header, plugin_registry) = validate_asset_permissions(
+    // Plugins only see the fields that actually change.
+    let update_info = UpdateInfo {
+        new_name: args.new_name.as_deref(),
+        new_uri: args.new_uri.as_deref(),
+        new_update_authority: args.new_update_authority.as_ref(),
+    };
+
+    let (_, plugin_header, plugin_registry) = validate_asset_permissions(
         authority,
-        ctx.accounts.asset,
-        ctx.accounts.collection,
+        accounts.remaining_accounts,
+        accounts.asset,
+        accounts.collection,
         None,
         None,
+        Some(&update_info),
+        Some(HookableLifecycleEvent::Update),
         AssetV1::check_update,
         CollectionV1::check_update,
         PluginType::check_update,
-        AssetV1::validate_update,
+        AssetHeaderV1::validate_update,
         CollectionV1::validate_update,
         Plugin::validate_update,
     )?;
+    let mut asset = AssetV1::load(accounts.asset, 0)?;
+
+    validate_tx_guard(
+        accounts.asset,
+        accounts.collection,
+        accounts.remaining_accounts,
+    )?;
+
+    // Changes to the name and uri must have been approved if the asset requires it.
+    if args.new_name.is_some() || args.new_uri.is_some() {
+        if let Ok((_, update_approval, _)) =
+            fetch_plugin::<AssetV1, UpdateApproval>(accounts.asset, PluginType::UpdateApproval)
+        {
+            let name = args.new_name.as_ref().unwrap_or(&asset.name);
+            let uri = args.new_uri.as_ref().unwrap_or(&asset.uri);
+            if !update_approval.is_approved(name, uri) {
+                msg!("UpdateApproval: Rejected");
+                return Err(MplCoreError::InvalidAuthority.into());
+            }
+        }
+    }
 
     // Increment sequence number and save only if it is `Some(_)`.
-    asset.increment_seq_and_save(ctx.accounts.asset)?;
+    asset.increment_seq_and_save(accounts.asset)?;
 
     let asset_size = asset.get_size() as isize;
 
     let mut dirty = false;
     if let Some(new_update_authority) = args.new_update_authority {
-        if let UpdateAuthority::Collection(_collection_address) = new_update_authority {
-            // Updating collections is not currently available.
+        let is_collection_change = matches!(new_update_authority, UpdateAuthority::Collection(_))
+            || matches!(asset.update_authority, UpdateAuthority::Collection(_));
+        if is_collection_change && !allow_collection_change {
+            // Moving an asset into or out of a collection is only available through UpdateV2.
             return Err(MplCoreError::NotAvailable.into());
-        };
+        }
 
-        if let UpdateAuthority::Collection(_collection_address) = asset.update_authority {
-            // Removing from collection is not currently available.
-            // will require the collection size to be updated
-            return Err(MplCoreError::NotAvailable.into());
+        // Delegates can update the asset but only the update authority itself can hand over
+        // the update authority.  For an asset in a collection this is the update authority of
+        // the collection, which is removed from the collection.
+        match asset.update_authority {
+            UpdateAuthority::Collection(_) => {
+                // The collection has been checked against the asset by the permission validation.
+                let collection_info = accounts.collection.ok_or(MplCoreError::MissingCollection)?;
+                let mut collection = CollectionV1::load(collection_info, 0)?;
+                if collection.update_authority != *authority.key {
+                    return Err(MplCoreError::InvalidAuthority.into());
+                }
+
+                collection.decrement()?;
+                collection.save(collection_info, 0)?;
+            }
+            _ => {
+                if asset.update_authority.key() != *authority.key {
+                    return Err(MplCoreError::InvalidAuthority.into());
+                }
+            }
+        }
+
+        // The update authority of the new collection must also approve the asset joining it.
+        if let UpdateAuthority::Collection(new_collection_address) = new_update_authority {
+            let new_collection_info = accounts
+                .new_collection
+                .filter(|new_collection| new_collection.key == &new_collection_address)
+                .ok_or(MplCoreError::InvalidCollection)?;
+            if new_collection_info.owner != &crate::ID {
+                return Err(MplCoreError::InvalidCollection.into());
+            }
+
+            let new_collection_authority =
+                resolve_authority(authority, accounts.new_collection_authority)?;
+            let mut new_collection = CollectionV1::load(new_collection_info, 0)?;
+            if new_collection.update_authority != *new_collection_authority.key {
+                return Err(MplCoreError::InvalidAuthority.into());
+            }
+
+            // No assets can join a paused collection, as no new assets can be minted into it.
+            if let Ok((_, paused, _)) =
+                fetch_plugin::<CollectionV1, Paused>(new_collection_info, PluginType::Paused)
+            {
+                if paused.paused {
+                    msg!("Paused: Rejected");
+                    return Err(rejection_error(Some(PluginType::Paused)));
+                }
+            }
+
+            new_collection.current_size = new_collection
+                .current_size
+                .checked_add(1)
+                .ok_or(MplCoreError::NumericalOverflowError)?;
+            new_collection.save(new_collection_info, 0)?;
+
+            // Count the joining asset against the maximum supply of the collection.
+            if let Ok((_, mut mint_limit, offset)) =
+                fetch_plugin::<CollectionV1, MintLimit>(new_collection_info, PluginType::MintLimit)
+            {
+                mint_limit.record_mint()?;
+                Plugin::MintLimit(mint_limit).save(new_collection_info, offset)?;
+            }
         }
 
         asset.update_authority = new_update_authority;
@@ -95,9 +254,9 @@ pub(crate) fn update<'a>(accounts: &'a [AccountInfo<'a>], args: UpdateV1Args) ->
             &plugin_header,
             &plugin_registry,
             asset_size,
-            ctx.accounts.asset,
-            ctx.accounts.payer,
-            ctx.accounts.system_program,
+            accounts.asset,
+            accounts.payer,
+            accounts.system_program,
         )?;
     }
 
@@ -132,10 +291,22 @@ pub(crate) fn update_collection<'a>(
         }
     }
 
+    let new_update_authority = ctx
+        .accounts
+        .new_update_authority
+        .map(|new_update_authority| UpdateAuthority::Address(*new_update_authority.key));
+    let update_info = UpdateInfo {
+        new_name: args.new_name.as_deref(),
+        new_uri: args.new_uri.as_deref(),
+        new_update_authority: new_update_authority.as_ref(),
+    };
+
     let (mut collection, plugin_header, plugin_registry) = validate_collection_permissions(
         authority,
+        ctx.remaining_accounts,
         ctx.accounts.collection,
         None,
+        Some(&update_info),
         CollectionV1::check_update,
         PluginType::check_update,
         CollectionV1::validate_update,
@@ -213,16 +384,23 @@ fn process_update<'a, T: DataBlob + SolanaAccount>(
             .checked_add(size_diff)
             .ok_or(MplCoreError::NumericalOverflow)?;
 
-        // //TODO: This is memory intensive, we should use memmove instead probably.
-        let src = account.data.borrow()[(plugin_offset as usize)..registry_offset].to_vec();
-
-        resize_or_reallocate_account(account, payer, system_program, new_size as usize)?;
-
-        sol_memcpy(
-            &mut account.data.borrow_mut()[(new_plugin_offset as usize)..],
-            &src,
-            src.len(),
-        );
+        // Shift the plugin data in place rather than copying it onto the heap, which would run out
+        // of memory for large accounts. When shrinking, the data must be moved down before the
+        // account is resized (which refunds the freed rent to the payer); when growing, after.
+        let plugin_data_range = (plugin_offset as usize)..registry_offset;
+        if size_diff < 0 {
+            account
+                .data
+                .borrow_mut()
+                .copy_within(plugin_data_range, new_plugin_offset as usize);
+            resize_or_reallocate_account(account, payer, system_program, new_size as usize)?;
+        } else {
+            resize_or_reallocate_account(account, payer, system_program, new_size as usize)?;
+            account
+                .data
+                .borrow_mut()
+                .copy_within(plugin_data_range, new_plugin_offset as usize);
+        }
 
         plugin_header.save(account, new_core_size as usize)?;
         plugin_registry.registry = plugin_registry
@@ -239,6 +417,7 @@ fn process_update<'a, T: DataBlob + SolanaAccount>(
                 })
             })
             .collect::<Result<Vec<_>, MplCoreError>>()?;
+        plugin_registry.shift_external_plugins(plugin_offset as usize, size_diff)?;
         plugin_registry.save(account, new_registry_offset as usize)?;
     } else {
         resize_or_reallocate_account(account, payer, system_program, core.get_size())?;
@@ -0,0 +1,107 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::{
+        UpdateCollectionExternalPluginV1Accounts, UpdateExternalPluginV1Accounts,
+    },
+    plugins::{update_external_plugin_config, ExternalPluginKey, ExternalPluginUpdateInfo},
+    state::{AssetV1, Authority, CollectionV1, Key, SolanaAccount},
+    utils::{fetch_core_data, load_key, resolve_authority, resolve_pubkey_to_authorities},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct UpdateExternalPluginV1Args {
+    key: ExternalPluginKey,
+    update_info: ExternalPluginUpdateInfo,
+}
+
+pub(crate) fn update_external_plugin<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: UpdateExternalPluginV1Args,
+) -> ProgramResult {
+    let ctx = UpdateExternalPluginV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Updating external plugin for compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    // External plugins can only be updated by the update authority.
+    let (mut asset, _, _) = fetch_core_data::<AssetV1>(ctx.accounts.asset)?;
+    if !resolve_pubkey_to_authorities(authority, ctx.accounts.collection, &asset.header())?
+        .contains(&Authority::UpdateAuthority)
+    {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    // Increment sequence number and save only if it is `Some(_)`.
+    asset.increment_seq_and_save(ctx.accounts.asset)?;
+
+    update_external_plugin_config::<AssetV1>(
+        &args.key,
+        &args.update_info,
+        ctx.accounts.asset,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct UpdateCollectionExternalPluginV1Args {
+    key: ExternalPluginKey,
+    update_info: ExternalPluginUpdateInfo,
+}
+
+pub(crate) fn update_collection_external_plugin<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: UpdateCollectionExternalPluginV1Args,
+) -> ProgramResult {
+    let ctx = UpdateCollectionExternalPluginV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    // External plugins can only be updated by the update authority.
+    let collection = CollectionV1::load(ctx.accounts.collection, 0)?;
+    if collection.update_authority != *authority.key {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    update_external_plugin_config::<CollectionV1>(
+        &args.key,
+        &args.update_info,
+        ctx.accounts.collection,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )
+}
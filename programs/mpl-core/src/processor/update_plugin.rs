@@ -1,14 +1,15 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_utils::assert_signer;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_memory::sol_memcpy,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    program_memory::sol_memcpy,
 };
 
 use crate::{
     error::MplCoreError,
     instruction::accounts::{UpdateCollectionPluginV1Accounts, UpdatePluginV1Accounts},
     plugins::{Plugin, PluginType, RegistryRecord},
-    state::{AssetV1, CollectionV1, DataBlob, Key, SolanaAccount},
+    state::{AssetHeaderV1, AssetV1, CollectionV1, DataBlob, Key, SolanaAccount},
     utils::{
         load_key, resize_or_reallocate_account, resolve_authority, validate_asset_permissions,
         validate_collection_permissions,
@@ -49,14 +50,17 @@ pub(crate) fn update_plugin<'a>(
 
     let (mut asset, plugin_header, plugin_registry) = validate_asset_permissions(
         authority,
+        ctx.remaining_accounts,
         ctx.accounts.asset,
         ctx.accounts.collection,
         None,
         Some(&args.plugin),
+        None,
+        None,
         AssetV1::check_update_plugin,
         CollectionV1::check_update_plugin,
         PluginType::check_update_plugin,
-        AssetV1::validate_update_plugin,
+        AssetHeaderV1::validate_update_plugin,
         CollectionV1::validate_update_plugin,
         Plugin::validate_update_plugin,
     )?;
@@ -67,18 +71,15 @@ pub(crate) fn update_plugin<'a>(
     let plugin_registry_clone = plugin_registry.clone();
     let plugin_type: PluginType = (&args.plugin).into();
     let registry_record = plugin_registry_clone
-        .registry
-        .iter()
-        .find(|record| record.plugin_type == plugin_type)
+        .find(plugin_type)
         .ok_or(MplCoreError::PluginNotFound)?;
 
     let plugin = Plugin::load(ctx.accounts.asset, registry_record.offset)?;
-    let plugin_data = plugin.try_to_vec()?;
-    let new_plugin_data = args.plugin.try_to_vec()?;
+    let new_plugin = apply_program_state(&plugin, args.plugin)?;
 
     // The difference in size between the new and old account which is used to calculate the new size of the account.
-    let plugin_size = plugin_data.len() as isize;
-    let size_diff = (new_plugin_data.len() as isize)
+    let plugin_size = plugin.get_size() as isize;
+    let size_diff = (new_plugin.get_size() as isize)
         .checked_sub(plugin_size)
         .ok_or(MplCoreError::NumericalOverflow)?;
 
@@ -120,7 +121,7 @@ pub(crate) fn update_plugin<'a>(
         src.len(),
     );
 
-    plugin_header.save(ctx.accounts.asset, asset.get_size())?;
+    plugin_header.save(ctx.accounts.asset, asset.size)?;
     plugin_registry.registry = plugin_registry
         .registry
         .clone()
@@ -140,9 +141,9 @@ pub(crate) fn update_plugin<'a>(
             })
         })
         .collect::<Result<Vec<_>, MplCoreError>>()?;
+    plugin_registry.shift_external_plugins(next_plugin_offset as usize, size_diff)?;
     plugin_registry.save(ctx.accounts.asset, new_registry_offset as usize)?;
-    args.plugin
-        .save(ctx.accounts.asset, registry_record.offset)?;
+    new_plugin.save(ctx.accounts.asset, registry_record.offset)?;
 
     // Increment sequence number and save only if it is `Some(_)`.
     asset.increment_seq_and_save(ctx.accounts.asset)?;
@@ -180,8 +181,10 @@ pub(crate) fn update_collection_plugin<'a>(
     // Validate collection permissions.
     let (collection, plugin_header, plugin_registry) = validate_collection_permissions(
         authority,
+        ctx.remaining_accounts,
         ctx.accounts.collection,
         Some(&args.plugin),
+        None,
         CollectionV1::check_update_plugin,
         PluginType::check_update_plugin,
         CollectionV1::validate_update_plugin,
@@ -196,18 +199,15 @@ pub(crate) fn update_collection_plugin<'a>(
     let plugin_registry_clone = plugin_registry.clone();
     let plugin_type: PluginType = (&args.plugin).into();
     let registry_record = plugin_registry_clone
-        .registry
-        .iter()
-        .find(|record| record.plugin_type == plugin_type)
+        .find(plugin_type)
         .ok_or(MplCoreError::PluginNotFound)?;
 
     let plugin = Plugin::load(ctx.accounts.collection, registry_record.offset)?;
-    let plugin_data = plugin.try_to_vec()?;
-    let new_plugin_data = args.plugin.try_to_vec()?;
+    let new_plugin = apply_program_state(&plugin, args.plugin)?;
 
     // The difference in size between the new and old account which is used to calculate the new size of the account.
-    let plugin_size = plugin_data.len() as isize;
-    let size_diff = (new_plugin_data.len() as isize)
+    let plugin_size = plugin.get_size() as isize;
+    let size_diff = (new_plugin.get_size() as isize)
         .checked_sub(plugin_size)
         .ok_or(MplCoreError::NumericalOverflow)?;
 
@@ -269,13 +269,24 @@ pub(crate) fn update_collection_plugin<'a>(
             })
         })
         .collect::<Result<Vec<_>, MplCoreError>>()?;
+    plugin_registry.shift_external_plugins(next_plugin_offset as usize, size_diff)?;
     plugin_registry.save(ctx.accounts.collection, new_registry_offset as usize)?;
-    args.plugin
-        .save(ctx.accounts.collection, registry_record.offset)?;
+    new_plugin.save(ctx.accounts.collection, registry_record.offset)?;
 
     process_update_plugin()
 }
 
+// Some plugins hold state that is maintained by the program rather than the caller, such as
+// the stake timestamps, so only the caller controlled fields are taken from the update.
+fn apply_program_state(plugin: &Plugin, new_plugin: Plugin) -> Result<Plugin, ProgramError> {
+    match (plugin, new_plugin) {
+        (Plugin::Staking(staking), Plugin::Staking(update)) => {
+            Ok(Plugin::Staking(staking.apply(update.staked)?))
+        }
+        (_, new_plugin) => Ok(new_plugin),
+    }
+}
+
 //TODO
 fn process_update_plugin() -> ProgramResult {
     Ok(())
@@ -0,0 +1,93 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::set_return_data,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::ValidateLifecycleV1Accounts,
+    plugins::{HookableLifecycleEvent, Plugin, PluginType},
+    state::{AssetHeaderV1, AssetV1, CollectionV1, Key},
+    utils::{evaluate_asset_permissions, load_key},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct ValidateLifecycleV1Args {
+    /// The lifecycle event to validate.
+    pub event: HookableLifecycleEvent,
+}
+
+pub(crate) fn validate_lifecycle<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: ValidateLifecycleV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = ValidateLifecycleV1Accounts::context(accounts)?;
+
+    // Guards.
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Validate lifecycle for compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    // The authority is not required to sign, so that any party can be checked in simulation.
+    let (_, _, _, validation) = match args.event {
+        HookableLifecycleEvent::Transfer => evaluate_asset_permissions(
+            ctx.accounts.authority,
+            ctx.remaining_accounts,
+            ctx.accounts.asset,
+            ctx.accounts.collection,
+            Some(
+                ctx.accounts
+                    .new_owner
+                    .ok_or(MplCoreError::MissingNewOwner)?,
+            ),
+            None,
+            None,
+            Some(HookableLifecycleEvent::Transfer),
+            AssetV1::check_transfer,
+            CollectionV1::check_transfer,
+            PluginType::check_transfer,
+            AssetHeaderV1::validate_transfer,
+            CollectionV1::validate_transfer,
+            Plugin::validate_transfer,
+        )?,
+        HookableLifecycleEvent::Burn => evaluate_asset_permissions(
+            ctx.accounts.authority,
+            ctx.remaining_accounts,
+            ctx.accounts.asset,
+            ctx.accounts.collection,
+            None,
+            None,
+            None,
+            Some(HookableLifecycleEvent::Burn),
+            AssetV1::check_burn,
+            CollectionV1::check_burn,
+            PluginType::check_burn,
+            AssetHeaderV1::validate_burn,
+            CollectionV1::validate_burn,
+            Plugin::validate_burn,
+        )?,
+        HookableLifecycleEvent::Update => evaluate_asset_permissions(
+            ctx.accounts.authority,
+            ctx.remaining_accounts,
+            ctx.accounts.asset,
+            ctx.accounts.collection,
+            None,
+            None,
+            None,
+            Some(HookableLifecycleEvent::Update),
+            AssetV1::check_update,
+            CollectionV1::check_update,
+            PluginType::check_update,
+            AssetHeaderV1::validate_update,
+            CollectionV1::validate_update,
+            Plugin::validate_update,
+        )?,
+    };
+
+    set_return_data(&validation.try_to_vec()?);
+
+    Ok(())
+}
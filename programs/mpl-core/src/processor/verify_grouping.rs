@@ -0,0 +1,66 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::VerifyGroupingV1Accounts,
+    plugins::{fetch_plugin, Groupings, Plugin, PluginType},
+    state::{AssetV1, CollectionV1, Key, SolanaAccount},
+    utils::{load_key, resolve_authority},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct VerifyGroupingV1Args {
+    pub verified: bool,
+}
+
+pub(crate) fn verify_grouping<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: VerifyGroupingV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = VerifyGroupingV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Verify grouping for compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    // The group must be a collection owned by this program and signed for by its update authority.
+    if ctx.accounts.group.owner != &crate::ID {
+        return Err(MplCoreError::InvalidCollection.into());
+    }
+    let group = CollectionV1::load(ctx.accounts.group, 0)?;
+    if authority.key != &group.update_authority {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    let mut asset = AssetV1::load(ctx.accounts.asset, 0)?;
+    let (_, mut groupings, offset) =
+        fetch_plugin::<AssetV1, Groupings>(ctx.accounts.asset, PluginType::Groupings)?;
+
+    let grouping = groupings
+        .groupings
+        .iter_mut()
+        .find(|grouping| &grouping.collection == ctx.accounts.group.key)
+        .ok_or(MplCoreError::InvalidCollection)?;
+    grouping.verified = args.verified;
+
+    // Verification does not change the size of the plugin so it can be saved in place.
+    Plugin::Groupings(groupings).save(ctx.accounts.asset, offset)?;
+
+    // Increment sequence number and save only if it is `Some(_)`.
+    asset.increment_seq_and_save(ctx.accounts.asset)
+}
@@ -0,0 +1,307 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::{assert_signer, create_or_allocate_account_raw};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_memory::sol_memcpy,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::{
+        WriteAppDataChunkV1Accounts, WriteAppDataV1Accounts, WriteCollectionAppDataV1Accounts,
+        WriteLinkedAppDataV1Accounts,
+    },
+    plugins::{
+        write_external_plugin_data, DataSection, ExternalPluginType, PluginHeaderV1,
+        PluginRegistryV1,
+    },
+    state::{
+        find_linked_data_section_address, AssetV1, Authority, CollectionV1, DataBlob, Key,
+        LinkedDataSectionV1, SolanaAccount, UpdateAuthority, LINKED_DATA_SECTION_PREFIX,
+    },
+    utils::{
+        fetch_core_registry, load_key, resize_or_reallocate_account, resolve_authority,
+        resolve_pubkey_to_authorities, resolve_pubkey_to_authorities_collection,
+    },
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct WriteAppDataV1Args {
+    data_authority: Authority,
+    data: Vec<u8>,
+}
+
+pub(crate) fn write_app_data<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: WriteAppDataV1Args,
+) -> ProgramResult {
+    let ctx = WriteAppDataV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Writing app data for compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    // Only the data authority can write the data.
+    let mut asset = AssetV1::load(ctx.accounts.asset, 0)?;
+    if !resolve_pubkey_to_authorities(authority, ctx.accounts.collection, &asset.header())?
+        .contains(&args.data_authority)
+    {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    // Increment sequence number and save only if it is `Some(_)`.
+    asset.increment_seq_and_save(ctx.accounts.asset)?;
+
+    write_external_plugin_data::<AssetV1>(
+        ExternalPluginType::AppData,
+        &args.data_authority,
+        &args.data,
+        ctx.accounts.asset,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct WriteAppDataChunkV1Args {
+    data_authority: Authority,
+    offset: u64,
+    data: Vec<u8>,
+}
+
+/// Write a chunk of the data of an app data external plugin of an asset in place, so that data
+/// larger than a transaction, or than an account can grow by in one instruction, is written
+/// across several instructions.
+pub(crate) fn write_app_data_chunk<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: WriteAppDataChunkV1Args,
+) -> ProgramResult {
+    let ctx = WriteAppDataChunkV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Writing app data for compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    // Only the data authority can write the data.
+    let mut asset = AssetV1::load(ctx.accounts.asset, 0)?;
+    if !resolve_pubkey_to_authorities(authority, ctx.accounts.collection, &asset.header())?
+        .contains(&args.data_authority)
+    {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    // Increment sequence number and save only if it is `Some(_)`.
+    asset.increment_seq_and_save(ctx.accounts.asset)?;
+
+    let header_offset = asset.get_size();
+    if header_offset == ctx.accounts.asset.data_len() {
+        return Err(MplCoreError::PluginNotFound.into());
+    }
+
+    let mut plugin_header = PluginHeaderV1::load(ctx.accounts.asset, header_offset)?;
+    let mut plugin_registry =
+        PluginRegistryV1::load(ctx.accounts.asset, plugin_header.plugin_registry_offset)?;
+
+    // As for inscriptions, chunks grow the data when written past its end and must not leave a
+    // gap after the existing data.
+    let mut data_section = DataSection::find(
+        &plugin_registry,
+        ExternalPluginType::AppData,
+        &args.data_authority,
+    )?;
+    let offset = args.offset as usize;
+    if offset > data_section.len() {
+        return Err(MplCoreError::InvalidDataOffset.into());
+    }
+
+    let end = offset
+        .checked_add(args.data.len())
+        .ok_or(MplCoreError::NumericalOverflow)?;
+    if end > data_section.len() {
+        data_section.resize(
+            end,
+            &mut plugin_header,
+            &mut plugin_registry,
+            ctx.accounts.asset,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+        )?;
+    }
+    data_section.write_at(offset, &args.data, ctx.accounts.asset)?;
+
+    plugin_header.save(ctx.accounts.asset, header_offset)?;
+    plugin_registry.save(ctx.accounts.asset, plugin_header.plugin_registry_offset)
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct WriteCollectionAppDataV1Args {
+    data_authority: Authority,
+    data: Vec<u8>,
+}
+
+pub(crate) fn write_collection_app_data<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: WriteCollectionAppDataV1Args,
+) -> ProgramResult {
+    let ctx = WriteCollectionAppDataV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    // Only the data authority can write the data.
+    if !resolve_pubkey_to_authorities_collection(authority, ctx.accounts.collection)?
+        .contains(&args.data_authority)
+    {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    write_external_plugin_data::<CollectionV1>(
+        ExternalPluginType::AppData,
+        &args.data_authority,
+        &args.data,
+        ctx.accounts.collection,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct WriteLinkedAppDataV1Args {
+    data_authority: Authority,
+    data: Vec<u8>,
+}
+
+pub(crate) fn write_linked_app_data<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: WriteLinkedAppDataV1Args,
+) -> ProgramResult {
+    let ctx = WriteLinkedAppDataV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    let asset = AssetV1::load(ctx.accounts.asset, 0)?;
+    if asset.update_authority != UpdateAuthority::Collection(*ctx.accounts.collection.key) {
+        return Err(MplCoreError::InvalidCollection.into());
+    }
+
+    // The collection must have linked app data for the data authority.
+    let (_, plugin_registry) = fetch_core_registry::<CollectionV1>(ctx.accounts.collection)?;
+    if !plugin_registry.is_some_and(|registry| {
+        registry.external_plugins.iter().any(|record| {
+            record.plugin_type == ExternalPluginType::LinkedAppData
+                && record.authority == args.data_authority
+        })
+    }) {
+        return Err(MplCoreError::PluginNotFound.into());
+    }
+
+    // Only the data authority can write the data.
+    if !resolve_pubkey_to_authorities(authority, Some(ctx.accounts.collection), &asset.header())?
+        .contains(&args.data_authority)
+    {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    let (linked_data_section_address, bump) = find_linked_data_section_address(
+        ctx.accounts.collection.key,
+        &args.data_authority,
+        ctx.accounts.asset.key,
+    );
+    if ctx.accounts.linked_data_section.key != &linked_data_section_address {
+        return Err(MplCoreError::IncorrectAccount.into());
+    }
+
+    let header = LinkedDataSectionV1 {
+        key: Key::LinkedDataSectionV1,
+        collection: *ctx.accounts.collection.key,
+        asset: *ctx.accounts.asset.key,
+        data_authority: args.data_authority,
+    };
+    let new_size = header
+        .get_size()
+        .checked_add(args.data.len())
+        .ok_or(MplCoreError::NumericalOverflow)?;
+
+    if ctx.accounts.linked_data_section.data_is_empty() {
+        create_or_allocate_account_raw(
+            crate::ID,
+            ctx.accounts.linked_data_section,
+            ctx.accounts.system_program,
+            ctx.accounts.payer,
+            new_size,
+            &[
+                LINKED_DATA_SECTION_PREFIX,
+                ctx.accounts.collection.key.as_ref(),
+                args.data_authority.to_seed(),
+                ctx.accounts.asset.key.as_ref(),
+                &[bump],
+            ],
+        )?;
+    } else {
+        resize_or_reallocate_account(
+            ctx.accounts.linked_data_section,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+            new_size,
+        )?;
+    }
+
+    header.save(ctx.accounts.linked_data_section, 0)?;
+    sol_memcpy(
+        &mut ctx.accounts.linked_data_section.data.borrow_mut()[header.get_size()..],
+        &args.data,
+        args.data.len(),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,312 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::{
+        TruncateCollectionExternalPluginDataV1Accounts, TruncateExternalPluginDataV1Accounts,
+        WriteCollectionExternalPluginDataV1Accounts, WriteExternalPluginDataV1Accounts,
+    },
+    plugins::{DataSection, ExternalPluginKey, PluginHeaderV1, PluginRegistryV1},
+    state::{AssetV1, Authority, CollectionV1, DataBlob, Key, SolanaAccount},
+    utils::{
+        load_key, resolve_authority, resolve_pubkey_to_authorities,
+        resolve_pubkey_to_authorities_collection,
+    },
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct WriteExternalPluginDataV1Args {
+    key: ExternalPluginKey,
+    data: Vec<u8>,
+    append: bool,
+}
+
+pub(crate) fn write_external_plugin_data<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: WriteExternalPluginDataV1Args,
+) -> ProgramResult {
+    let ctx = WriteExternalPluginDataV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Writing external plugin data for compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    // Only the data authority can write the data.
+    let mut asset = AssetV1::load(ctx.accounts.asset, 0)?;
+    if !resolve_pubkey_to_authorities(authority, ctx.accounts.collection, &asset.header())?
+        .contains(&data_authority(&args.key)?)
+    {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    // Increment sequence number and save only if it is `Some(_)`.
+    asset.increment_seq_and_save(ctx.accounts.asset)?;
+
+    process_change_external_plugin_data::<AssetV1>(
+        &args.key,
+        DataChange::Write {
+            data: &args.data,
+            append: args.append,
+        },
+        ctx.accounts.asset,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct WriteCollectionExternalPluginDataV1Args {
+    key: ExternalPluginKey,
+    data: Vec<u8>,
+    append: bool,
+}
+
+pub(crate) fn write_collection_external_plugin_data<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: WriteCollectionExternalPluginDataV1Args,
+) -> ProgramResult {
+    let ctx = WriteCollectionExternalPluginDataV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    // Only the data authority can write the data.
+    if !resolve_pubkey_to_authorities_collection(authority, ctx.accounts.collection)?
+        .contains(&data_authority(&args.key)?)
+    {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    process_change_external_plugin_data::<CollectionV1>(
+        &args.key,
+        DataChange::Write {
+            data: &args.data,
+            append: args.append,
+        },
+        ctx.accounts.collection,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct TruncateExternalPluginDataV1Args {
+    key: ExternalPluginKey,
+    len: u64,
+}
+
+pub(crate) fn truncate_external_plugin_data<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: TruncateExternalPluginDataV1Args,
+) -> ProgramResult {
+    let ctx = TruncateExternalPluginDataV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Truncating external plugin data for compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    // Only the data authority can truncate the data.
+    let mut asset = AssetV1::load(ctx.accounts.asset, 0)?;
+    if !resolve_pubkey_to_authorities(authority, ctx.accounts.collection, &asset.header())?
+        .contains(&data_authority(&args.key)?)
+    {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    // Increment sequence number and save only if it is `Some(_)`.
+    asset.increment_seq_and_save(ctx.accounts.asset)?;
+
+    process_change_external_plugin_data::<AssetV1>(
+        &args.key,
+        DataChange::Truncate {
+            len: args.len as usize,
+        },
+        ctx.accounts.asset,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct TruncateCollectionExternalPluginDataV1Args {
+    key: ExternalPluginKey,
+    len: u64,
+}
+
+pub(crate) fn truncate_collection_external_plugin_data<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: TruncateCollectionExternalPluginDataV1Args,
+) -> ProgramResult {
+    let ctx = TruncateCollectionExternalPluginDataV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    // Only the data authority can truncate the data.
+    if !resolve_pubkey_to_authorities_collection(authority, ctx.accounts.collection)?
+        .contains(&data_authority(&args.key)?)
+    {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    process_change_external_plugin_data::<CollectionV1>(
+        &args.key,
+        DataChange::Truncate {
+            len: args.len as usize,
+        },
+        ctx.accounts.collection,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )
+}
+
+/// The data authority of an external plugin whose data is written directly by its authority.
+/// Lifecycle hook data is configuration, linked app data is written per asset, and a sealed
+/// inscription cannot be written at all.
+fn data_authority(key: &ExternalPluginKey) -> Result<Authority, MplCoreError> {
+    match key {
+        ExternalPluginKey::AppData(authority) | ExternalPluginKey::Inscription(authority) => {
+            if *authority == Authority::None {
+                return Err(MplCoreError::InvalidAuthority);
+            }
+            Ok(*authority)
+        }
+        ExternalPluginKey::LifecycleHook(_) | ExternalPluginKey::LinkedAppData(_) => {
+            Err(MplCoreError::InvalidPlugin)
+        }
+    }
+}
+
+enum DataChange<'b> {
+    /// Replace the data, or append to it.
+    Write { data: &'b [u8], append: bool },
+    /// Cut the data down to a length no longer than the data.
+    Truncate { len: usize },
+}
+
+fn process_change_external_plugin_data<'a, T: DataBlob + SolanaAccount>(
+    key: &ExternalPluginKey,
+    change: DataChange,
+    account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    let core = T::load(account, 0)?;
+    let header_offset = core.get_size();
+    if header_offset == account.data_len() {
+        return Err(MplCoreError::PluginNotFound.into());
+    }
+
+    let mut plugin_header = PluginHeaderV1::load(account, header_offset)?;
+    let mut plugin_registry =
+        PluginRegistryV1::load(account, plugin_header.plugin_registry_offset)?;
+
+    let index = plugin_registry.find_external_plugin(key, account)?;
+    let mut data_section = DataSection::at(&plugin_registry, index)?;
+    match change {
+        DataChange::Write { data, append: true } => {
+            let offset = data_section.len();
+            let new_len = offset
+                .checked_add(data.len())
+                .ok_or(MplCoreError::NumericalOverflow)?;
+            data_section.resize(
+                new_len,
+                &mut plugin_header,
+                &mut plugin_registry,
+                account,
+                payer,
+                system_program,
+            )?;
+            data_section.write_at(offset, data, account)?;
+        }
+        DataChange::Write {
+            data,
+            append: false,
+        } => {
+            data_section.write(
+                data,
+                &mut plugin_header,
+                &mut plugin_registry,
+                account,
+                payer,
+                system_program,
+            )?;
+        }
+        DataChange::Truncate { len } => {
+            if len > data_section.len() {
+                return Err(MplCoreError::InvalidDataOffset.into());
+            }
+            data_section.resize(
+                len,
+                &mut plugin_header,
+                &mut plugin_registry,
+                account,
+                payer,
+                system_program,
+            )?;
+        }
+    }
+
+    plugin_header.save(account, header_offset)?;
+    plugin_registry.save(account, plugin_header.plugin_registry_offset)?;
+
+    Ok(())
+}
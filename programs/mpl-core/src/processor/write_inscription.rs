@@ -0,0 +1,127 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::WriteInscriptionV1Accounts,
+    plugins::{DataSection, ExternalPluginType, PluginHeaderV1, PluginRegistryV1},
+    state::{AssetV1, Authority, DataBlob, Key, SolanaAccount},
+    utils::{
+        load_key, resize_or_reallocate_account, resolve_authority, resolve_pubkey_to_authorities,
+    },
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct WriteInscriptionV1Args {
+    inscription_authority: Authority,
+    offset: u64,
+    data: Vec<u8>,
+    seal: bool,
+}
+
+pub(crate) fn write_inscription<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: WriteInscriptionV1Args,
+) -> ProgramResult {
+    let ctx = WriteInscriptionV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Writing inscription for compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    // Only the inscription authority can write the data, and a sealed inscription has none.
+    let mut asset = AssetV1::load(ctx.accounts.asset, 0)?;
+    if args.inscription_authority == Authority::None
+        || !resolve_pubkey_to_authorities(authority, ctx.accounts.collection, &asset.header())?
+            .contains(&args.inscription_authority)
+    {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    // Increment sequence number and save only if it is `Some(_)`.
+    asset.increment_seq_and_save(ctx.accounts.asset)?;
+
+    let header_offset = asset.get_size();
+    if header_offset == ctx.accounts.asset.data_len() {
+        return Err(MplCoreError::PluginNotFound.into());
+    }
+
+    let mut plugin_header = PluginHeaderV1::load(ctx.accounts.asset, header_offset)?;
+    let mut plugin_registry =
+        PluginRegistryV1::load(ctx.accounts.asset, plugin_header.plugin_registry_offset)?;
+
+    // Chunks are written in place, growing the inscription when written past its end.  Chunks
+    // must not leave a gap after the existing data.
+    let mut data_section = DataSection::find(
+        &plugin_registry,
+        ExternalPluginType::Inscription,
+        &args.inscription_authority,
+    )?;
+    let offset = args.offset as usize;
+    if offset > data_section.len() {
+        return Err(MplCoreError::InvalidDataOffset.into());
+    }
+
+    let end = offset
+        .checked_add(args.data.len())
+        .ok_or(MplCoreError::NumericalOverflow)?;
+    if end > data_section.len() {
+        data_section.resize(
+            end,
+            &mut plugin_header,
+            &mut plugin_registry,
+            ctx.accounts.asset,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+        )?;
+    }
+    data_section.write_at(offset, &args.data, ctx.accounts.asset)?;
+
+    // Sealing removes the authority, which shrinks the registry at the end of the account.
+    if args.seal {
+        plugin_registry
+            .external_plugins
+            .iter_mut()
+            .find(|record| {
+                record.plugin_type == ExternalPluginType::Inscription
+                    && record.authority == args.inscription_authority
+            })
+            .ok_or(MplCoreError::PluginNotFound)?
+            .authority = Authority::None;
+    }
+
+    plugin_header.save(ctx.accounts.asset, header_offset)?;
+    plugin_registry.save(ctx.accounts.asset, plugin_header.plugin_registry_offset)?;
+
+    if args.seal {
+        let new_size = plugin_header
+            .plugin_registry_offset
+            .checked_add(plugin_registry.try_to_vec()?.len())
+            .ok_or(MplCoreError::NumericalOverflow)?;
+        resize_or_reallocate_account(
+            ctx.accounts.asset,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+            new_size,
+        )?;
+    }
+
+    Ok(())
+}
@@ -10,11 +10,14 @@ use crate::{
     error::MplCoreError,
     plugins::{CheckResult, Plugin, ValidationResult},
     state::{Compressible, CompressionProof, DataBlob, Key, SolanaAccount},
+    utils::{cmp_pubkeys, load_key},
 };
 
 use super::{Authority, CoreAsset, UpdateAuthority};
 
 /// The Core Asset structure that exists at the beginning of every asset account.
+/// Its layout is shared by every existing account and client, so new per-asset state is stored
+/// in a plugin rather than as a new field.
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount, Eq, PartialEq)]
 pub struct AssetV1 {
     /// The account discriminator.
@@ -32,6 +35,28 @@ pub struct AssetV1 {
     pub seq: Option<u64>, //1
 }
 
+/// The hot fields of an asset account, read at their offsets without deserializing the name and
+/// uri of the asset.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetHeaderV1 {
+    /// The owner of the asset.
+    pub owner: Pubkey,
+    /// The update authority of the asset.
+    pub update_authority: UpdateAuthority,
+    /// The size of the asset, at which its plugin header starts.
+    pub size: usize,
+    /// The sequence number used for indexing with compression.
+    pub seq: Option<u64>,
+}
+
+/// Read the length of a Borsh string or vector at an offset of account data.
+pub(crate) fn read_borsh_len(data: &[u8], offset: usize) -> Result<usize, ProgramError> {
+    data.get(offset..offset.saturating_add(4))
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(|bytes| u32::from_le_bytes(bytes) as usize)
+        .ok_or(MplCoreError::DeserializationError.into())
+}
+
 impl AssetV1 {
     /// Create a new `Asset` with correct `Key` and `seq` of None.
     pub fn new(
@@ -63,6 +88,61 @@ impl AssetV1 {
     /// The base length of the asset account with an empty name and uri and no seq.
     pub const BASE_LENGTH: usize = 1 + 32 + 33 + 4 + 4 + 1;
 
+    /// Load the owner, update authority and size of an asset from their offsets, skipping over
+    /// the name and uri instead of deserializing them.
+    pub fn load_header(account: &AccountInfo) -> Result<AssetHeaderV1, ProgramError> {
+        if load_key(account, 0)? != Key::AssetV1 {
+            return Err(MplCoreError::DeserializationError.into());
+        }
+
+        let data = account.data.borrow();
+        let read_pubkey = |offset: usize| {
+            data.get(offset..offset + 32)
+                .and_then(|bytes| Pubkey::try_from(bytes).ok())
+                .ok_or(MplCoreError::DeserializationError)
+        };
+
+        let owner = read_pubkey(1)?;
+        let (update_authority, name_offset) = match data.get(33) {
+            Some(0) => (UpdateAuthority::None, 34),
+            Some(1) => (UpdateAuthority::Address(read_pubkey(34)?), 66),
+            Some(2) => (UpdateAuthority::Collection(read_pubkey(34)?), 66),
+            _ => return Err(MplCoreError::DeserializationError.into()),
+        };
+
+        let name_len = read_borsh_len(&data, name_offset)?;
+        let uri_offset = name_offset + 4 + name_len;
+        let uri_len = read_borsh_len(&data, uri_offset)?;
+        let seq_offset = uri_offset + 4 + uri_len;
+        let seq = match data.get(seq_offset) {
+            Some(0) => None,
+            Some(1) => Some(
+                data.get(seq_offset + 1..seq_offset + 1 + size_of::<u64>())
+                    .and_then(|bytes| bytes.try_into().ok())
+                    .map(u64::from_le_bytes)
+                    .ok_or(MplCoreError::DeserializationError)?,
+            ),
+            _ => return Err(MplCoreError::DeserializationError.into()),
+        };
+
+        Ok(AssetHeaderV1 {
+            owner,
+            update_authority,
+            size: AssetV1::BASE_LENGTH + name_len + uri_len + seq.map_or(0, |_| size_of::<u64>()),
+            seq,
+        })
+    }
+
+    /// The hot fields of the asset, as `load_header` reads them from account space.
+    pub fn header(&self) -> AssetHeaderV1 {
+        AssetHeaderV1 {
+            owner: self.owner,
+            update_authority: self.update_authority.clone(),
+            size: self.get_size(),
+            seq: self.seq,
+        }
+    }
+
     /// Check permissions for the add plugin lifecycle event.
     pub fn check_add_plugin() -> CheckResult {
         CheckResult::CanApprove
@@ -113,6 +193,35 @@ impl AssetV1 {
         CheckResult::CanApprove
     }
 
+    /// Check permissions for the execute lifecycle event.
+    pub fn check_execute() -> CheckResult {
+        CheckResult::CanApprove
+    }
+}
+
+impl AssetHeaderV1 {
+    /// Write the owner and sequence number back to the asset account in place.  The update
+    /// authority is not written, since its variants differ in size.
+    pub fn save(&self, account: &AccountInfo) -> ProgramResult {
+        let mut data = account.data.borrow_mut();
+        data[1..33].copy_from_slice(self.owner.as_ref());
+        if let Some(seq) = self.seq {
+            data[self.size - size_of::<u64>()..self.size].copy_from_slice(&seq.to_le_bytes());
+        }
+
+        Ok(())
+    }
+
+    /// If `seq` is `Some(_)` then increment and save it to account space.
+    pub fn increment_seq_and_save(&mut self, account: &AccountInfo) -> ProgramResult {
+        if let Some(seq) = &mut self.seq {
+            *seq = seq.saturating_add(1);
+            self.save(account)?;
+        };
+
+        Ok(())
+    }
+
     /// Validate the add plugin lifecycle event.
     pub fn validate_add_plugin(
         &self,
@@ -126,11 +235,12 @@ impl AssetV1 {
 
         // If it's an owner managed plugin or a UA managed plugin and the asset
         // is not in a collection, then it can be added.
-        if (authority_info.key == &self.owner && new_plugin.manager() == Authority::Owner)
+        if (cmp_pubkeys(authority_info.key, &self.owner)
+            && new_plugin.manager() == Authority::Owner)
             || (UpdateAuthority::Address(*authority_info.key) == self.update_authority
                 && new_plugin.manager() == Authority::UpdateAuthority)
         {
-            solana_program::msg!("Asset: Approved");
+            debug_msg!("Asset: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
@@ -150,9 +260,10 @@ impl AssetV1 {
 
         if (plugin.manager() == Authority::UpdateAuthority
             && self.update_authority == UpdateAuthority::Address(*authority_info.key))
-            || (plugin.manager() == Authority::Owner && authority_info.key == &self.owner)
+            || (plugin.manager() == Authority::Owner
+                && cmp_pubkeys(authority_info.key, &self.owner))
         {
-            solana_program::msg!("Asset: Approved");
+            debug_msg!("Asset: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
@@ -177,9 +288,10 @@ impl AssetV1 {
         if let Some(plugin) = plugin {
             if (plugin.manager() == Authority::UpdateAuthority
                 && self.update_authority == UpdateAuthority::Address(*authority_info.key))
-                || (plugin.manager() == Authority::Owner && authority_info.key == &self.owner)
+                || (plugin.manager() == Authority::Owner
+                    && cmp_pubkeys(authority_info.key, &self.owner))
             {
-                solana_program::msg!("Asset: Approved");
+                debug_msg!("Asset: Approved");
                 Ok(ValidationResult::Approved)
             } else {
                 Ok(ValidationResult::Pass)
@@ -198,9 +310,10 @@ impl AssetV1 {
         if let Some(plugin) = plugin {
             if (plugin.manager() == Authority::UpdateAuthority
                 && self.update_authority == UpdateAuthority::Address(*authority_info.key))
-                || (plugin.manager() == Authority::Owner && authority_info.key == &self.owner)
+                || (plugin.manager() == Authority::Owner
+                    && cmp_pubkeys(authority_info.key, &self.owner))
             {
-                solana_program::msg!("Asset: Approved");
+                debug_msg!("Asset: Approved");
                 Ok(ValidationResult::Approved)
             } else {
                 Ok(ValidationResult::Pass)
@@ -216,8 +329,8 @@ impl AssetV1 {
         authority_info: &AccountInfo,
         _: Option<&Plugin>,
     ) -> Result<ValidationResult, ProgramError> {
-        if authority_info.key == &self.update_authority.key() {
-            solana_program::msg!("Asset: Approved");
+        if cmp_pubkeys(authority_info.key, &self.update_authority.key()) {
+            debug_msg!("Asset: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
@@ -230,8 +343,8 @@ impl AssetV1 {
         authority_info: &AccountInfo,
         _: Option<&Plugin>,
     ) -> Result<ValidationResult, ProgramError> {
-        if authority_info.key == &self.owner {
-            solana_program::msg!("Asset: Approved");
+        if cmp_pubkeys(authority_info.key, &self.owner) {
+            debug_msg!("Asset: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
@@ -244,8 +357,8 @@ impl AssetV1 {
         authority_info: &AccountInfo,
         _: Option<&Plugin>,
     ) -> Result<ValidationResult, ProgramError> {
-        if authority_info.key == &self.owner {
-            solana_program::msg!("Asset: Approved");
+        if cmp_pubkeys(authority_info.key, &self.owner) {
+            debug_msg!("Asset: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
@@ -258,8 +371,8 @@ impl AssetV1 {
         authority_info: &AccountInfo,
         _: Option<&Plugin>,
     ) -> Result<ValidationResult, ProgramError> {
-        if authority_info.key == &self.owner {
-            solana_program::msg!("Asset: Approved");
+        if cmp_pubkeys(authority_info.key, &self.owner) {
+            debug_msg!("Asset: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
@@ -272,8 +385,22 @@ impl AssetV1 {
         authority_info: &AccountInfo,
         _: Option<&Plugin>,
     ) -> Result<ValidationResult, ProgramError> {
-        if authority_info.key == &self.owner {
-            solana_program::msg!("Asset: Approved");
+        if cmp_pubkeys(authority_info.key, &self.owner) {
+            debug_msg!("Asset: Approved");
+            Ok(ValidationResult::Approved)
+        } else {
+            Ok(ValidationResult::Pass)
+        }
+    }
+
+    /// Validate the execute lifecycle event.
+    pub fn validate_execute(
+        &self,
+        authority_info: &AccountInfo,
+        _: Option<&Plugin>,
+    ) -> Result<ValidationResult, ProgramError> {
+        if cmp_pubkeys(authority_info.key, &self.owner) {
+            debug_msg!("Asset: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
@@ -301,6 +428,10 @@ impl SolanaAccount for AssetV1 {
     fn key() -> Key {
         Key::AssetV1
     }
+
+    fn load_size(account: &AccountInfo) -> Result<usize, ProgramError> {
+        Ok(Self::load_header(account)?.size)
+    }
 }
 
 impl From<CompressionProof> for AssetV1 {
@@ -0,0 +1,18 @@
+use solana_program::pubkey::Pubkey;
+
+/// The seed prefix used when deriving the signer of an asset.
+pub const ASSET_SIGNER_PREFIX: &[u8] = b"mpl-core-execute";
+
+/// The SPL Token program, whose token accounts can be held by an asset signer.
+pub(crate) const SPL_TOKEN_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// The SPL Token-2022 program, whose token accounts can be held by an asset signer.
+pub(crate) const SPL_TOKEN_2022_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// Find the address of the signer of an asset. Accounts such as token vaults and escrows that
+/// are held by the asset signer are controlled by whoever owns the asset.
+pub fn find_asset_signer_address(asset: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ASSET_SIGNER_PREFIX, asset.as_ref()], &crate::ID)
+}
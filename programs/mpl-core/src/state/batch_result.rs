@@ -0,0 +1,29 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::state::Wrappable;
+
+/// The outcome of one item of a batch instruction.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub enum BatchItemStatus {
+    /// The item was processed.
+    Success,
+    /// The item was skipped without error, e.g. because it was already processed.
+    Skipped,
+}
+
+/// A record of the outcome of one item of a batch instruction, emitted through the spl-noop
+/// program when the log wrapper is passed.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct BatchItemResult {
+    /// The position of the item in the batch.
+    pub index: u32, //4
+    /// The asset the item was for.
+    pub asset: Pubkey, //32
+    /// The outcome of the item.
+    pub status: BatchItemStatus, //1
+}
+
+impl Wrappable for BatchItemResult {}
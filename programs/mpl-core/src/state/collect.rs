@@ -1,9 +1,8 @@
 use solana_program::pubkey::Pubkey;
 
-pub(crate) const COLLECT_RECIPIENT1: Pubkey =
+/// The authority allowed to initialize the fee config.
+pub(crate) const FEE_CONFIG_AUTHORITY: Pubkey =
     solana_program::pubkey!("8AT6o8Qk5T9QnZvPThMrF9bcCQLTGkyGvVZZzHgCw11v");
 
-pub(crate) const COLLECT_RECIPIENT2: Pubkey =
-    solana_program::pubkey!("MmHsqX4LxTfifxoH8BVRLUKrwDn1LPCac6YcCZTHhwt");
-
+/// The legacy creation fee, left on new assets when the fee config is not passed.
 pub(crate) const COLLECT_AMOUNT: u64 = 1_500_000;
@@ -5,9 +5,10 @@ use solana_program::{account_info::AccountInfo, program_error::ProgramError, pub
 use crate::{
     error::MplCoreError,
     plugins::{CheckResult, Plugin, ValidationResult},
+    utils::{cmp_pubkeys, load_key},
 };
 
-use super::{Authority, CoreAsset, DataBlob, Key, SolanaAccount, UpdateAuthority};
+use super::{read_borsh_len, Authority, CoreAsset, DataBlob, Key, SolanaAccount, UpdateAuthority};
 
 /// The representation of a collection of assets.
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
@@ -98,6 +99,11 @@ impl CollectionV1 {
         CheckResult::None
     }
 
+    /// Check permissions for the execute lifecycle event.
+    pub fn check_execute() -> CheckResult {
+        CheckResult::None
+    }
+
     /// Validate the add plugin lifecycle event.
     pub fn validate_add_plugin(
         &self,
@@ -109,10 +115,10 @@ impl CollectionV1 {
             None => return Err(MplCoreError::InvalidPlugin.into()),
         };
 
-        if *authority_info.key == self.update_authority
+        if cmp_pubkeys(authority_info.key, &self.update_authority)
             && new_plugin.manager() == Authority::UpdateAuthority
         {
-            solana_program::msg!("Collection: Approved");
+            debug_msg!("Collection: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
@@ -130,10 +136,10 @@ impl CollectionV1 {
             None => return Err(MplCoreError::InvalidPlugin.into()),
         };
 
-        if *authority_info.key == self.update_authority
+        if cmp_pubkeys(authority_info.key, &self.update_authority)
             && plugin_to_remove.manager() == Authority::UpdateAuthority
         {
-            solana_program::msg!("Collection: Approved");
+            debug_msg!("Collection: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
@@ -160,10 +166,10 @@ impl CollectionV1 {
             None => return Err(MplCoreError::InvalidPlugin.into()),
         };
 
-        if *authority_info.key == self.update_authority
+        if cmp_pubkeys(authority_info.key, &self.update_authority)
             && plugin.manager() == Authority::UpdateAuthority
         {
-            solana_program::msg!("Collection: Approved");
+            debug_msg!("Collection: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
@@ -181,10 +187,10 @@ impl CollectionV1 {
             None => return Err(MplCoreError::InvalidPlugin.into()),
         };
 
-        if *authority_info.key == self.update_authority
+        if cmp_pubkeys(authority_info.key, &self.update_authority)
             && plugin.manager() == Authority::UpdateAuthority
         {
-            solana_program::msg!("Collection: Approved");
+            debug_msg!("Collection: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
@@ -216,7 +222,7 @@ impl CollectionV1 {
         _: Option<&Plugin>,
     ) -> Result<ValidationResult, ProgramError> {
         if authority_info.key == &self.update_authority {
-            solana_program::msg!("Collection: Approved");
+            debug_msg!("Collection: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
@@ -241,6 +247,15 @@ impl CollectionV1 {
         Ok(ValidationResult::Pass)
     }
 
+    /// Validate the execute lifecycle event.
+    pub fn validate_execute(
+        &self,
+        _authority_info: &AccountInfo,
+        _: Option<&Plugin>,
+    ) -> Result<ValidationResult, ProgramError> {
+        Ok(ValidationResult::Pass)
+    }
+
     /// Increment size of the Collection
     pub fn increment(&mut self) -> Result<(), ProgramError> {
         self.num_minted = self
@@ -281,6 +296,19 @@ impl SolanaAccount for CollectionV1 {
     fn key() -> Key {
         Key::CollectionV1
     }
+
+    /// The size is read from the lengths of the name and uri, without deserializing them.
+    fn load_size(account: &AccountInfo) -> Result<usize, ProgramError> {
+        if load_key(account, 0)? != Key::CollectionV1 {
+            return Err(MplCoreError::DeserializationError.into());
+        }
+
+        let data = account.data.borrow();
+        let name_len = read_borsh_len(&data, 33)?;
+        let uri_len = read_borsh_len(&data, 33 + 4 + name_len)?;
+
+        Ok(Self::BASE_LENGTH + name_len + uri_len)
+    }
 }
 
 impl CoreAsset for CollectionV1 {
@@ -0,0 +1,45 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::{DataBlob, Key, SolanaAccount};
+
+/// The seed used when deriving the fee config of the program.
+pub const FEE_CONFIG_PREFIX: &[u8] = b"fee_config";
+
+/// Find the address of the fee config of the program.
+pub fn find_fee_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_CONFIG_PREFIX], &crate::ID)
+}
+
+/// The protocol fees charged on creation.  The fees are paid into the fee config account itself
+/// and can be withdrawn by its authority.
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount, PartialEq, Eq)]
+pub struct FeeConfigV1 {
+    /// The account discriminator.
+    pub key: Key, //1
+    /// The authority allowed to change the fees and withdraw them.
+    pub authority: Pubkey, //32
+    /// The fee, in lamports, charged when creating an asset.
+    pub create_fee: u64, //8
+    /// The fee, in lamports, charged when creating a collection.
+    pub create_collection_fee: u64, //8
+    /// The bump of the fee config address.
+    pub bump: u8, //1
+}
+
+impl DataBlob for FeeConfigV1 {
+    fn get_initial_size() -> usize {
+        1 + 32 + 8 + 8 + 1
+    }
+
+    fn get_size(&self) -> usize {
+        Self::get_initial_size()
+    }
+}
+
+impl SolanaAccount for FeeConfigV1 {
+    fn key() -> Key {
+        Key::FeeConfigV1
+    }
+}
@@ -1,4 +1,5 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{keccak, program_error::ProgramError};
 use std::cmp::Ordering;
 
 use crate::{
@@ -25,6 +26,21 @@ impl HashablePluginSchema {
     pub fn compare_indeces(a: &HashablePluginSchema, b: &HashablePluginSchema) -> Ordering {
         a.index.cmp(&b.index)
     }
+
+    /// Hash the schema of a plugin from its data as serialized in account space, which is the
+    /// same as hashing the schema of the deserialized plugin without serializing it again.
+    pub(crate) fn hash_serialized(
+        index: usize,
+        authority: &Authority,
+        plugin_data: &[u8],
+    ) -> Result<[u8; 32], ProgramError> {
+        Ok(keccak::hashv(&[
+            &(index as u64).to_le_bytes(),
+            &authority.try_to_vec()?,
+            plugin_data,
+        ])
+        .to_bytes())
+    }
 }
 
 impl Compressible for HashablePluginSchema {}
@@ -0,0 +1,95 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::{Authority, DataBlob, Key, SolanaAccount};
+
+/// The seed prefix used when deriving the location of a linked data section.
+pub const LINKED_DATA_SECTION_PREFIX: &[u8] = b"linked_data_section";
+
+impl Authority {
+    /// Get the bytes used to represent the authority in a PDA derivation.
+    pub fn to_seed(&self) -> &[u8] {
+        match self {
+            Authority::None => b"none",
+            Authority::Owner => b"owner",
+            Authority::UpdateAuthority => b"update_authority",
+            Authority::Address { address } | Authority::AddressWithExpiry { address, .. } => {
+                address.as_ref()
+            }
+        }
+    }
+}
+
+/// Find the address of the data section for an asset that belongs to a collection-level
+/// linked adapter, identified by the collection and the adapter's data authority.
+pub fn find_linked_data_section_address(
+    collection: &Pubkey,
+    data_authority: &Authority,
+    asset: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            LINKED_DATA_SECTION_PREFIX,
+            collection.as_ref(),
+            data_authority.to_seed(),
+            asset.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Create the address of a linked data section from a known bump, avoiding the cost of
+/// searching for the bump on-chain.
+pub fn create_linked_data_section_address(
+    collection: &Pubkey,
+    data_authority: &Authority,
+    asset: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, solana_program::pubkey::PubkeyError> {
+    Pubkey::create_program_address(
+        &[
+            LINKED_DATA_SECTION_PREFIX,
+            collection.as_ref(),
+            data_authority.to_seed(),
+            asset.as_ref(),
+            &[bump],
+        ],
+        &crate::ID,
+    )
+}
+
+/// The header of the data section of an asset for a collection-level `LinkedAppData` plugin.
+/// The data written by the data authority follows the header to the end of the account.
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount, PartialEq, Eq)]
+pub struct LinkedDataSectionV1 {
+    /// The account discriminator.
+    pub key: Key, //1
+    /// The collection with the `LinkedAppData` plugin.
+    pub collection: Pubkey, //32
+    /// The asset the data is for.
+    pub asset: Pubkey, //32
+    /// The authority who can write the data.
+    pub data_authority: Authority, //Variable
+}
+
+impl DataBlob for LinkedDataSectionV1 {
+    fn get_initial_size() -> usize {
+        1 + 32 + 32 + 1
+    }
+
+    fn get_size(&self) -> usize {
+        let data_authority_size = match self.data_authority {
+            Authority::Address { .. } => 33,
+            Authority::AddressWithExpiry { .. } => 41,
+            _ => 1,
+        };
+        1 + 32 + 32 + data_authority_size
+    }
+}
+
+impl SolanaAccount for LinkedDataSectionV1 {
+    fn key() -> Key {
+        Key::LinkedDataSectionV1
+    }
+}
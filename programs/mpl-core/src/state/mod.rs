@@ -1,6 +1,12 @@
 mod asset;
 pub use asset::*;
 
+mod asset_signer;
+pub use asset_signer::*;
+
+mod batch_result;
+pub use batch_result::*;
+
 mod collect;
 pub(crate) use collect::*;
 
@@ -10,6 +16,9 @@ pub use collection::*;
 mod compression_proof;
 pub use compression_proof::*;
 
+mod fee_config;
+pub use fee_config::*;
+
 mod hashable_plugin_schema;
 pub use hashable_plugin_schema::*;
 
@@ -19,6 +28,21 @@ pub use hashed_asset_schema::*;
 mod hashed_asset;
 pub use hashed_asset::*;
 
+mod linked_data;
+pub use linked_data::*;
+
+mod proof_cache;
+pub use proof_cache::*;
+
+mod redemption;
+pub use redemption::*;
+
+mod referral;
+pub use referral::*;
+
+mod token_metadata;
+pub(crate) use token_metadata::*;
+
 mod traits;
 pub use traits::*;
 
@@ -27,7 +51,7 @@ pub use update_authority::*;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use num_derive::{FromPrimitive, ToPrimitive};
-use solana_program::pubkey::Pubkey;
+use solana_program::{clock::Clock, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar};
 
 /// An enum representing the two types of data, compressed (stored in ledger) and uncompressed (stored in account state).
 #[repr(C)]
@@ -54,6 +78,55 @@ pub enum Authority {
         /// The address of the authority.
         address: Pubkey,
     },
+    /// A pubkey that is the authority over a plugin until it expires.
+    AddressWithExpiry {
+        /// The address of the authority.
+        address: Pubkey,
+        /// The unix timestamp at which the authority expires.
+        expires_at: i64,
+    },
+}
+
+impl Authority {
+    /// Whether the authority is an address whose expiry has passed.
+    pub fn is_expired(&self) -> Result<bool, ProgramError> {
+        match self {
+            Authority::AddressWithExpiry { expires_at, .. } => {
+                Ok(Clock::get()?.unix_timestamp >= *expires_at)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// The authority in effect at the current time.  An address with an expiry is the address
+    /// until it expires, after which the authority falls back to `manager` so that the expired
+    /// delegate can still be thawed, revoked, removed or replaced.
+    pub fn active(&self, manager: Authority) -> Result<Self, ProgramError> {
+        match self {
+            Authority::AddressWithExpiry { address, .. } => {
+                if self.is_expired()? {
+                    Ok(manager)
+                } else {
+                    Ok(Authority::Address { address: *address })
+                }
+            }
+            _ => Ok(*self),
+        }
+    }
+}
+
+impl DataBlob for Authority {
+    fn get_initial_size() -> usize {
+        1
+    }
+
+    fn get_size(&self) -> usize {
+        match self {
+            Authority::None | Authority::Owner | Authority::UpdateAuthority => 1,
+            Authority::Address { .. } => 1 + 32,
+            Authority::AddressWithExpiry { .. } => 1 + 32 + 8,
+        }
+    }
 }
 
 /// Different types of extra accounts that can be passed in for lifecycle hooks.
@@ -95,6 +168,12 @@ pub enum Key {
     PluginRegistryV1,
     /// A discriminator indicating the collection.
     CollectionV1,
+    /// An account caching a verified compression proof.
+    ProofCacheV1,
+    /// An account holding the linked app data of an asset.
+    LinkedDataSectionV1,
+    /// An account holding the protocol fee config.
+    FeeConfigV1,
 }
 
 impl Key {
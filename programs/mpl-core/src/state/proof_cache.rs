@@ -0,0 +1,51 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::{CompressionProof, DataBlob, Key, SolanaAccount};
+
+/// The seed prefix used when deriving the proof cache of a compressed asset.
+pub const PROOF_CACHE_PREFIX: &[u8] = b"proof_cache";
+
+/// Find the address of the proof cache of a compressed asset.
+pub fn find_proof_cache_address(asset: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROOF_CACHE_PREFIX, asset.as_ref()], &crate::ID)
+}
+
+/// A verified compression proof staged for a compressed asset, so that later compressed
+/// operations in the same epoch can reference it instead of passing and verifying the proof.
+/// The cache is only used while the hash of the asset is unchanged.
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount, PartialEq, Eq)]
+pub struct ProofCacheV1 {
+    /// The account discriminator.
+    pub key: Key, //1
+    /// The compressed asset the proof is for.
+    pub asset: Pubkey, //32
+    /// The hash of the asset when the proof was verified.
+    pub hash: [u8; 32], //32
+    /// The epoch in which the proof was verified.
+    pub epoch: u64, //8
+    /// The verified compression proof, with its plugins sorted by index.
+    pub compression_proof: CompressionProof, //Variable
+}
+
+impl DataBlob for ProofCacheV1 {
+    fn get_initial_size() -> usize {
+        1 + 32 + 32 + 8
+    }
+
+    fn get_size(&self) -> usize {
+        // The compression proof has no size helper, so measure it serialized.
+        Self::get_initial_size()
+            + self
+                .compression_proof
+                .try_to_vec()
+                .map_or(0, |proof| proof.len())
+    }
+}
+
+impl SolanaAccount for ProofCacheV1 {
+    fn key() -> Key {
+        Key::ProofCacheV1
+    }
+}
@@ -0,0 +1,23 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::state::Wrappable;
+
+/// A record of a redeemed asset, emitted through the spl-noop program so that the issuer can
+/// fulfill the reward.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct RedemptionRecord {
+    /// The address of the redeemed asset.
+    pub asset: Pubkey, // 32
+    /// The collection the asset belonged to, if any.
+    pub collection: Option<Pubkey>, // 33
+    /// The owner that redeemed the asset.
+    pub owner: Pubkey, // 32
+    /// The issuer fulfilling the reward.
+    pub issuer: Pubkey, // 32
+    /// An identifier of the reward for the fulfillment system of the issuer.
+    pub reward: String, // 4 + len
+}
+
+impl Wrappable for RedemptionRecord {}
@@ -0,0 +1,21 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::state::Wrappable;
+
+/// A record of the referrer of a newly created asset, emitted through the spl-noop
+/// program so that launchpads can attribute mints to their referrers.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct MintReferral {
+    /// The address of the new asset.
+    pub asset: Pubkey, //32
+    /// The collection the asset was minted into, if any.
+    pub collection: Option<Pubkey>, //33
+    /// The account that referred the mint.
+    pub referrer: Pubkey, //32
+    /// The account that paid for the mint.
+    pub payer: Pubkey, //32
+}
+
+impl Wrappable for MintReferral {}
@@ -0,0 +1,129 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::error::MplCoreError;
+
+/// The Token Metadata program, whose NFTs can be migrated to mpl-core.
+pub(crate) const TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// The SPL Associated Token Account program, which Token Metadata creates token accounts with.
+pub(crate) const SPL_ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+/// The seed prefix of the metadata accounts of the Token Metadata program.
+const TOKEN_METADATA_PREFIX: &[u8] = b"metadata";
+
+/// The account key of a Token Metadata metadata account.
+const TOKEN_METADATA_KEY_METADATA_V1: u8 = 4;
+
+/// The seed suffix of the master edition accounts of the Token Metadata program.
+const TOKEN_METADATA_EDITION_SUFFIX: &[u8] = b"edition";
+
+/// Find the address of the Token Metadata metadata account of a mint.
+pub(crate) fn find_token_metadata_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            TOKEN_METADATA_PREFIX,
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            mint.as_ref(),
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    )
+}
+
+/// Find the address of the Token Metadata master edition account of a mint.
+pub(crate) fn find_token_metadata_master_edition_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            TOKEN_METADATA_PREFIX,
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            mint.as_ref(),
+            TOKEN_METADATA_EDITION_SUFFIX,
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    )
+}
+
+/// A creator of a Token Metadata NFT.
+#[allow(dead_code)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct TokenMetadataCreator {
+    pub(crate) address: Pubkey,
+    pub(crate) verified: bool,
+    pub(crate) share: u8,
+}
+
+/// The collection of a Token Metadata NFT.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct TokenMetadataCollection {
+    pub(crate) verified: bool,
+    pub(crate) key: Pubkey,
+}
+
+/// The leading fields of a Token Metadata metadata account, after its key.  Later fields are
+/// not needed for a migration and are not read.
+#[allow(dead_code)]
+#[derive(BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct TokenMetadata {
+    pub(crate) update_authority: Pubkey,
+    pub(crate) mint: Pubkey,
+    pub(crate) name: String,
+    pub(crate) symbol: String,
+    pub(crate) uri: String,
+    pub(crate) seller_fee_basis_points: u16,
+    pub(crate) creators: Option<Vec<TokenMetadataCreator>>,
+    pub(crate) primary_sale_happened: bool,
+    pub(crate) is_mutable: bool,
+    pub(crate) edition_nonce: Option<u8>,
+    pub(crate) token_standard: Option<u8>,
+    pub(crate) collection: Option<TokenMetadataCollection>,
+}
+
+impl TokenMetadata {
+    /// Load the metadata of a mint, checking that it is the metadata account of the mint.
+    pub(crate) fn load(metadata_info: &AccountInfo, mint: &Pubkey) -> Result<Self, ProgramError> {
+        let (metadata_address, _) = find_token_metadata_address(mint);
+        if metadata_info.owner != &TOKEN_METADATA_PROGRAM_ID
+            || metadata_info.key != &metadata_address
+        {
+            return Err(MplCoreError::InvalidTokenMetadata.into());
+        }
+
+        let data = metadata_info.data.borrow();
+        let (key, mut bytes) = data
+            .split_first()
+            .ok_or(MplCoreError::InvalidTokenMetadata)?;
+        if *key != TOKEN_METADATA_KEY_METADATA_V1 {
+            return Err(MplCoreError::InvalidTokenMetadata.into());
+        }
+
+        let metadata =
+            Self::deserialize(&mut bytes).map_err(|_| MplCoreError::InvalidTokenMetadata)?;
+        if &metadata.mint != mint {
+            return Err(MplCoreError::InvalidTokenMetadata.into());
+        }
+
+        Ok(metadata)
+    }
+}
+
+/// Strip the null padding Token Metadata stores its strings with.
+pub(crate) fn trim_token_metadata_string(value: &str) -> String {
+    value.trim_end_matches('\0').to_string()
+}
+
+/// The leading fields of the `CreateV1` arguments of Token Metadata for a non-fungible asset.
+/// The optional uses, collection details and rule set that follow are never set.
+#[derive(BorshSerialize, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct TokenMetadataAssetData {
+    pub(crate) name: String,
+    pub(crate) symbol: String,
+    pub(crate) uri: String,
+    pub(crate) seller_fee_basis_points: u16,
+    pub(crate) creators: Option<Vec<TokenMetadataCreator>>,
+    pub(crate) primary_sale_happened: bool,
+    pub(crate) is_mutable: bool,
+    pub(crate) token_standard: u8,
+    pub(crate) collection: Option<TokenMetadataCollection>,
+}
@@ -25,7 +25,13 @@ pub trait SolanaAccount: BorshSerialize + BorshDeserialize {
         let key = load_key(account, offset)?;
 
         if key != Self::key() {
-            return Err(MplCoreError::DeserializationError.into());
+            msg!(
+                "Error: Account {} is a {:?}, expected a {:?}",
+                account.key,
+                key,
+                Self::key()
+            );
+            return Err(MplCoreError::IncorrectAccountKey.into());
         }
 
         let mut bytes: &[u8] = &(*account.data).borrow()[offset..];
@@ -35,6 +41,15 @@ pub trait SolanaAccount: BorshSerialize + BorshDeserialize {
         })
     }
 
+    /// Load the size of the account data preceding its plugin header.  Accounts whose size can
+    /// be read from fixed offsets override this to avoid deserializing the whole account.
+    fn load_size(account: &AccountInfo) -> Result<usize, ProgramError>
+    where
+        Self: DataBlob,
+    {
+        Ok(Self::load(account, 0)?.get_size())
+    }
+
     /// Save the account to the given account info starting at the offset.
     fn save(&self, account: &AccountInfo, offset: usize) -> ProgramResult {
         borsh::to_writer(&mut account.data.borrow_mut()[offset..], self).map_err(|error| {
@@ -110,7 +110,7 @@ impl UpdateAuthority {
         };
 
         if ctx.authority.unwrap_or(ctx.payer).key == authority {
-            solana_program::msg!("UA: Approved");
+            debug_msg!("UA: Approved");
             Ok(ValidationResult::Approved)
         } else {
             Ok(ValidationResult::Pass)
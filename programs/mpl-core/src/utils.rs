@@ -1,25 +1,35 @@
-use std::collections::BTreeMap;
-
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_utils::assert_signer;
 use num_traits::{FromPrimitive, ToPrimitive};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke,
-    program_error::ProgramError, program_memory::sol_memcpy, rent::Rent, system_instruction,
-    sysvar::Sysvar,
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE},
+    msg,
+    program::{invoke, set_return_data},
+    program_error::ProgramError,
+    program_memory::{sol_memcmp, sol_memcpy},
+    pubkey::{Pubkey, PUBKEY_BYTES},
+    rent::Rent,
+    system_instruction,
+    sysvar::{self, Sysvar},
 };
 
 use crate::{
     error::MplCoreError,
     plugins::{
-        create_meta_idempotent, initialize_plugin, validate_plugin_checks, CheckResult, Plugin,
-        PluginHeaderV1, PluginRegistryV1, PluginType, PluginValidationContext, RegistryRecord,
+        create_meta_idempotent, evaluate_plugin_checks, fetch_plugin, find_multisig_address,
+        initialize_plugin, CheckResult, ExternalPluginType, HookableLifecycleEvent, LifecycleHook,
+        LifecycleHookArgs, LifecycleValidation, Multisig, Plugin, PluginChecks, PluginHeaderV1,
+        PluginRegistryV1, PluginType, PluginValidationContext, RegistryRecord, TxGuard, UpdateInfo,
         ValidationResult,
     },
     state::{
-        AssetV1, Authority, CollectionV1, Compressible, CompressionProof, CoreAsset, DataBlob,
-        HashablePluginSchema, HashedAssetSchema, HashedAssetV1, Key, SolanaAccount,
-        UpdateAuthority,
+        find_fee_config_address, AssetHeaderV1, AssetV1, Authority, BatchItemResult,
+        BatchItemStatus, CollectionV1, Compressible, CompressionProof, CoreAsset, DataBlob,
+        FeeConfigV1, HashablePluginSchema, HashedAssetSchema, HashedAssetV1, Key, ProofCacheV1,
+        SolanaAccount, UpdateAuthority, Wrappable, COLLECT_AMOUNT, SPL_TOKEN_2022_PROGRAM_ID,
+        SPL_TOKEN_PROGRAM_ID,
     },
 };
 
@@ -31,26 +41,33 @@ pub fn load_key(account: &AccountInfo, offset: usize) -> Result<Key, ProgramErro
     Ok(key)
 }
 
+/// Compare two pubkeys with the `sol_memcmp` syscall, which costs fewer compute units than
+/// comparing them byte by byte.
+pub(crate) fn cmp_pubkeys(a: &Pubkey, b: &Pubkey) -> bool {
+    sol_memcmp(a.as_ref(), b.as_ref(), PUBKEY_BYTES) == 0
+}
+
 /// Assert that the account info address is in the same as the authority.
 pub fn assert_authority<T: CoreAsset>(
     asset: &T,
     authority_info: &AccountInfo,
     authority: &Authority,
 ) -> ProgramResult {
-    match authority {
-        Authority::None => (),
+    // An expired address no longer matches anyone.
+    match authority.active(Authority::None)? {
+        Authority::None | Authority::AddressWithExpiry { .. } => (),
         Authority::Owner => {
-            if asset.owner() == authority_info.key {
+            if cmp_pubkeys(asset.owner(), authority_info.key) {
                 return Ok(());
             }
         }
         Authority::UpdateAuthority => {
-            if asset.update_authority().key() == *authority_info.key {
+            if cmp_pubkeys(&asset.update_authority().key(), authority_info.key) {
                 return Ok(());
             }
         }
         Authority::Address { address } => {
-            if authority_info.key == address {
+            if cmp_pubkeys(authority_info.key, &address) {
                 return Ok(());
             }
         }
@@ -65,15 +82,16 @@ pub fn assert_collection_authority(
     authority_info: &AccountInfo,
     authority: &Authority,
 ) -> ProgramResult {
-    match authority {
-        Authority::None | Authority::Owner => (),
+    // An expired address no longer matches anyone.
+    match authority.active(Authority::None)? {
+        Authority::None | Authority::Owner | Authority::AddressWithExpiry { .. } => (),
         Authority::UpdateAuthority => {
-            if &asset.update_authority == authority_info.key {
+            if cmp_pubkeys(&asset.update_authority, authority_info.key) {
                 return Ok(());
             }
         }
         Authority::Address { address } => {
-            if authority_info.key == address {
+            if cmp_pubkeys(authority_info.key, &address) {
                 return Ok(());
             }
         }
@@ -87,47 +105,105 @@ pub fn fetch_core_data<T: DataBlob + SolanaAccount>(
     account: &AccountInfo,
 ) -> Result<(T, Option<PluginHeaderV1>, Option<PluginRegistryV1>), ProgramError> {
     let asset = T::load(account, 0)?;
+    let (plugin_header, plugin_registry) = load_plugin_data(account, asset.get_size())?;
 
-    if asset.get_size() != account.data_len() {
-        let plugin_header = PluginHeaderV1::load(account, asset.get_size())?;
-        let plugin_registry =
-            PluginRegistryV1::load(account, plugin_header.plugin_registry_offset)?;
+    Ok((asset, plugin_header, plugin_registry))
+}
 
-        Ok((asset, Some(plugin_header), Some(plugin_registry)))
-    } else {
-        Ok((asset, None, None))
+/// Fetch the plugin header and plugin registry (if present) from the account, finding them from
+/// the length prefixes of the asset instead of deserializing it.
+pub fn fetch_core_registry<T: DataBlob + SolanaAccount>(
+    account: &AccountInfo,
+) -> Result<(Option<PluginHeaderV1>, Option<PluginRegistryV1>), ProgramError> {
+    load_plugin_data(account, T::load_size(account)?)
+}
+
+/// Load the plugin header at the end of the asset and the registry it points to, if the account
+/// has any plugin data.
+fn load_plugin_data(
+    account: &AccountInfo,
+    header_offset: usize,
+) -> Result<(Option<PluginHeaderV1>, Option<PluginRegistryV1>), ProgramError> {
+    if header_offset == account.data_len() {
+        return Ok((None, None));
     }
+
+    let plugin_header = PluginHeaderV1::load(account, header_offset)?;
+    let plugin_registry = PluginRegistryV1::load(account, plugin_header.plugin_registry_offset)?;
+
+    Ok((Some(plugin_header), Some(plugin_registry)))
 }
 
 /// Check that a compression proof results in same on-chain hash.
 pub fn verify_proof(
     hashed_asset: &AccountInfo,
-    compression_proof: &CompressionProof,
+    mut compression_proof: CompressionProof,
 ) -> Result<(AssetV1, Vec<HashablePluginSchema>), ProgramError> {
-    let asset = AssetV1::from(compression_proof.clone());
-    let asset_hash = asset.hash()?;
-
-    let mut sorted_plugins = compression_proof.plugins.clone();
+    // The proof is taken apart rather than copied, and its plugins are sorted in place.
+    let mut sorted_plugins = std::mem::take(&mut compression_proof.plugins);
     sorted_plugins.sort_by(HashablePluginSchema::compare_indeces);
+    let asset = AssetV1::from(compression_proof);
 
+    let hashed_asset_schema_hash = hash_compressed_asset(&asset, &sorted_plugins)?;
+
+    let current_account_hash = HashedAssetV1::load(hashed_asset, 0)?.hash;
+    if hashed_asset_schema_hash != current_account_hash {
+        return Err(MplCoreError::IncorrectAssetHash.into());
+    }
+
+    Ok((asset, sorted_plugins))
+}
+
+/// Compute the on-chain hash of a compressed asset from its plugins sorted by index.
+pub(crate) fn hash_compressed_asset(
+    asset: &AssetV1,
+    sorted_plugins: &[HashablePluginSchema],
+) -> Result<[u8; 32], ProgramError> {
     let plugin_hashes = sorted_plugins
         .iter()
         .map(|plugin| plugin.hash())
         .collect::<Result<Vec<[u8; 32]>, ProgramError>>()?;
 
-    let hashed_asset_schema = HashedAssetSchema {
-        asset_hash,
+    HashedAssetSchema {
+        asset_hash: asset.hash()?,
         plugin_hashes,
-    };
+    }
+    .hash()
+}
 
-    let hashed_asset_schema_hash = hashed_asset_schema.hash()?;
+/// Resolve the compression proof of a compressed asset, verifying the proof if it is passed in,
+/// or otherwise using the proof cache of the asset from the remaining accounts.  A cached proof
+/// is only used in the epoch it was staged and while the hash of the asset is unchanged.
+pub(crate) fn resolve_compression_proof(
+    hashed_asset: &AccountInfo,
+    compression_proof: Option<CompressionProof>,
+    remaining_accounts: &[AccountInfo],
+) -> Result<(AssetV1, Vec<HashablePluginSchema>), ProgramError> {
+    if let Some(compression_proof) = compression_proof {
+        return verify_proof(hashed_asset, compression_proof);
+    }
 
-    let current_account_hash = HashedAssetV1::load(hashed_asset, 0)?.hash;
-    if hashed_asset_schema_hash != current_account_hash {
-        return Err(MplCoreError::IncorrectAssetHash.into());
+    let proof_cache_info = remaining_accounts
+        .iter()
+        .find(|account_info| {
+            account_info.owner == &crate::ID
+                && matches!(load_key(account_info, 0), Ok(Key::ProofCacheV1))
+        })
+        .ok_or(MplCoreError::MissingCompressionProof)?;
+
+    let proof_cache = ProofCacheV1::load(proof_cache_info, 0)?;
+    if proof_cache.asset != *hashed_asset.key {
+        return Err(MplCoreError::InvalidProofCache.into());
+    }
+    if proof_cache.epoch != Clock::get()?.epoch
+        || proof_cache.hash != HashedAssetV1::load(hashed_asset, 0)?.hash
+    {
+        return Err(MplCoreError::StaleProofCache.into());
     }
 
-    Ok((asset, sorted_plugins))
+    let mut compression_proof = proof_cache.compression_proof;
+    let plugins = std::mem::take(&mut compression_proof.plugins);
+    Ok((AssetV1::from(compression_proof), plugins))
 }
 
 pub(crate) fn close_program_account<'a>(
@@ -157,6 +233,27 @@ pub(crate) fn close_program_account<'a>(
     Ok(())
 }
 
+/// Whether an account holds exactly the legacy fee beyond the rent for its size, as an asset
+/// created without a fee config does until the fee is collected.
+pub(crate) fn holds_legacy_fee(account: &AccountInfo, rent: &Rent) -> bool {
+    account.lamports().saturating_sub(rent.minimum_balance(account.data_len())) == COLLECT_AMOUNT
+}
+
+/// The lamports reserved on an asset with `ReserveV1` for later growth.  Other accounts, such as
+/// a collection holding a rent pool, and the legacy fee left on an asset for `Collect` are never
+/// spent on rent.
+pub(crate) fn reserved_lamports(account: &AccountInfo, rent: &Rent) -> u64 {
+    if (*account.data).borrow().first().copied() != Key::AssetV1.to_u8()
+        || holds_legacy_fee(account, rent)
+    {
+        return 0;
+    }
+
+    account
+        .lamports()
+        .saturating_sub(rent.minimum_balance(account.data_len()))
+}
+
 /// Resize an account using realloc and retain any lamport overages, modified from Solana Cookbook
 pub(crate) fn resize_or_reallocate_account<'a>(
     target_account: &AccountInfo<'a>,
@@ -164,6 +261,17 @@ pub(crate) fn resize_or_reallocate_account<'a>(
     system_program: &AccountInfo<'a>,
     new_size: usize,
 ) -> ProgramResult {
+    // The runtime only lets an account grow by `MAX_PERMITTED_DATA_INCREASE` in one instruction,
+    // counting from its size when the instruction started rather than after earlier resizes, so
+    // larger data has to be written in chunks across instructions.
+    // SAFETY: The account was deserialized by the entrypoint, which serializes the original data
+    // length in front of the account key.
+    let original_data_len = unsafe { target_account.original_data_len() };
+    if new_size.saturating_sub(original_data_len) > MAX_PERMITTED_DATA_INCREASE {
+        msg!("Error: Write the data in chunks across instructions");
+        return Err(MplCoreError::AccountGrowthLimitExceeded.into());
+    }
+
     let rent = Rent::get()?;
     let new_minimum_balance = rent.minimum_balance(new_size);
     let current_minimum_balance = rent.minimum_balance(target_account.data_len());
@@ -174,11 +282,21 @@ pub(crate) fn resize_or_reallocate_account<'a>(
     ];
 
     if new_minimum_balance >= current_minimum_balance {
-        let lamports_diff = new_minimum_balance.saturating_sub(current_minimum_balance);
-        invoke(
-            &system_instruction::transfer(funding_account.key, target_account.key, lamports_diff),
-            account_infos,
-        )?;
+        // Lamports reserved on an asset count towards the new balance, so only the shortfall is
+        // transferred.
+        let lamports_diff = new_minimum_balance
+            .saturating_sub(current_minimum_balance)
+            .saturating_sub(reserved_lamports(target_account, &rent));
+        if lamports_diff > 0 {
+            invoke(
+                &system_instruction::transfer(
+                    funding_account.key,
+                    target_account.key,
+                    lamports_diff,
+                ),
+                account_infos,
+            )?;
+        }
     } else {
         // return lamports to the compressor
         let lamports_diff = current_minimum_balance.saturating_sub(new_minimum_balance);
@@ -196,15 +314,81 @@ pub(crate) fn resize_or_reallocate_account<'a>(
 /// Validate asset permissions using lifecycle validations for asset, collection, and plugins.
 pub(crate) fn validate_asset_permissions<'a>(
     authority_info: &'a AccountInfo<'a>,
+    remaining_accounts: &[AccountInfo<'a>],
+    asset: &AccountInfo<'a>,
+    collection: Option<&AccountInfo<'a>>,
+    new_owner: Option<&'a AccountInfo<'a>>,
+    new_plugin: Option<&Plugin>,
+    update_info: Option<&UpdateInfo>,
+    lifecycle_event: Option<HookableLifecycleEvent>,
+    asset_check_fp: fn() -> CheckResult,
+    collection_check_fp: fn() -> CheckResult,
+    plugin_check_fp: fn(&PluginType) -> CheckResult,
+    asset_validate_fp: fn(
+        &AssetHeaderV1,
+        &AccountInfo,
+        Option<&Plugin>,
+    ) -> Result<ValidationResult, ProgramError>,
+    collection_validate_fp: fn(
+        &CollectionV1,
+        &AccountInfo,
+        Option<&Plugin>,
+    ) -> Result<ValidationResult, ProgramError>,
+    plugin_validate_fp: fn(
+        &Plugin,
+        &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError>,
+) -> Result<
+    (
+        AssetHeaderV1,
+        Option<PluginHeaderV1>,
+        Option<PluginRegistryV1>,
+    ),
+    ProgramError,
+> {
+    let (asset_header, plugin_header, plugin_registry, validation) = evaluate_asset_permissions(
+        authority_info,
+        remaining_accounts,
+        asset,
+        collection,
+        new_owner,
+        new_plugin,
+        update_info,
+        lifecycle_event,
+        asset_check_fp,
+        collection_check_fp,
+        plugin_check_fp,
+        asset_validate_fp,
+        collection_validate_fp,
+        plugin_validate_fp,
+    )?;
+
+    match validation.result {
+        ValidationResult::Rejected => Err(rejection_error(validation.deciding_plugin)),
+        ValidationResult::Pass => Err(MplCoreError::NoApprovals.into()),
+        ValidationResult::Approved | ValidationResult::ForceApproved => {
+            Ok((asset_header, plugin_header, plugin_registry))
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+/// Run the lifecycle validations of `validate_asset_permissions` and report the outcome along
+/// with the plugin that decided it, instead of failing on a rejection or a lack of approvals.
+pub(crate) fn evaluate_asset_permissions<'a>(
+    authority_info: &'a AccountInfo<'a>,
+    remaining_accounts: &[AccountInfo<'a>],
     asset: &AccountInfo<'a>,
     collection: Option<&AccountInfo<'a>>,
     new_owner: Option<&'a AccountInfo<'a>>,
     new_plugin: Option<&Plugin>,
+    update_info: Option<&UpdateInfo>,
+    lifecycle_event: Option<HookableLifecycleEvent>,
     asset_check_fp: fn() -> CheckResult,
     collection_check_fp: fn() -> CheckResult,
     plugin_check_fp: fn(&PluginType) -> CheckResult,
     asset_validate_fp: fn(
-        &AssetV1,
+        &AssetHeaderV1,
         &AccountInfo,
         Option<&Plugin>,
     ) -> Result<ValidationResult, ProgramError>,
@@ -217,13 +401,24 @@ pub(crate) fn validate_asset_permissions<'a>(
         &Plugin,
         &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError>,
-) -> Result<(AssetV1, Option<PluginHeaderV1>, Option<PluginRegistryV1>), ProgramError> {
-    let (deserialized_asset, plugin_header, plugin_registry) = fetch_core_data::<AssetV1>(asset)?;
-    let resolved_authorities =
-        resolve_pubkey_to_authorities(authority_info, collection, &deserialized_asset)?;
+) -> Result<
+    (
+        AssetHeaderV1,
+        Option<PluginHeaderV1>,
+        Option<PluginRegistryV1>,
+        LifecycleValidation,
+    ),
+    ProgramError,
+> {
+    // Only the owner and update authority are needed to validate, so the name and uri of the
+    // asset are skipped over rather than deserialized.
+    let asset_header = AssetV1::load_header(asset)?;
+    let (plugin_header, plugin_registry) = load_plugin_data(asset, asset_header.size)?;
+    let mut resolved_authorities =
+        resolve_pubkey_to_authorities(authority_info, collection, &asset_header)?;
 
     // If the asset is part of a collection, the collection must be passed in and it must be correct.
-    if let UpdateAuthority::Collection(collection_address) = deserialized_asset.update_authority {
+    if let UpdateAuthority::Collection(collection_address) = asset_header.update_authority {
         if collection.is_none() {
             return Err(MplCoreError::MissingCollection.into());
         } else if collection.unwrap().key != &collection_address {
@@ -233,7 +428,25 @@ pub(crate) fn validate_asset_permissions<'a>(
         return Err(MplCoreError::InvalidCollection.into());
     }
 
-    let mut checks: BTreeMap<PluginType, (Key, CheckResult, RegistryRecord)> = BTreeMap::new();
+    resolved_authorities.extend(resolve_multisig_authority::<AssetV1>(
+        asset,
+        authority_info,
+        remaining_accounts,
+    ));
+    if let Some(collection_info) = collection {
+        resolved_authorities.extend(resolve_multisig_authority::<CollectionV1>(
+            collection_info,
+            authority_info,
+            remaining_accounts,
+        ));
+    }
+
+    // The collection registry is loaded up front, since the checks borrow its records.
+    let collection_registry = collection
+        .map(|collection_info| fetch_core_registry::<CollectionV1>(collection_info))
+        .transpose()?
+        .and_then(|(_, registry)| registry);
+    let mut checks = PluginChecks::new();
 
     // The asset approval overrides the collection approval.
     let asset_check = asset_check_fp();
@@ -244,13 +457,8 @@ pub(crate) fn validate_asset_permissions<'a>(
     };
 
     // Check the collection plugins first.
-    if let Some(collection_info) = collection {
-        fetch_core_data::<CollectionV1>(collection_info).map(|(_, _, registry)| {
-            registry.map(|r| {
-                r.check_registry(Key::CollectionV1, plugin_check_fp, &mut checks);
-                r
-            })
-        })?;
+    if let Some(registry) = collection_registry.as_ref() {
+        registry.check_registry(Key::CollectionV1, plugin_check_fp, &mut checks);
     }
 
     // Next check the asset plugins. Plugins on the asset override the collection plugins,
@@ -259,16 +467,29 @@ pub(crate) fn validate_asset_permissions<'a>(
         registry.check_registry(Key::AssetV1, plugin_check_fp, &mut checks);
     }
 
-    // Do the core validation.
-    let mut approved = false;
-    let mut rejected = false;
+    // Do the core validation. The first plugin to reject or approve is reported as deciding.
+    let mut approved: Option<Option<PluginType>> = None;
+    let mut rejected: Option<Option<PluginType>> = None;
+    let force_approved = |deciding_plugin| LifecycleValidation {
+        result: ValidationResult::ForceApproved,
+        deciding_plugin,
+    };
     if asset_check != CheckResult::None {
-        match asset_validate_fp(&deserialized_asset, authority_info, new_plugin)? {
-            ValidationResult::Approved => approved = true,
-            ValidationResult::Rejected => rejected = true,
+        match asset_validate_fp(&asset_header, authority_info, new_plugin)? {
+            ValidationResult::Approved => {
+                approved.get_or_insert(None);
+            }
+            ValidationResult::Rejected => {
+                rejected.get_or_insert(None);
+            }
             ValidationResult::Pass => (),
             ValidationResult::ForceApproved => {
-                return Ok((deserialized_asset, plugin_header, plugin_registry))
+                return Ok((
+                    asset_header,
+                    plugin_header,
+                    plugin_registry,
+                    force_approved(None),
+                ))
             }
         }
     };
@@ -279,68 +500,309 @@ pub(crate) fn validate_asset_permissions<'a>(
             authority_info,
             new_plugin,
         )? {
-            ValidationResult::Approved => approved = true,
-            ValidationResult::Rejected => rejected = true,
+            ValidationResult::Approved => {
+                approved.get_or_insert(None);
+            }
+            ValidationResult::Rejected => {
+                rejected.get_or_insert(None);
+            }
             ValidationResult::Pass => (),
             ValidationResult::ForceApproved => {
-                return Ok((deserialized_asset, plugin_header, plugin_registry))
+                return Ok((
+                    asset_header,
+                    plugin_header,
+                    plugin_registry,
+                    force_approved(None),
+                ))
             }
         }
     };
 
-    match validate_plugin_checks(
-        Key::CollectionV1,
-        &checks,
-        authority_info,
-        new_owner,
-        new_plugin,
-        Some(asset),
-        collection,
-        &resolved_authorities,
-        plugin_validate_fp,
-    )? {
-        ValidationResult::Approved => approved = true,
-        ValidationResult::Rejected => rejected = true,
-        ValidationResult::Pass => (),
-        ValidationResult::ForceApproved => {
-            return Ok((deserialized_asset, plugin_header, plugin_registry))
+    for key in [Key::CollectionV1, Key::AssetV1] {
+        let validation = evaluate_plugin_checks(
+            key,
+            &checks,
+            authority_info,
+            new_owner,
+            new_plugin,
+            update_info,
+            Some(asset),
+            collection,
+            &resolved_authorities,
+            plugin_validate_fp,
+        )?;
+        match validation.result {
+            ValidationResult::Approved => {
+                approved.get_or_insert(validation.deciding_plugin);
+            }
+            ValidationResult::Rejected => {
+                rejected.get_or_insert(validation.deciding_plugin);
+            }
+            ValidationResult::Pass => (),
+            ValidationResult::ForceApproved => {
+                return Ok((
+                    asset_header,
+                    plugin_header,
+                    plugin_registry,
+                    force_approved(validation.deciding_plugin),
+                ))
+            }
+        }
+    }
+
+    if let Some(lifecycle_event) = lifecycle_event {
+        match invoke_lifecycle_hooks(
+            lifecycle_event,
+            authority_info,
+            asset,
+            collection,
+            new_owner,
+            remaining_accounts,
+        )? {
+            ValidationResult::Approved => {
+                approved.get_or_insert(None);
+            }
+            ValidationResult::Rejected => {
+                rejected.get_or_insert(None);
+            }
+            ValidationResult::Pass | ValidationResult::ForceApproved => (),
         }
+    }
+
+    let (result, deciding_plugin) = match (rejected, approved) {
+        (Some(deciding_plugin), _) => (ValidationResult::Rejected, deciding_plugin),
+        (None, Some(deciding_plugin)) => (ValidationResult::Approved, deciding_plugin),
+        (None, None) => (ValidationResult::Pass, None),
     };
 
-    match validate_plugin_checks(
-        Key::AssetV1,
+    Ok((
+        asset_header,
+        plugin_header,
+        plugin_registry,
+        LifecycleValidation {
+            result,
+            deciding_plugin,
+        },
+    ))
+}
+
+/// Invoke the hooked programs of the `LifecycleHook` external plugins of the asset and its
+/// collection that are registered for a lifecycle event.  Any rejection rejects the event.
+pub(crate) fn invoke_lifecycle_hooks<'a>(
+    lifecycle_event: HookableLifecycleEvent,
+    authority_info: &AccountInfo<'a>,
+    asset: &AccountInfo<'a>,
+    collection: Option<&AccountInfo<'a>>,
+    new_owner: Option<&AccountInfo<'a>>,
+    remaining_accounts: &[AccountInfo<'a>],
+) -> Result<ValidationResult, ProgramError> {
+    // A compressed asset has no plugins in account space.
+    let mut registries = vec![];
+    if load_key(asset, 0)? == Key::AssetV1 {
+        registries.push((asset, fetch_core_registry::<AssetV1>(asset)?.1));
+    }
+    if let Some(collection_info) = collection {
+        registries.push((
+            collection_info,
+            fetch_core_registry::<CollectionV1>(collection_info)?.1,
+        ));
+    }
+
+    let mut hooks = vec![];
+    for (core_info, plugin_registry) in registries {
+        for record in plugin_registry
+            .iter()
+            .flat_map(|registry| registry.external_plugins.iter())
+            .filter(|record| record.plugin_type == ExternalPluginType::LifecycleHook)
+        {
+            hooks.push(LifecycleHook::try_from_slice(
+                &record.load_data(core_info)?,
+            )?);
+        }
+    }
+
+    let args = LifecycleHookArgs {
+        event: lifecycle_event,
+        authority: *authority_info.key,
+        new_owner: new_owner.map(|new_owner| *new_owner.key),
+    };
+
+    let mut result = ValidationResult::Pass;
+    for hook in hooks
+        .iter()
+        .filter(|hook| hook.events.contains(&lifecycle_event))
+    {
+        let hooked_program_info = remaining_accounts
+            .iter()
+            .find(|account_info| account_info.key == &hook.hooked_program)
+            .ok_or(MplCoreError::MissingHookedProgram)?;
+        match hook.invoke(hooked_program_info, asset, collection, &args)? {
+            ValidationResult::Rejected => return Ok(ValidationResult::Rejected),
+            ValidationResult::Approved => result = ValidationResult::Approved,
+            ValidationResult::Pass | ValidationResult::ForceApproved => (),
+        }
+    }
+
+    Ok(result)
+}
+
+/// Validate a lifecycle event of a compressed asset.  Its plugins are read from the verified
+/// compression proof instead of account space, and override the plugins of its collection.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(crate) fn validate_compressed_asset_permissions<'a>(
+    authority_info: &'a AccountInfo<'a>,
+    remaining_accounts: &[AccountInfo<'a>],
+    asset_info: &AccountInfo<'a>,
+    asset: &AssetV1,
+    plugins: &[HashablePluginSchema],
+    collection: Option<&AccountInfo<'a>>,
+    new_owner: Option<&'a AccountInfo<'a>>,
+    lifecycle_event: Option<HookableLifecycleEvent>,
+    asset_check_fp: fn() -> CheckResult,
+    collection_check_fp: fn() -> CheckResult,
+    plugin_check_fp: fn(&PluginType) -> CheckResult,
+    asset_validate_fp: fn(
+        &AssetHeaderV1,
+        &AccountInfo,
+        Option<&Plugin>,
+    ) -> Result<ValidationResult, ProgramError>,
+    collection_validate_fp: fn(
+        &CollectionV1,
+        &AccountInfo,
+        Option<&Plugin>,
+    ) -> Result<ValidationResult, ProgramError>,
+    plugin_validate_fp: fn(
+        &Plugin,
+        &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError>,
+) -> ProgramResult {
+    let asset_header = asset.header();
+    let mut resolved_authorities =
+        resolve_pubkey_to_authorities(authority_info, collection, &asset_header)?;
+
+    // If the asset is part of a collection, the collection must be passed in and it must be correct.
+    match (&asset.update_authority, collection) {
+        (UpdateAuthority::Collection(_), None) => {
+            return Err(MplCoreError::MissingCollection.into());
+        }
+        (UpdateAuthority::Collection(collection_address), Some(collection_info))
+            if collection_info.key != collection_address =>
+        {
+            return Err(MplCoreError::InvalidCollection.into());
+        }
+        (UpdateAuthority::Collection(_), _) | (_, None) => (),
+        (_, Some(_)) => return Err(MplCoreError::InvalidCollection.into()),
+    }
+
+    let mut collection_registry = None;
+    if let Some(collection_info) = collection {
+        resolved_authorities.extend(resolve_multisig_authority::<CollectionV1>(
+            collection_info,
+            authority_info,
+            remaining_accounts,
+        ));
+
+        collection_registry = fetch_core_registry::<CollectionV1>(collection_info)?.1;
+    }
+
+    let mut checks = PluginChecks::new();
+    if let Some(registry) = collection_registry.as_ref() {
+        registry.check_registry(Key::CollectionV1, plugin_check_fp, &mut checks);
+    }
+
+    // Plugins on the asset override the collection plugins.
+    for plugin in plugins {
+        checks.remove(PluginType::from(&plugin.plugin));
+    }
+
+    let mut results = vec![];
+    if asset_check_fp() != CheckResult::None {
+        results.push(asset_validate_fp(&asset_header, authority_info, None)?);
+    }
+
+    if let (Some(collection_info), true) = (collection, collection_check_fp() != CheckResult::None)
+    {
+        results.push(collection_validate_fp(
+            &CollectionV1::load(collection_info, 0)?,
+            authority_info,
+            None,
+        )?);
+    }
+
+    // The first plugin to reject is reported in the error.
+    let mut rejecting_plugin = None;
+    let validation = evaluate_plugin_checks(
+        Key::CollectionV1,
         &checks,
         authority_info,
         new_owner,
-        new_plugin,
-        Some(asset),
+        None,
+        None,
+        None,
         collection,
         &resolved_authorities,
         plugin_validate_fp,
-    )? {
-        ValidationResult::Approved => approved = true,
-        ValidationResult::Rejected => rejected = true,
-        ValidationResult::Pass => (),
-        ValidationResult::ForceApproved => {
-            return Ok((deserialized_asset, plugin_header, plugin_registry))
+    )?;
+    if validation.result == ValidationResult::Rejected {
+        rejecting_plugin = validation.deciding_plugin;
+    }
+    results.push(validation.result);
+
+    for plugin in plugins.iter().filter(|plugin| {
+        matches!(
+            plugin_check_fp(&PluginType::from(&plugin.plugin)),
+            CheckResult::CanApprove | CheckResult::CanReject
+        )
+    }) {
+        // Expired authorities fall back to the plugin's manager.
+        let self_authority = plugin.authority.active(plugin.plugin.manager())?;
+        let ctx = PluginValidationContext {
+            self_authority: &self_authority,
+            authority_info,
+            resolved_authorities: Some(&resolved_authorities),
+            new_owner,
+            target_plugin: None,
+            update_info: None,
+        };
+        let result = plugin_validate_fp(&plugin.plugin, &ctx)?;
+        if result == ValidationResult::Rejected {
+            rejecting_plugin.get_or_insert(PluginType::from(&plugin.plugin));
         }
-    };
+        results.push(result);
+    }
 
-    if rejected {
-        return Err(MplCoreError::InvalidAuthority.into());
-    } else if !approved {
-        return Err(MplCoreError::NoApprovals.into());
+    if results.contains(&ValidationResult::ForceApproved) {
+        return Ok(());
     }
 
-    Ok((deserialized_asset, plugin_header, plugin_registry))
+    if let Some(lifecycle_event) = lifecycle_event {
+        results.push(invoke_lifecycle_hooks(
+            lifecycle_event,
+            authority_info,
+            asset_info,
+            collection,
+            new_owner,
+            remaining_accounts,
+        )?);
+    }
+
+    if results.contains(&ValidationResult::Rejected) {
+        Err(rejection_error(rejecting_plugin))
+    } else if results.contains(&ValidationResult::Approved) {
+        Ok(())
+    } else {
+        Err(MplCoreError::NoApprovals.into())
+    }
 }
 
 /// Validate collection permissions using lifecycle validations for collection and plugins.
-#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub(crate) fn validate_collection_permissions<'a>(
     authority_info: &'a AccountInfo<'a>,
+    signers: &[AccountInfo],
     collection: &AccountInfo<'a>,
     new_plugin: Option<&Plugin>,
+    update_info: Option<&UpdateInfo>,
     collection_check_fp: fn() -> CheckResult,
     plugin_check_fp: fn(&PluginType) -> CheckResult,
     collection_validate_fp: fn(
@@ -362,9 +824,14 @@ pub(crate) fn validate_collection_permissions<'a>(
 > {
     let (deserialized_collection, plugin_header, plugin_registry) =
         fetch_core_data::<CollectionV1>(collection)?;
-    let resolved_authorities =
+    let mut resolved_authorities =
         resolve_pubkey_to_authorities_collection(authority_info, collection)?;
-    let mut checks: BTreeMap<PluginType, (Key, CheckResult, RegistryRecord)> = BTreeMap::new();
+    resolved_authorities.extend(resolve_multisig_authority::<CollectionV1>(
+        collection,
+        authority_info,
+        signers,
+    ));
+    let mut checks = PluginChecks::new();
 
     let core_check = (Key::CollectionV1, collection_check_fp());
 
@@ -399,19 +866,21 @@ pub(crate) fn validate_collection_permissions<'a>(
         }
     };
 
-    match validate_plugin_checks(
+    let validation = evaluate_plugin_checks(
         Key::CollectionV1,
         &checks,
         authority_info,
         None,
         new_plugin,
+        update_info,
         None,
         Some(collection),
         &resolved_authorities,
         plugin_validate_fp,
-    )? {
+    )?;
+    match validation.result {
         ValidationResult::Approved => approved = true,
-        ValidationResult::Rejected => rejected = true,
+        ValidationResult::Rejected => return Err(rejection_error(validation.deciding_plugin)),
         ValidationResult::Pass => (),
         ValidationResult::ForceApproved => {
             return Ok((deserialized_collection, plugin_header, plugin_registry))
@@ -425,10 +894,25 @@ pub(crate) fn validate_collection_permissions<'a>(
     Ok((deserialized_collection, plugin_header, plugin_registry))
 }
 
+/// The error of a rejected lifecycle event: `PluginRejected`, if a plugin rejected it, or
+/// `InvalidAuthority` if the asset, the collection or a lifecycle hook did.  The rejecting plugin
+/// type is set as the return data, so that clients can identify it without parsing the logs.
+pub(crate) fn rejection_error(rejecting_plugin: Option<PluginType>) -> ProgramError {
+    match rejecting_plugin {
+        Some(plugin_type) => {
+            msg!("Error: The {:?} plugin rejected the operation", plugin_type);
+            // The Borsh encoding of the plugin type is its discriminant.
+            set_return_data(&[plugin_type as u8]);
+            MplCoreError::PluginRejected.into()
+        }
+        None => MplCoreError::InvalidAuthority.into(),
+    }
+}
+
 /// Take an `Asset` and Vec of `HashablePluginSchema` and rebuild the asset in account space.
 pub fn rebuild_account_state_from_proof_data<'a>(
-    asset: AssetV1,
-    plugins: Vec<HashablePluginSchema>,
+    asset: &AssetV1,
+    plugins: &[HashablePluginSchema],
     asset_info: &AccountInfo<'a>,
     payer: &AccountInfo<'a>,
     system_program: &AccountInfo<'a>,
@@ -484,19 +968,27 @@ pub fn compress_into_account_space<'a>(
         // It should already be sorted but we just want to make sure.
         registry_records.sort_by(RegistryRecord::compare_offsets);
 
+        let data = asset_info.data.borrow();
         for (i, record) in registry_records.into_iter().enumerate() {
-            let plugin = Plugin::deserialize(&mut &(*asset_info.data).borrow()[record.offset..])?;
-
-            let hashable_plugin_schema = HashablePluginSchema {
+            // The plugin is hashed from the bytes it was deserialized from.
+            let plugin_data = data
+                .get(record.offset..)
+                .ok_or(MplCoreError::InvalidPlugin)?;
+            let mut remaining = plugin_data;
+            let plugin = Plugin::deserialize(&mut remaining)?;
+            let plugin_size = plugin_data.len() - remaining.len();
+
+            plugin_hashes.push(HashablePluginSchema::hash_serialized(
+                i,
+                &record.authority,
+                &plugin_data[..plugin_size],
+            )?);
+
+            compression_proof.plugins.push(HashablePluginSchema {
                 index: i,
                 authority: record.authority,
                 plugin,
-            };
-
-            let plugin_hash = hashable_plugin_schema.hash()?;
-            plugin_hashes.push(plugin_hash);
-
-            compression_proof.plugins.push(hashable_plugin_schema);
+            });
         }
     }
 
@@ -522,10 +1014,10 @@ pub fn compress_into_account_space<'a>(
 pub(crate) fn resolve_pubkey_to_authorities(
     authority_info: &AccountInfo,
     maybe_collection_info: Option<&AccountInfo>,
-    asset: &AssetV1,
+    asset: &AssetHeaderV1,
 ) -> Result<Vec<Authority>, ProgramError> {
     let mut authorities = Vec::with_capacity(3);
-    if authority_info.key == &asset.owner {
+    if cmp_pubkeys(authority_info.key, &asset.owner) {
         authorities.push(Authority::Owner);
     }
 
@@ -538,7 +1030,7 @@ pub(crate) fn resolve_pubkey_to_authorities(
                     return Err(MplCoreError::InvalidCollection.into());
                 }
                 let collection: CollectionV1 = CollectionV1::load(collection_info, 0)?;
-                if authority_info.key == &collection.update_authority {
+                if cmp_pubkeys(authority_info.key, &collection.update_authority) {
                     authorities.push(Authority::UpdateAuthority);
                 }
             }
@@ -559,11 +1051,11 @@ pub(crate) fn resolve_pubkey_to_authorities_collection(
 ) -> Result<Vec<Authority>, ProgramError> {
     let collection: CollectionV1 = CollectionV1::load(collection_info, 0)?;
     let mut authorities = Vec::with_capacity(3);
-    if authority_info.key == collection.owner() {
+    if cmp_pubkeys(authority_info.key, collection.owner()) {
         authorities.push(Authority::Owner);
     }
 
-    if authority_info.key == &collection.update_authority {
+    if cmp_pubkeys(authority_info.key, &collection.update_authority) {
         authorities.push(Authority::UpdateAuthority)
     }
 
@@ -587,3 +1079,184 @@ pub(crate) fn resolve_authority<'a>(
         None => Ok(payer),
     }
 }
+
+/// Resolve the multisig of an asset or collection to its authority if enough of its signers
+/// have signed the transaction.
+pub(crate) fn resolve_multisig_authority<T: DataBlob + SolanaAccount>(
+    core_info: &AccountInfo,
+    authority_info: &AccountInfo,
+    signers: &[AccountInfo],
+) -> Option<Authority> {
+    match fetch_plugin::<T, Multisig>(core_info, PluginType::Multisig) {
+        Ok((_, multisig, _)) if multisig.is_signed(authority_info, signers) => {
+            Some(Authority::Address {
+                address: find_multisig_address(core_info.key).0,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Check whether an account is a token account.  Only token accounts passed to a transfer are
+/// treated as dependent accounts, so hooked programs and other extra accounts are left alone.
+pub(crate) fn is_token_account(account_info: &AccountInfo) -> bool {
+    account_info.owner == &SPL_TOKEN_PROGRAM_ID || account_info.owner == &SPL_TOKEN_2022_PROGRAM_ID
+}
+
+/// Validate that a dependent account, such as a token vault or escrow, is a token account held
+/// by the asset signer so that control of it moves with the asset.
+pub(crate) fn validate_dependent_account(
+    account_info: &AccountInfo,
+    asset_signer: &Pubkey,
+) -> ProgramResult {
+    if !is_token_account(account_info) {
+        return Err(MplCoreError::InvalidDependentAccount.into());
+    }
+
+    // The owner of a token account is stored after the mint.
+    let data = account_info.data.borrow();
+    match data.get(32..64) {
+        Some(owner) if owner == asset_signer.as_ref() => Ok(()),
+        _ => Err(MplCoreError::InvalidDependentAccount.into()),
+    }
+}
+
+/// Reject the transaction if it invokes a program blocked by the `TxGuard` plugin of the asset or
+/// its collection.  The instructions sysvar must be passed in the remaining accounts.
+pub(crate) fn validate_tx_guard(
+    asset_info: &AccountInfo,
+    collection_info: Option<&AccountInfo>,
+    remaining_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let tx_guards = [
+        fetch_plugin::<AssetV1, TxGuard>(asset_info, PluginType::TxGuard).ok(),
+        collection_info.and_then(|collection_info| {
+            fetch_plugin::<CollectionV1, TxGuard>(collection_info, PluginType::TxGuard).ok()
+        }),
+    ];
+
+    for (_, tx_guard, _) in tx_guards.into_iter().flatten() {
+        let instructions_sysvar = remaining_accounts
+            .iter()
+            .find(|account_info| account_info.key == &sysvar::instructions::ID)
+            .ok_or(MplCoreError::MissingInstructionsSysvar)?;
+        tx_guard.check_transaction(instructions_sysvar)?;
+    }
+
+    Ok(())
+}
+
+/// Report the outcome of each item of a batch instruction.  The statuses, in batch order, are
+/// set as return data, and each item is emitted as an event if the log wrapper is passed.
+pub(crate) fn emit_batch_results(
+    results: &[BatchItemResult],
+    log_wrapper: Option<&AccountInfo>,
+) -> ProgramResult {
+    if log_wrapper.is_some() {
+        for result in results {
+            result.wrap()?;
+        }
+    }
+
+    let statuses: Vec<BatchItemStatus> = results.iter().map(|result| result.status).collect();
+    set_return_data(&statuses.try_to_vec()?);
+
+    Ok(())
+}
+
+/// Split the fee config off the end of the remaining accounts of a creation, if it was passed.
+pub(crate) fn split_fee_config<'b, 'a>(
+    remaining_accounts: &'b [AccountInfo<'a>],
+) -> (Option<&'b AccountInfo<'a>>, &'b [AccountInfo<'a>]) {
+    let (fee_config_address, _) = find_fee_config_address();
+    match remaining_accounts.split_last() {
+        Some((last, rest)) if last.key == &fee_config_address => (Some(last), rest),
+        _ => (None, remaining_accounts),
+    }
+}
+
+/// Load the fee config, checking that it is the initialized fee config of the program.
+pub(crate) fn fetch_fee_config(fee_config_info: &AccountInfo) -> Result<FeeConfigV1, ProgramError> {
+    if fee_config_info.owner != &crate::ID || fee_config_info.key != &find_fee_config_address().0 {
+        return Err(MplCoreError::InvalidFeeConfig.into());
+    }
+
+    FeeConfigV1::load(fee_config_info, 0)
+}
+
+/// Pay a protocol fee from the payer into the fee config.
+pub(crate) fn pay_fee<'a>(
+    payer: &AccountInfo<'a>,
+    fee_config_info: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    fee: u64,
+) -> ProgramResult {
+    if fee == 0 {
+        return Ok(());
+    }
+
+    invoke(
+        &system_instruction::transfer(payer.key, fee_config_info.key, fee),
+        &[
+            payer.clone(),
+            fee_config_info.clone(),
+            system_program.clone(),
+        ],
+    )
+}
+
+/// Transfer the rent contributions of the funders to the payer before an instruction is processed.
+/// Returns the lamports of the funded account before the instruction, for use with
+/// `refund_rent_contributions`.
+pub(crate) fn collect_rent_contributions<'a>(
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    funded_account: &AccountInfo<'a>,
+    funders: &[AccountInfo<'a>],
+    contributions: &[u64],
+) -> Result<u64, ProgramError> {
+    if funders.len() != contributions.len() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let starting_lamports = funded_account.lamports();
+    for (funder, contribution) in funders.iter().zip(contributions) {
+        assert_signer(funder)?;
+        invoke(
+            &system_instruction::transfer(funder.key, payer.key, *contribution),
+            &[funder.clone(), payer.clone(), system_program.clone()],
+        )?;
+    }
+
+    Ok(starting_lamports)
+}
+
+/// Return the part of each rent contribution that was not spent on the rent of the funded account.
+/// The rent is taken from the contributions in order, so the first funders are spent first.  Fees
+/// and subsidies received by the payer are not rent, and are never refunded to the funders.
+pub(crate) fn refund_rent_contributions<'a>(
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    funded_account: &AccountInfo<'a>,
+    funders: &[AccountInfo<'a>],
+    contributions: &[u64],
+    starting_lamports: u64,
+) -> ProgramResult {
+    let mut rent_spent = Rent::get()?
+        .minimum_balance(funded_account.data_len())
+        .saturating_sub(starting_lamports);
+    for (funder, contribution) in funders.iter().zip(contributions) {
+        let spent = rent_spent.min(*contribution);
+        rent_spent -= spent;
+
+        let refund = contribution - spent;
+        if refund > 0 {
+            invoke(
+                &system_instruction::transfer(payer.key, funder.key, refund),
+                &[payer.clone(), funder.clone(), system_program.clone()],
+            )?;
+        }
+    }
+
+    Ok(())
+}